@@ -0,0 +1,35 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use anyhow::{bail, Result};
+use common_arrow::arrow;
+
+use crate::DataBlock;
+
+/// Concatenate the blocks with the same schema into one block.
+pub fn block_concat(blocks: &[DataBlock]) -> Result<DataBlock> {
+    if blocks.is_empty() {
+        bail!("DataBlock Error: Can't concat empty blocks");
+    }
+
+    let first_block = &blocks[0];
+    for block in blocks.iter() {
+        if block.schema().ne(first_block.schema()) {
+            bail!("DataBlock Error: Schema not matched");
+        }
+    }
+
+    let mut concat_columns = Vec::with_capacity(first_block.num_columns());
+    for i in 0..first_block.num_columns() {
+        let columns = blocks
+            .iter()
+            .map(|block| block.column(i).as_ref())
+            .collect::<Vec<_>>();
+        concat_columns.push(arrow::compute::concat(&columns)?);
+    }
+    Ok(DataBlock::create(
+        first_block.schema().clone(),
+        concat_columns,
+    ))
+}
@@ -0,0 +1,40 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+#[test]
+fn test_data_block_concat() -> anyhow::Result<()> {
+    use std::sync::Arc;
+
+    use common_datavalues::*;
+    use pretty_assertions::assert_eq;
+
+    use crate::*;
+
+    let schema = Arc::new(DataSchema::new(vec![DataField::new(
+        "a",
+        DataType::Int64,
+        false,
+    )]));
+
+    let blocks = vec![
+        DataBlock::create(schema.clone(), vec![Arc::new(Int64Array::from(vec![1, 2]))]),
+        DataBlock::create(schema.clone(), vec![Arc::new(Int64Array::from(vec![3]))]),
+    ];
+    let block = block_concat(&blocks)?;
+    assert_eq!(&schema, block.schema());
+
+    let a = block
+        .column(0)
+        .as_any()
+        .downcast_ref::<Int64Array>()
+        .unwrap();
+    assert_eq!(a.clone(), Int64Array::from(vec![1, 2, 3]));
+
+    let result = block_concat(&[]);
+    assert_eq!(
+        "DataBlock Error: Can't concat empty blocks",
+        format!("{}", result.err().unwrap())
+    );
+    Ok(())
+}
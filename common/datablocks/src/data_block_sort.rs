@@ -0,0 +1,184 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+use anyhow::{bail, Result};
+use common_arrow::arrow;
+use common_datavalues::DataArrayRef;
+
+use crate::{block_concat, block_take_by_indices, DataBlock};
+
+#[derive(Clone, Debug)]
+pub struct SortColumnDescription {
+    pub column_name: String,
+    pub asc: bool,
+    pub nulls_first: bool,
+}
+
+/// Compare two rows of the sort columns, the rows are indexed in the same arrays.
+fn compare_rows<F: Fn(usize, usize) -> Ordering>(
+    columns: &[DataArrayRef],
+    comparators: &[F],
+    sort_columns_descriptions: &[SortColumnDescription],
+    left: usize,
+    right: usize,
+) -> Ordering {
+    for (i, description) in sort_columns_descriptions.iter().enumerate() {
+        let column = &columns[i];
+        let order = match (column.is_null(left), column.is_null(right)) {
+            (true, true) => Ordering::Equal,
+            (true, false) if description.nulls_first => Ordering::Less,
+            (true, false) => Ordering::Greater,
+            (false, true) if description.nulls_first => Ordering::Greater,
+            (false, true) => Ordering::Less,
+            (false, false) if description.asc => (comparators[i])(left, right),
+            (false, false) => (comparators[i])(left, right).reverse(),
+        };
+
+        if order != Ordering::Equal {
+            return order;
+        }
+    }
+    Ordering::Equal
+}
+
+fn sort_columns(
+    block: &DataBlock,
+    sort_columns_descriptions: &[SortColumnDescription],
+) -> Result<Vec<DataArrayRef>> {
+    if sort_columns_descriptions.is_empty() {
+        bail!("DataBlock Error: Sort columns can't be empty");
+    }
+
+    sort_columns_descriptions
+        .iter()
+        .map(|description| Ok(block.column_by_name(&description.column_name)?.clone()))
+        .collect()
+}
+
+/// Sort the block by the sort columns, only the first `limit` rows are kept if the limit is set.
+pub fn block_sort(
+    block: &DataBlock,
+    sort_columns_descriptions: &[SortColumnDescription],
+    limit: Option<usize>,
+) -> Result<DataBlock> {
+    let columns = sort_columns(block, sort_columns_descriptions)?;
+    let comparators = columns
+        .iter()
+        .map(|column| arrow::array::build_compare(column.as_ref(), column.as_ref()))
+        .collect::<arrow::error::Result<Vec<_>>>()?;
+
+    let compare = |left: &u32, right: &u32| {
+        compare_rows(
+            &columns,
+            &comparators,
+            sort_columns_descriptions,
+            *left as usize,
+            *right as usize,
+        )
+    };
+
+    let mut indices = (0..block.num_rows() as u32).collect::<Vec<_>>();
+    match limit {
+        // Top-N: partition the first N rows out and sort them only.
+        Some(limit) if limit < indices.len() => {
+            if limit > 0 {
+                indices.select_nth_unstable_by(limit - 1, compare);
+            }
+            indices.truncate(limit);
+            indices.sort_by(compare);
+        }
+        _ => indices.sort_by(compare),
+    }
+    block_take_by_indices(block, &indices)
+}
+
+/// K-way merge the blocks which have already been sorted by the sort columns, the heads of the
+/// blocks are kept in a binary heap.
+pub fn block_merge_sort(
+    blocks: &[DataBlock],
+    sort_columns_descriptions: &[SortColumnDescription],
+    limit: Option<usize>,
+) -> Result<DataBlock> {
+    let block = block_concat(blocks)?;
+    let columns = sort_columns(&block, sort_columns_descriptions)?;
+    let comparators = columns
+        .iter()
+        .map(|column| arrow::array::build_compare(column.as_ref(), column.as_ref()))
+        .collect::<arrow::error::Result<Vec<_>>>()?;
+    let compare = |left: usize, right: usize| {
+        compare_rows(
+            &columns,
+            &comparators,
+            sort_columns_descriptions,
+            left,
+            right,
+        )
+    };
+
+    // The cursor [start, end) of every sorted block in the concatenated block.
+    let mut heap = BinaryHeap::with_capacity(blocks.len());
+    let mut offset = 0;
+    for block in blocks {
+        let rows = block.num_rows();
+        if rows > 0 {
+            heap.push(MergeCursor {
+                start: offset,
+                end: offset + rows,
+                compare: &compare,
+            });
+        }
+        offset += rows;
+    }
+
+    let rows = match limit {
+        Some(limit) => std::cmp::min(limit, block.num_rows()),
+        None => block.num_rows(),
+    };
+    let mut indices = Vec::with_capacity(rows);
+    while indices.len() < rows {
+        let mut cursor = match heap.pop() {
+            Some(cursor) => cursor,
+            None => break,
+        };
+        indices.push(cursor.start as u32);
+        cursor.start += 1;
+        if cursor.start < cursor.end {
+            heap.push(cursor);
+        }
+    }
+    block_take_by_indices(&block, &indices)
+}
+
+struct MergeCursor<'a, F: Fn(usize, usize) -> Ordering> {
+    start: usize,
+    end: usize,
+    compare: &'a F,
+}
+
+impl<'a, F: Fn(usize, usize) -> Ordering> Ord for MergeCursor<'a, F> {
+    // Reversed, the max heap pops the cursor of the least row first, the earlier block wins
+    // the ties to keep the merge stable.
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.compare)(self.start, other.start)
+            .then(self.start.cmp(&other.start))
+            .reverse()
+    }
+}
+
+impl<'a, F: Fn(usize, usize) -> Ordering> PartialOrd for MergeCursor<'a, F> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a, F: Fn(usize, usize) -> Ordering> PartialEq for MergeCursor<'a, F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<'a, F: Fn(usize, usize) -> Ordering> Eq for MergeCursor<'a, F> {}
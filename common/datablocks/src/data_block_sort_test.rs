@@ -0,0 +1,140 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+#[test]
+fn test_data_block_sort() -> anyhow::Result<()> {
+    use std::sync::Arc;
+
+    use common_datavalues::*;
+    use pretty_assertions::assert_eq;
+
+    use crate::*;
+
+    let schema = Arc::new(DataSchema::new(vec![
+        DataField::new("a", DataType::Int64, false),
+        DataField::new("b", DataType::Utf8, true),
+    ]));
+
+    let raw = DataBlock::create(
+        schema.clone(),
+        vec![
+            Arc::new(Int64Array::from(vec![1, 2, 1, 3])),
+            Arc::new(StringArray::from(vec![
+                Some("x"),
+                None,
+                Some("y"),
+                Some("z"),
+            ])),
+        ],
+    );
+
+    // order by a desc, b asc nulls last
+    let descriptions = vec![
+        SortColumnDescription {
+            column_name: "a".to_string(),
+            asc: false,
+            nulls_first: false,
+        },
+        SortColumnDescription {
+            column_name: "b".to_string(),
+            asc: true,
+            nulls_first: false,
+        },
+    ];
+    let sorted = block_sort(&raw, &descriptions, None)?;
+    let a = sorted
+        .column(0)
+        .as_any()
+        .downcast_ref::<Int64Array>()
+        .unwrap();
+    assert_eq!(a.clone(), Int64Array::from(vec![3, 2, 1, 1]));
+    let b = sorted
+        .column(1)
+        .as_any()
+        .downcast_ref::<StringArray>()
+        .unwrap();
+    assert_eq!(
+        b.clone(),
+        StringArray::from(vec![Some("z"), None, Some("x"), Some("y")])
+    );
+
+    // Top-N.
+    let sorted = block_sort(&raw, &descriptions, Some(2))?;
+    let a = sorted
+        .column(0)
+        .as_any()
+        .downcast_ref::<Int64Array>()
+        .unwrap();
+    assert_eq!(a.clone(), Int64Array::from(vec![3, 2]));
+
+    // order by b asc nulls first
+    let descriptions = vec![SortColumnDescription {
+        column_name: "b".to_string(),
+        asc: true,
+        nulls_first: true,
+    }];
+    let sorted = block_sort(&raw, &descriptions, None)?;
+    let b = sorted
+        .column(1)
+        .as_any()
+        .downcast_ref::<StringArray>()
+        .unwrap();
+    assert_eq!(
+        b.clone(),
+        StringArray::from(vec![None, Some("x"), Some("y"), Some("z")])
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_data_block_merge_sort() -> anyhow::Result<()> {
+    use std::sync::Arc;
+
+    use common_datavalues::*;
+    use pretty_assertions::assert_eq;
+
+    use crate::*;
+
+    let schema = Arc::new(DataSchema::new(vec![DataField::new(
+        "a",
+        DataType::Int64,
+        false,
+    )]));
+
+    let blocks = vec![
+        DataBlock::create(
+            schema.clone(),
+            vec![Arc::new(Int64Array::from(vec![1, 4, 7]))],
+        ),
+        DataBlock::create(schema.clone(), vec![Arc::new(Int64Array::from(vec![2, 3]))]),
+        DataBlock::create(
+            schema.clone(),
+            vec![Arc::new(Int64Array::from(vec![0, 5, 6]))],
+        ),
+    ];
+    let descriptions = vec![SortColumnDescription {
+        column_name: "a".to_string(),
+        asc: true,
+        nulls_first: false,
+    }];
+
+    let merged = block_merge_sort(&blocks, &descriptions, None)?;
+    let a = merged
+        .column(0)
+        .as_any()
+        .downcast_ref::<Int64Array>()
+        .unwrap();
+    assert_eq!(a.clone(), Int64Array::from(vec![0, 1, 2, 3, 4, 5, 6, 7]));
+
+    let merged = block_merge_sort(&blocks, &descriptions, Some(3))?;
+    let a = merged
+        .column(0)
+        .as_any()
+        .downcast_ref::<Int64Array>()
+        .unwrap();
+    assert_eq!(a.clone(), Int64Array::from(vec![0, 1, 2]));
+
+    Ok(())
+}
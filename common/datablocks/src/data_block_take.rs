@@ -0,0 +1,24 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use anyhow::Result;
+use common_arrow::arrow;
+use common_datavalues::UInt32Array;
+
+use crate::DataBlock;
+
+/// Take the rows of the block by the given row indices.
+pub fn block_take_by_indices(raw: &DataBlock, indices: &[u32]) -> Result<DataBlock> {
+    let indices = UInt32Array::from(indices.to_vec());
+
+    let mut columns = Vec::with_capacity(raw.num_columns());
+    for i in 0..raw.num_columns() {
+        columns.push(arrow::compute::take(
+            raw.column(i).as_ref(),
+            &indices,
+            None,
+        )?);
+    }
+    Ok(DataBlock::create(raw.schema().clone(), columns))
+}
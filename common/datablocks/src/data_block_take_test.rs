@@ -0,0 +1,45 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+#[test]
+fn test_data_block_take() -> anyhow::Result<()> {
+    use std::sync::Arc;
+
+    use common_datavalues::*;
+
+    use crate::*;
+
+    let schema = Arc::new(DataSchema::new(vec![
+        DataField::new("a", DataType::Int64, false),
+        DataField::new("b", DataType::Utf8, false),
+    ]));
+
+    let raw = DataBlock::create(
+        schema.clone(),
+        vec![
+            Arc::new(Int64Array::from(vec![1, 2, 3])),
+            Arc::new(StringArray::from(vec!["b1", "b2", "b3"])),
+        ],
+    );
+
+    let taken = block_take_by_indices(&raw, &[2, 0])?;
+    assert_eq!(&schema, taken.schema());
+    assert_eq!(2, taken.num_rows());
+
+    let a = taken
+        .column(0)
+        .as_any()
+        .downcast_ref::<Int64Array>()
+        .unwrap();
+    assert_eq!(a.clone(), Int64Array::from(vec![3, 1]));
+
+    let b = taken
+        .column(1)
+        .as_any()
+        .downcast_ref::<StringArray>()
+        .unwrap();
+    assert_eq!(b.clone(), StringArray::from(vec!["b3", "b1"]));
+
+    Ok(())
+}
@@ -2,9 +2,21 @@
 //
 // SPDX-License-Identifier: Apache-2.0.
 
+#[cfg(test)]
+mod data_block_concat_test;
+#[cfg(test)]
+mod data_block_sort_test;
+#[cfg(test)]
+mod data_block_take_test;
 #[cfg(test)]
 mod data_block_test;
 
 mod data_block;
+mod data_block_concat;
+mod data_block_sort;
+mod data_block_take;
 
 pub use crate::data_block::DataBlock;
+pub use crate::data_block_concat::block_concat;
+pub use crate::data_block_sort::{block_merge_sort, block_sort, SortColumnDescription};
+pub use crate::data_block_take::block_take_by_indices;
@@ -19,3 +19,10 @@ pub type UInt64Array = arrow::array::UInt64Array;
 pub type Float32Array = arrow::array::Float32Array;
 pub type Float64Array = arrow::array::Float64Array;
 pub type StringArray = arrow::array::StringArray;
+pub type Date32Array = arrow::array::Date32Array;
+pub type TimestampSecondArray = arrow::array::TimestampSecondArray;
+pub type TimestampMicrosecondArray = arrow::array::TimestampMicrosecondArray;
+pub type DecimalArray = arrow::array::DecimalArray;
+pub type DecimalBuilder = arrow::array::DecimalBuilder;
+pub type ListArray = arrow::array::ListArray;
+pub type StringBuilder = arrow::array::StringBuilder;
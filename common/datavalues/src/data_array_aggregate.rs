@@ -2,8 +2,10 @@
 //
 // SPDX-License-Identifier: Apache-2.0.
 
-use anyhow::{bail, Result};
+use anyhow::{anyhow, bail, Result};
 
+use crate::data_array_cast::decimal_values;
+use crate::data_decimal::{check_decimal_precision, MAX_DECIMAL_PRECISION};
 use crate::{DataArrayRef, DataType, DataValue, DataValueAggregateOperator, StringArray};
 use crate::{
     Float32Array, Float64Array, Int16Array, Int32Array, Int64Array, Int8Array, UInt16Array,
@@ -15,6 +17,11 @@ pub fn data_array_aggregate_op(
     value: DataArrayRef,
 ) -> Result<DataValue> {
     Ok(match value.data_type() {
+        // The aggregates skip the NULLs.
+        DataType::Null => match op {
+            DataValueAggregateOperator::Count => DataValue::UInt64(Some(0)),
+            _ => DataValue::Null,
+        },
         DataType::Int8 => match op {
             DataValueAggregateOperator::Min => {
                 typed_array_min_max_to_data_value!(value, Int8Array, Int8, min)
@@ -25,7 +32,9 @@ pub fn data_array_aggregate_op(
             DataValueAggregateOperator::Sum => {
                 typed_array_sum_to_data_value!(value, Int8Array, Int8)
             }
-            DataValueAggregateOperator::Count => DataValue::UInt64(Some(value.len() as u64)),
+            DataValueAggregateOperator::Count => {
+                DataValue::UInt64(Some((value.len() - value.null_count()) as u64))
+            }
             DataValueAggregateOperator::Avg => {
                 bail!(format!(
                     "DataValue Error: Unsupported data_array_{} for data type: {:?}",
@@ -44,7 +53,9 @@ pub fn data_array_aggregate_op(
             DataValueAggregateOperator::Sum => {
                 typed_array_sum_to_data_value!(value, Int16Array, Int16)
             }
-            DataValueAggregateOperator::Count => DataValue::UInt64(Some(value.len() as u64)),
+            DataValueAggregateOperator::Count => {
+                DataValue::UInt64(Some((value.len() - value.null_count()) as u64))
+            }
             DataValueAggregateOperator::Avg => {
                 bail!(format!(
                     "DataValue Error: Unsupported data_array_{} for data type: {:?}",
@@ -63,7 +74,9 @@ pub fn data_array_aggregate_op(
             DataValueAggregateOperator::Sum => {
                 typed_array_sum_to_data_value!(value, Int32Array, Int32)
             }
-            DataValueAggregateOperator::Count => DataValue::UInt64(Some(value.len() as u64)),
+            DataValueAggregateOperator::Count => {
+                DataValue::UInt64(Some((value.len() - value.null_count()) as u64))
+            }
 
             DataValueAggregateOperator::Avg => {
                 bail!(format!(
@@ -83,7 +96,9 @@ pub fn data_array_aggregate_op(
             DataValueAggregateOperator::Sum => {
                 typed_array_sum_to_data_value!(value, Int64Array, Int64)
             }
-            DataValueAggregateOperator::Count => DataValue::UInt64(Some(value.len() as u64)),
+            DataValueAggregateOperator::Count => {
+                DataValue::UInt64(Some((value.len() - value.null_count()) as u64))
+            }
 
             DataValueAggregateOperator::Avg => {
                 bail!(format!(
@@ -103,7 +118,9 @@ pub fn data_array_aggregate_op(
             DataValueAggregateOperator::Sum => {
                 typed_array_sum_to_data_value!(value, UInt8Array, UInt8)
             }
-            DataValueAggregateOperator::Count => DataValue::UInt64(Some(value.len() as u64)),
+            DataValueAggregateOperator::Count => {
+                DataValue::UInt64(Some((value.len() - value.null_count()) as u64))
+            }
 
             DataValueAggregateOperator::Avg => {
                 bail!(format!(
@@ -123,7 +140,9 @@ pub fn data_array_aggregate_op(
             DataValueAggregateOperator::Sum => {
                 typed_array_sum_to_data_value!(value, UInt16Array, UInt16)
             }
-            DataValueAggregateOperator::Count => DataValue::UInt64(Some(value.len() as u64)),
+            DataValueAggregateOperator::Count => {
+                DataValue::UInt64(Some((value.len() - value.null_count()) as u64))
+            }
 
             DataValueAggregateOperator::Avg => {
                 bail!(format!(
@@ -143,7 +162,9 @@ pub fn data_array_aggregate_op(
             DataValueAggregateOperator::Sum => {
                 typed_array_sum_to_data_value!(value, UInt32Array, UInt32)
             }
-            DataValueAggregateOperator::Count => DataValue::UInt64(Some(value.len() as u64)),
+            DataValueAggregateOperator::Count => {
+                DataValue::UInt64(Some((value.len() - value.null_count()) as u64))
+            }
 
             DataValueAggregateOperator::Avg => {
                 bail!(format!(
@@ -163,7 +184,9 @@ pub fn data_array_aggregate_op(
             DataValueAggregateOperator::Sum => {
                 typed_array_sum_to_data_value!(value, UInt64Array, UInt64)
             }
-            DataValueAggregateOperator::Count => DataValue::UInt64(Some(value.len() as u64)),
+            DataValueAggregateOperator::Count => {
+                DataValue::UInt64(Some((value.len() - value.null_count()) as u64))
+            }
             DataValueAggregateOperator::Avg => {
                 bail!(format!(
                     "DataValue Error: Unsupported data_array_{} for data type: {:?}",
@@ -182,7 +205,9 @@ pub fn data_array_aggregate_op(
             DataValueAggregateOperator::Sum => {
                 typed_array_sum_to_data_value!(value, Float32Array, Float32)
             }
-            DataValueAggregateOperator::Count => DataValue::UInt64(Some(value.len() as u64)),
+            DataValueAggregateOperator::Count => {
+                DataValue::UInt64(Some((value.len() - value.null_count()) as u64))
+            }
             DataValueAggregateOperator::Avg => {
                 bail!(format!(
                     "DataValue Error: Unsupported data_array_{} for data type: {:?}",
@@ -201,7 +226,9 @@ pub fn data_array_aggregate_op(
             DataValueAggregateOperator::Sum => {
                 typed_array_sum_to_data_value!(value, Float64Array, Float64)
             }
-            DataValueAggregateOperator::Count => DataValue::UInt64(Some(value.len() as u64)),
+            DataValueAggregateOperator::Count => {
+                DataValue::UInt64(Some((value.len() - value.null_count()) as u64))
+            }
             DataValueAggregateOperator::Avg => {
                 bail!(format!(
                     "DataValue Error: Unsupported data_array_{} for data type: {:?}",
@@ -210,6 +237,38 @@ pub fn data_array_aggregate_op(
                 ));
             }
         },
+        DataType::Decimal(precision, scale) => {
+            let values = decimal_values(&value)?.into_iter().flatten();
+            match op {
+                DataValueAggregateOperator::Min => {
+                    DataValue::Decimal128(values.min(), *precision, *scale)
+                }
+                DataValueAggregateOperator::Max => {
+                    DataValue::Decimal128(values.max(), *precision, *scale)
+                }
+                DataValueAggregateOperator::Sum => {
+                    // The sum is widened to the max precision like the integers to 64 bits.
+                    let mut sum = None;
+                    for v in values {
+                        let v = sum.unwrap_or(0_i128).checked_add(v).ok_or_else(|| {
+                            anyhow!("DataValue Error: Decimal value overflow in sum")
+                        })?;
+                        sum = Some(check_decimal_precision(v, MAX_DECIMAL_PRECISION)?);
+                    }
+                    DataValue::Decimal128(sum, MAX_DECIMAL_PRECISION, *scale)
+                }
+                DataValueAggregateOperator::Count => {
+                    DataValue::UInt64(Some((value.len() - value.null_count()) as u64))
+                }
+                DataValueAggregateOperator::Avg => {
+                    bail!(format!(
+                        "DataValue Error: Unsupported data_array_{} for data type: {:?}",
+                        op,
+                        value.data_type()
+                    ));
+                }
+            }
+        }
         DataType::Utf8 => match op {
             DataValueAggregateOperator::Min => {
                 typed_array_min_max_string_to_data_value!(value, StringArray, String, min_string)
@@ -217,7 +276,9 @@ pub fn data_array_aggregate_op(
             DataValueAggregateOperator::Max => {
                 typed_array_min_max_string_to_data_value!(value, StringArray, String, max_string)
             }
-            DataValueAggregateOperator::Count => DataValue::UInt64(Some(value.len() as u64)),
+            DataValueAggregateOperator::Count => {
+                DataValue::UInt64(Some((value.len() - value.null_count()) as u64))
+            }
             _ => {
                 bail!(format!(
                     "DataValue Error: Unsupported data_array_{} for data type: {:?}",
@@ -5,12 +5,15 @@
 use std::sync::Arc;
 
 use anyhow::Result;
-use common_arrow::arrow;
 
-use crate::{DataArrayRef, DataColumnarValue, DataType, DataValueArithmeticOperator};
+use crate::data_array_cast::{decimal_array, decimal_values};
+use crate::data_decimal::{decimal_arithmetic, integer_decimal_type};
 use crate::{
-    Float32Array, Float64Array, Int16Array, Int32Array, Int64Array, Int8Array, UInt16Array,
-    UInt32Array, UInt64Array, UInt8Array,
+    data_array_cast, DataArrayRef, DataColumnarValue, DataType, DataValueArithmeticOperator,
+};
+use crate::{
+    Float32Array, Float64Array, Int16Array, Int32Array, Int64Array, Int8Array, NullArray,
+    UInt16Array, UInt32Array, UInt64Array, UInt8Array,
 };
 
 pub fn data_array_arithmetic_op(
@@ -38,8 +41,15 @@ pub fn data_array_arithmetic_op(
         &left_array.data_type(),
         &right_array.data_type(),
     )?;
-    let left_array = arrow::compute::cast(&left_array, &coercion_type)?;
-    let right_array = arrow::compute::cast(&right_array, &coercion_type)?;
+    if coercion_type == DataType::Null {
+        return Ok(Arc::new(NullArray::new(left_array.len())));
+    }
+    if let DataType::Decimal(precision, scale) = coercion_type {
+        return decimal_array_arithmetic_op(op, &left_array, &right_array, (precision, scale));
+    }
+
+    let left_array = data_array_cast(&left_array, &coercion_type)?;
+    let right_array = data_array_cast(&right_array, &coercion_type)?;
     match op {
         DataValueArithmeticOperator::Plus => {
             arrow_primitive_array_op!(&left_array, &right_array, &coercion_type, add)
@@ -63,3 +73,39 @@ pub fn data_array_arithmetic_op(
         }
     }
 }
+
+/// The decimal arithmetic is exact, each side keeps its own scale and the integers are
+/// converted to decimals with scale 0.
+fn decimal_array_arithmetic_op(
+    op: DataValueArithmeticOperator,
+    left_array: &DataArrayRef,
+    right_array: &DataArrayRef,
+    (precision, scale): (usize, usize),
+) -> Result<DataArrayRef> {
+    let left_type = integer_decimal_type(left_array.data_type())?;
+    let right_type = integer_decimal_type(right_array.data_type())?;
+    let (left_scale, right_scale) = match (&left_type, &right_type) {
+        (DataType::Decimal(_, left_scale), DataType::Decimal(_, right_scale)) => {
+            (*left_scale, *right_scale)
+        }
+        _ => unreachable!(),
+    };
+
+    let left_values = decimal_values(&data_array_cast(left_array, &left_type)?)?;
+    let right_values = decimal_values(&data_array_cast(right_array, &right_type)?)?;
+    let values = left_values
+        .into_iter()
+        .zip(right_values.into_iter())
+        .map(|(lhs, rhs)| match (lhs, rhs) {
+            (Some(lhs), Some(rhs)) => decimal_arithmetic(
+                &op,
+                (lhs, left_scale),
+                (rhs, right_scale),
+                (precision, scale),
+            )
+            .map(Some),
+            _ => Ok(None),
+        })
+        .collect::<Result<Vec<_>>>()?;
+    decimal_array(values, precision, scale)
+}
@@ -0,0 +1,281 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::convert::TryFrom;
+use std::sync::Arc;
+
+use anyhow::{anyhow, bail, Result};
+use common_arrow::arrow;
+use common_arrow::arrow::array::{
+    Array, ListBuilder, TimestampMillisecondArray, TimestampNanosecondArray,
+};
+use common_arrow::arrow::datatypes::TimeUnit;
+
+use crate::data_decimal::{
+    check_decimal_precision, format_decimal, parse_decimal, rescale_decimal,
+};
+use crate::data_temporal::{
+    format_temporal, is_temporal, parse_temporal, temporal_from_micros, temporal_to_micros,
+};
+use crate::data_type::{is_floating, is_integer};
+use crate::{
+    DataArrayRef, DataType, DataValue, Date32Array, DecimalArray, DecimalBuilder, Float64Array,
+    Int64Array, StringArray, StringBuilder, TimestampMicrosecondArray, TimestampSecondArray,
+    UInt64Array,
+};
+
+/// Cast the array to the data type.
+/// The temporal types are parsed from and formatted to strings in the same way as the literals,
+/// the decimals are converted exactly with the fraction digits rounded half away from zero,
+/// the NULLs are NULLs of any type, the lists are formatted to strings,
+/// the others are casted by the arrow cast kernel.
+pub fn data_array_cast(array: &DataArrayRef, to_type: &DataType) -> Result<DataArrayRef> {
+    let from_type = array.data_type();
+    if from_type == to_type {
+        return Ok(array.clone());
+    }
+
+    match (from_type, to_type) {
+        (DataType::Null, DataType::Decimal(precision, scale)) => {
+            decimal_array(vec![None; array.len()], *precision, *scale)
+        }
+        (DataType::Null, to) => Ok(arrow::array::new_null_array(to, array.len())),
+        (DataType::Utf8, to) if is_temporal(to) => {
+            let strings = downcast_array!(array, StringArray)?;
+            let values = (0..strings.len())
+                .map(|i| match strings.is_null(i) {
+                    true => Ok(None),
+                    false => parse_temporal(strings.value(i), to).map(Some),
+                })
+                .collect::<Result<Vec<_>>>()?;
+            temporal_array(values, to)
+        }
+        (from, DataType::Utf8) if is_temporal(from) => {
+            let values = temporal_values(array)?
+                .into_iter()
+                .map(|v| v.map(|v| format_temporal(v, from)).transpose())
+                .collect::<Result<Vec<_>>>()?;
+            Ok(Arc::new(StringArray::from(
+                values.iter().map(|v| v.as_deref()).collect::<Vec<_>>(),
+            )))
+        }
+        // The timestamps of the other units are converted through the microseconds as well.
+        (from, to)
+            if (is_temporal(from) || matches!(from, DataType::Timestamp(_, _)))
+                && is_temporal(to) =>
+        {
+            let values = temporal_values(array)?
+                .into_iter()
+                .map(|v| {
+                    v.map(|v| temporal_from_micros(temporal_to_micros(v, from)?, to))
+                        .transpose()
+                })
+                .collect::<Result<Vec<_>>>()?;
+            temporal_array(values, to)
+        }
+        (DataType::Utf8, DataType::Decimal(precision, scale)) => {
+            let strings = downcast_array!(array, StringArray)?;
+            let values = (0..strings.len())
+                .map(|i| match strings.is_null(i) {
+                    true => Ok(None),
+                    false => parse_decimal(strings.value(i), *precision, *scale).map(Some),
+                })
+                .collect::<Result<Vec<_>>>()?;
+            decimal_array(values, *precision, *scale)
+        }
+        (DataType::Decimal(_, from_scale), DataType::Decimal(precision, scale)) => {
+            let values = decimal_values(array)?
+                .into_iter()
+                .map(|v| {
+                    v.map(|v| {
+                        check_decimal_precision(
+                            rescale_decimal(v, *from_scale, *scale)?,
+                            *precision,
+                        )
+                    })
+                    .transpose()
+                })
+                .collect::<Result<Vec<_>>>()?;
+            decimal_array(values, *precision, *scale)
+        }
+        (from, DataType::Decimal(precision, scale)) if is_integer(from) => {
+            let values = integer_values(array)?
+                .into_iter()
+                .map(|v| {
+                    v.map(|v| check_decimal_precision(rescale_decimal(v, 0, *scale)?, *precision))
+                        .transpose()
+                })
+                .collect::<Result<Vec<_>>>()?;
+            decimal_array(values, *precision, *scale)
+        }
+        (from, DataType::Decimal(_, _)) if is_floating(from) => {
+            // The shortest representation of the float is the value the user wrote.
+            let floats = arrow::compute::cast(array, &DataType::Float64)?;
+            let floats = downcast_array!(floats, Float64Array)?;
+            let strings = (0..floats.len())
+                .map(|i| match floats.is_null(i) {
+                    true => None,
+                    false => Some(floats.value(i).to_string()),
+                })
+                .collect::<Vec<_>>();
+            let strings: DataArrayRef = Arc::new(StringArray::from(
+                strings.iter().map(|v| v.as_deref()).collect::<Vec<_>>(),
+            ));
+            data_array_cast(&strings, to_type)
+        }
+        (DataType::Decimal(_, scale), to) if to == &DataType::Utf8 || is_floating(to) => {
+            let values = decimal_values(array)?
+                .into_iter()
+                .map(|v| v.map(|v| format_decimal(v, *scale)))
+                .collect::<Vec<_>>();
+            let strings: DataArrayRef = Arc::new(StringArray::from(
+                values.iter().map(|v| v.as_deref()).collect::<Vec<_>>(),
+            ));
+            Ok(arrow::compute::cast(&strings, to)?)
+        }
+        (DataType::Decimal(_, scale), to) if is_integer(to) => {
+            // The fraction digits are truncated like the float to integer cast.
+            let values = decimal_values(array)?
+                .into_iter()
+                .map(|v| {
+                    v.map(|v| {
+                        i64::try_from(v / 10_i128.pow(*scale as u32)).map_err(|_| {
+                            anyhow!("DataValue Error: Decimal value overflow {:?}", to)
+                        })
+                    })
+                    .transpose()
+                })
+                .collect::<Result<Vec<_>>>()?;
+            let integers: DataArrayRef = Arc::new(Int64Array::from(values));
+            Ok(arrow::compute::cast(&integers, to)?)
+        }
+        (DataType::List(_), DataType::Utf8) => {
+            // The lists are formatted like the literals.
+            let values = (0..array.len())
+                .map(|i| match array.is_null(i) {
+                    true => Ok(None),
+                    false => DataValue::try_from_array(array, i).map(|v| Some(v.to_string())),
+                })
+                .collect::<Result<Vec<_>>>()?;
+            Ok(Arc::new(StringArray::from(
+                values.iter().map(|v| v.as_deref()).collect::<Vec<_>>(),
+            )))
+        }
+        _ => Ok(arrow::compute::cast(array, to_type)?),
+    }
+}
+
+/// The values of the temporal array, in the unit of its type.
+pub fn temporal_values(array: &DataArrayRef) -> Result<Vec<Option<i64>>> {
+    macro_rules! values {
+        ($ARRAY_TYPE:ident) => {{
+            let array = downcast_array!(array, $ARRAY_TYPE)?;
+            (0..array.len())
+                .map(|i| match array.is_null(i) {
+                    true => None,
+                    false => Some(array.value(i) as i64),
+                })
+                .collect()
+        }};
+    }
+
+    Ok(match array.data_type() {
+        DataType::Date32 => values!(Date32Array),
+        DataType::Timestamp(TimeUnit::Second, _) => values!(TimestampSecondArray),
+        DataType::Timestamp(TimeUnit::Millisecond, _) => {
+            values!(TimestampMillisecondArray)
+        }
+        DataType::Timestamp(TimeUnit::Microsecond, _) => values!(TimestampMicrosecondArray),
+        DataType::Timestamp(TimeUnit::Nanosecond, _) => values!(TimestampNanosecondArray),
+        other => bail!("DataValue Error: {:?} is not a temporal type", other),
+    })
+}
+
+/// Build the temporal array from the values in the unit of the type.
+pub fn temporal_array(values: Vec<Option<i64>>, data_type: &DataType) -> Result<DataArrayRef> {
+    Ok(match data_type {
+        DataType::Date32 => Arc::new(Date32Array::from(
+            values
+                .into_iter()
+                .map(|v| v.map(|v| v as i32))
+                .collect::<Vec<_>>(),
+        )),
+        DataType::Timestamp(TimeUnit::Second, _) => {
+            Arc::new(TimestampSecondArray::from_opt_vec(values, None))
+        }
+        DataType::Timestamp(TimeUnit::Microsecond, _) => {
+            Arc::new(TimestampMicrosecondArray::from_opt_vec(values, None))
+        }
+        other => bail!("DataValue Error: {:?} is not a temporal type", other),
+    })
+}
+
+/// The unscaled values of the decimal array.
+pub fn decimal_values(array: &DataArrayRef) -> Result<Vec<Option<i128>>> {
+    let array = downcast_array!(array, DecimalArray)?;
+    Ok((0..array.len())
+        .map(|i| match array.is_null(i) {
+            true => None,
+            false => Some(array.value(i)),
+        })
+        .collect())
+}
+
+/// Build the decimal array from the unscaled values.
+pub fn decimal_array(
+    values: Vec<Option<i128>>,
+    precision: usize,
+    scale: usize,
+) -> Result<DataArrayRef> {
+    let mut builder = DecimalBuilder::new(values.len(), precision, scale);
+    for value in values {
+        match value {
+            Some(v) => builder.append_value(v)?,
+            None => builder.append_null()?,
+        }
+    }
+    Ok(Arc::new(builder.finish()))
+}
+
+/// Build the list array of strings from the lists.
+pub fn string_list_array(values: &[Option<Vec<Option<&str>>>]) -> Result<DataArrayRef> {
+    let mut builder = ListBuilder::new(StringBuilder::new(values.len()));
+    for value in values {
+        match value {
+            Some(items) => {
+                for item in items {
+                    match item {
+                        Some(v) => builder.values().append_value(v)?,
+                        None => builder.values().append_null()?,
+                    }
+                }
+                builder.append(true)?;
+            }
+            None => builder.append(false)?,
+        }
+    }
+    Ok(Arc::new(builder.finish()))
+}
+
+fn integer_values(array: &DataArrayRef) -> Result<Vec<Option<i128>>> {
+    macro_rules! values {
+        ($ARRAY:expr, $ARRAY_TYPE:ident) => {{
+            let array = downcast_array!($ARRAY, $ARRAY_TYPE)?;
+            (0..array.len())
+                .map(|i| match array.is_null(i) {
+                    true => None,
+                    false => Some(array.value(i) as i128),
+                })
+                .collect()
+        }};
+    }
+
+    Ok(match array.data_type() {
+        DataType::UInt64 => values!(array, UInt64Array),
+        _ => {
+            let array = arrow::compute::cast(array, &DataType::Int64)?;
+            values!(array, Int64Array)
+        }
+    })
+}
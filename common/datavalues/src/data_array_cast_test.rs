@@ -0,0 +1,124 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+#[test]
+fn test_data_array_cast() -> anyhow::Result<()> {
+    use std::sync::Arc;
+
+    use common_arrow::arrow::datatypes::TimeUnit;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    let date_time = DataType::Timestamp(TimeUnit::Second, None);
+    let timestamp = DataType::Timestamp(TimeUnit::Microsecond, None);
+
+    // Parse the strings, a time without time zone is in UTC.
+    let strings: DataArrayRef = Arc::new(StringArray::from(vec![
+        Some("2021-01-01"),
+        Some("2021-01-01 12:30:00"),
+        Some("2021-01-01T12:30:00.5+08:00"),
+        None,
+    ]));
+    let array = data_array_cast(&strings, &DataType::Date32)?;
+    assert_eq!(
+        array.as_any().downcast_ref::<Date32Array>().unwrap(),
+        &Date32Array::from(vec![Some(18628), Some(18628), Some(18628), None])
+    );
+    let array = data_array_cast(&strings, &date_time)?;
+    assert_eq!(
+        array
+            .as_any()
+            .downcast_ref::<TimestampSecondArray>()
+            .unwrap(),
+        &TimestampSecondArray::from_opt_vec(
+            vec![Some(1609459200), Some(1609504200), Some(1609475400), None],
+            None
+        )
+    );
+    let array = data_array_cast(&strings, &timestamp)?;
+    assert_eq!(
+        DataValue::try_from_array(&array, 2)?,
+        DataValue::TimestampMicrosecond(Some(1609475400500000))
+    );
+
+    // Format the values.
+    let array = data_array_cast(&array, &DataType::Utf8)?;
+    assert_eq!(
+        array.as_any().downcast_ref::<StringArray>().unwrap(),
+        &StringArray::from(vec![
+            Some("2021-01-01 00:00:00.000000"),
+            Some("2021-01-01 12:30:00.000000"),
+            Some("2021-01-01 04:30:00.500000"),
+            None
+        ])
+    );
+    assert_eq!("2021-01-01", DataValue::Date32(Some(18628)).to_string());
+    assert_eq!(
+        "2021-01-01 12:30:00",
+        DataValue::TimestampSecond(Some(1609504200)).to_string()
+    );
+
+    // Between the temporal types, rounding down.
+    let times: DataArrayRef = Arc::new(TimestampSecondArray::from_opt_vec(
+        vec![Some(1609504200), Some(-1)],
+        None,
+    ));
+    let array = data_array_cast(&times, &DataType::Date32)?;
+    assert_eq!(
+        array.as_any().downcast_ref::<Date32Array>().unwrap(),
+        &Date32Array::from(vec![18628, -1])
+    );
+
+    // The timestamps in milliseconds and nanoseconds, rounding down.
+    let millis: DataArrayRef = Arc::new(
+        common_arrow::arrow::array::TimestampMillisecondArray::from_opt_vec(
+            vec![Some(1609504200500), None],
+            None,
+        ),
+    );
+    let array = data_array_cast(&millis, &timestamp)?;
+    assert_eq!(
+        DataValue::try_from_array(&array, 0)?,
+        DataValue::TimestampMicrosecond(Some(1609504200500000))
+    );
+    let nanos: DataArrayRef = Arc::new(
+        common_arrow::arrow::array::TimestampNanosecondArray::from_opt_vec(
+            vec![Some(1609504200500000999), Some(-1)],
+            None,
+        ),
+    );
+    let array = data_array_cast(&nanos, &date_time)?;
+    assert_eq!(
+        array
+            .as_any()
+            .downcast_ref::<TimestampSecondArray>()
+            .unwrap(),
+        &TimestampSecondArray::from_opt_vec(vec![Some(1609504200), Some(-1)], None)
+    );
+
+    // The values out of the range are errors.
+    let result = temporal_to_micros(i64::MAX, &date_time);
+    assert_eq!(
+        "DataValue Error: 9223372036854775807 is out of the range of DateTime",
+        result.err().unwrap().to_string()
+    );
+    let result = format_temporal(i64::MAX, &timestamp);
+    assert_eq!(
+        "DataValue Error: 9223372036854775807 is out of the range of Timestamp",
+        result.err().unwrap().to_string()
+    );
+    assert_eq!(
+        "9223372036854775807",
+        DataValue::TimestampMicrosecond(Some(i64::MAX)).to_string()
+    );
+
+    let invalid: DataArrayRef = Arc::new(StringArray::from(vec!["2021-01-01 25:00:00"]));
+    let result = data_array_cast(&invalid, &date_time);
+    assert_eq!(
+        "DataValue Error: Cannot parse '2021-01-01 25:00:00' as DateTime",
+        result.err().unwrap().to_string()
+    );
+    Ok(())
+}
@@ -7,10 +7,14 @@ use std::sync::Arc;
 use anyhow::Result;
 use common_arrow::arrow;
 
-use crate::{DataArrayRef, DataColumnarValue, DataType, DataValueComparisonOperator};
+use crate::data_array_cast::decimal_values;
 use crate::{
-    Float32Array, Float64Array, Int16Array, Int32Array, Int64Array, Int8Array, StringArray,
-    UInt16Array, UInt32Array, UInt64Array, UInt8Array,
+    data_array_cast, DataArrayRef, DataColumnarValue, DataType, DataValueComparisonOperator,
+};
+use crate::{
+    BooleanArray, Date32Array, Float32Array, Float64Array, Int16Array, Int32Array, Int64Array,
+    Int8Array, StringArray, TimestampMicrosecondArray, TimestampSecondArray, UInt16Array,
+    UInt32Array, UInt64Array, UInt8Array,
 };
 
 pub fn data_array_comparison_op(
@@ -18,14 +22,29 @@ pub fn data_array_comparison_op(
     left: &DataColumnarValue,
     right: &DataColumnarValue,
 ) -> Result<DataArrayRef> {
+    let coercion_type = super::data_type::equal_coercion(&left.data_type(), &right.data_type())?;
+    if is_null_scalar(left) || is_null_scalar(right) || coercion_type == DataType::Null {
+        // Comparing with NULL is NULL.
+        let size = match (left, right) {
+            (DataColumnarValue::Array(array), _) | (_, DataColumnarValue::Array(array)) => {
+                array.len()
+            }
+            _ => 1,
+        };
+        return Ok(arrow::array::new_null_array(&DataType::Boolean, size));
+    }
+    if let DataType::Decimal(_, _) = coercion_type {
+        return decimal_array_comparison_op(op, left, right, &coercion_type);
+    }
+
     match (left, right) {
         (DataColumnarValue::Array(left_array), DataColumnarValue::Array(right_array)) => {
             let coercion_type = super::data_type::equal_coercion(
                 &left_array.data_type(),
                 &right_array.data_type(),
             )?;
-            let left_array = arrow::compute::cast(&left_array, &coercion_type)?;
-            let right_array = arrow::compute::cast(&right_array, &coercion_type)?;
+            let left_array = data_array_cast(&left_array, &coercion_type)?;
+            let right_array = data_array_cast(&right_array, &coercion_type)?;
 
             match op {
                 DataValueComparisonOperator::Eq => arrow_array_op!(&left_array, &right_array, eq),
@@ -46,8 +65,8 @@ pub fn data_array_comparison_op(
         (DataColumnarValue::Array(array), DataColumnarValue::Scalar(scalar)) => {
             let coercion_type =
                 super::data_type::equal_coercion(&array.data_type(), &scalar.data_type())?;
-            let left_array = arrow::compute::cast(&array, &coercion_type)?;
-            let right_array = arrow::compute::cast(&scalar.to_array(1)?, &coercion_type)?;
+            let left_array = data_array_cast(&array, &coercion_type)?;
+            let right_array = data_array_cast(&scalar.to_array(1)?, &coercion_type)?;
             let scalar = super::DataValue::try_from_array(&right_array, 0)?;
 
             match op {
@@ -69,8 +88,8 @@ pub fn data_array_comparison_op(
         (DataColumnarValue::Scalar(scalar), DataColumnarValue::Array(array)) => {
             let coercion_type =
                 super::data_type::equal_coercion(&array.data_type(), &scalar.data_type())?;
-            let left_array = arrow::compute::cast(&scalar.to_array(1)?, &coercion_type)?;
-            let right_array = arrow::compute::cast(&array, &coercion_type)?;
+            let left_array = data_array_cast(&scalar.to_array(1)?, &coercion_type)?;
+            let right_array = data_array_cast(&array, &coercion_type)?;
             let scalar = super::DataValue::try_from_array(&left_array, 0)?;
 
             match op {
@@ -93,8 +112,8 @@ pub fn data_array_comparison_op(
                 &left_scala.data_type(),
                 &right_scalar.data_type(),
             )?;
-            let left_array = arrow::compute::cast(&left_scala.to_array(1)?, &coercion_type)?;
-            let right_array = arrow::compute::cast(&right_scalar.to_array(1)?, &coercion_type)?;
+            let left_array = data_array_cast(&left_scala.to_array(1)?, &coercion_type)?;
+            let right_array = data_array_cast(&right_scalar.to_array(1)?, &coercion_type)?;
 
             match op {
                 DataValueComparisonOperator::Eq => arrow_array_op!(&left_array, &right_array, eq),
@@ -113,3 +132,40 @@ pub fn data_array_comparison_op(
         }
     }
 }
+
+fn is_null_scalar(value: &DataColumnarValue) -> bool {
+    matches!(value, DataColumnarValue::Scalar(v) if v.is_null())
+}
+
+/// The arrow comparison kernels don't support the decimals, compare the unscaled values
+/// in the common scale instead.
+fn decimal_array_comparison_op(
+    op: DataValueComparisonOperator,
+    left: &DataColumnarValue,
+    right: &DataColumnarValue,
+    coercion_type: &DataType,
+) -> Result<DataArrayRef> {
+    let size = match (left, right) {
+        (DataColumnarValue::Array(array), _) | (_, DataColumnarValue::Array(array)) => array.len(),
+        _ => 1,
+    };
+    let left_values = decimal_values(&data_array_cast(&left.to_array(size)?, coercion_type)?)?;
+    let right_values = decimal_values(&data_array_cast(&right.to_array(size)?, coercion_type)?)?;
+
+    let values = left_values
+        .iter()
+        .zip(right_values.iter())
+        .map(|(lhs, rhs)| match (lhs, rhs) {
+            (Some(lhs), Some(rhs)) => Some(match op {
+                DataValueComparisonOperator::Eq => lhs == rhs,
+                DataValueComparisonOperator::Lt => lhs < rhs,
+                DataValueComparisonOperator::LtEq => lhs <= rhs,
+                DataValueComparisonOperator::Gt => lhs > rhs,
+                DataValueComparisonOperator::GtEq => lhs >= rhs,
+                DataValueComparisonOperator::NotEq => lhs != rhs,
+            }),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    Ok(Arc::new(BooleanArray::from(values)))
+}
@@ -265,6 +265,22 @@ fn test_scalar_array_comparison() {
             expect: Arc::new(BooleanArray::from(vec![false, true, true, true])),
             error: "",
         },
+        ArrayTest {
+            name: "date-string-lt-passed",
+            array: Arc::new(Date32Array::from(vec![18628, 18629, 18630])),
+            scalar: DataValue::String(Some("2021-01-02".to_string())),
+            op: DataValueComparisonOperator::Lt,
+            expect: Arc::new(BooleanArray::from(vec![false, false, true])),
+            error: "",
+        },
+        ArrayTest {
+            name: "date-string-error",
+            array: Arc::new(Date32Array::from(vec![18628])),
+            scalar: DataValue::String(Some("2021-13-01".to_string())),
+            op: DataValueComparisonOperator::Eq,
+            expect: Arc::new(BooleanArray::from(vec![false])),
+            error: "DataValue Error: Cannot parse '2021-13-01' as Date",
+        },
     ];
 
     for t in tests {
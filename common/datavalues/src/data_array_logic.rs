@@ -4,29 +4,44 @@
 
 use std::sync::Arc;
 
-use anyhow::{bail, Result};
+use anyhow::Result;
 
-use crate::{BooleanArray, DataArrayRef, DataColumnarValue, DataValueLogicOperator};
+use crate::{
+    data_array_cast, BooleanArray, DataArrayRef, DataColumnarValue, DataType,
+    DataValueLogicOperator,
+};
 
+/// The logic operators are in the three-valued logic:
+/// `NULL AND false` is false, `NULL OR true` is true, the others with NULL are NULL.
 pub fn data_array_logic_op(
     op: DataValueLogicOperator,
     left: &DataColumnarValue,
     right: &DataColumnarValue,
 ) -> Result<DataArrayRef> {
-    match (left, right) {
-        (DataColumnarValue::Array(left_array), DataColumnarValue::Array(right_array)) => match op {
-            DataValueLogicOperator::And => {
-                array_boolean_op!(left_array, right_array, and, BooleanArray)
-            }
-            DataValueLogicOperator::Or => {
-                array_boolean_op!(left_array, right_array, or, BooleanArray)
-            }
-        },
-        _ => bail!(
-            "DataValue Error: Cannot do data_array {}, left:{:?}, right:{:?}",
-            op,
-            left.data_type(),
-            right.data_type()
-        ),
+    let size = match (left, right) {
+        (DataColumnarValue::Array(array), _) | (_, DataColumnarValue::Array(array)) => array.len(),
+        _ => 1,
+    };
+    let left_array = data_array_cast(&left.to_array(size)?, &DataType::Boolean)?;
+    let right_array = data_array_cast(&right.to_array(size)?, &DataType::Boolean)?;
+
+    match op {
+        DataValueLogicOperator::And => {
+            array_boolean_op!(left_array, right_array, and_kleene, BooleanArray)
+        }
+        DataValueLogicOperator::Or => {
+            array_boolean_op!(left_array, right_array, or_kleene, BooleanArray)
+        }
     }
 }
+
+/// `NOT NULL` is NULL.
+pub fn data_array_logic_not(value: &DataColumnarValue) -> Result<DataArrayRef> {
+    let size = match value {
+        DataColumnarValue::Array(array) => array.len(),
+        _ => 1,
+    };
+    let array = data_array_cast(&value.to_array(size)?, &DataType::Boolean)?;
+    let array = downcast_array!(array, BooleanArray)?;
+    Ok(Arc::new(common_arrow::arrow::compute::not(array)?))
+}
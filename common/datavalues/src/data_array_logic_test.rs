@@ -56,3 +56,18 @@ fn test_array_logic() {
         }
     }
 }
+
+#[test]
+fn test_array_logic_not() -> anyhow::Result<()> {
+    use std::sync::Arc;
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    let array: DataArrayRef = Arc::new(BooleanArray::from(vec![Some(true), None, Some(false)]));
+    let result = data_array_logic_not(&DataColumnarValue::Array(array))?;
+    let expect: DataArrayRef = Arc::new(BooleanArray::from(vec![Some(false), None, Some(true)]));
+    assert_eq!(result.as_ref(), expect.as_ref());
+    Ok(())
+}
@@ -0,0 +1,62 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::sync::Arc;
+
+use anyhow::{ensure, Result};
+use common_arrow::arrow;
+
+use crate::data_array_cast::{decimal_array, decimal_values};
+use crate::{DataArrayRef, DataType, NullArray, UInt32Array};
+
+/// Build the array by taking every row from the array at its index, the row is NULL if there is
+/// no index. The arrays must have the same type and the same length as the indices.
+pub fn data_array_select(
+    arrays: &[DataArrayRef],
+    indices: &[Option<usize>],
+) -> Result<DataArrayRef> {
+    ensure!(
+        !arrays.is_empty(),
+        "DataValue Error: Can't select from empty arrays"
+    );
+    let rows = indices.len();
+    for array in arrays {
+        ensure!(
+            array.data_type() == arrays[0].data_type() && array.len() == rows,
+            "DataValue Error: Can't select from {:?} and {:?} with {} rows",
+            arrays[0].data_type(),
+            array.data_type(),
+            rows
+        );
+    }
+
+    match arrays[0].data_type() {
+        DataType::Null => Ok(Arc::new(NullArray::new(rows))),
+        DataType::Decimal(precision, scale) => {
+            let values = arrays
+                .iter()
+                .map(decimal_values)
+                .collect::<Result<Vec<_>>>()?;
+            let values = indices
+                .iter()
+                .enumerate()
+                .map(|(row, index)| index.and_then(|i| values[i][row]))
+                .collect();
+            decimal_array(values, *precision, *scale)
+        }
+        _ => {
+            // Take the rows from the arrays concatenated, the NULL indices take the NULLs.
+            let concatenated = arrays.iter().map(|a| a.as_ref()).collect::<Vec<_>>();
+            let concatenated = arrow::compute::concat(&concatenated)?;
+            let indices = UInt32Array::from(
+                indices
+                    .iter()
+                    .enumerate()
+                    .map(|(row, index)| index.map(|i| (i * rows + row) as u32))
+                    .collect::<Vec<_>>(),
+            );
+            Ok(arrow::compute::take(concatenated.as_ref(), &indices, None)?)
+        }
+    }
+}
@@ -0,0 +1,37 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+#[test]
+fn test_data_array_select() -> anyhow::Result<()> {
+    use std::sync::Arc;
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    let arrays: Vec<DataArrayRef> = vec![
+        Arc::new(Int64Array::from(vec![Some(1), None, Some(3)])),
+        Arc::new(Int64Array::from(vec![Some(10), Some(20), Some(30)])),
+    ];
+    let result = data_array_select(&arrays, &[Some(1), Some(0), None])?;
+    assert_eq!(
+        result.as_any().downcast_ref::<Int64Array>().unwrap(),
+        &Int64Array::from(vec![Some(10), None, None])
+    );
+
+    let decimals = vec![
+        decimal_array(vec![Some(1), Some(2)], 5, 2)?,
+        decimal_array(vec![Some(3), None], 5, 2)?,
+    ];
+    let result = data_array_select(&decimals, &[Some(1), Some(0)])?;
+    assert_eq!(vec![Some(3), Some(2)], decimal_values(&result)?);
+
+    let integers: DataArrayRef = Arc::new(Int64Array::from(vec![1, 2]));
+    let result = data_array_select(&[integers, decimals[0].clone()], &[None, None]);
+    assert_eq!(
+        "DataValue Error: Can't select from Int64 and Decimal(5, 2) with 2 rows",
+        result.err().unwrap().to_string()
+    );
+    Ok(())
+}
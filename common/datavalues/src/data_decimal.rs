@@ -0,0 +1,243 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::cmp::Ordering;
+
+use anyhow::{anyhow, bail, ensure, Result};
+
+use crate::{DataType, DataValueArithmeticOperator};
+
+/// The max precision of the decimal, the unscaled value is stored in i128.
+pub const MAX_DECIMAL_PRECISION: usize = 38;
+
+/// Decimal(p, s) stores the value multiplied by 10^s in i128, with at most p digits.
+pub fn is_decimal(data_type: &DataType) -> bool {
+    matches!(data_type, DataType::Decimal(_, _))
+}
+
+/// Check the precision and scale of the decimal type.
+pub fn check_decimal_type(precision: usize, scale: usize) -> Result<()> {
+    ensure!(
+        (1..=MAX_DECIMAL_PRECISION).contains(&precision),
+        "DataValue Error: Decimal precision {} is out of range [1, {}]",
+        precision,
+        MAX_DECIMAL_PRECISION
+    );
+    ensure!(
+        scale <= precision,
+        "DataValue Error: Decimal scale {} is larger than precision {}",
+        scale,
+        precision
+    );
+    Ok(())
+}
+
+fn pow10(exp: usize) -> Result<i128> {
+    10_i128
+        .checked_pow(exp as u32)
+        .ok_or_else(|| anyhow!("DataValue Error: Decimal scale {} overflow", exp))
+}
+
+/// Check the unscaled value fits in the precision.
+pub fn check_decimal_precision(value: i128, precision: usize) -> Result<i128> {
+    let max = pow10(precision)?;
+    ensure!(
+        value.unsigned_abs() < max as u128,
+        "DataValue Error: Decimal value overflow the precision {}",
+        precision
+    );
+    Ok(value)
+}
+
+/// Divide and round half away from zero.
+fn div_round(value: i128, divisor: i128) -> Result<i128> {
+    ensure!(divisor != 0, "DataValue Error: Decimal division by zero");
+    let quotient = value / divisor;
+    let remainder = value % divisor;
+    if remainder.unsigned_abs() * 2 >= divisor.unsigned_abs() {
+        if (value < 0) == (divisor < 0) {
+            return Ok(quotient + 1);
+        }
+        return Ok(quotient - 1);
+    }
+    Ok(quotient)
+}
+
+/// Rescale the unscaled value, the dropped digits are rounded half away from zero.
+pub fn rescale_decimal(value: i128, from_scale: usize, to_scale: usize) -> Result<i128> {
+    match from_scale.cmp(&to_scale) {
+        Ordering::Equal => Ok(value),
+        Ordering::Less => value
+            .checked_mul(pow10(to_scale - from_scale)?)
+            .ok_or_else(|| anyhow!("DataValue Error: Decimal value overflow")),
+        Ordering::Greater => div_round(value, pow10(from_scale - to_scale)?),
+    }
+}
+
+/// Parse the string like `-123.4500` into the unscaled value of Decimal(precision, scale),
+/// the extra fraction digits are rounded half away from zero.
+pub fn parse_decimal(value: &str, precision: usize, scale: usize) -> Result<i128> {
+    let error = || anyhow!("DataValue Error: Cannot parse '{}' as Decimal", value);
+
+    let text = value.trim();
+    let (negative, text) = match text.as_bytes().first() {
+        Some(b'-') => (true, &text[1..]),
+        Some(b'+') => (false, &text[1..]),
+        _ => (false, text),
+    };
+    let (integer, fraction) = match text.find('.') {
+        Some(pos) => (&text[..pos], &text[pos + 1..]),
+        None => (text, ""),
+    };
+    if integer.is_empty() && fraction.is_empty() {
+        return Err(error());
+    }
+    if !integer
+        .bytes()
+        .chain(fraction.bytes())
+        .all(|c| c.is_ascii_digit())
+    {
+        return Err(error());
+    }
+
+    let mut unscaled: i128 = 0;
+    let mut digits = integer.to_string();
+    digits.push_str(fraction);
+    for c in digits.bytes() {
+        unscaled = unscaled
+            .checked_mul(10)
+            .and_then(|v| v.checked_add((c - b'0') as i128))
+            .ok_or_else(error)?;
+    }
+    let unscaled = rescale_decimal(unscaled, fraction.len(), scale).map_err(|_| error())?;
+    let unscaled = if negative { -unscaled } else { unscaled };
+    check_decimal_precision(unscaled, precision)
+}
+
+/// Format the unscaled value with the scale, like `-123.4500`.
+pub fn format_decimal(value: i128, scale: usize) -> String {
+    let digits = value.unsigned_abs().to_string();
+    let sign = if value < 0 { "-" } else { "" };
+    if scale == 0 {
+        return format!("{}{}", sign, digits);
+    }
+    let digits = format!("{:0>width$}", digits, width = scale + 1);
+    let (integer, fraction) = digits.split_at(digits.len() - scale);
+    format!("{}{}.{}", sign, integer, fraction)
+}
+
+/// Compare the unscaled values with different scales.
+pub fn decimal_cmp(lhs: i128, lhs_scale: usize, rhs: i128, rhs_scale: usize) -> Ordering {
+    let scale = lhs_scale.max(rhs_scale);
+    match (
+        rescale_decimal(lhs, lhs_scale, scale),
+        rescale_decimal(rhs, rhs_scale, scale),
+    ) {
+        (Ok(lhs), Ok(rhs)) => lhs.cmp(&rhs),
+        // Only the values too far apart can overflow.
+        _ => lhs.signum().cmp(&rhs.signum()).then_with(|| {
+            let lhs = lhs as f64 / 10_f64.powi(lhs_scale as i32);
+            let rhs = rhs as f64 / 10_f64.powi(rhs_scale as i32);
+            lhs.partial_cmp(&rhs).unwrap_or(Ordering::Equal)
+        }),
+    }
+}
+
+/// The decimal type that holds all the values of the integer type.
+pub fn integer_decimal_type(data_type: &DataType) -> Result<DataType> {
+    Ok(match data_type {
+        DataType::Int8 | DataType::UInt8 => DataType::Decimal(3, 0),
+        DataType::Int16 | DataType::UInt16 => DataType::Decimal(5, 0),
+        DataType::Int32 | DataType::UInt32 => DataType::Decimal(10, 0),
+        DataType::Int64 => DataType::Decimal(19, 0),
+        DataType::UInt64 => DataType::Decimal(20, 0),
+        DataType::Decimal(_, _) => data_type.clone(),
+        other => bail!(
+            "DataValue Error: {:?} cannot be converted to Decimal",
+            other
+        ),
+    })
+}
+
+/// The result type of the decimal arithmetic:
+/// `+`, `-`: Decimal(max(p1 - s1, p2 - s2) + max(s1, s2) + 1, max(s1, s2))
+/// `*`: Decimal(p1 + p2, s1 + s2)
+/// `/`: Decimal(38, max(6, s1, s2))
+/// `%`: Decimal(max(p1 - s1, p2 - s2) + max(s1, s2), max(s1, s2))
+/// The precision is limited to 38.
+pub fn decimal_arithmetic_type(
+    op: &DataValueArithmeticOperator,
+    lhs_type: &DataType,
+    rhs_type: &DataType,
+) -> Result<DataType> {
+    let (p1, s1, p2, s2) = match (
+        integer_decimal_type(lhs_type)?,
+        integer_decimal_type(rhs_type)?,
+    ) {
+        (DataType::Decimal(p1, s1), DataType::Decimal(p2, s2)) => (p1, s1, p2, s2),
+        _ => unreachable!(),
+    };
+
+    let integer_digits = (p1 - s1).max(p2 - s2);
+    let (precision, scale) = match op {
+        DataValueArithmeticOperator::Plus | DataValueArithmeticOperator::Minus => {
+            (integer_digits + s1.max(s2) + 1, s1.max(s2))
+        }
+        DataValueArithmeticOperator::Mul => (p1 + p2, s1 + s2),
+        DataValueArithmeticOperator::Div => (MAX_DECIMAL_PRECISION, 6.max(s1).max(s2)),
+        DataValueArithmeticOperator::Modulo => (integer_digits + s1.max(s2), s1.max(s2)),
+    };
+    ensure!(
+        scale <= MAX_DECIMAL_PRECISION,
+        "DataValue Error: Decimal scale {} of ({:?}) {} ({:?}) is out of range",
+        scale,
+        lhs_type,
+        op,
+        rhs_type
+    );
+    Ok(DataType::Decimal(
+        precision.min(MAX_DECIMAL_PRECISION),
+        scale,
+    ))
+}
+
+/// Exact arithmetic on the unscaled values, the result is in the scale of the result type
+/// and is checked against its precision.
+pub fn decimal_arithmetic(
+    op: &DataValueArithmeticOperator,
+    (lhs, lhs_scale): (i128, usize),
+    (rhs, rhs_scale): (i128, usize),
+    (precision, scale): (usize, usize),
+) -> Result<i128> {
+    let overflow = || anyhow!("DataValue Error: Decimal value overflow in {}", op);
+
+    let value = match op {
+        DataValueArithmeticOperator::Plus => rescale_decimal(lhs, lhs_scale, scale)?
+            .checked_add(rescale_decimal(rhs, rhs_scale, scale)?)
+            .ok_or_else(overflow)?,
+        DataValueArithmeticOperator::Minus => rescale_decimal(lhs, lhs_scale, scale)?
+            .checked_sub(rescale_decimal(rhs, rhs_scale, scale)?)
+            .ok_or_else(overflow)?,
+        DataValueArithmeticOperator::Mul => {
+            let product = lhs.checked_mul(rhs).ok_or_else(overflow)?;
+            rescale_decimal(product, lhs_scale + rhs_scale, scale)?
+        }
+        DataValueArithmeticOperator::Div => {
+            // lhs / rhs * 10^scale = lhs * 10^(scale + rhs_scale - lhs_scale) / rhs
+            let numerator = match (scale + rhs_scale).checked_sub(lhs_scale) {
+                Some(exp) => lhs.checked_mul(pow10(exp)?).ok_or_else(overflow)?,
+                None => rescale_decimal(lhs, lhs_scale, scale + rhs_scale)?,
+            };
+            div_round(numerator, rhs)?
+        }
+        DataValueArithmeticOperator::Modulo => {
+            let common = lhs_scale.max(rhs_scale);
+            let divisor = rescale_decimal(rhs, rhs_scale, common)?;
+            ensure!(divisor != 0, "DataValue Error: Decimal division by zero");
+            let remainder = rescale_decimal(lhs, lhs_scale, common)? % divisor;
+            rescale_decimal(remainder, common, scale)?
+        }
+    };
+    check_decimal_precision(value, precision)
+}
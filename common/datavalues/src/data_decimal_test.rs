@@ -0,0 +1,186 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+#[test]
+fn test_decimal_parse_and_format() -> anyhow::Result<()> {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    assert_eq!(12345, parse_decimal("123.45", 5, 2)?);
+    assert_eq!(-12300, parse_decimal("-123", 5, 2)?);
+    assert_eq!(50, parse_decimal(".5", 3, 2)?);
+    // The extra fraction digits are rounded half away from zero.
+    assert_eq!(124, parse_decimal("1.235", 3, 2)?);
+    assert_eq!(-124, parse_decimal("-1.235", 3, 2)?);
+    assert_eq!(
+        "DataValue Error: Decimal value overflow the precision 5",
+        parse_decimal("1234.5", 5, 2).err().unwrap().to_string()
+    );
+    assert_eq!(
+        "DataValue Error: Cannot parse '1.2.3' as Decimal",
+        parse_decimal("1.2.3", 5, 2).err().unwrap().to_string()
+    );
+
+    assert_eq!("123.45", format_decimal(12345, 2));
+    assert_eq!("-0.05", format_decimal(-5, 2));
+    assert_eq!("-123", format_decimal(-123, 0));
+    assert_eq!("0.001", DataValue::Decimal128(Some(1), 10, 3).to_string());
+    assert_eq!("NULL", DataValue::Decimal128(None, 10, 3).to_string());
+    Ok(())
+}
+
+#[test]
+fn test_decimal_array_arithmetic() -> anyhow::Result<()> {
+    use std::sync::Arc;
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    let lhs = decimal_array(vec![Some(110), Some(-250), None], 5, 2)?;
+    let rhs = decimal_array(vec![Some(3), Some(1), Some(1)], 3, 1)?;
+
+    struct ArithmeticTest {
+        op: DataValueArithmeticOperator,
+        expect: DataValue,
+        expect_type: DataType,
+    }
+
+    // 1.10 op 0.3
+    let tests = vec![
+        ArithmeticTest {
+            op: DataValueArithmeticOperator::Plus,
+            expect: DataValue::Decimal128(Some(140), 6, 2),
+            expect_type: DataType::Decimal(6, 2),
+        },
+        ArithmeticTest {
+            op: DataValueArithmeticOperator::Minus,
+            expect: DataValue::Decimal128(Some(80), 6, 2),
+            expect_type: DataType::Decimal(6, 2),
+        },
+        ArithmeticTest {
+            op: DataValueArithmeticOperator::Mul,
+            expect: DataValue::Decimal128(Some(330), 8, 3),
+            expect_type: DataType::Decimal(8, 3),
+        },
+        ArithmeticTest {
+            op: DataValueArithmeticOperator::Div,
+            expect: DataValue::Decimal128(Some(3666667), 38, 6),
+            expect_type: DataType::Decimal(38, 6),
+        },
+        ArithmeticTest {
+            op: DataValueArithmeticOperator::Modulo,
+            expect: DataValue::Decimal128(Some(20), 5, 2),
+            expect_type: DataType::Decimal(5, 2),
+        },
+    ];
+    for t in tests {
+        let result = data_array_arithmetic_op(
+            t.op.clone(),
+            &DataColumnarValue::Array(lhs.clone()),
+            &DataColumnarValue::Array(rhs.clone()),
+        )?;
+        assert_eq!(&t.expect_type, result.data_type());
+        assert_eq!(t.expect, DataValue::try_from_array(&result, 0)?);
+        assert!(result.is_null(2));
+    }
+
+    // The integer is a decimal with scale 0, the float makes the result a float.
+    let result = data_array_arithmetic_op(
+        DataValueArithmeticOperator::Mul,
+        &DataColumnarValue::Array(lhs.clone()),
+        &DataColumnarValue::Scalar(DataValue::UInt8(Some(3))),
+    )?;
+    assert_eq!(
+        DataValue::Decimal128(Some(-750), 8, 2),
+        DataValue::try_from_array(&result, 1)?
+    );
+    let result = data_array_arithmetic_op(
+        DataValueArithmeticOperator::Plus,
+        &DataColumnarValue::Array(lhs.clone()),
+        &DataColumnarValue::Scalar(DataValue::Float64(Some(0.5))),
+    )?;
+    assert_eq!(
+        result.as_any().downcast_ref::<Float64Array>().unwrap(),
+        &Float64Array::from(vec![Some(1.6), Some(-2.0), None])
+    );
+
+    let zero: DataArrayRef = Arc::new(Int32Array::from(vec![0, 0, 0]));
+    let result = data_array_arithmetic_op(
+        DataValueArithmeticOperator::Div,
+        &DataColumnarValue::Array(lhs.clone()),
+        &DataColumnarValue::Array(zero),
+    );
+    assert_eq!(
+        "DataValue Error: Decimal division by zero",
+        result.err().unwrap().to_string()
+    );
+
+    // Comparison in the common scale.
+    let result = data_array_comparison_op(
+        DataValueComparisonOperator::Lt,
+        &DataColumnarValue::Array(lhs),
+        &DataColumnarValue::Scalar(DataValue::String(Some("1.1".to_string()))),
+    )?;
+    assert_eq!(
+        result.as_any().downcast_ref::<BooleanArray>().unwrap(),
+        &BooleanArray::from(vec![Some(false), Some(true), None])
+    );
+    Ok(())
+}
+
+#[test]
+fn test_decimal_aggregate_and_cast() -> anyhow::Result<()> {
+    use std::sync::Arc;
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    let array = decimal_array(vec![Some(110), Some(-250), None, Some(5)], 5, 2)?;
+    assert_eq!(
+        DataValue::Decimal128(Some(-135), 38, 2),
+        data_array_aggregate_op(DataValueAggregateOperator::Sum, array.clone())?
+    );
+    assert_eq!(
+        DataValue::Decimal128(Some(-250), 5, 2),
+        data_array_aggregate_op(DataValueAggregateOperator::Min, array.clone())?
+    );
+    assert_eq!(
+        DataValue::Decimal128(Some(11), 5, 1),
+        data_value_aggregate_op(
+            DataValueAggregateOperator::Max,
+            DataValue::Decimal128(Some(11), 5, 1),
+            DataValue::Decimal128(Some(109), 5, 2),
+        )?
+    );
+
+    // Average is the sum divided by the count.
+    assert_eq!(
+        DataValue::Decimal128(Some(-337500), 38, 6),
+        data_value_arithmetic_op(
+            DataValueArithmeticOperator::Div,
+            data_array_aggregate_op(DataValueAggregateOperator::Sum, array.clone())?,
+            DataValue::UInt64(Some(4)),
+        )?
+    );
+
+    let strings = data_array_cast(&array, &DataType::Utf8)?;
+    assert_eq!(
+        strings.as_any().downcast_ref::<StringArray>().unwrap(),
+        &StringArray::from(vec![Some("1.10"), Some("-2.50"), None, Some("0.05")])
+    );
+    let integers = data_array_cast(&array, &DataType::Int32)?;
+    assert_eq!(
+        integers.as_any().downcast_ref::<Int32Array>().unwrap(),
+        &Int32Array::from(vec![Some(1), Some(-2), None, Some(0)])
+    );
+    let floats: DataArrayRef = Arc::new(Float64Array::from(vec![0.1, 2.345]));
+    let decimals = data_array_cast(&floats, &DataType::Decimal(10, 2))?;
+    assert_eq!(vec![Some(10), Some(235)], decimal_values(&decimals)?);
+    let rescaled = data_array_cast(&decimals, &DataType::Decimal(3, 1))?;
+    assert_eq!(vec![Some(1), Some(24)], decimal_values(&rescaled)?);
+    Ok(())
+}
@@ -0,0 +1,15 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+#[test]
+fn test_data_hash() -> anyhow::Result<()> {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    // The hash is pinned, the states and the buckets of the different builds must be the same.
+    assert_eq!(0xefd0_1f60_ba99_2926, data_hash(b""));
+    assert_eq!(0x9260_d82f_0b9e_ba6d, data_hash(b"datafuse"));
+    Ok(())
+}
@@ -0,0 +1,111 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use anyhow::{anyhow, bail, Result};
+use chrono::{DateTime, NaiveDate, NaiveDateTime};
+use common_arrow::arrow::datatypes::TimeUnit;
+
+use crate::DataType;
+
+const MICROS_PER_SECOND: i64 = 1_000_000;
+const MICROS_PER_MILLI: i64 = 1_000;
+const NANOS_PER_MICRO: i64 = 1_000;
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Date is the days since 1970-01-01, DateTime is the seconds and Timestamp is the microseconds
+/// since 1970-01-01 00:00:00 UTC. The timestamps in milliseconds and nanoseconds, like the ones
+/// in the Parquet files written by the other tools, are only cast to them.
+pub fn is_temporal(data_type: &DataType) -> bool {
+    matches!(
+        data_type,
+        DataType::Date32
+            | DataType::Timestamp(TimeUnit::Second, _)
+            | DataType::Timestamp(TimeUnit::Microsecond, _)
+    )
+}
+
+/// Parse the string into the value of the temporal type.
+/// The date is in `YYYY-MM-DD`, the time is in `YYYY-MM-DD hh:mm:ss[.ffffff]` or RFC 3339,
+/// a time without the time zone is in UTC.
+pub fn parse_temporal(value: &str, data_type: &DataType) -> Result<i64> {
+    let value = value.trim();
+    let micros = match parse_date_time(value) {
+        Some(v) => v.timestamp() * MICROS_PER_SECOND + v.timestamp_subsec_micros() as i64,
+        None => bail!(
+            "DataValue Error: Cannot parse '{}' as {}",
+            value,
+            temporal_name(data_type)
+        ),
+    };
+    temporal_from_micros(micros, data_type)
+}
+
+/// Format the value of the temporal type, the time is in UTC.
+pub fn format_temporal(value: i64, data_type: &DataType) -> Result<String> {
+    let micros = temporal_to_micros(value, data_type)?;
+    let seconds = micros.div_euclid(MICROS_PER_SECOND);
+    let nanos = (micros.rem_euclid(MICROS_PER_SECOND) * 1000) as u32;
+    let time = NaiveDateTime::from_timestamp_opt(seconds, nanos)
+        .ok_or_else(|| out_of_range(value, data_type))?;
+    Ok(match data_type {
+        DataType::Date32 => time.format("%Y-%m-%d").to_string(),
+        DataType::Timestamp(TimeUnit::Second, _) => time.format("%Y-%m-%d %H:%M:%S").to_string(),
+        _ => time.format("%Y-%m-%d %H:%M:%S%.6f").to_string(),
+    })
+}
+
+/// Convert the value of the temporal type to the microseconds since the epoch.
+/// The timestamps in milliseconds and nanoseconds are converted as well, the nanoseconds are
+/// rounded down.
+pub fn temporal_to_micros(value: i64, data_type: &DataType) -> Result<i64> {
+    let micros = match data_type {
+        DataType::Date32 => value.checked_mul(SECONDS_PER_DAY * MICROS_PER_SECOND),
+        DataType::Timestamp(TimeUnit::Second, _) => value.checked_mul(MICROS_PER_SECOND),
+        DataType::Timestamp(TimeUnit::Millisecond, _) => value.checked_mul(MICROS_PER_MILLI),
+        DataType::Timestamp(TimeUnit::Microsecond, _) => Some(value),
+        DataType::Timestamp(TimeUnit::Nanosecond, _) => Some(value.div_euclid(NANOS_PER_MICRO)),
+        other => bail!("DataValue Error: {:?} is not a temporal type", other),
+    };
+    micros.ok_or_else(|| out_of_range(value, data_type))
+}
+
+/// Convert the microseconds since the epoch to the value of the temporal type, rounding down.
+pub fn temporal_from_micros(micros: i64, data_type: &DataType) -> Result<i64> {
+    Ok(match data_type {
+        DataType::Date32 => micros.div_euclid(SECONDS_PER_DAY * MICROS_PER_SECOND),
+        DataType::Timestamp(TimeUnit::Second, _) => micros.div_euclid(MICROS_PER_SECOND),
+        DataType::Timestamp(TimeUnit::Microsecond, _) => micros,
+        other => bail!("DataValue Error: {:?} is not a temporal type", other),
+    })
+}
+
+fn temporal_name(data_type: &DataType) -> &'static str {
+    match data_type {
+        DataType::Date32 => "Date",
+        DataType::Timestamp(TimeUnit::Second, _) => "DateTime",
+        _ => "Timestamp",
+    }
+}
+
+fn out_of_range(value: i64, data_type: &DataType) -> anyhow::Error {
+    anyhow!(
+        "DataValue Error: {} is out of the range of {}",
+        value,
+        temporal_name(data_type)
+    )
+}
+
+fn parse_date_time(value: &str) -> Option<NaiveDateTime> {
+    if let Ok(v) = DateTime::parse_from_rfc3339(value) {
+        return Some(v.naive_utc());
+    }
+    for format in &["%Y-%m-%d %H:%M:%S%.f", "%Y-%m-%dT%H:%M:%S%.f"] {
+        if let Ok(v) = NaiveDateTime::parse_from_str(value, format) {
+            return Some(v);
+        }
+    }
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .ok()
+        .map(|v| v.and_hms(0, 0, 0))
+}
@@ -7,7 +7,11 @@ use std::cmp;
 use anyhow::{bail, Result};
 use common_arrow::arrow::datatypes;
 use common_arrow::arrow::datatypes::DataType::*;
+use common_arrow::arrow::datatypes::TimeUnit;
 
+use crate::data_decimal::MAX_DECIMAL_PRECISION;
+use crate::data_decimal::{decimal_arithmetic_type, integer_decimal_type, is_decimal};
+use crate::data_temporal::is_temporal;
 use crate::DataValueArithmeticOperator;
 
 pub type DataType = datatypes::DataType;
@@ -226,6 +230,21 @@ pub fn numerical_arithmetic_coercion(
     lhs_type: &DataType,
     rhs_type: &DataType,
 ) -> Result<DataType> {
+    // NULL is NULL of the type of the other side.
+    match (lhs_type, rhs_type) {
+        (Null, Null) => return Ok(Null),
+        (Null, rhs) => return numerical_arithmetic_coercion(op, rhs, rhs),
+        (lhs, Null) => return numerical_arithmetic_coercion(op, lhs, lhs),
+        _ => {}
+    }
+
+    if is_decimal(lhs_type) || is_decimal(rhs_type) {
+        return match (lhs_type, rhs_type) {
+            (lhs, rhs) if is_floating(lhs) || is_floating(rhs) => Ok(Float64),
+            (lhs, rhs) => decimal_arithmetic_type(op, lhs, rhs),
+        };
+    }
+
     // error on any non-numeric type
     if !is_numeric(lhs_type) || !is_numeric(rhs_type) {
         bail!(format!(
@@ -262,6 +281,43 @@ pub fn numerical_arithmetic_coercion(
     }
 }
 
+/// Coercion rules for temporal types: the strings are parsed as the temporal type,
+/// the date is widened to the time, and the time to the timestamp.
+pub fn temporal_coercion(lhs_type: &DataType, rhs_type: &DataType) -> Result<DataType> {
+    match (lhs_type, rhs_type) {
+        (lhs, Utf8) if is_temporal(lhs) => Ok(lhs.clone()),
+        (Utf8, rhs) if is_temporal(rhs) => Ok(rhs.clone()),
+        (Date32, rhs) if is_temporal(rhs) => Ok(rhs.clone()),
+        (lhs, Date32) if is_temporal(lhs) => Ok(lhs.clone()),
+        (Timestamp(_, _), Timestamp(_, _)) if is_temporal(lhs_type) && is_temporal(rhs_type) => {
+            Ok(Timestamp(TimeUnit::Microsecond, None))
+        }
+        _ => bail!("Can't construct type from {} and {}", lhs_type, rhs_type),
+    }
+}
+
+/// Coercion rules for decimal types: the decimal holds the integer and fraction digits of both sides,
+/// the strings are parsed as the decimal, and a float makes it a float.
+pub fn decimal_coercion(lhs_type: &DataType, rhs_type: &DataType) -> Result<DataType> {
+    if !is_decimal(lhs_type) && !is_decimal(rhs_type) {
+        bail!("Can't construct type from {} and {}", lhs_type, rhs_type);
+    }
+
+    match (lhs_type, rhs_type) {
+        (lhs, Utf8) => Ok(lhs.clone()),
+        (Utf8, rhs) => Ok(rhs.clone()),
+        (lhs, rhs) if is_floating(lhs) || is_floating(rhs) => Ok(Float64),
+        (lhs, rhs) => match (integer_decimal_type(lhs)?, integer_decimal_type(rhs)?) {
+            (Decimal(p1, s1), Decimal(p2, s2)) => {
+                let scale = cmp::max(s1, s2);
+                let precision = cmp::max(p1 - s1, p2 - s2) + scale;
+                Ok(Decimal(cmp::min(precision, MAX_DECIMAL_PRECISION), scale))
+            }
+            _ => bail!("Can't construct type from {} and {}", lhs_type, rhs_type),
+        },
+    }
+}
+
 // coercion rules for equality operations. This is a superset of all numerical coercion rules.
 pub fn equal_coercion(lhs_type: &DataType, rhs_type: &DataType) -> Result<DataType> {
     if lhs_type == rhs_type {
@@ -269,7 +325,18 @@ pub fn equal_coercion(lhs_type: &DataType, rhs_type: &DataType) -> Result<DataTy
         return Ok(lhs_type.clone());
     }
 
-    numerical_coercion(lhs_type, rhs_type).or_else(|_| dictionary_coercion(lhs_type, rhs_type))
+    // NULL is NULL of the type of the other side.
+    if lhs_type == &Null {
+        return Ok(rhs_type.clone());
+    }
+    if rhs_type == &Null {
+        return Ok(lhs_type.clone());
+    }
+
+    numerical_coercion(lhs_type, rhs_type)
+        .or_else(|_| decimal_coercion(lhs_type, rhs_type))
+        .or_else(|_| temporal_coercion(lhs_type, rhs_type))
+        .or_else(|_| dictionary_coercion(lhs_type, rhs_type))
 }
 
 // coercion rules that assume an ordered set, such as "less than".
@@ -281,7 +348,17 @@ pub fn order_coercion(lhs_type: &DataType, rhs_type: &DataType) -> Result<DataTy
         return Ok(lhs_type.clone());
     }
 
+    // NULL is NULL of the type of the other side.
+    if lhs_type == &Null {
+        return Ok(rhs_type.clone());
+    }
+    if rhs_type == &Null {
+        return Ok(lhs_type.clone());
+    }
+
     numerical_coercion(lhs_type, rhs_type)
         .or_else(|_| string_coercion(lhs_type, rhs_type))
+        .or_else(|_| decimal_coercion(lhs_type, rhs_type))
+        .or_else(|_| temporal_coercion(lhs_type, rhs_type))
         .or_else(|_| dictionary_coercion(lhs_type, rhs_type))
 }
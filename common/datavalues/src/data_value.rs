@@ -7,11 +7,17 @@ use std::fmt;
 use std::sync::Arc;
 
 use anyhow::{bail, Error, Result};
+use common_arrow::arrow;
+use common_arrow::arrow::datatypes::TimeUnit;
 use serde::{Deserialize, Serialize};
 
+use crate::data_array_cast::{decimal_array, decimal_values, string_list_array};
+use crate::data_decimal::format_decimal;
+use crate::data_temporal::format_temporal;
 use crate::{
-    BooleanArray, DataArrayRef, DataType, Float32Array, Float64Array, Int16Array, Int32Array,
-    Int64Array, Int8Array, NullArray, StringArray, UInt16Array, UInt32Array, UInt64Array,
+    BooleanArray, DataArrayRef, DataField, DataType, Date32Array, Float32Array, Float64Array,
+    Int16Array, Int32Array, Int64Array, Int8Array, ListArray, NullArray, StringArray,
+    TimestampMicrosecondArray, TimestampSecondArray, UInt16Array, UInt32Array, UInt64Array,
     UInt8Array,
 };
 
@@ -31,6 +37,16 @@ pub enum DataValue {
     Float32(Option<f32>),
     Float64(Option<f64>),
     String(Option<String>),
+    /// The days since 1970-01-01.
+    Date32(Option<i32>),
+    /// The seconds since 1970-01-01 00:00:00 UTC.
+    TimestampSecond(Option<i64>),
+    /// The microseconds since 1970-01-01 00:00:00 UTC.
+    TimestampMicrosecond(Option<i64>),
+    /// The unscaled value of the decimal with the precision and scale.
+    Decimal128(Option<i128>, usize, usize),
+    /// The list of the values with the item type.
+    List(Option<Vec<DataValue>>, DataType),
     Struct(Vec<DataValue>),
 }
 
@@ -40,7 +56,8 @@ impl DataValue {
     pub fn is_null(&self) -> bool {
         matches!(
             self,
-            DataValue::Boolean(None)
+            DataValue::Null
+                | DataValue::Boolean(None)
                 | DataValue::Int8(None)
                 | DataValue::Int16(None)
                 | DataValue::Int32(None)
@@ -52,6 +69,11 @@ impl DataValue {
                 | DataValue::Float32(None)
                 | DataValue::Float64(None)
                 | DataValue::String(None)
+                | DataValue::Date32(None)
+                | DataValue::TimestampSecond(None)
+                | DataValue::TimestampMicrosecond(None)
+                | DataValue::Decimal128(None, _, _)
+                | DataValue::List(None, _)
         )
     }
 
@@ -70,6 +92,13 @@ impl DataValue {
             DataValue::Float32(_) => DataType::Float32,
             DataValue::Float64(_) => DataType::Float64,
             DataValue::String(_) => DataType::Utf8,
+            DataValue::Date32(_) => DataType::Date32,
+            DataValue::TimestampSecond(_) => DataType::Timestamp(TimeUnit::Second, None),
+            DataValue::TimestampMicrosecond(_) => DataType::Timestamp(TimeUnit::Microsecond, None),
+            DataValue::Decimal128(_, precision, scale) => DataType::Decimal(*precision, *scale),
+            DataValue::List(_, item_type) => {
+                DataType::List(Box::new(DataField::new("item", item_type.clone(), true)))
+            }
             DataValue::Struct(_) => unimplemented!(),
         }
     }
@@ -109,33 +138,47 @@ impl DataValue {
     pub fn to_array(&self, size: usize) -> Result<DataArrayRef> {
         Ok(match self {
             DataValue::Null => Arc::new(NullArray::new(size)),
-            DataValue::Boolean(Some(v)) => {
-                Arc::new(BooleanArray::from(vec![*v; size])) as DataArrayRef
-            }
-            DataValue::Int8(Some(v)) => Arc::new(Int8Array::from(vec![*v; size])) as DataArrayRef,
-            DataValue::Int16(Some(v)) => Arc::new(Int16Array::from(vec![*v; size])) as DataArrayRef,
-            DataValue::Int32(Some(v)) => Arc::new(Int32Array::from(vec![*v; size])) as DataArrayRef,
-            DataValue::Int64(Some(v)) => Arc::new(Int64Array::from(vec![*v; size])) as DataArrayRef,
-            DataValue::UInt8(Some(v)) => Arc::new(UInt8Array::from(vec![*v; size])) as DataArrayRef,
-            DataValue::UInt16(Some(v)) => {
-                Arc::new(UInt16Array::from(vec![*v; size])) as DataArrayRef
-            }
-            DataValue::UInt32(Some(v)) => {
-                Arc::new(UInt32Array::from(vec![*v; size])) as DataArrayRef
-            }
-            DataValue::UInt64(Some(v)) => {
-                Arc::new(UInt64Array::from(vec![*v; size])) as DataArrayRef
+            DataValue::Boolean(v) => Arc::new(BooleanArray::from(vec![*v; size])) as DataArrayRef,
+            DataValue::Int8(v) => Arc::new(Int8Array::from(vec![*v; size])) as DataArrayRef,
+            DataValue::Int16(v) => Arc::new(Int16Array::from(vec![*v; size])) as DataArrayRef,
+            DataValue::Int32(v) => Arc::new(Int32Array::from(vec![*v; size])) as DataArrayRef,
+            DataValue::Int64(v) => Arc::new(Int64Array::from(vec![*v; size])) as DataArrayRef,
+            DataValue::UInt8(v) => Arc::new(UInt8Array::from(vec![*v; size])) as DataArrayRef,
+            DataValue::UInt16(v) => Arc::new(UInt16Array::from(vec![*v; size])) as DataArrayRef,
+            DataValue::UInt32(v) => Arc::new(UInt32Array::from(vec![*v; size])) as DataArrayRef,
+            DataValue::UInt64(v) => Arc::new(UInt64Array::from(vec![*v; size])) as DataArrayRef,
+            DataValue::Float32(v) => Arc::new(Float32Array::from(vec![*v; size])) as DataArrayRef,
+            DataValue::Float64(v) => Arc::new(Float64Array::from(vec![*v; size])) as DataArrayRef,
+            DataValue::String(v) => Arc::new(StringArray::from(vec![v.as_deref(); size])),
+            DataValue::Date32(v) => Arc::new(Date32Array::from(vec![*v; size])) as DataArrayRef,
+            DataValue::TimestampSecond(v) => {
+                Arc::new(TimestampSecondArray::from_opt_vec(vec![*v; size], None)) as DataArrayRef
             }
-            DataValue::Float32(Some(v)) => {
-                Arc::new(Float32Array::from(vec![*v; size])) as DataArrayRef
+            DataValue::TimestampMicrosecond(v) => Arc::new(TimestampMicrosecondArray::from_opt_vec(
+                vec![*v; size],
+                None,
+            )) as DataArrayRef,
+            DataValue::Decimal128(v, precision, scale) => {
+                decimal_array(vec![*v; size], *precision, *scale)?
             }
-            DataValue::Float64(Some(v)) => {
-                Arc::new(Float64Array::from(vec![*v; size])) as DataArrayRef
+            DataValue::List(v, DataType::Utf8) => {
+                let items = v
+                    .as_ref()
+                    .map(|items| {
+                        items
+                            .iter()
+                            .map(|item| match item {
+                                DataValue::String(v) => Ok(v.as_deref()),
+                                other => bail!("DataValue Error: {:?} is not a string", other),
+                            })
+                            .collect::<Result<Vec<_>>>()
+                    })
+                    .transpose()?;
+                string_list_array(&vec![items; size])?
             }
-            DataValue::String(v) => Arc::new(StringArray::from(vec![v.as_deref(); size])),
             other => {
                 bail!(format!(
-                    "DataValue Error: DataValue to array cannot be NONE {:?}",
+                    "DataValue Error: DataValue to array is unsupported for {:?}",
                     other
                 ));
             }
@@ -145,6 +188,7 @@ impl DataValue {
     /// Converts a value in `array` at `index` into a ScalarValue
     pub fn try_from_array(array: &DataArrayRef, index: usize) -> Result<Self> {
         Ok(match array.data_type() {
+            DataType::Null => DataValue::Null,
             DataType::Boolean => {
                 typed_cast_from_array_to_data_value!(array, index, BooleanArray, Boolean)
             }
@@ -179,6 +223,39 @@ impl DataValue {
             DataType::Utf8 => {
                 typed_cast_from_array_to_data_value!(array, index, StringArray, String)
             }
+            DataType::Date32 => {
+                typed_cast_from_array_to_data_value!(array, index, Date32Array, Date32)
+            }
+            DataType::Timestamp(TimeUnit::Second, _) => typed_cast_from_array_to_data_value!(
+                array,
+                index,
+                TimestampSecondArray,
+                TimestampSecond
+            ),
+            DataType::Timestamp(TimeUnit::Microsecond, _) => typed_cast_from_array_to_data_value!(
+                array,
+                index,
+                TimestampMicrosecondArray,
+                TimestampMicrosecond
+            ),
+            DataType::Decimal(precision, scale) => {
+                DataValue::Decimal128(decimal_values(array)?[index], *precision, *scale)
+            }
+            DataType::List(field) => {
+                let list = downcast_array!(array, ListArray)?;
+                let items = match list.is_null(index) {
+                    true => None,
+                    false => {
+                        let items = list.value(index);
+                        Some(
+                            (0..items.len())
+                                .map(|i| DataValue::try_from_array(&items, i))
+                                .collect::<Result<Vec<_>>>()?,
+                        )
+                    }
+                };
+                DataValue::List(items, field.data_type().clone())
+            }
             other => {
                 bail!(format!(
                     "DataValue Error: Can't create a scalar of array of type \"{:?}\"",
@@ -188,6 +265,34 @@ impl DataValue {
         })
     }
 
+    /// Build an array from the values, all the values must have the same type.
+    pub fn try_into_data_array(values: &[DataValue]) -> Result<DataArrayRef> {
+        if values.is_empty() {
+            bail!("DataValue Error: Can't create an array from empty values");
+        }
+
+        if let DataValue::Decimal128(_, precision, scale) = values[0] {
+            let values = values
+                .iter()
+                .map(|value| match value {
+                    DataValue::Decimal128(v, _, _) => Ok(*v),
+                    other => bail!("DataValue Error: {:?} is not a decimal value", other),
+                })
+                .collect::<Result<Vec<_>>>()?;
+            return decimal_array(values, precision, scale);
+        }
+
+        let arrays = values
+            .iter()
+            .map(|value| value.to_array(1))
+            .collect::<Result<Vec<_>>>()?;
+        let arrays = arrays
+            .iter()
+            .map(|array| array.as_ref())
+            .collect::<Vec<_>>();
+        Ok(arrow::compute::concat(&arrays)?)
+    }
+
     pub fn try_from_literal(literal: &str) -> Result<Self> {
         match literal.parse::<i64>() {
             Ok(n) => {
@@ -204,8 +309,8 @@ impl DataValue {
 
 typed_cast_from_data_value_to_std!(Int8, i8);
 typed_cast_from_data_value_to_std!(Int16, i16);
-typed_cast_from_data_value_to_std!(Int32, i32);
-typed_cast_from_data_value_to_std!(Int64, i64);
+typed_cast_from_data_value_to_std!(Int32 | Date32, i32);
+typed_cast_from_data_value_to_std!(Int64 | TimestampSecond | TimestampMicrosecond, i64);
 typed_cast_from_data_value_to_std!(UInt8, u8);
 typed_cast_from_data_value_to_std!(UInt16, u16);
 typed_cast_from_data_value_to_std!(UInt32, u32);
@@ -231,6 +336,12 @@ impl TryFrom<&DataType> for DataValue {
             DataType::UInt64 => DataValue::UInt64(None),
             DataType::Float32 => DataValue::Float32(None),
             DataType::Float64 => DataValue::Float64(None),
+            DataType::Utf8 => DataValue::String(None),
+            DataType::Date32 => DataValue::Date32(None),
+            DataType::Timestamp(TimeUnit::Second, _) => DataValue::TimestampSecond(None),
+            DataType::Timestamp(TimeUnit::Microsecond, _) => DataValue::TimestampMicrosecond(None),
+            DataType::Decimal(precision, scale) => DataValue::Decimal128(None, *precision, *scale),
+            DataType::List(field) => DataValue::List(None, field.data_type().clone()),
             _ => {
                 bail!(format!(
                     "DataValue Error: Unsupported try_from() for data type: {:?}",
@@ -241,6 +352,45 @@ impl TryFrom<&DataType> for DataValue {
     }
 }
 
+impl DataValue {
+    fn format_temporal(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let value = match self {
+            DataValue::Date32(v) => v.map(|v| v as i64),
+            DataValue::TimestampSecond(v) | DataValue::TimestampMicrosecond(v) => *v,
+            _ => None,
+        };
+        // The value out of the range is written as it is.
+        match value {
+            Some(v) => match format_temporal(v, &self.data_type()) {
+                Ok(time) => write!(f, "{}", time),
+                Err(_) => write!(f, "{}", v),
+            },
+            None => write!(f, "NULL"),
+        }
+    }
+}
+
+impl DataValue {
+    /// The list is like `['a', NULL, 'b']`.
+    fn format_list(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let items = match self {
+            DataValue::List(Some(items), _) => items,
+            _ => return write!(f, "NULL"),
+        };
+        write!(f, "[")?;
+        for (i, item) in items.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            match item {
+                DataValue::String(Some(v)) => write!(f, "'{}'", v)?,
+                other => write!(f, "{}", other)?,
+            }
+        }
+        write!(f, "]")
+    }
+}
+
 impl fmt::Display for DataValue {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
@@ -257,6 +407,14 @@ impl fmt::Display for DataValue {
             DataValue::UInt32(v) => format_data_value_with_option!(f, v),
             DataValue::UInt64(v) => format_data_value_with_option!(f, v),
             DataValue::String(v) => format_data_value_with_option!(f, v),
+            DataValue::Date32(_)
+            | DataValue::TimestampSecond(_)
+            | DataValue::TimestampMicrosecond(_) => self.format_temporal(f),
+            DataValue::Decimal128(v, _, scale) => match v {
+                Some(v) => write!(f, "{}", format_decimal(*v, *scale)),
+                None => write!(f, "NULL"),
+            },
+            DataValue::List(_, _) => self.format_list(f),
             DataValue::Struct(v) => write!(f, "{:?}", v),
         }
     }
@@ -278,6 +436,14 @@ impl fmt::Debug for DataValue {
             DataValue::Float32(v) => format_data_value_with_option!(f, v),
             DataValue::Float64(v) => format_data_value_with_option!(f, v),
             DataValue::String(v) => format_data_value_with_option!(f, v),
+            DataValue::Date32(_)
+            | DataValue::TimestampSecond(_)
+            | DataValue::TimestampMicrosecond(_) => self.format_temporal(f),
+            DataValue::Decimal128(v, _, scale) => match v {
+                Some(v) => write!(f, "{}", format_decimal(*v, *scale)),
+                None => write!(f, "NULL"),
+            },
+            DataValue::List(_, _) => self.format_list(f),
             DataValue::Struct(v) => write!(f, "{:?}", v),
         }
     }
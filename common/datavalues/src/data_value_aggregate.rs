@@ -2,9 +2,14 @@
 //
 // SPDX-License-Identifier: Apache-2.0.
 
+use std::cmp::Ordering;
+
 use anyhow::{bail, Result};
 
-use crate::{DataValue, DataValueAggregateOperator};
+use crate::data_decimal::decimal_cmp;
+use crate::{
+    data_value_arithmetic_op, DataValue, DataValueAggregateOperator, DataValueArithmeticOperator,
+};
 
 pub fn data_value_aggregate_op(
     op: DataValueAggregateOperator,
@@ -154,6 +159,38 @@ pub fn data_value_aggregate_op(
                 ));
             }
         },
+        (DataValue::Decimal128(lhs, _, lhs_scale), DataValue::Decimal128(rhs, _, rhs_scale)) => {
+            match op {
+                DataValueAggregateOperator::Min | DataValueAggregateOperator::Max => {
+                    match (lhs, rhs) {
+                        (None, _) => right,
+                        (_, None) => left,
+                        (Some(lhs), Some(rhs)) => {
+                            let ordering = decimal_cmp(*lhs, *lhs_scale, *rhs, *rhs_scale);
+                            match (op, ordering) {
+                                (DataValueAggregateOperator::Min, Ordering::Greater) => right,
+                                (DataValueAggregateOperator::Max, Ordering::Less) => right,
+                                _ => left,
+                            }
+                        }
+                    }
+                }
+                DataValueAggregateOperator::Sum => match (lhs, rhs) {
+                    (None, _) => right,
+                    (_, None) => left,
+                    _ => data_value_arithmetic_op(DataValueArithmeticOperator::Plus, left, right)?,
+                },
+                DataValueAggregateOperator::Count => DataValue::UInt64(Some(1)),
+                _ => {
+                    bail!(format!(
+                        "DataValue Error: Unsupported data_value_{} for data type: left:{:?}, right:{:?}",
+                        op,
+                        left.data_type(),
+                        right.data_type()
+                    ));
+                }
+            }
+        }
         (DataValue::String(lhs), DataValue::String(rhs)) => match op {
             DataValueAggregateOperator::Min => {
                 typed_data_value_min_max_string!(lhs, rhs, String, min)
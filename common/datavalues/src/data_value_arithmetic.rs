@@ -2,9 +2,14 @@
 //
 // SPDX-License-Identifier: Apache-2.0.
 
+use std::convert::TryFrom;
+
 use anyhow::{bail, Result};
 
-use crate::{DataValue, DataValueArithmeticOperator};
+use crate::{
+    data_array_arithmetic_op, numerical_arithmetic_coercion, DataColumnarValue, DataValue,
+    DataValueArithmeticOperator,
+};
 
 macro_rules! typed_data_value_operator {
     ($OP: expr, $LHS:expr, $RHS:expr, $SCALAR:ident, $TYPE:ident) => {{
@@ -33,9 +38,25 @@ pub fn data_value_arithmetic_op(
     left: DataValue,
     right: DataValue,
 ) -> Result<DataValue> {
+    // NULL in the arithmetic is NULL.
+    if left.is_null() || right.is_null() {
+        return Ok(
+            numerical_arithmetic_coercion(&op, &left.data_type(), &right.data_type())
+                .and_then(|data_type| DataValue::try_from(&data_type))
+                .unwrap_or(DataValue::Null),
+        );
+    }
+
     Ok(match (&left, &right) {
-        (DataValue::Null, _) => right,
-        (_, DataValue::Null) => left,
+        // Decimal.
+        (DataValue::Decimal128(..), _) | (_, DataValue::Decimal128(..)) => {
+            let array = data_array_arithmetic_op(
+                op,
+                &DataColumnarValue::Scalar(left.clone()),
+                &DataColumnarValue::Scalar(right.clone()),
+            )?;
+            DataValue::try_from_array(&array, 0)?
+        }
         _ => match (&left, &right) {
             // Float.
             (DataValue::Float64(lhs), DataValue::Float64(rhs)) => {
@@ -0,0 +1,65 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+#[test]
+fn test_data_value_try_into_data_array() -> anyhow::Result<()> {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    let values = vec![
+        DataValue::UInt64(Some(1)),
+        DataValue::UInt64(Some(3)),
+        DataValue::UInt64(Some(2)),
+    ];
+    let array = DataValue::try_into_data_array(&values)?;
+    let actual = array.as_any().downcast_ref::<UInt64Array>().unwrap();
+    assert_eq!(actual.clone(), UInt64Array::from(vec![1, 3, 2]));
+
+    let values = vec![
+        DataValue::String(Some("x".to_string())),
+        DataValue::String(Some("y".to_string())),
+    ];
+    let array = DataValue::try_into_data_array(&values)?;
+    let actual = array.as_any().downcast_ref::<StringArray>().unwrap();
+    assert_eq!(actual.clone(), StringArray::from(vec!["x", "y"]));
+
+    let result = DataValue::try_into_data_array(&[]);
+    assert_eq!(
+        "DataValue Error: Can't create an array from empty values",
+        format!("{}", result.err().unwrap())
+    );
+    Ok(())
+}
+
+#[test]
+fn test_data_value_list() -> anyhow::Result<()> {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    let array = string_list_array(&[Some(vec![Some("a"), None]), None])?;
+    let value = DataValue::try_from_array(&array, 0)?;
+    assert_eq!(
+        DataValue::List(
+            Some(vec![
+                DataValue::String(Some("a".to_string())),
+                DataValue::String(None)
+            ]),
+            DataType::Utf8
+        ),
+        value
+    );
+    assert_eq!("['a', NULL]", value.to_string());
+    assert_eq!(array.data_type(), &value.data_type());
+    assert!(DataValue::try_from_array(&array, 1)?.is_null());
+
+    // The lists are casted to the strings like the literals.
+    let strings = data_array_cast(&value.to_array(2)?, &DataType::Utf8)?;
+    assert_eq!(
+        strings.as_any().downcast_ref::<StringArray>().unwrap(),
+        &StringArray::from(vec!["['a', NULL]", "['a', NULL]"])
+    );
+    Ok(())
+}
@@ -7,13 +7,23 @@ mod data_array_aggregate_test;
 #[cfg(test)]
 mod data_array_arithmetic_test;
 #[cfg(test)]
+mod data_array_cast_test;
+#[cfg(test)]
 mod data_array_comparison_test;
 #[cfg(test)]
 mod data_array_logic_test;
 #[cfg(test)]
+mod data_array_select_test;
+#[cfg(test)]
+mod data_decimal_test;
+#[cfg(test)]
+mod data_hash_test;
+#[cfg(test)]
 mod data_value_aggregate_test;
 #[cfg(test)]
 mod data_value_arithmetic_test;
+#[cfg(test)]
+mod data_value_test;
 
 #[macro_use]
 mod macros;
@@ -21,11 +31,16 @@ mod macros;
 mod data_array;
 mod data_array_aggregate;
 mod data_array_arithmetic;
+mod data_array_cast;
 mod data_array_comparison;
 mod data_array_logic;
+mod data_array_select;
 mod data_columnar_value;
+mod data_decimal;
 mod data_field;
+mod data_hash;
 mod data_schema;
+mod data_temporal;
 mod data_type;
 mod data_value;
 mod data_value_aggregate;
@@ -33,18 +48,36 @@ mod data_value_arithmetic;
 mod data_value_operator;
 
 pub use crate::data_array::{
-    BooleanArray, DataArrayRef, Float32Array, Float64Array, Int16Array, Int32Array, Int64Array,
-    Int8Array, NullArray, StringArray, UInt16Array, UInt32Array, UInt64Array, UInt8Array,
+    BooleanArray, DataArrayRef, Date32Array, DecimalArray, DecimalBuilder, Float32Array,
+    Float64Array, Int16Array, Int32Array, Int64Array, Int8Array, ListArray, NullArray, StringArray,
+    StringBuilder, TimestampMicrosecondArray, TimestampSecondArray, UInt16Array, UInt32Array,
+    UInt64Array, UInt8Array,
 };
 pub use crate::data_array_aggregate::data_array_aggregate_op;
 pub use crate::data_array_arithmetic::data_array_arithmetic_op;
+pub use crate::data_array_cast::{
+    data_array_cast, decimal_array, decimal_values, string_list_array, temporal_array,
+    temporal_values,
+};
 pub use crate::data_array_comparison::data_array_comparison_op;
-pub use crate::data_array_logic::data_array_logic_op;
+pub use crate::data_array_logic::{data_array_logic_not, data_array_logic_op};
+pub use crate::data_array_select::data_array_select;
 pub use crate::data_columnar_value::DataColumnarValue;
+pub use crate::data_decimal::{
+    check_decimal_type, format_decimal, is_decimal, parse_decimal, MAX_DECIMAL_PRECISION,
+};
 pub use crate::data_field::DataField;
+pub use crate::data_hash::data_hash;
 pub use crate::data_schema::{DataSchema, DataSchemaRef};
+pub use crate::data_temporal::{
+    format_temporal, is_temporal, parse_temporal, temporal_from_micros, temporal_to_micros,
+};
+pub use crate::data_type::decimal_coercion;
+pub use crate::data_type::equal_coercion;
+pub use crate::data_type::is_integer;
 pub use crate::data_type::numerical_arithmetic_coercion;
 pub use crate::data_type::numerical_coercion;
+pub use crate::data_type::temporal_coercion;
 pub use crate::data_type::DataType;
 pub use crate::data_value::{DataValue, DataValueRef};
 pub use crate::data_value_aggregate::data_value_aggregate_op;
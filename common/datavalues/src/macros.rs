@@ -112,6 +112,13 @@ macro_rules! arrow_array_op {
             DataType::Float32 => compute_op!($LEFT, $RIGHT, $OP, Float32Array),
             DataType::Float64 => compute_op!($LEFT, $RIGHT, $OP, Float64Array),
             DataType::Utf8 => compute_utf8_op!($LEFT, $RIGHT, $OP, StringArray),
+            DataType::Date32 => compute_op!($LEFT, $RIGHT, $OP, Date32Array),
+            DataType::Timestamp(common_arrow::arrow::datatypes::TimeUnit::Second, _) => {
+                compute_op!($LEFT, $RIGHT, $OP, TimestampSecondArray)
+            }
+            DataType::Timestamp(common_arrow::arrow::datatypes::TimeUnit::Microsecond, _) => {
+                compute_op!($LEFT, $RIGHT, $OP, TimestampMicrosecondArray)
+            }
             _ => anyhow::bail!(format!(
                 "Unsupported arithmetic_compute::{} for data type: {:?}",
                 stringify!($OP),
@@ -169,6 +176,13 @@ macro_rules! arrow_array_op_scalar {
             DataType::Float32 => compute_op_scalar!($LEFT, $RIGHT, $OP, Float32Array),
             DataType::Float64 => compute_op_scalar!($LEFT, $RIGHT, $OP, Float64Array),
             DataType::Utf8 => compute_utf8_op_scalar!($LEFT, $RIGHT, $OP, StringArray),
+            DataType::Date32 => compute_op_scalar!($LEFT, $RIGHT, $OP, Date32Array),
+            DataType::Timestamp(common_arrow::arrow::datatypes::TimeUnit::Second, _) => {
+                compute_op_scalar!($LEFT, $RIGHT, $OP, TimestampSecondArray)
+            }
+            DataType::Timestamp(common_arrow::arrow::datatypes::TimeUnit::Microsecond, _) => {
+                compute_op_scalar!($LEFT, $RIGHT, $OP, TimestampMicrosecondArray)
+            }
             other => Err(anyhow::Error::msg(format!(
                 "DataValue Error: Unsupported data type {:?}",
                 other
@@ -315,13 +329,13 @@ macro_rules! typed_cast_from_array_to_data_value {
 }
 
 macro_rules! typed_cast_from_data_value_to_std {
-    ($SCALAR:ident, $NATIVE:ident) => {
+    ($($SCALAR:ident)|+, $NATIVE:ident) => {
         impl TryFrom<DataValue> for $NATIVE {
             type Error = anyhow::Error;
 
             fn try_from(value: DataValue) -> anyhow::Result<Self> {
                 match value {
-                    DataValue::$SCALAR(Some(inner_value)) => Ok(inner_value),
+                    $(DataValue::$SCALAR(Some(inner_value)))|+ => Ok(inner_value),
                     _ => anyhow::bail!(format!(
                         "DataValue Error:  Cannot convert {:?} to {}",
                         value,
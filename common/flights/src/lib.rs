@@ -3,9 +3,11 @@
 // SPDX-License-Identifier: Apache-2.0.
 
 pub mod query_do_action;
+pub mod query_do_exchange;
 pub mod query_do_get;
 pub mod store_do_action;
 pub mod store_do_get;
+pub mod store_do_put;
 
 // ProtoBuf generated files.
 #[allow(clippy::all)]
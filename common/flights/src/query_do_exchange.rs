@@ -0,0 +1,67 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+/// Actions for query do_exchange.
+use std::convert::TryInto;
+use std::io::Cursor;
+
+use common_arrow::arrow_flight::flight_descriptor::DescriptorType;
+use common_arrow::arrow_flight::{FlightData, FlightDescriptor};
+use prost::Message;
+
+use crate::protobuf::FlightQueryRequest;
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+pub struct ShuffleAction {
+    /// The exchange id, the blocks are routed to the join bucket by it.
+    pub id: String,
+}
+
+// Action wrapper for do_exchange.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+pub enum DoExchangeAction {
+    Shuffle(ShuffleAction),
+}
+
+/// Try convert the descriptor of the first FlightData to DoExchangeAction.
+impl TryInto<DoExchangeAction> for &FlightData {
+    type Error = tonic::Status;
+
+    fn try_into(self) -> Result<DoExchangeAction, Self::Error> {
+        let descriptor = self
+            .flight_descriptor
+            .as_ref()
+            .ok_or_else(|| tonic::Status::invalid_argument("Exchange descriptor is missing"))?;
+        let mut buf = Cursor::new(&descriptor.cmd);
+
+        // Decode FlightQueryRequest from buffer.
+        let request: FlightQueryRequest = FlightQueryRequest::decode(&mut buf)
+            .map_err(|e| tonic::Status::internal(e.to_string()))?;
+
+        // Decode DoExchangeAction from request body.
+        let json_str = request.body.as_str();
+        let action = serde_json::from_str::<DoExchangeAction>(json_str)
+            .map_err(|e| tonic::Status::internal(e.to_string()))?;
+        Ok(action)
+    }
+}
+
+/// Try convert DoExchangeAction to FlightDescriptor.
+impl TryInto<FlightDescriptor> for &DoExchangeAction {
+    type Error = anyhow::Error;
+
+    fn try_into(self) -> Result<FlightDescriptor, Self::Error> {
+        let flight_request = FlightQueryRequest {
+            body: serde_json::to_string(&self)?,
+        };
+
+        let mut buf = vec![];
+        flight_request.encode(&mut buf)?;
+        Ok(FlightDescriptor {
+            r#type: DescriptorType::Cmd as i32,
+            cmd: buf,
+            path: vec![],
+        })
+    }
+}
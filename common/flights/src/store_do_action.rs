@@ -7,7 +7,11 @@ use std::convert::TryInto;
 use std::io::Cursor;
 
 use common_arrow::arrow_flight::Action;
-use common_planners::CreateDatabasePlan;
+use common_datavalues::DataSchemaRef;
+use common_planners::{
+    CreateDatabasePlan, CreateTablePlan, DropDatabasePlan, DropTablePlan, Partitions, PlanNode,
+    Statistics, TableOptions,
+};
 use prost::Message;
 use tonic::Request;
 
@@ -18,10 +22,106 @@ pub struct CreateDatabaseAction {
     pub plan: CreateDatabasePlan,
 }
 
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct CreateDatabaseActionResult {
+    pub database_id: i64,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone)]
+pub struct GetDatabaseAction {
+    pub db: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct GetDatabaseActionResult {
+    pub database_id: i64,
+    pub db: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone)]
+pub struct GetDatabasesAction {}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct GetDatabasesActionResult {
+    pub databases: Vec<GetDatabaseActionResult>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone)]
+pub struct DropDatabaseAction {
+    pub plan: DropDatabasePlan,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct DropDatabaseActionResult {}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone)]
+pub struct CreateTableAction {
+    pub plan: CreateTablePlan,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct CreateTableActionResult {
+    pub table_id: i64,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone)]
+pub struct GetTableAction {
+    pub db: String,
+    pub table: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct GetTableActionResult {
+    pub table_id: i64,
+    pub db: String,
+    pub name: String,
+    pub schema: DataSchemaRef,
+    pub options: TableOptions,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone)]
+pub struct GetTablesAction {
+    pub db: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct GetTablesActionResult {
+    pub tables: Vec<GetTableActionResult>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone)]
+pub struct DropTableAction {
+    pub plan: DropTablePlan,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct DropTableActionResult {}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone)]
+pub struct ReadPlanAction {
+    pub db: String,
+    pub table: String,
+    pub push_down: PlanNode,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+pub struct ReadPlanActionResult {
+    pub partitions: Partitions,
+    pub statistics: Statistics,
+}
+
 // Action wrapper for do_action.
 #[derive(serde::Serialize, serde::Deserialize, Clone)]
 pub enum DoActionAction {
     CreateDatabase(CreateDatabaseAction),
+    GetDatabase(GetDatabaseAction),
+    GetDatabases(GetDatabasesAction),
+    DropDatabase(DropDatabaseAction),
+    CreateTable(CreateTableAction),
+    GetTable(GetTableAction),
+    GetTables(GetTablesAction),
+    DropTable(DropTableAction),
+    ReadPlan(ReadPlanAction),
 }
 
 /// Try convert tonic::Request<Action> to DoActionAction.
@@ -7,7 +7,7 @@ use std::convert::TryInto;
 use std::io::Cursor;
 
 use common_arrow::arrow_flight::Ticket;
-use common_planners::PlanNode;
+use common_planners::{Extras, Partition};
 use prost::Message;
 use tonic::Request;
 
@@ -15,7 +15,11 @@ use crate::protobuf::FlightStoreRequest;
 
 #[derive(serde::Serialize, serde::Deserialize, Clone)]
 pub struct ReadAction {
-    pub plan: PlanNode,
+    pub db: String,
+    pub table: String,
+    pub partition: Partition,
+    /// The projection and filters to read the partition with.
+    pub push_downs: Extras,
 }
 
 // Action wrapper for do_get.
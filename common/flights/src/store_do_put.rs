@@ -0,0 +1,74 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+/// Actions for store do_put.
+use std::convert::TryInto;
+use std::io::Cursor;
+
+use common_arrow::arrow_flight::flight_descriptor::DescriptorType;
+use common_arrow::arrow_flight::{FlightData, FlightDescriptor};
+use prost::Message;
+
+use crate::protobuf::FlightStoreRequest;
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+pub struct AppendAction {
+    pub db: String,
+    pub table: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct AppendActionResult {
+    /// The number of the blocks committed to the table.
+    pub blocks: usize,
+    pub rows: usize,
+}
+
+// Action wrapper for do_put.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+pub enum DoPutAction {
+    Append(AppendAction),
+}
+
+/// Try convert the descriptor of the first FlightData to DoPutAction.
+impl TryInto<DoPutAction> for &FlightData {
+    type Error = tonic::Status;
+
+    fn try_into(self) -> Result<DoPutAction, Self::Error> {
+        let descriptor = self
+            .flight_descriptor
+            .as_ref()
+            .ok_or_else(|| tonic::Status::invalid_argument("Put descriptor is missing"))?;
+        let mut buf = Cursor::new(&descriptor.cmd);
+
+        // Decode FlightStoreRequest from buffer.
+        let request: FlightStoreRequest = FlightStoreRequest::decode(&mut buf)
+            .map_err(|e| tonic::Status::internal(e.to_string()))?;
+
+        // Decode DoPutAction from request body.
+        let json_str = request.body.as_str();
+        let action = serde_json::from_str::<DoPutAction>(json_str)
+            .map_err(|e| tonic::Status::internal(e.to_string()))?;
+        Ok(action)
+    }
+}
+
+/// Try convert DoPutAction to FlightDescriptor.
+impl TryInto<FlightDescriptor> for &DoPutAction {
+    type Error = anyhow::Error;
+
+    fn try_into(self) -> Result<FlightDescriptor, Self::Error> {
+        let flight_request = FlightStoreRequest {
+            body: serde_json::to_string(&self)?,
+        };
+
+        let mut buf = vec![];
+        flight_request.encode(&mut buf)?;
+        Ok(FlightDescriptor {
+            r#type: DescriptorType::Cmd as i32,
+            cmd: buf,
+            path: vec![],
+        })
+    }
+}
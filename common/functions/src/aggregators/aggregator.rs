@@ -6,7 +6,7 @@ use anyhow::Result;
 
 use crate::aggregators::{
     AggregatorAvgFunction, AggregatorCountFunction, AggregatorMaxFunction, AggregatorMinFunction,
-    AggregatorSumFunction,
+    AggregatorSumFunction, AggregatorUniqExactFunction, AggregatorUniqFunction,
 };
 use crate::FactoryFuncRef;
 
@@ -20,6 +20,8 @@ impl AggregatorFunction {
         map.insert("max", AggregatorMaxFunction::try_create);
         map.insert("sum", AggregatorSumFunction::try_create);
         map.insert("avg", AggregatorAvgFunction::try_create);
+        map.insert("uniq", AggregatorUniqFunction::try_create);
+        map.insert("uniqexact", AggregatorUniqExactFunction::try_create);
         Ok(())
     }
 }
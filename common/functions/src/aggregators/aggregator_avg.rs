@@ -38,7 +38,15 @@ impl AggregatorAvgFunction {
 
 impl IFunction for AggregatorAvgFunction {
     fn return_type(&self, input_schema: &DataSchema) -> Result<DataType> {
-        self.arg.return_type(input_schema)
+        Ok(match self.arg.return_type(input_schema)? {
+            // The sum divided by the count in the decimal arithmetic.
+            DataType::Decimal(_, scale) => datavalues::numerical_arithmetic_coercion(
+                &DataValueArithmeticOperator::Div,
+                &DataType::Decimal(datavalues::MAX_DECIMAL_PRECISION, scale),
+                &DataType::UInt64,
+            )?,
+            other => other,
+        })
     }
 
     fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
@@ -58,18 +66,20 @@ impl IFunction for AggregatorAvgFunction {
         let val = self.arg.eval(&block)?;
 
         if let DataValue::Struct(values) = self.state.clone() {
-            let sum = datavalues::data_value_arithmetic_op(
-                DataValueArithmeticOperator::Plus,
+            let array = val.to_array(rows)?;
+            let sum = datavalues::data_value_aggregate_op(
+                DataValueAggregateOperator::Sum,
                 values[0].clone(),
                 datavalues::data_array_aggregate_op(
                     DataValueAggregateOperator::Sum,
-                    val.to_array(1)?,
+                    array.clone(),
                 )?,
             )?;
-            let count = datavalues::data_value_arithmetic_op(
-                DataValueArithmeticOperator::Plus,
+            // The NULLs are skipped in both the sum and the count.
+            let count = datavalues::data_value_aggregate_op(
+                DataValueAggregateOperator::Sum,
                 values[1].clone(),
-                DataValue::UInt64(Some(rows as u64)),
+                DataValue::UInt64(Some((array.len() - array.null_count()) as u64)),
             )?;
 
             self.state = DataValue::Struct(vec![sum, count]);
@@ -86,13 +96,13 @@ impl IFunction for AggregatorAvgFunction {
         if let (DataValue::Struct(new_states), DataValue::Struct(old_states)) =
             (val, self.state.clone())
         {
-            let sum = datavalues::data_value_arithmetic_op(
-                DataValueArithmeticOperator::Plus,
+            let sum = datavalues::data_value_aggregate_op(
+                DataValueAggregateOperator::Sum,
                 new_states[0].clone(),
                 old_states[0].clone(),
             )?;
-            let count = datavalues::data_value_arithmetic_op(
-                DataValueArithmeticOperator::Plus,
+            let count = datavalues::data_value_aggregate_op(
+                DataValueAggregateOperator::Sum,
                 new_states[1].clone(),
                 old_states[1].clone(),
             )?;
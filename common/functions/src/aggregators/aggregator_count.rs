@@ -8,7 +8,7 @@ use anyhow::Result;
 use common_datablocks::DataBlock;
 use common_datavalues::{
     self as datavalues, DataColumnarValue, DataSchema, DataType, DataValue,
-    DataValueArithmeticOperator,
+    DataValueAggregateOperator,
 };
 
 use crate::{IFunction, LiteralFunction};
@@ -17,27 +17,34 @@ use crate::{IFunction, LiteralFunction};
 pub struct AggregatorCountFunction {
     depth: usize,
     arg: Box<dyn IFunction>,
+    // count() and count(*) count all the rows, no column is evaluated.
+    all_rows: bool,
     state: DataValue,
 }
 
 impl AggregatorCountFunction {
     pub fn try_create(args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
-        let arg = if args.is_empty() {
-            LiteralFunction::try_create(DataValue::UInt64(Some(1)))?
+        let (arg, all_rows) = if args.is_empty() {
+            (
+                LiteralFunction::try_create(DataValue::UInt64(Some(1)))?,
+                true,
+            )
         } else {
-            args[0].clone()
+            // The wildcard is kept for the name of count(*).
+            (args[0].clone(), format!("{}", args[0]) == "*")
         };
         Ok(Box::new(AggregatorCountFunction {
             depth: 0,
             arg,
+            all_rows,
             state: DataValue::Null,
         }))
     }
 }
 
 impl IFunction for AggregatorCountFunction {
-    fn return_type(&self, input_schema: &DataSchema) -> Result<DataType> {
-        self.arg.return_type(input_schema)
+    fn return_type(&self, _input_schema: &DataSchema) -> Result<DataType> {
+        Ok(DataType::UInt64)
     }
 
     fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
@@ -53,9 +60,18 @@ impl IFunction for AggregatorCountFunction {
     }
 
     fn accumulate(&mut self, block: &DataBlock) -> Result<()> {
-        let rows = block.num_rows();
-        self.state = datavalues::data_value_arithmetic_op(
-            DataValueArithmeticOperator::Plus,
+        // Only the rows not NULL are counted.
+        let rows = if self.all_rows {
+            block.num_rows()
+        } else {
+            match self.arg.eval(block)? {
+                DataColumnarValue::Scalar(value) if value.is_null() => 0,
+                DataColumnarValue::Scalar(_) => block.num_rows(),
+                DataColumnarValue::Array(array) => array.len() - array.null_count(),
+            }
+        };
+        self.state = datavalues::data_value_aggregate_op(
+            DataValueAggregateOperator::Sum,
             self.state.clone(),
             DataValue::UInt64(Some(rows as u64)),
         )?;
@@ -68,8 +84,8 @@ impl IFunction for AggregatorCountFunction {
 
     fn merge(&mut self, states: &[DataValue]) -> Result<()> {
         let val = states[self.depth].clone();
-        self.state = datavalues::data_value_arithmetic_op(
-            DataValueArithmeticOperator::Plus,
+        self.state = datavalues::data_value_aggregate_op(
+            DataValueAggregateOperator::Sum,
             self.state.clone(),
             val,
         )?;
@@ -77,7 +93,11 @@ impl IFunction for AggregatorCountFunction {
     }
 
     fn merge_result(&self) -> Result<DataValue> {
-        Ok(self.state.clone())
+        // The count of no rows is 0 rather than NULL.
+        Ok(match self.state {
+            DataValue::Null => DataValue::UInt64(Some(0)),
+            _ => self.state.clone(),
+        })
     }
 
     fn is_aggregator(&self) -> bool {
@@ -8,7 +8,7 @@ use anyhow::{bail, Result};
 use common_datablocks::DataBlock;
 use common_datavalues::{
     self as datavalues, DataColumnarValue, DataSchema, DataType, DataValue,
-    DataValueAggregateOperator, DataValueArithmeticOperator,
+    DataValueAggregateOperator,
 };
 
 use crate::IFunction;
@@ -35,7 +35,12 @@ impl AggregatorSumFunction {
 
 impl IFunction for AggregatorSumFunction {
     fn return_type(&self, input_schema: &DataSchema) -> Result<DataType> {
-        self.arg.return_type(input_schema)
+        Ok(match self.arg.return_type(input_schema)? {
+            DataType::Decimal(_, scale) => {
+                DataType::Decimal(datavalues::MAX_DECIMAL_PRECISION, scale)
+            }
+            other => other,
+        })
     }
 
     fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
@@ -54,8 +59,8 @@ impl IFunction for AggregatorSumFunction {
         let rows = block.num_rows();
         let val = self.arg.eval(&block)?;
 
-        self.state = datavalues::data_value_arithmetic_op(
-            DataValueArithmeticOperator::Plus,
+        self.state = datavalues::data_value_aggregate_op(
+            DataValueAggregateOperator::Sum,
             self.state.clone(),
             datavalues::data_array_aggregate_op(
                 DataValueAggregateOperator::Sum,
@@ -72,8 +77,8 @@ impl IFunction for AggregatorSumFunction {
 
     fn merge(&mut self, states: &[DataValue]) -> Result<()> {
         let val = states[self.depth].clone();
-        self.state = datavalues::data_value_arithmetic_op(
-            DataValueArithmeticOperator::Plus,
+        self.state = datavalues::data_value_aggregate_op(
+            DataValueAggregateOperator::Sum,
             self.state.clone(),
             val,
         )?;
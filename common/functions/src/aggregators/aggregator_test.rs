@@ -98,6 +98,31 @@ fn test_aggregator_function() -> anyhow::Result<()> {
             expect: DataValue::Int64(Some(10)),
             error: "",
         },
+        Test {
+            name: "uniq-exact-passed",
+            eval_nums: 2,
+            args: vec![field_a.clone(), field_b.clone()],
+            display: "uniqExact(a, b)",
+            nullable: false,
+            func: AggregatorUniqExactFunction::try_create(&[
+                ColumnFunction::try_create("a")?,
+                ColumnFunction::try_create("b")?,
+            ])?,
+            block: block.clone(),
+            expect: DataValue::UInt64(Some(4)),
+            error: "",
+        },
+        Test {
+            name: "uniq-passed",
+            eval_nums: 2,
+            args: vec![field_a.clone(), field_b.clone()],
+            display: "uniq(a)",
+            nullable: false,
+            func: AggregatorUniqFunction::try_create(&[ColumnFunction::try_create("a")?])?,
+            block: block.clone(),
+            expect: DataValue::UInt64(Some(4)),
+            error: "",
+        },
         Test {
             name: "1+1+sum(a)-merge-passed",
             eval_nums: 4,
@@ -129,6 +154,31 @@ fn test_aggregator_function() -> anyhow::Result<()> {
             expect: DataValue::Float64(Some(2.5)),
             error: "",
         },
+        Test {
+            name: "a-group-column-merge-passed",
+            eval_nums: 1,
+            args: vec![field_a.clone(), field_b.clone()],
+            display: "a",
+            nullable: false,
+            func: ColumnFunction::try_create("a")?,
+            block: block.clone(),
+            expect: DataValue::Int64(Some(4)),
+            error: "",
+        },
+        Test {
+            name: "a+sum(b)-merge-passed",
+            eval_nums: 2,
+            args: vec![field_a.clone(), field_b.clone()],
+            display: "plus(a, sum(b))",
+            nullable: false,
+            func: ArithmeticPlusFunction::try_create_func(&[
+                ColumnFunction::try_create("a")?,
+                AggregatorSumFunction::try_create(&[ColumnFunction::try_create("b")?])?,
+            ])?,
+            block: block.clone(),
+            expect: DataValue::Int64(Some(34)),
+            error: "",
+        },
         Test {
             name: "(sum(a+1)+2)-merge-passed",
             eval_nums: 4,
@@ -173,3 +223,150 @@ fn test_aggregator_function() -> anyhow::Result<()> {
     }
     Ok(())
 }
+
+#[test]
+fn test_aggregator_function_with_nulls() -> anyhow::Result<()> {
+    use std::sync::Arc;
+
+    use common_datablocks::DataBlock;
+    use common_datavalues::*;
+    use pretty_assertions::assert_eq;
+
+    use crate::aggregators::*;
+    use crate::*;
+
+    let schema = Arc::new(DataSchema::new(vec![DataField::new(
+        "a",
+        DataType::Int64,
+        true,
+    )]));
+    let block = DataBlock::create(
+        schema.clone(),
+        vec![Arc::new(Int64Array::from(vec![
+            Some(4),
+            None,
+            Some(2),
+            None,
+        ]))],
+    );
+    let empty_block = DataBlock::create(
+        schema.clone(),
+        vec![Arc::new(Int64Array::from(Vec::<Option<i64>>::new()))],
+    );
+
+    let field_a = ColumnFunction::try_create("a")?;
+
+    // The NULLs are skipped, the aggregates except count are NULL if there are no values.
+    let tests = vec![
+        (
+            AggregatorCountFunction::try_create(&[field_a.clone()])?,
+            DataValue::UInt64(Some(2)),
+            DataValue::UInt64(Some(0)),
+        ),
+        (
+            AggregatorCountFunction::try_create(&[])?,
+            DataValue::UInt64(Some(4)),
+            DataValue::UInt64(Some(0)),
+        ),
+        (
+            AggregatorCountFunction::try_create(&[ColumnFunction::try_create("*")?])?,
+            DataValue::UInt64(Some(4)),
+            DataValue::UInt64(Some(0)),
+        ),
+        (
+            AggregatorSumFunction::try_create(&[field_a.clone()])?,
+            DataValue::Int64(Some(6)),
+            DataValue::Int64(None),
+        ),
+        (
+            AggregatorAvgFunction::try_create(&[field_a.clone()])?,
+            DataValue::Float64(Some(3.0)),
+            DataValue::Float64(None),
+        ),
+        (
+            AggregatorMinFunction::try_create(&[field_a.clone()])?,
+            DataValue::Int64(Some(2)),
+            DataValue::Int64(None),
+        ),
+        (
+            AggregatorMaxFunction::try_create(&[field_a.clone()])?,
+            DataValue::Int64(Some(4)),
+            DataValue::Int64(None),
+        ),
+        (
+            AggregatorUniqExactFunction::try_create(&[field_a.clone()])?,
+            DataValue::UInt64(Some(2)),
+            DataValue::UInt64(Some(0)),
+        ),
+        (
+            AggregatorUniqFunction::try_create(&[field_a.clone()])?,
+            DataValue::UInt64(Some(2)),
+            DataValue::UInt64(Some(0)),
+        ),
+    ];
+
+    for (func, expect, expect_empty) in tests {
+        for (block, expect) in vec![(&block, expect), (&empty_block, expect_empty)] {
+            let mut func1 = func.clone();
+            func1.accumulate(block)?;
+            let state = func1.accumulate_result()?;
+
+            let mut final_func = func.clone();
+            final_func.set_depth(0);
+            final_func.merge(&*state)?;
+            assert_eq!(expect, final_func.merge_result()?, "{}", func);
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_aggregator_uniq_function() -> anyhow::Result<()> {
+    use std::sync::Arc;
+
+    use common_datablocks::DataBlock;
+    use common_datavalues::*;
+
+    use crate::aggregators::*;
+    use crate::*;
+
+    let schema = Arc::new(DataSchema::new(vec![DataField::new(
+        "a",
+        DataType::UInt64,
+        false,
+    )]));
+    let block = |start: u64, end: u64| {
+        DataBlock::create(
+            schema.clone(),
+            vec![Arc::new(UInt64Array::from(
+                (start..end).collect::<Vec<_>>(),
+            ))],
+        )
+    };
+
+    // The two partial states overlap, the distinct values are 0..100000.
+    let field_a = ColumnFunction::try_create("a")?;
+    for func in vec![
+        AggregatorUniqExactFunction::try_create(&[field_a.clone()])?,
+        AggregatorUniqFunction::try_create(&[field_a.clone()])?,
+    ] {
+        let mut func1 = func.clone();
+        func1.accumulate(&block(0, 60000))?;
+        let mut func2 = func.clone();
+        func2.accumulate(&block(40000, 100000))?;
+
+        let mut final_func = func.clone();
+        final_func.set_depth(0);
+        final_func.merge(&*func1.accumulate_result()?)?;
+        final_func.merge(&*func2.accumulate_result()?)?;
+
+        match final_func.merge_result()? {
+            DataValue::UInt64(Some(v)) => {
+                let error = (v as f64 - 100000.0).abs() / 100000.0;
+                assert!(error < 0.05, "{}: {}", final_func, v);
+            }
+            other => panic!("{}: {:?}", final_func, other),
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,175 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::fmt;
+
+use anyhow::{bail, Result};
+use common_datablocks::DataBlock;
+use common_datavalues::{data_hash, DataColumnarValue, DataSchema, DataType, DataValue};
+
+use crate::aggregators::aggregator_uniq_exact::uniq_row_keys;
+use crate::IFunction;
+
+/// The bits of the hash to pick the register, there are 2^12 registers and the standard error
+/// is about 1.6%.
+const HLL_PRECISION: u32 = 12;
+const HLL_REGISTERS: usize = 1 << HLL_PRECISION;
+
+/// `uniq(a, ...)` is the approximate number of the distinct rows of the args by HyperLogLog,
+/// it takes the fixed memory no matter how many the rows are. The rows have NULL are skipped.
+#[derive(Clone)]
+pub struct AggregatorUniqFunction {
+    depth: usize,
+    args: Vec<Box<dyn IFunction>>,
+    state: HyperLogLog,
+}
+
+impl AggregatorUniqFunction {
+    pub fn try_create(args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+        if args.is_empty() {
+            bail!("Function Error: Aggregator function uniq args require at least one argument");
+        }
+        Ok(Box::new(AggregatorUniqFunction {
+            depth: 0,
+            args: args.to_vec(),
+            state: HyperLogLog::new(),
+        }))
+    }
+}
+
+impl IFunction for AggregatorUniqFunction {
+    fn return_type(&self, _input_schema: &DataSchema) -> Result<DataType> {
+        Ok(DataType::UInt64)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, block: &DataBlock) -> Result<DataColumnarValue> {
+        self.args[0].eval(block)
+    }
+
+    fn set_depth(&mut self, depth: usize) {
+        self.depth = depth;
+    }
+
+    fn accumulate(&mut self, block: &DataBlock) -> Result<()> {
+        for key in uniq_row_keys(&self.args, block)?.into_iter().flatten() {
+            self.state.add(&key);
+        }
+        Ok(())
+    }
+
+    fn accumulate_result(&self) -> Result<Vec<DataValue>> {
+        Ok(vec![DataValue::String(Some(self.state.serialize()))])
+    }
+
+    fn merge(&mut self, states: &[DataValue]) -> Result<()> {
+        match &states[self.depth] {
+            DataValue::String(Some(state)) => {
+                self.state.merge(&HyperLogLog::deserialize(state)?);
+                Ok(())
+            }
+            other => bail!(
+                "Function Error: Aggregator function uniq unexpected state: {:?}",
+                other
+            ),
+        }
+    }
+
+    fn merge_result(&self) -> Result<DataValue> {
+        Ok(DataValue::UInt64(Some(self.state.estimate())))
+    }
+
+    fn is_aggregator(&self) -> bool {
+        true
+    }
+}
+
+impl fmt::Display for AggregatorUniqFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let args = self
+            .args
+            .iter()
+            .map(|arg| format!("{}", arg))
+            .collect::<Vec<_>>();
+        write!(f, "uniq({})", args.join(", "))
+    }
+}
+
+#[derive(Clone)]
+struct HyperLogLog {
+    // The max rank of the hashes fall into each register.
+    registers: Vec<u8>,
+}
+
+impl HyperLogLog {
+    fn new() -> Self {
+        HyperLogLog {
+            registers: vec![0; HLL_REGISTERS],
+        }
+    }
+
+    fn add(&mut self, key: &str) {
+        // The hash is fixed, so the partial states of the nodes built by the different Rust
+        // releases are still comparable.
+        let hash = data_hash(key.as_bytes());
+
+        let index = (hash >> (64 - HLL_PRECISION)) as usize;
+        // The rank is the position of the first 1 bit of the rest bits, the trailing bit
+        // bounds it if the rest are all 0.
+        let rest = (hash << HLL_PRECISION) | (1 << (HLL_PRECISION - 1));
+        let rank = rest.leading_zeros() as u8 + 1;
+        if rank > self.registers[index] {
+            self.registers[index] = rank;
+        }
+    }
+
+    fn merge(&mut self, other: &HyperLogLog) {
+        for (register, other) in self.registers.iter_mut().zip(&other.registers) {
+            *register = (*register).max(*other);
+        }
+    }
+
+    fn estimate(&self) -> u64 {
+        let m = HLL_REGISTERS as f64;
+        let alpha = 0.7213 / (1.0 + 1.079 / m);
+        let sum = self
+            .registers
+            .iter()
+            .map(|register| 2f64.powi(-(*register as i32)))
+            .sum::<f64>();
+        let estimate = alpha * m * m / sum;
+
+        // The linear counting is more accurate for the small cardinalities.
+        let zeros = self
+            .registers
+            .iter()
+            .filter(|register| **register == 0)
+            .count();
+        if estimate <= 2.5 * m && zeros > 0 {
+            return (m * (m / zeros as f64).ln()).round() as u64;
+        }
+        estimate.round() as u64
+    }
+
+    /// The registers in hex, two digits for each.
+    fn serialize(&self) -> String {
+        self.registers
+            .iter()
+            .map(|register| format!("{:02x}", register))
+            .collect()
+    }
+
+    fn deserialize(state: &str) -> Result<Self> {
+        if state.len() != HLL_REGISTERS * 2 || !state.is_ascii() {
+            bail!("Function Error: Aggregator function uniq invalid state of HyperLogLog");
+        }
+        let registers = (0..HLL_REGISTERS)
+            .map(|i| Ok(u8::from_str_radix(&state[i * 2..i * 2 + 2], 16)?))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(HyperLogLog { registers })
+    }
+}
@@ -0,0 +1,133 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::collections::HashSet;
+use std::fmt;
+
+use anyhow::{bail, Result};
+use common_datablocks::DataBlock;
+use common_datavalues::{DataColumnarValue, DataSchema, DataType, DataValue};
+
+use crate::IFunction;
+
+/// `uniqExact(a, ...)` is the exact number of the distinct rows of the args, and
+/// `count(DISTINCT a, ...)` is planned as it. The rows have NULL are skipped.
+#[derive(Clone)]
+pub struct AggregatorUniqExactFunction {
+    depth: usize,
+    args: Vec<Box<dyn IFunction>>,
+    // The serialized rows seen, they are the keys to merge the states.
+    state: HashSet<String>,
+}
+
+impl AggregatorUniqExactFunction {
+    pub fn try_create(args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+        if args.is_empty() {
+            bail!(
+                "Function Error: Aggregator function uniqExact args require at least one argument"
+            );
+        }
+        Ok(Box::new(AggregatorUniqExactFunction {
+            depth: 0,
+            args: args.to_vec(),
+            state: HashSet::new(),
+        }))
+    }
+}
+
+impl IFunction for AggregatorUniqExactFunction {
+    fn return_type(&self, _input_schema: &DataSchema) -> Result<DataType> {
+        Ok(DataType::UInt64)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, block: &DataBlock) -> Result<DataColumnarValue> {
+        self.args[0].eval(block)
+    }
+
+    fn set_depth(&mut self, depth: usize) {
+        self.depth = depth;
+    }
+
+    fn accumulate(&mut self, block: &DataBlock) -> Result<()> {
+        for key in uniq_row_keys(&self.args, block)?.into_iter().flatten() {
+            self.state.insert(key);
+        }
+        Ok(())
+    }
+
+    fn accumulate_result(&self) -> Result<Vec<DataValue>> {
+        let keys = self
+            .state
+            .iter()
+            .map(|key| DataValue::String(Some(key.clone())))
+            .collect();
+        Ok(vec![DataValue::List(Some(keys), DataType::Utf8)])
+    }
+
+    fn merge(&mut self, states: &[DataValue]) -> Result<()> {
+        match &states[self.depth] {
+            DataValue::List(Some(keys), _) => {
+                for key in keys {
+                    if let DataValue::String(Some(key)) = key {
+                        self.state.insert(key.clone());
+                    }
+                }
+            }
+            other => bail!(
+                "Function Error: Aggregator function uniqExact unexpected state: {:?}",
+                other
+            ),
+        }
+        Ok(())
+    }
+
+    fn merge_result(&self) -> Result<DataValue> {
+        Ok(DataValue::UInt64(Some(self.state.len() as u64)))
+    }
+
+    fn is_aggregator(&self) -> bool {
+        true
+    }
+}
+
+impl fmt::Display for AggregatorUniqExactFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let args = self
+            .args
+            .iter()
+            .map(|arg| format!("{}", arg))
+            .collect::<Vec<_>>();
+        write!(f, "uniqExact({})", args.join(", "))
+    }
+}
+
+/// The serialized values of the args for each row, None if any of them is NULL.
+pub(crate) fn uniq_row_keys(
+    args: &[Box<dyn IFunction>],
+    block: &DataBlock,
+) -> Result<Vec<Option<String>>> {
+    let rows = block.num_rows();
+    let arrays = args
+        .iter()
+        .map(|arg| arg.eval(block)?.to_array(rows))
+        .collect::<Result<Vec<_>>>()?;
+
+    (0..rows)
+        .map(|row| {
+            let mut values = Vec::with_capacity(arrays.len());
+            for array in &arrays {
+                let value = DataValue::try_from_array(array, row)?;
+                if value.is_null() {
+                    return Ok(None);
+                }
+                values.push(value);
+            }
+            Ok(Some(serde_json::to_string(&DataValue::Struct(values))?))
+        })
+        .collect()
+}
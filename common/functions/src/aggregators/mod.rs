@@ -11,6 +11,8 @@ mod aggregator_count;
 mod aggregator_max;
 mod aggregator_min;
 mod aggregator_sum;
+mod aggregator_uniq;
+mod aggregator_uniq_exact;
 
 pub use aggregator::AggregatorFunction;
 pub use aggregator_avg::AggregatorAvgFunction;
@@ -18,3 +20,5 @@ pub use aggregator_count::AggregatorCountFunction;
 pub use aggregator_max::AggregatorMaxFunction;
 pub use aggregator_min::AggregatorMinFunction;
 pub use aggregator_sum::AggregatorSumFunction;
+pub use aggregator_uniq::AggregatorUniqFunction;
+pub use aggregator_uniq_exact::AggregatorUniqExactFunction;
@@ -13,7 +13,7 @@ use common_datavalues::{
 
 use crate::arithmetics::{
     ArithmeticDivFunction, ArithmeticMinusFunction, ArithmeticModuloFunction,
-    ArithmeticMulFunction, ArithmeticPlusFunction,
+    ArithmeticMulFunction, ArithmeticNegateFunction, ArithmeticPlusFunction,
 };
 use crate::{FactoryFuncRef, IFunction};
 
@@ -38,6 +38,7 @@ impl ArithmeticFunction {
         map.insert("divide", ArithmeticDivFunction::try_create_func);
         map.insert("%", ArithmeticModuloFunction::try_create_func);
         map.insert("modulo", ArithmeticModuloFunction::try_create_func);
+        map.insert("negate", ArithmeticNegateFunction::try_create_func);
         Ok(())
     }
 
@@ -69,8 +70,8 @@ impl IFunction for ArithmeticFunction {
         )
     }
 
-    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
-        Ok(false)
+    fn nullable(&self, input_schema: &DataSchema) -> Result<bool> {
+        Ok(self.left.nullable(input_schema)? || self.right.nullable(input_schema)?)
     }
 
     fn eval(&self, block: &DataBlock) -> Result<DataColumnarValue> {
@@ -0,0 +1,83 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::fmt;
+
+use anyhow::{ensure, Result};
+use common_datablocks::DataBlock;
+use common_datavalues::{
+    DataColumnarValue, DataSchema, DataType, DataValue, DataValueArithmeticOperator,
+};
+
+use crate::arithmetics::ArithmeticFunction;
+use crate::{IFunction, LiteralFunction};
+
+/// `-v`, which is evaluated as `0 - v` so the result type is the one of the minus.
+#[derive(Clone)]
+pub struct ArithmeticNegateFunction {
+    arg: Box<dyn IFunction>,
+    minus: Box<dyn IFunction>,
+}
+
+impl ArithmeticNegateFunction {
+    pub fn try_create_func(args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+        ensure!(
+            args.len() == 1,
+            "Function Error: Arithmetic function negate args length must be 1"
+        );
+
+        let zero = LiteralFunction::try_create(DataValue::Int8(Some(0)))?;
+        Ok(Box::new(ArithmeticNegateFunction {
+            arg: args[0].clone(),
+            minus: ArithmeticFunction::try_create_func(
+                DataValueArithmeticOperator::Minus,
+                &[zero, args[0].clone()],
+            )?,
+        }))
+    }
+}
+
+impl IFunction for ArithmeticNegateFunction {
+    fn return_type(&self, input_schema: &DataSchema) -> Result<DataType> {
+        self.minus.return_type(input_schema)
+    }
+
+    fn nullable(&self, input_schema: &DataSchema) -> Result<bool> {
+        self.arg.nullable(input_schema)
+    }
+
+    fn eval(&self, block: &DataBlock) -> Result<DataColumnarValue> {
+        self.minus.eval(block)
+    }
+
+    fn set_depth(&mut self, depth: usize) {
+        self.minus.set_depth(depth);
+    }
+
+    fn accumulate(&mut self, block: &DataBlock) -> Result<()> {
+        self.minus.accumulate(block)
+    }
+
+    fn accumulate_result(&self) -> Result<Vec<DataValue>> {
+        self.minus.accumulate_result()
+    }
+
+    fn merge(&mut self, states: &[DataValue]) -> Result<()> {
+        self.minus.merge(states)
+    }
+
+    fn merge_result(&self) -> Result<DataValue> {
+        self.minus.merge_result()
+    }
+
+    fn is_aggregator(&self) -> bool {
+        self.minus.is_aggregator()
+    }
+}
+
+impl fmt::Display for ArithmeticNegateFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "negate({})", self.arg)
+    }
+}
@@ -131,6 +131,22 @@ fn test_arithmetic_function() -> anyhow::Result<()> {
             expect: Arc::new(Int64Array::from(vec![0, 1, 2])),
             error: "",
         },
+        Test {
+            name: "negate-int16-passed",
+            display: "negate(c)",
+            nullable: false,
+            func: ArithmeticNegateFunction::try_create_func(&[field_c.clone()])?,
+            block: DataBlock::create(
+                schema.clone(),
+                vec![
+                    Arc::new(Int64Array::from(vec![4, 3, 2])),
+                    Arc::new(Int64Array::from(vec![1, 2, 3])),
+                    Arc::new(Int16Array::from(vec![1, -2, 3])),
+                ],
+            ),
+            expect: Arc::new(Int32Array::from(vec![-1, 2, -3])),
+            error: "",
+        },
     ];
 
     for t in tests {
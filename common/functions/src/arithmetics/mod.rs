@@ -10,6 +10,7 @@ mod arithmetic_div;
 mod arithmetic_minus;
 mod arithmetic_modulo;
 mod arithmetic_mul;
+mod arithmetic_negate;
 mod arithmetic_plus;
 
 pub use arithmetic::ArithmeticFunction;
@@ -17,4 +18,5 @@ pub use arithmetic_div::ArithmeticDivFunction;
 pub use arithmetic_minus::ArithmeticMinusFunction;
 pub use arithmetic_modulo::ArithmeticModuloFunction;
 pub use arithmetic_mul::ArithmeticMulFunction;
+pub use arithmetic_negate::ArithmeticNegateFunction;
 pub use arithmetic_plus::ArithmeticPlusFunction;
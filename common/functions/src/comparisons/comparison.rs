@@ -11,8 +11,8 @@ use common_datavalues::{
 };
 
 use crate::comparisons::{
-    ComparisonEqFunction, ComparisonGtEqFunction, ComparisonGtFunction, ComparisonLtEqFunction,
-    ComparisonLtFunction, ComparisonNotEqFunction,
+    ComparisonBetweenFunction, ComparisonEqFunction, ComparisonGtEqFunction, ComparisonGtFunction,
+    ComparisonInFunction, ComparisonLtEqFunction, ComparisonLtFunction, ComparisonNotEqFunction,
 };
 use crate::{FactoryFuncRef, IFunction};
 
@@ -36,6 +36,13 @@ impl ComparisonFunction {
         map.insert(">=", ComparisonGtEqFunction::try_create_func);
         map.insert("!=", ComparisonNotEqFunction::try_create_func);
         map.insert("<>", ComparisonNotEqFunction::try_create_func);
+        map.insert("in", ComparisonInFunction::try_create_in);
+        map.insert("not in", ComparisonInFunction::try_create_not_in);
+        map.insert("between", ComparisonBetweenFunction::try_create_between);
+        map.insert(
+            "not between",
+            ComparisonBetweenFunction::try_create_not_between,
+        );
         Ok(())
     }
 
@@ -64,8 +71,8 @@ impl IFunction for ComparisonFunction {
         Ok(DataType::Boolean)
     }
 
-    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
-        Ok(false)
+    fn nullable(&self, input_schema: &DataSchema) -> Result<bool> {
+        Ok(self.left.nullable(input_schema)? || self.right.nullable(input_schema)?)
     }
 
     fn eval(&self, block: &DataBlock) -> Result<DataColumnarValue> {
@@ -0,0 +1,96 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::fmt;
+
+use anyhow::{ensure, Result};
+use common_datablocks::DataBlock;
+use common_datavalues::{
+    self as datavalues, DataColumnarValue, DataSchema, DataType, DataValueComparisonOperator,
+    DataValueLogicOperator,
+};
+
+use crate::IFunction;
+
+/// `v BETWEEN low AND high` is `v >= low AND v <= high`, and `v NOT BETWEEN low AND high` is
+/// the negation of it.
+#[derive(Clone)]
+pub struct ComparisonBetweenFunction {
+    negated: bool,
+    value: Box<dyn IFunction>,
+    low: Box<dyn IFunction>,
+    high: Box<dyn IFunction>,
+}
+
+impl ComparisonBetweenFunction {
+    pub fn try_create_between(args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+        Self::try_create(false, args)
+    }
+
+    pub fn try_create_not_between(args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+        Self::try_create(true, args)
+    }
+
+    fn try_create(negated: bool, args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+        ensure!(
+            args.len() == 3,
+            "Function Error: Function {} args length must be 3",
+            if negated { "NOT BETWEEN" } else { "BETWEEN" }
+        );
+
+        Ok(Box::new(ComparisonBetweenFunction {
+            negated,
+            value: args[0].clone(),
+            low: args[1].clone(),
+            high: args[2].clone(),
+        }))
+    }
+}
+
+impl IFunction for ComparisonBetweenFunction {
+    fn return_type(&self, _input_schema: &DataSchema) -> Result<DataType> {
+        Ok(DataType::Boolean)
+    }
+
+    fn nullable(&self, input_schema: &DataSchema) -> Result<bool> {
+        Ok(self.value.nullable(input_schema)?
+            || self.low.nullable(input_schema)?
+            || self.high.nullable(input_schema)?)
+    }
+
+    fn eval(&self, block: &DataBlock) -> Result<DataColumnarValue> {
+        let value = self.value.eval(block)?;
+        let ge_low = datavalues::data_array_comparison_op(
+            DataValueComparisonOperator::GtEq,
+            &value,
+            &self.low.eval(block)?,
+        )?;
+        let le_high = datavalues::data_array_comparison_op(
+            DataValueComparisonOperator::LtEq,
+            &value,
+            &self.high.eval(block)?,
+        )?;
+        let result = datavalues::data_array_logic_op(
+            DataValueLogicOperator::And,
+            &DataColumnarValue::Array(ge_low),
+            &DataColumnarValue::Array(le_high),
+        )?;
+
+        Ok(DataColumnarValue::Array(match self.negated {
+            true => datavalues::data_array_logic_not(&DataColumnarValue::Array(result))?,
+            false => result,
+        }))
+    }
+}
+
+impl fmt::Display for ComparisonBetweenFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let op = if self.negated {
+            "NOT BETWEEN"
+        } else {
+            "BETWEEN"
+        };
+        write!(f, "{} {} {} AND {}", self.value, op, self.low, self.high)
+    }
+}
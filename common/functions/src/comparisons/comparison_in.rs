@@ -0,0 +1,268 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::collections::HashSet;
+use std::convert::TryFrom;
+use std::fmt;
+use std::sync::Arc;
+
+use anyhow::{bail, ensure, Result};
+use common_arrow::arrow::array::Array;
+use common_datablocks::DataBlock;
+use common_datavalues::{
+    self as datavalues, BooleanArray, DataArrayRef, DataColumnarValue, DataSchema, DataType,
+    DataValue, DataValueComparisonOperator, DataValueLogicOperator, Int64Array, StringArray,
+    UInt64Array,
+};
+
+use crate::IFunction;
+
+/// The literal lists of at least this length are matched by the hash set rather than compared
+/// one by one.
+const HASH_SET_MIN_ITEMS: usize = 8;
+
+/// `v IN (a, b, ...)` and `v NOT IN (a, b, ...)`. It's NULL if v is NULL, or if v isn't in the
+/// list but the list has NULL, like `v = a OR v = b OR ...`.
+#[derive(Clone)]
+pub struct ComparisonInFunction {
+    negated: bool,
+    value: Box<dyn IFunction>,
+    list: Vec<Box<dyn IFunction>>,
+    // The values of the list if all the items are literals.
+    literals: Option<Vec<DataValue>>,
+    // The hash set of the long literal list, it's built once for all the blocks.
+    literal_set: Option<LiteralSet>,
+}
+
+/// The hash set of the literals of the same kind, the NULLs are not in the set.
+#[derive(Clone)]
+pub(crate) enum LiteralSet {
+    Int64 {
+        set: Arc<HashSet<i64>>,
+        has_null: bool,
+    },
+    Utf8 {
+        set: Arc<HashSet<String>>,
+        has_null: bool,
+    },
+}
+
+impl ComparisonInFunction {
+    pub fn try_create_in(args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+        Self::try_create(false, args)
+    }
+
+    pub fn try_create_not_in(args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+        Self::try_create(true, args)
+    }
+
+    fn try_create(negated: bool, args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+        ensure!(
+            args.len() >= 2,
+            "Function Error: Function {} args length must be at least 2",
+            if negated { "NOT IN" } else { "IN" }
+        );
+
+        let literals = args[1..]
+            .iter()
+            .map(|arg| match arg.eval(&DataBlock::empty()) {
+                Ok(DataColumnarValue::Scalar(value)) => Some(value),
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>();
+        let literal_set = match &literals {
+            Some(literals) if literals.len() >= HASH_SET_MIN_ITEMS => Self::literal_set(literals),
+            _ => None,
+        };
+        Ok(Box::new(ComparisonInFunction {
+            negated,
+            value: args[0].clone(),
+            list: args[1..].to_vec(),
+            literals,
+            literal_set,
+        }))
+    }
+
+    /// The set of the literals if they are all strings or all integers fit in Int64.
+    pub(crate) fn literal_set(literals: &[DataValue]) -> Option<LiteralSet> {
+        let has_null = literals.iter().any(|literal| literal.is_null());
+        let values = literals.iter().filter(|literal| !literal.is_null());
+        if values.clone().all(|v| matches!(v, DataValue::String(_))) {
+            let set: HashSet<String> = values
+                .filter_map(|v| match v {
+                    DataValue::String(Some(v)) => Some(v.clone()),
+                    _ => None,
+                })
+                .collect();
+            return Some(LiteralSet::Utf8 {
+                set: Arc::new(set),
+                has_null,
+            });
+        }
+        let set: HashSet<i64> = values.map(integer_literal).collect::<Option<_>>()?;
+        Some(LiteralSet::Int64 {
+            set: Arc::new(set),
+            has_null,
+        })
+    }
+
+    /// Compare the value with the items one by one, the NULLs are in the three-valued logic.
+    fn eval_compare(&self, value: &DataColumnarValue, block: &DataBlock) -> Result<DataArrayRef> {
+        let mut result: Option<DataArrayRef> = None;
+        for item in &self.list {
+            let eq = datavalues::data_array_comparison_op(
+                DataValueComparisonOperator::Eq,
+                value,
+                &item.eval(block)?,
+            )?;
+            result = Some(match result {
+                Some(result) => datavalues::data_array_logic_op(
+                    DataValueLogicOperator::Or,
+                    &DataColumnarValue::Array(result),
+                    &DataColumnarValue::Array(eq),
+                )?,
+                None => eq,
+            });
+        }
+        match result {
+            Some(result) => Ok(result),
+            None => bail!("Function Error: The list of IN is empty"),
+        }
+    }
+
+    /// Match the value by the hash set of the literal list, if the common type is of the set.
+    pub(crate) fn eval_hash_set(
+        value: &DataArrayRef,
+        literal_set: &LiteralSet,
+        data_type: &DataType,
+    ) -> Result<Option<DataArrayRef>> {
+        Ok(match (literal_set, data_type) {
+            (LiteralSet::Utf8 { set, has_null }, DataType::Utf8) => {
+                let value = datavalues::data_array_cast(value, &DataType::Utf8)?;
+                let values = downcast::<StringArray>(&value)?;
+                Some(hash_set_in(values.len(), *has_null, |row| {
+                    values
+                        .is_valid(row)
+                        .then(|| set.contains(values.value(row)))
+                }))
+            }
+            // The values beyond Int64 are not in the set, all the literals fit in Int64.
+            (LiteralSet::Int64 { set, has_null }, DataType::UInt64) => {
+                let value = datavalues::data_array_cast(value, &DataType::UInt64)?;
+                let values = downcast::<UInt64Array>(&value)?;
+                Some(hash_set_in(values.len(), *has_null, |row| {
+                    values.is_valid(row).then(|| {
+                        i64::try_from(values.value(row)).map_or(false, |v| set.contains(&v))
+                    })
+                }))
+            }
+            (LiteralSet::Int64 { set, has_null }, t)
+                if datavalues::is_integer(t) && t != &DataType::UInt64 =>
+            {
+                let value = datavalues::data_array_cast(value, &DataType::Int64)?;
+                let values = downcast::<Int64Array>(&value)?;
+                Some(hash_set_in(values.len(), *has_null, |row| {
+                    values
+                        .is_valid(row)
+                        .then(|| set.contains(&values.value(row)))
+                }))
+            }
+            _ => None,
+        })
+    }
+}
+
+impl IFunction for ComparisonInFunction {
+    fn return_type(&self, _input_schema: &DataSchema) -> Result<DataType> {
+        Ok(DataType::Boolean)
+    }
+
+    fn nullable(&self, input_schema: &DataSchema) -> Result<bool> {
+        if self.value.nullable(input_schema)? {
+            return Ok(true);
+        }
+        for item in &self.list {
+            if item.nullable(input_schema)? {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    fn eval(&self, block: &DataBlock) -> Result<DataColumnarValue> {
+        let value = self.value.eval(block)?;
+        let mut result = None;
+        if let (Some(literals), Some(literal_set), DataColumnarValue::Array(array)) =
+            (&self.literals, &self.literal_set, &value)
+        {
+            let mut data_type = array.data_type().clone();
+            for literal in literals {
+                data_type = datavalues::equal_coercion(&data_type, &literal.data_type())?;
+            }
+            result = Self::eval_hash_set(array, literal_set, &data_type)?;
+        }
+        let result = match result {
+            Some(result) => result,
+            None => self.eval_compare(&value, block)?,
+        };
+
+        Ok(DataColumnarValue::Array(match self.negated {
+            true => datavalues::data_array_logic_not(&DataColumnarValue::Array(result))?,
+            false => result,
+        }))
+    }
+}
+
+impl fmt::Display for ComparisonInFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let list = self
+            .list
+            .iter()
+            .map(|item| format!("{}", item))
+            .collect::<Vec<_>>();
+        let op = if self.negated { "NOT IN" } else { "IN" };
+        write!(f, "{} {} ({})", self.value, op, list.join(", "))
+    }
+}
+
+fn downcast<T: 'static>(array: &DataArrayRef) -> Result<&T> {
+    match array.as_any().downcast_ref::<T>() {
+        Some(array) => Ok(array),
+        None => bail!(
+            "Function Error: Cannot downcast the array of {:?}",
+            array.data_type()
+        ),
+    }
+}
+
+fn integer_literal(value: &DataValue) -> Option<i64> {
+    match value {
+        DataValue::Int8(Some(v)) => Some(*v as i64),
+        DataValue::Int16(Some(v)) => Some(*v as i64),
+        DataValue::Int32(Some(v)) => Some(*v as i64),
+        DataValue::Int64(Some(v)) => Some(*v),
+        DataValue::UInt8(Some(v)) => Some(*v as i64),
+        DataValue::UInt16(Some(v)) => Some(*v as i64),
+        DataValue::UInt32(Some(v)) => Some(*v as i64),
+        DataValue::UInt64(Some(v)) => i64::try_from(*v).ok(),
+        _ => None,
+    }
+}
+
+/// The result of the rows by whether the set contains the value, None if the value is NULL.
+fn hash_set_in(
+    rows: usize,
+    has_null: bool,
+    contains: impl Fn(usize) -> Option<bool>,
+) -> DataArrayRef {
+    let result = (0..rows)
+        .map(|row| match contains(row) {
+            Some(true) => Some(true),
+            Some(false) if has_null => None,
+            Some(false) => Some(false),
+            None => None,
+        })
+        .collect::<BooleanArray>();
+    Arc::new(result)
+}
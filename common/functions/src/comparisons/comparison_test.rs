@@ -31,6 +31,12 @@ fn test_comparison_function() -> anyhow::Result<()> {
 
     let field_a = ColumnFunction::try_create("a").unwrap();
     let field_b = ColumnFunction::try_create("b").unwrap();
+    let number = |v: i64| LiteralFunction::try_create(DataValue::Int64(Some(v)));
+    // The long literal list is matched by the hash set.
+    let mut not_in_args = vec![field_a.clone()];
+    for v in &[1, 2, 3, 5, 6, 7, 8, 9] {
+        not_in_args.push(number(*v)?);
+    }
 
     let tests = vec![
         Test {
@@ -123,6 +129,78 @@ fn test_comparison_function() -> anyhow::Result<()> {
             expect: Arc::new(BooleanArray::from(vec![true, true, true, false])),
             error: "",
         },
+        Test {
+            name: "in-passed",
+            display: "a IN (b, 2)",
+            nullable: false,
+            func: ComparisonInFunction::try_create_in(&[
+                field_a.clone(),
+                field_b.clone(),
+                number(2)?,
+            ])?,
+            block: DataBlock::create(
+                schema.clone(),
+                vec![
+                    Arc::new(Int64Array::from(vec![4, 3, 2, 4])),
+                    Arc::new(Int64Array::from(vec![1, 2, 3, 4])),
+                ],
+            ),
+            expect: Arc::new(BooleanArray::from(vec![false, false, true, true])),
+            error: "",
+        },
+        Test {
+            name: "not-in-hash-set-passed",
+            display: "a NOT IN (1, 2, 3, 5, 6, 7, 8, 9)",
+            nullable: false,
+            func: ComparisonInFunction::try_create_not_in(&not_in_args)?,
+            block: DataBlock::create(
+                schema.clone(),
+                vec![
+                    Arc::new(Int64Array::from(vec![4, 3, 2, 10])),
+                    Arc::new(Int64Array::from(vec![1, 2, 3, 4])),
+                ],
+            ),
+            expect: Arc::new(BooleanArray::from(vec![true, false, false, true])),
+            error: "",
+        },
+        Test {
+            name: "between-passed",
+            display: "a BETWEEN b AND 3",
+            nullable: false,
+            func: ComparisonBetweenFunction::try_create_between(&[
+                field_a.clone(),
+                field_b.clone(),
+                number(3)?,
+            ])?,
+            block: DataBlock::create(
+                schema.clone(),
+                vec![
+                    Arc::new(Int64Array::from(vec![4, 3, 2, 4])),
+                    Arc::new(Int64Array::from(vec![1, 2, 3, 4])),
+                ],
+            ),
+            expect: Arc::new(BooleanArray::from(vec![false, true, false, false])),
+            error: "",
+        },
+        Test {
+            name: "not-between-passed",
+            display: "a NOT BETWEEN b AND 3",
+            nullable: false,
+            func: ComparisonBetweenFunction::try_create_not_between(&[
+                field_a.clone(),
+                field_b.clone(),
+                number(3)?,
+            ])?,
+            block: DataBlock::create(
+                schema.clone(),
+                vec![
+                    Arc::new(Int64Array::from(vec![4, 3, 2, 4])),
+                    Arc::new(Int64Array::from(vec![1, 2, 3, 4])),
+                ],
+            ),
+            expect: Arc::new(BooleanArray::from(vec![true, false, true, true])),
+            error: "",
+        },
     ];
 
     for t in tests {
@@ -152,3 +230,55 @@ fn test_comparison_function() -> anyhow::Result<()> {
     }
     Ok(())
 }
+
+#[test]
+fn test_comparison_in_hash_set_uint64() -> anyhow::Result<()> {
+    use std::sync::Arc;
+
+    use common_datablocks::*;
+    use common_datavalues::*;
+    use pretty_assertions::assert_eq;
+
+    use crate::comparisons::*;
+    use crate::*;
+
+    let schema = Arc::new(DataSchema::new(vec![DataField::new(
+        "number",
+        DataType::UInt64,
+        true,
+    )]));
+    let array: DataArrayRef = Arc::new(UInt64Array::from(vec![
+        Some(1),
+        Some(4),
+        None,
+        Some(u64::MAX),
+        Some(9),
+    ]));
+    let block = DataBlock::create(schema, vec![array.clone()]);
+
+    // `number IN (1, 2, 3, 5, 6, 7, 8, 9)`, the unsigned literals are UInt64 like numbers().
+    let literals = [1, 2, 3, 5, 6, 7, 8, 9]
+        .iter()
+        .map(|v| DataValue::UInt64(Some(*v)))
+        .collect::<Vec<_>>();
+    let mut args = vec![ColumnFunction::try_create("number")?];
+    for literal in &literals {
+        args.push(LiteralFunction::try_create(literal.clone())?);
+    }
+    let func = ComparisonInFunction::try_create_in(&args)?;
+    let expect: DataArrayRef = Arc::new(BooleanArray::from(vec![
+        Some(true),
+        Some(false),
+        None,
+        Some(false),
+        Some(true),
+    ]));
+    let actual = func.eval(&block)?.to_array(block.num_rows())?;
+    assert_eq!(expect.as_ref(), actual.as_ref());
+
+    // The UInt64 values are matched by the hash set rather than compared one by one.
+    let literal_set = ComparisonInFunction::literal_set(&literals).unwrap();
+    let actual = ComparisonInFunction::eval_hash_set(&array, &literal_set, &DataType::UInt64)?;
+    assert_eq!(expect.as_ref(), actual.unwrap().as_ref());
+    Ok(())
+}
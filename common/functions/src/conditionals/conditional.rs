@@ -0,0 +1,20 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use anyhow::Result;
+
+use crate::conditionals::MultiIfFunction;
+use crate::FactoryFuncRef;
+
+#[derive(Clone)]
+pub struct ConditionalFunction;
+
+impl ConditionalFunction {
+    pub fn register(map: FactoryFuncRef) -> Result<()> {
+        let mut map = map.write();
+        map.insert("if", MultiIfFunction::try_create_if);
+        map.insert("multiif", MultiIfFunction::try_create_multi_if);
+        Ok(())
+    }
+}
@@ -0,0 +1,146 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+#[test]
+fn test_conditional_function() -> anyhow::Result<()> {
+    use std::sync::Arc;
+
+    use common_datablocks::*;
+    use common_datavalues::*;
+    use pretty_assertions::assert_eq;
+
+    use crate::*;
+
+    #[allow(dead_code)]
+    struct Test {
+        name: &'static str,
+        display: &'static str,
+        nullable: bool,
+        expect_type: DataType,
+        expect: DataArrayRef,
+        func: Box<dyn IFunction>,
+    }
+
+    let schema = Arc::new(DataSchema::new(vec![
+        DataField::new("a", DataType::Int32, true),
+        DataField::new("b", DataType::Int64, false),
+        DataField::new("c", DataType::Boolean, true),
+    ]));
+    let block = DataBlock::create(
+        schema.clone(),
+        vec![
+            Arc::new(Int32Array::from(vec![Some(1), None, Some(3), Some(4)])),
+            Arc::new(Int64Array::from(vec![10, 20, 30, 40])),
+            Arc::new(BooleanArray::from(vec![
+                Some(true),
+                None,
+                Some(false),
+                Some(true),
+            ])),
+        ],
+    );
+
+    let field_a = ColumnFunction::try_create("a")?;
+    let field_b = ColumnFunction::try_create("b")?;
+    let field_c = ColumnFunction::try_create("c")?;
+
+    let tests = vec![
+        Test {
+            name: "if-passed",
+            display: "if(c, a, b)",
+            nullable: true,
+            expect_type: DataType::Int64,
+            func: FunctionFactory::get("if", &[field_c.clone(), field_a.clone(), field_b.clone()])?,
+            expect: Arc::new(Int64Array::from(vec![1, 20, 30, 4])),
+        },
+        Test {
+            name: "multi-if-passed",
+            display: "multiIf(a > 2, b, c, a, 0)",
+            nullable: true,
+            expect_type: DataType::Int64,
+            func: FunctionFactory::get(
+                "multiIf",
+                &[
+                    FunctionFactory::get(
+                        ">",
+                        &[
+                            field_a.clone(),
+                            LiteralFunction::try_create(DataValue::Int64(Some(2)))?,
+                        ],
+                    )?,
+                    field_b.clone(),
+                    field_c.clone(),
+                    field_a.clone(),
+                    LiteralFunction::try_create(DataValue::Int8(Some(0)))?,
+                ],
+            )?,
+            expect: Arc::new(Int64Array::from(vec![1, 0, 30, 40])),
+        },
+        Test {
+            name: "if-null-else-passed",
+            display: "if(c, b, Null)",
+            nullable: true,
+            expect_type: DataType::Int64,
+            func: FunctionFactory::get(
+                "if",
+                &[
+                    field_c.clone(),
+                    field_b.clone(),
+                    LiteralFunction::try_create(DataValue::Null)?,
+                ],
+            )?,
+            expect: Arc::new(Int64Array::from(vec![Some(10), None, None, Some(40)])),
+        },
+    ];
+
+    for t in tests {
+        let func = t.func;
+        assert_eq!(t.display, format!("{}", func), "{}", t.name);
+        assert_eq!(t.nullable, func.nullable(&schema)?, "{}", t.name);
+        assert_eq!(t.expect_type, func.return_type(&schema)?, "{}", t.name);
+
+        let v = func.eval(&block)?;
+        assert_eq!(t.expect_type, v.data_type(), "{}", t.name);
+        assert_eq!(
+            v.to_array(block.num_rows())?.as_ref(),
+            t.expect.as_ref(),
+            "{}",
+            t.name
+        );
+    }
+
+    // The scalars result in a scalar.
+    let func = FunctionFactory::get(
+        "if",
+        &[
+            LiteralFunction::try_create(DataValue::Boolean(Some(false)))?,
+            LiteralFunction::try_create(DataValue::Int8(Some(1)))?,
+            LiteralFunction::try_create(DataValue::Int8(Some(2)))?,
+        ],
+    )?;
+    assert!(matches!(
+        func.eval(&block)?,
+        DataColumnarValue::Scalar(DataValue::Int8(Some(2)))
+    ));
+
+    let func = FunctionFactory::get(
+        "if",
+        &[
+            field_c.clone(),
+            field_a.clone(),
+            LiteralFunction::try_create(DataValue::String(Some("x".to_string())))?,
+        ],
+    )?;
+    assert_eq!(
+        "Function Error: The results of if have no common type, Int32 and Utf8",
+        func.return_type(&schema).err().unwrap().to_string()
+    );
+
+    let func = FunctionFactory::get("multiIf", &[field_c.clone(), field_a.clone()]);
+    assert_eq!(
+        "Function Error: Function multiIf args length must be odd and at least 3",
+        func.err().unwrap().to_string()
+    );
+    Ok(())
+}
@@ -0,0 +1,12 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+#[cfg(test)]
+mod conditional_test;
+
+mod conditional;
+mod multi_if;
+
+pub use conditional::ConditionalFunction;
+pub use multi_if::MultiIfFunction;
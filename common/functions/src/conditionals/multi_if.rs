@@ -0,0 +1,140 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::fmt;
+
+use anyhow::{bail, ensure, Result};
+use common_arrow::arrow::array::Array;
+use common_datablocks::DataBlock;
+use common_datavalues::{
+    self as datavalues, BooleanArray, DataColumnarValue, DataSchema, DataType,
+};
+
+use crate::function_common::{eval_arrays, to_columnar};
+use crate::IFunction;
+
+/// `multiIf(cond1, then1, cond2, then2, ..., else)` is the result of the first true condition,
+/// `if(cond, then, else)` is the one condition one, and CASE expressions are planned as it.
+/// The NULL condition is false, and the results are casted to their common type.
+#[derive(Clone)]
+pub struct MultiIfFunction {
+    display_name: &'static str,
+    args: Vec<Box<dyn IFunction>>,
+}
+
+impl MultiIfFunction {
+    pub fn try_create_if(args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+        ensure!(
+            args.len() == 3,
+            "Function Error: Function if args length must be 3"
+        );
+        Self::try_create("if", args)
+    }
+
+    pub fn try_create_multi_if(args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+        ensure!(
+            args.len() >= 3 && args.len() % 2 == 1,
+            "Function Error: Function multiIf args length must be odd and at least 3"
+        );
+        Self::try_create("multiIf", args)
+    }
+
+    fn try_create(
+        display_name: &'static str,
+        args: &[Box<dyn IFunction>],
+    ) -> Result<Box<dyn IFunction>> {
+        Ok(Box::new(MultiIfFunction {
+            display_name,
+            args: args.to_vec(),
+        }))
+    }
+
+    /// The then results followed by the else result.
+    fn results(&self) -> Vec<&dyn IFunction> {
+        self.args
+            .iter()
+            .skip(1)
+            .step_by(2)
+            .map(|arg| arg.as_ref())
+            .collect()
+    }
+}
+
+impl IFunction for MultiIfFunction {
+    fn return_type(&self, input_schema: &DataSchema) -> Result<DataType> {
+        let results = self.results();
+        let mut data_type = results[0].return_type(input_schema)?;
+        for result in &results[1..] {
+            let result_type = result.return_type(input_schema)?;
+            data_type = match datavalues::equal_coercion(&data_type, &result_type) {
+                Ok(data_type) => data_type,
+                Err(_) => bail!(
+                    "Function Error: The results of {} have no common type, {:?} and {:?}",
+                    self.display_name,
+                    data_type,
+                    result_type
+                ),
+            };
+        }
+        Ok(data_type)
+    }
+
+    fn nullable(&self, input_schema: &DataSchema) -> Result<bool> {
+        for result in self.results() {
+            if result.nullable(input_schema)? {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    fn eval(&self, block: &DataBlock) -> Result<DataColumnarValue> {
+        let data_type = self.return_type(block.schema())?;
+        let funcs = self.args.iter().map(|arg| arg.as_ref()).collect::<Vec<_>>();
+        let (arrays, scalar) = eval_arrays(&funcs, block)?;
+
+        let mut conditions = Vec::with_capacity(arrays.len() / 2);
+        let mut results = Vec::with_capacity(arrays.len() / 2 + 1);
+        for (i, array) in arrays.iter().enumerate() {
+            if i % 2 == 0 && i + 1 < arrays.len() {
+                conditions.push(datavalues::data_array_cast(array, &DataType::Boolean)?);
+            } else {
+                results.push(datavalues::data_array_cast(array, &data_type)?);
+            }
+        }
+        let conditions = conditions
+            .iter()
+            .map(
+                |array| match array.as_any().downcast_ref::<BooleanArray>() {
+                    Some(array) => Ok(array),
+                    None => bail!(
+                        "Function Error: Cannot downcast the condition of {} to BooleanArray",
+                        self.display_name
+                    ),
+                },
+            )
+            .collect::<Result<Vec<_>>>()?;
+
+        let indices = (0..results[0].len())
+            .map(|row| {
+                let index = conditions
+                    .iter()
+                    .position(|cond| cond.is_valid(row) && cond.value(row));
+                Some(index.unwrap_or(conditions.len()))
+            })
+            .collect::<Vec<_>>();
+        to_columnar(datavalues::data_array_select(&results, &indices)?, scalar)
+    }
+}
+
+impl fmt::Display for MultiIfFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let args = self
+            .args
+            .iter()
+            .map(|arg| format!("{}", arg))
+            .collect::<Vec<_>>();
+        write!(f, "{}({})", self.display_name, args.join(", "))
+    }
+}
@@ -0,0 +1,123 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::fmt;
+
+use anyhow::{bail, ensure, Result};
+use common_arrow::arrow;
+use common_arrow::arrow::array::Array;
+use common_datablocks::DataBlock;
+use common_datavalues::{
+    data_array_cast, DataArrayRef, DataColumnarValue, DataSchema, DataType, DataValue,
+};
+
+use crate::function_common::{eval_arrays, to_columnar};
+use crate::IFunction;
+
+/// `CAST(v AS type)` and `v::type` are planned as the cast to the type, `toX(v)` is the one
+/// to the type X. The value which can't be casted is an error, `toXOrNull(v)` is NULL instead.
+#[derive(Clone)]
+pub struct CastFunction {
+    display_name: &'static str,
+    data_type: DataType,
+    or_null: bool,
+    arg: Box<dyn IFunction>,
+}
+
+impl CastFunction {
+    pub fn try_create_cast(
+        arg: Box<dyn IFunction>,
+        data_type: DataType,
+    ) -> Result<Box<dyn IFunction>> {
+        Self::try_create("CAST", data_type, false, &[arg])
+    }
+
+    pub fn try_create(
+        display_name: &'static str,
+        data_type: DataType,
+        or_null: bool,
+        args: &[Box<dyn IFunction>],
+    ) -> Result<Box<dyn IFunction>> {
+        ensure!(
+            args.len() == 1,
+            "Function Error: Function {} args length must be 1",
+            display_name
+        );
+
+        Ok(Box::new(CastFunction {
+            display_name,
+            data_type,
+            or_null,
+            arg: args[0].clone(),
+        }))
+    }
+
+    fn cast(&self, array: &DataArrayRef) -> Result<DataArrayRef> {
+        if self.or_null {
+            return match data_array_cast(array, &self.data_type) {
+                Ok(result) => Ok(result),
+                // Cast the rows one by one to find out the ones can't be casted.
+                Err(_) => {
+                    let rows = (0..array.len())
+                        .map(
+                            |row| match data_array_cast(&array.slice(row, 1), &self.data_type) {
+                                Ok(result) => Ok(result),
+                                Err(_) => data_array_cast(
+                                    &arrow::array::new_null_array(&DataType::Null, 1),
+                                    &self.data_type,
+                                ),
+                            },
+                        )
+                        .collect::<Result<Vec<_>>>()?;
+                    let rows = rows.iter().map(|row| row.as_ref()).collect::<Vec<_>>();
+                    Ok(arrow::compute::concat(&rows)?)
+                }
+            };
+        }
+
+        Self::strict_cast(array, &self.data_type)
+    }
+
+    /// Cast the array to the type, the value which can't be casted is an error rather than
+    /// the NULL of the arrow cast kernel. It's also used by the writes like INSERT.
+    pub fn strict_cast(array: &DataArrayRef, data_type: &DataType) -> Result<DataArrayRef> {
+        let result = data_array_cast(array, data_type)?;
+        if array.data_type() != &DataType::Null && result.null_count() > array.null_count() {
+            for row in 0..array.len() {
+                if array.is_valid(row) && result.is_null(row) {
+                    bail!(
+                        "Function Error: Cannot cast {} to {:?}",
+                        DataValue::try_from_array(array, row)?,
+                        data_type
+                    );
+                }
+            }
+        }
+        Ok(result)
+    }
+}
+
+impl IFunction for CastFunction {
+    fn return_type(&self, _input_schema: &DataSchema) -> Result<DataType> {
+        Ok(self.data_type.clone())
+    }
+
+    fn nullable(&self, input_schema: &DataSchema) -> Result<bool> {
+        Ok(self.or_null || self.arg.nullable(input_schema)?)
+    }
+
+    fn eval(&self, block: &DataBlock) -> Result<DataColumnarValue> {
+        let (arrays, scalar) = eval_arrays(&[self.arg.as_ref()], block)?;
+        to_columnar(self.cast(&arrays[0])?, scalar)
+    }
+}
+
+impl fmt::Display for CastFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.display_name {
+            "CAST" => write!(f, "CAST({} AS {:?})", self.arg, self.data_type),
+            name => write!(f, "{}({})", name, self.arg),
+        }
+    }
+}
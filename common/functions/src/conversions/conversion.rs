@@ -0,0 +1,50 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use anyhow::Result;
+use common_arrow::arrow::datatypes::TimeUnit;
+use common_datavalues::DataType;
+
+use crate::conversions::CastFunction;
+use crate::FactoryFuncRef;
+
+/// Register `toX(v)` and `toXOrNull(v)` of the type X, the latter is NULL if the cast fails.
+macro_rules! register_conversion {
+    ($map: expr, $key: expr, $name: expr, $data_type: expr) => {
+        $map.insert(concat!("to", $key), |args| {
+            CastFunction::try_create(concat!("to", $name), $data_type, false, args)
+        });
+        $map.insert(concat!("to", $key, "ornull"), |args| {
+            CastFunction::try_create(concat!("to", $name, "OrNull"), $data_type, true, args)
+        });
+    };
+}
+
+#[derive(Clone)]
+pub struct ConversionFunction;
+
+impl ConversionFunction {
+    pub fn register(map: FactoryFuncRef) -> Result<()> {
+        let mut map = map.write();
+        register_conversion!(map, "int8", "Int8", DataType::Int8);
+        register_conversion!(map, "int16", "Int16", DataType::Int16);
+        register_conversion!(map, "int32", "Int32", DataType::Int32);
+        register_conversion!(map, "int64", "Int64", DataType::Int64);
+        register_conversion!(map, "uint8", "UInt8", DataType::UInt8);
+        register_conversion!(map, "uint16", "UInt16", DataType::UInt16);
+        register_conversion!(map, "uint32", "UInt32", DataType::UInt32);
+        register_conversion!(map, "uint64", "UInt64", DataType::UInt64);
+        register_conversion!(map, "float32", "Float32", DataType::Float32);
+        register_conversion!(map, "float64", "Float64", DataType::Float64);
+        register_conversion!(map, "string", "String", DataType::Utf8);
+        register_conversion!(map, "date", "Date", DataType::Date32);
+        register_conversion!(
+            map,
+            "datetime",
+            "DateTime",
+            DataType::Timestamp(TimeUnit::Second, None)
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,123 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+#[test]
+fn test_conversion_function() -> anyhow::Result<()> {
+    use std::sync::Arc;
+
+    use common_datablocks::*;
+    use common_datavalues::*;
+    use pretty_assertions::assert_eq;
+
+    use crate::*;
+
+    #[allow(dead_code)]
+    struct Test {
+        name: &'static str,
+        display: &'static str,
+        nullable: bool,
+        expect_type: DataType,
+        expect: DataArrayRef,
+        func: Box<dyn IFunction>,
+    }
+
+    let schema = Arc::new(DataSchema::new(vec![
+        DataField::new("a", DataType::Utf8, true),
+        DataField::new("b", DataType::Int64, false),
+    ]));
+    let block = DataBlock::create(
+        schema.clone(),
+        vec![
+            Arc::new(StringArray::from(vec![
+                Some("1"),
+                Some("x"),
+                None,
+                Some("300"),
+            ])),
+            Arc::new(Int64Array::from(vec![1, 2, 3, 4])),
+        ],
+    );
+
+    let field_a = ColumnFunction::try_create("a")?;
+    let field_b = ColumnFunction::try_create("b")?;
+    let literal = |v: &str| LiteralFunction::try_create(DataValue::String(Some(v.to_string())));
+
+    let tests = vec![
+        Test {
+            name: "cast-passed",
+            display: "CAST(b AS Int8)",
+            nullable: false,
+            expect_type: DataType::Int8,
+            func: CastFunction::try_create_cast(field_b.clone(), DataType::Int8)?,
+            expect: Arc::new(Int8Array::from(vec![1, 2, 3, 4])),
+        },
+        Test {
+            name: "to-string-passed",
+            display: "toString(b)",
+            nullable: false,
+            expect_type: DataType::Utf8,
+            func: FunctionFactory::get("toString", &[field_b.clone()])?,
+            expect: Arc::new(StringArray::from(vec!["1", "2", "3", "4"])),
+        },
+        Test {
+            name: "to-float64-passed",
+            display: "toFloat64(b)",
+            nullable: false,
+            expect_type: DataType::Float64,
+            func: FunctionFactory::get("toFloat64", &[field_b.clone()])?,
+            expect: Arc::new(Float64Array::from(vec![1.0, 2.0, 3.0, 4.0])),
+        },
+        Test {
+            name: "to-int64-or-null-passed",
+            display: "toInt64OrNull(a)",
+            nullable: true,
+            expect_type: DataType::Int64,
+            func: FunctionFactory::get("toInt64OrNull", &[field_a.clone()])?,
+            expect: Arc::new(Int64Array::from(vec![Some(1), None, None, Some(300)])),
+        },
+        Test {
+            name: "to-uint8-or-null-overflow-passed",
+            display: "toUInt8OrNull(a)",
+            nullable: true,
+            expect_type: DataType::UInt8,
+            func: FunctionFactory::get("toUInt8OrNull", &[field_a.clone()])?,
+            expect: Arc::new(UInt8Array::from(vec![Some(1), None, None, None])),
+        },
+    ];
+
+    for t in tests {
+        let func = t.func;
+        assert_eq!(t.display, format!("{}", func), "{}", t.name);
+        assert_eq!(t.nullable, func.nullable(&schema)?, "{}", t.name);
+        assert_eq!(t.expect_type, func.return_type(&schema)?, "{}", t.name);
+
+        let v = func.eval(&block)?;
+        assert_eq!(t.expect_type, v.data_type(), "{}", t.name);
+        assert_eq!(
+            v.to_array(block.num_rows())?.as_ref(),
+            t.expect.as_ref(),
+            "{}",
+            t.name
+        );
+    }
+
+    // The literals result in a literal, the dates are parsed like the date literals.
+    let func = FunctionFactory::get("toDate", &[literal("2021-04-01")?])?;
+    assert!(matches!(
+        func.eval(&block)?,
+        DataColumnarValue::Scalar(DataValue::Date32(Some(18718)))
+    ));
+    let func = FunctionFactory::get("toDateOrNull", &[literal("2021-04-01 x")?])?;
+    assert!(matches!(
+        func.eval(&block)?,
+        DataColumnarValue::Scalar(DataValue::Date32(None))
+    ));
+
+    let func = FunctionFactory::get("toInt64", &[field_a.clone()])?;
+    assert_eq!(
+        "Function Error: Cannot cast x to Int64",
+        func.eval(&block).err().unwrap().to_string()
+    );
+    Ok(())
+}
@@ -0,0 +1,12 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+#[cfg(test)]
+mod conversion_test;
+
+mod cast;
+mod conversion;
+
+pub use cast::CastFunction;
+pub use conversion::ConversionFunction;
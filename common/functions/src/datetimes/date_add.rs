@@ -0,0 +1,154 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::fmt;
+
+use anyhow::{anyhow, bail, ensure, Result};
+use chrono_tz::Tz;
+use common_datablocks::DataBlock;
+use common_datavalues::{is_integer, DataColumnarValue, DataSchema, DataType};
+
+use crate::datetimes::{
+    check_temporal_type, integer_values, local_array, local_values, time_zone, time_zone_arg,
+    DateTimeUnit,
+};
+use crate::function_common::{eval_arrays, to_columnar};
+use crate::IFunction;
+
+/// Add or subtract the number of the units in the optional time zone, like `addDays(t, n[, tz])`.
+/// The days and the larger units keep the local time, the smaller units are the elapsed time.
+#[derive(Clone)]
+pub struct DateAddFunction {
+    display_name: &'static str,
+    unit: DateTimeUnit,
+    negative: bool,
+    arg: Box<dyn IFunction>,
+    delta: Box<dyn IFunction>,
+    tz: Option<Tz>,
+    tz_arg: Option<Box<dyn IFunction>>,
+}
+
+macro_rules! date_add_creator {
+    ($FUNC:ident, $NAME:expr, $UNIT:ident, $NEGATIVE:expr) => {
+        pub fn $FUNC(args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+            Self::try_create_func($NAME, DateTimeUnit::$UNIT, $NEGATIVE, args)
+        }
+    };
+}
+
+impl DateAddFunction {
+    pub fn try_create_func(
+        display_name: &'static str,
+        unit: DateTimeUnit,
+        negative: bool,
+        args: &[Box<dyn IFunction>],
+    ) -> Result<Box<dyn IFunction>> {
+        ensure!(
+            args.len() == 2 || args.len() == 3,
+            "Function Error: Function {} expects the date time, the number and the optional time zone arguments",
+            display_name
+        );
+
+        Ok(Box::new(DateAddFunction {
+            display_name,
+            unit,
+            negative,
+            arg: args[0].clone(),
+            delta: args[1].clone(),
+            tz: time_zone_arg(args.get(2))?,
+            tz_arg: args.get(2).cloned(),
+        }))
+    }
+
+    date_add_creator!(try_create_add_years, "addYears", Year, false);
+    date_add_creator!(try_create_add_quarters, "addQuarters", Quarter, false);
+    date_add_creator!(try_create_add_months, "addMonths", Month, false);
+    date_add_creator!(try_create_add_weeks, "addWeeks", Week, false);
+    date_add_creator!(try_create_add_days, "addDays", Day, false);
+    date_add_creator!(try_create_add_hours, "addHours", Hour, false);
+    date_add_creator!(try_create_add_minutes, "addMinutes", Minute, false);
+    date_add_creator!(try_create_add_seconds, "addSeconds", Second, false);
+    date_add_creator!(try_create_subtract_years, "subtractYears", Year, true);
+    date_add_creator!(
+        try_create_subtract_quarters,
+        "subtractQuarters",
+        Quarter,
+        true
+    );
+    date_add_creator!(try_create_subtract_months, "subtractMonths", Month, true);
+    date_add_creator!(try_create_subtract_weeks, "subtractWeeks", Week, true);
+    date_add_creator!(try_create_subtract_days, "subtractDays", Day, true);
+    date_add_creator!(try_create_subtract_hours, "subtractHours", Hour, true);
+    date_add_creator!(try_create_subtract_minutes, "subtractMinutes", Minute, true);
+    date_add_creator!(try_create_subtract_seconds, "subtractSeconds", Second, true);
+}
+
+impl IFunction for DateAddFunction {
+    fn return_type(&self, input_schema: &DataSchema) -> Result<DataType> {
+        let data_type = self.arg.return_type(input_schema)?;
+        check_temporal_type(self.display_name, &data_type)?;
+        let delta_type = self.delta.return_type(input_schema)?;
+        if !is_integer(&delta_type) {
+            bail!(
+                "Function Error: {} expects an integer argument, but got {:?}",
+                self.display_name,
+                delta_type
+            );
+        }
+        Ok(self.unit.result_type(&data_type))
+    }
+
+    fn nullable(&self, input_schema: &DataSchema) -> Result<bool> {
+        Ok(self.arg.nullable(input_schema)? || self.delta.nullable(input_schema)?)
+    }
+
+    fn eval(&self, block: &DataBlock) -> Result<DataColumnarValue> {
+        let (arrays, scalar) = eval_arrays(&[self.arg.as_ref(), self.delta.as_ref()], block)?;
+        check_temporal_type(self.display_name, arrays[0].data_type())?;
+        // The elapsed time is added in UTC.
+        let tz = match self.unit {
+            DateTimeUnit::Second | DateTimeUnit::Minute | DateTimeUnit::Hour => Tz::UTC,
+            _ => time_zone(&self.tz, arrays[0].data_type())?,
+        };
+        let values = local_values(&arrays[0], &tz)?;
+        let deltas = integer_values(&arrays[1])?;
+
+        let values = values
+            .iter()
+            .zip(deltas.iter())
+            .map(|(value, delta)| match (value, delta) {
+                (Some(value), Some(delta)) => {
+                    let delta = match self.negative {
+                        true => delta.checked_neg().ok_or_else(|| {
+                            anyhow!(
+                                "Function Error: {}({}, {}) is out of range",
+                                self.display_name,
+                                value,
+                                delta
+                            )
+                        })?,
+                        false => *delta,
+                    };
+                    self.unit.add(value, delta).map(Some)
+                }
+                _ => Ok(None),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let result = local_array(values, &self.unit.result_type(arrays[0].data_type()), &tz)?;
+        to_columnar(result, scalar)
+    }
+}
+
+impl fmt::Display for DateAddFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.tz_arg {
+            Some(tz) => write!(
+                f,
+                "{}({}, {}, {})",
+                self.display_name, self.arg, self.delta, tz
+            ),
+            None => write!(f, "{}({}, {})", self.display_name, self.arg, self.delta),
+        }
+    }
+}
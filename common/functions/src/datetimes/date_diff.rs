@@ -0,0 +1,89 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::fmt;
+use std::sync::Arc;
+
+use anyhow::{ensure, Result};
+use chrono_tz::Tz;
+use common_datablocks::DataBlock;
+use common_datavalues::{DataColumnarValue, DataSchema, DataType, Int64Array};
+
+use crate::datetimes::{
+    check_temporal_type, constant_string, local_values, time_zone, time_zone_arg, DateTimeUnit,
+};
+use crate::function_common::{eval_arrays, to_columnar};
+use crate::IFunction;
+
+/// The number of the unit boundaries crossed from the start to the end in the optional
+/// time zone, like `dateDiff('day', start, end[, tz])`.
+#[derive(Clone)]
+pub struct DateDiffFunction {
+    unit: DateTimeUnit,
+    start: Box<dyn IFunction>,
+    end: Box<dyn IFunction>,
+    tz: Option<Tz>,
+    args: Vec<Box<dyn IFunction>>,
+}
+
+impl DateDiffFunction {
+    pub fn try_create(args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+        ensure!(
+            args.len() == 3 || args.len() == 4,
+            "Function Error: Function dateDiff expects the unit, the start, the end and the optional time zone arguments"
+        );
+
+        Ok(Box::new(DateDiffFunction {
+            unit: DateTimeUnit::try_from_name(&constant_string(args[0].as_ref())?)?,
+            start: args[1].clone(),
+            end: args[2].clone(),
+            tz: time_zone_arg(args.get(3))?,
+            args: args.to_vec(),
+        }))
+    }
+}
+
+impl IFunction for DateDiffFunction {
+    fn return_type(&self, input_schema: &DataSchema) -> Result<DataType> {
+        check_temporal_type("dateDiff", &self.start.return_type(input_schema)?)?;
+        check_temporal_type("dateDiff", &self.end.return_type(input_schema)?)?;
+        Ok(DataType::Int64)
+    }
+
+    fn nullable(&self, input_schema: &DataSchema) -> Result<bool> {
+        Ok(self.start.nullable(input_schema)? || self.end.nullable(input_schema)?)
+    }
+
+    fn eval(&self, block: &DataBlock) -> Result<DataColumnarValue> {
+        let (arrays, scalar) = eval_arrays(&[self.start.as_ref(), self.end.as_ref()], block)?;
+        check_temporal_type("dateDiff", arrays[0].data_type())?;
+        check_temporal_type("dateDiff", arrays[1].data_type())?;
+        let tz = time_zone(&self.tz, arrays[0].data_type())?;
+        let starts = local_values(&arrays[0], &tz)?;
+        let ends = local_values(&arrays[1], &tz)?;
+
+        let result = starts
+            .iter()
+            .zip(ends.iter())
+            .map(|(start, end)| match (start, end) {
+                (Some(start), Some(end)) => {
+                    Some(self.unit.relative_num(end) - self.unit.relative_num(start))
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        to_columnar(Arc::new(Int64Array::from(result)), scalar)
+    }
+}
+
+impl fmt::Display for DateDiffFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let args = self
+            .args
+            .iter()
+            .map(|arg| arg.to_string())
+            .collect::<Vec<_>>();
+        write!(f, "dateDiff({})", args.join(", "))
+    }
+}
@@ -0,0 +1,168 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::fmt;
+use std::sync::Arc;
+
+use anyhow::{ensure, Result};
+use chrono::{Datelike, NaiveDateTime, Timelike};
+use chrono_tz::Tz;
+use common_datablocks::DataBlock;
+use common_datavalues::{
+    DataArrayRef, DataColumnarValue, DataSchema, DataType, UInt16Array, UInt8Array,
+};
+
+use crate::datetimes::{check_temporal_type, local_values, time_zone, time_zone_arg};
+use crate::function_common::{eval_arrays, to_columnar};
+use crate::IFunction;
+
+#[derive(Clone, Copy, Debug)]
+pub enum DatePart {
+    Year,
+    Quarter,
+    Month,
+    DayOfYear,
+    DayOfMonth,
+    /// Monday is 1 and Sunday is 7.
+    DayOfWeek,
+    Hour,
+    Minute,
+    Second,
+}
+
+/// Extract the part of the date or time in the optional time zone, like `toYear(t[, tz])`.
+#[derive(Clone)]
+pub struct DatePartFunction {
+    part: DatePart,
+    arg: Box<dyn IFunction>,
+    tz: Option<Tz>,
+}
+
+impl DatePartFunction {
+    pub fn try_create_func(
+        part: DatePart,
+        args: &[Box<dyn IFunction>],
+    ) -> Result<Box<dyn IFunction>> {
+        ensure!(
+            args.len() == 1 || args.len() == 2,
+            "Function Error: Function {} expects the date time and the optional time zone arguments",
+            part.name()
+        );
+
+        Ok(Box::new(DatePartFunction {
+            part,
+            arg: args[0].clone(),
+            tz: time_zone_arg(args.get(1))?,
+        }))
+    }
+
+    pub fn try_create_year(args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+        Self::try_create_func(DatePart::Year, args)
+    }
+
+    pub fn try_create_quarter(args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+        Self::try_create_func(DatePart::Quarter, args)
+    }
+
+    pub fn try_create_month(args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+        Self::try_create_func(DatePart::Month, args)
+    }
+
+    pub fn try_create_day_of_year(args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+        Self::try_create_func(DatePart::DayOfYear, args)
+    }
+
+    pub fn try_create_day_of_month(args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+        Self::try_create_func(DatePart::DayOfMonth, args)
+    }
+
+    pub fn try_create_day_of_week(args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+        Self::try_create_func(DatePart::DayOfWeek, args)
+    }
+
+    pub fn try_create_hour(args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+        Self::try_create_func(DatePart::Hour, args)
+    }
+
+    pub fn try_create_minute(args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+        Self::try_create_func(DatePart::Minute, args)
+    }
+
+    pub fn try_create_second(args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+        Self::try_create_func(DatePart::Second, args)
+    }
+}
+
+impl DatePart {
+    fn name(&self) -> &'static str {
+        match self {
+            DatePart::Year => "toYear",
+            DatePart::Quarter => "toQuarter",
+            DatePart::Month => "toMonth",
+            DatePart::DayOfYear => "toDayOfYear",
+            DatePart::DayOfMonth => "toDayOfMonth",
+            DatePart::DayOfWeek => "toDayOfWeek",
+            DatePart::Hour => "toHour",
+            DatePart::Minute => "toMinute",
+            DatePart::Second => "toSecond",
+        }
+    }
+
+    fn value(&self, time: &NaiveDateTime) -> u32 {
+        match self {
+            DatePart::Year => time.year() as u32,
+            DatePart::Quarter => time.month0() / 3 + 1,
+            DatePart::Month => time.month(),
+            DatePart::DayOfYear => time.ordinal(),
+            DatePart::DayOfMonth => time.day(),
+            DatePart::DayOfWeek => time.weekday().number_from_monday(),
+            DatePart::Hour => time.hour(),
+            DatePart::Minute => time.minute(),
+            DatePart::Second => time.second(),
+        }
+    }
+}
+
+impl IFunction for DatePartFunction {
+    fn return_type(&self, input_schema: &DataSchema) -> Result<DataType> {
+        check_temporal_type(self.part.name(), &self.arg.return_type(input_schema)?)?;
+        Ok(match self.part {
+            DatePart::Year | DatePart::DayOfYear => DataType::UInt16,
+            _ => DataType::UInt8,
+        })
+    }
+
+    fn nullable(&self, input_schema: &DataSchema) -> Result<bool> {
+        self.arg.nullable(input_schema)
+    }
+
+    fn eval(&self, block: &DataBlock) -> Result<DataColumnarValue> {
+        let (arrays, scalar) = eval_arrays(&[self.arg.as_ref()], block)?;
+        let array = &arrays[0];
+        check_temporal_type(self.part.name(), array.data_type())?;
+        let tz = time_zone(&self.tz, array.data_type())?;
+        let values = local_values(array, &tz)?
+            .into_iter()
+            .map(|v| v.map(|v| self.part.value(&v)));
+
+        let result: DataArrayRef = match self.part {
+            DatePart::Year | DatePart::DayOfYear => Arc::new(UInt16Array::from(
+                values.map(|v| v.map(|v| v as u16)).collect::<Vec<_>>(),
+            )),
+            _ => Arc::new(UInt8Array::from(
+                values.map(|v| v.map(|v| v as u8)).collect::<Vec<_>>(),
+            )),
+        };
+        to_columnar(result, scalar)
+    }
+}
+
+impl fmt::Display for DatePartFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.tz {
+            Some(tz) => write!(f, "{}({}, {})", self.part.name(), self.arg, tz.name()),
+            None => write!(f, "{}({})", self.part.name(), self.arg),
+        }
+    }
+}
@@ -0,0 +1,424 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::convert::TryFrom;
+use std::sync::Arc;
+
+use anyhow::{anyhow, bail, Result};
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, TimeZone};
+use chrono_tz::Tz;
+use common_arrow::arrow::datatypes::TimeUnit;
+use common_datablocks::DataBlock;
+use common_datavalues::{
+    data_array_cast, is_temporal, temporal_array, temporal_from_micros, temporal_to_micros,
+    temporal_values, DataArrayRef, DataColumnarValue, DataType, DataValue, Int64Array, StringArray,
+};
+
+use crate::datetimes::{
+    DateAddFunction, DateDiffFunction, DatePartFunction, FormatDateTimeFunction, NowFunction,
+    ParseDateTimeFunction, StartOfFunction, TodayFunction,
+};
+use crate::{FactoryFuncRef, IFunction};
+
+#[derive(Clone)]
+pub struct DateTimeFunction;
+
+impl DateTimeFunction {
+    pub fn register(map: FactoryFuncRef) -> Result<()> {
+        let mut map = map.write();
+        map.insert("now", NowFunction::try_create);
+        map.insert("today", TodayFunction::try_create);
+
+        map.insert("toyear", DatePartFunction::try_create_year);
+        map.insert("toquarter", DatePartFunction::try_create_quarter);
+        map.insert("tomonth", DatePartFunction::try_create_month);
+        map.insert("todayofyear", DatePartFunction::try_create_day_of_year);
+        map.insert("todayofmonth", DatePartFunction::try_create_day_of_month);
+        map.insert("todayofweek", DatePartFunction::try_create_day_of_week);
+        map.insert("tohour", DatePartFunction::try_create_hour);
+        map.insert("tominute", DatePartFunction::try_create_minute);
+        map.insert("tosecond", DatePartFunction::try_create_second);
+
+        map.insert("tostartofyear", StartOfFunction::try_create_year);
+        map.insert("tostartofquarter", StartOfFunction::try_create_quarter);
+        map.insert("tostartofmonth", StartOfFunction::try_create_month);
+        map.insert("tostartofweek", StartOfFunction::try_create_week);
+        map.insert("tostartofday", StartOfFunction::try_create_day);
+        map.insert("tostartofhour", StartOfFunction::try_create_hour);
+        map.insert("tostartofminute", StartOfFunction::try_create_minute);
+        map.insert("tostartofinterval", StartOfFunction::try_create_interval);
+        map.insert("date_trunc", StartOfFunction::try_create_date_trunc);
+
+        map.insert("datediff", DateDiffFunction::try_create);
+        map.insert("date_diff", DateDiffFunction::try_create);
+
+        map.insert("addyears", DateAddFunction::try_create_add_years);
+        map.insert("addquarters", DateAddFunction::try_create_add_quarters);
+        map.insert("addmonths", DateAddFunction::try_create_add_months);
+        map.insert("addweeks", DateAddFunction::try_create_add_weeks);
+        map.insert("adddays", DateAddFunction::try_create_add_days);
+        map.insert("addhours", DateAddFunction::try_create_add_hours);
+        map.insert("addminutes", DateAddFunction::try_create_add_minutes);
+        map.insert("addseconds", DateAddFunction::try_create_add_seconds);
+        map.insert("subtractyears", DateAddFunction::try_create_subtract_years);
+        map.insert(
+            "subtractquarters",
+            DateAddFunction::try_create_subtract_quarters,
+        );
+        map.insert(
+            "subtractmonths",
+            DateAddFunction::try_create_subtract_months,
+        );
+        map.insert("subtractweeks", DateAddFunction::try_create_subtract_weeks);
+        map.insert("subtractdays", DateAddFunction::try_create_subtract_days);
+        map.insert("subtracthours", DateAddFunction::try_create_subtract_hours);
+        map.insert(
+            "subtractminutes",
+            DateAddFunction::try_create_subtract_minutes,
+        );
+        map.insert(
+            "subtractseconds",
+            DateAddFunction::try_create_subtract_seconds,
+        );
+
+        map.insert("formatdatetime", FormatDateTimeFunction::try_create);
+        map.insert("parsedatetime", ParseDateTimeFunction::try_create);
+        Ok(())
+    }
+}
+
+/// The unit of the date and time functions, the name is case insensitive and may be plural.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub enum DateTimeUnit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Quarter,
+    Year,
+}
+
+impl DateTimeUnit {
+    pub fn try_from_name(name: &str) -> Result<Self> {
+        let lower = name.to_lowercase();
+        Ok(match lower.trim_end_matches('s') {
+            "second" => DateTimeUnit::Second,
+            "minute" => DateTimeUnit::Minute,
+            "hour" => DateTimeUnit::Hour,
+            "day" => DateTimeUnit::Day,
+            "week" => DateTimeUnit::Week,
+            "month" => DateTimeUnit::Month,
+            "quarter" => DateTimeUnit::Quarter,
+            "year" => DateTimeUnit::Year,
+            _ => bail!("Function Error: Unknown date time unit '{}'", name),
+        })
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            DateTimeUnit::Second => "second",
+            DateTimeUnit::Minute => "minute",
+            DateTimeUnit::Hour => "hour",
+            DateTimeUnit::Day => "day",
+            DateTimeUnit::Week => "week",
+            DateTimeUnit::Month => "month",
+            DateTimeUnit::Quarter => "quarter",
+            DateTimeUnit::Year => "year",
+        }
+    }
+
+    fn seconds(&self) -> i64 {
+        match self {
+            DateTimeUnit::Second => 1,
+            DateTimeUnit::Minute => 60,
+            DateTimeUnit::Hour => 3600,
+            _ => 86400,
+        }
+    }
+
+    fn months(&self) -> i64 {
+        match self {
+            DateTimeUnit::Quarter => 3,
+            DateTimeUnit::Year => 12,
+            _ => 1,
+        }
+    }
+
+    /// The number of the units from 1970-01-01 00:00:00 to the time, the weeks start on Monday.
+    pub fn relative_num(&self, time: &NaiveDateTime) -> i64 {
+        match self {
+            DateTimeUnit::Month | DateTimeUnit::Quarter | DateTimeUnit::Year => {
+                (time.year() as i64 * 12 + time.month0() as i64).div_euclid(self.months())
+            }
+            // 1970-01-01 is Thursday, 1969-12-29 is Monday.
+            DateTimeUnit::Week => (time.timestamp().div_euclid(86400) + 3).div_euclid(7),
+            _ => time.timestamp().div_euclid(self.seconds()),
+        }
+    }
+
+    /// The start of the n units which the time is in, counted from 1970-01-01 00:00:00,
+    /// or from the year 0 for the months.
+    pub fn truncate(&self, time: &NaiveDateTime, n: i64) -> Result<NaiveDateTime> {
+        if n <= 0 {
+            bail!(
+                "Function Error: The interval must be positive, but got {}",
+                n
+            );
+        }
+        let out_of_range = || {
+            anyhow!(
+                "Function Error: The start of {} {}s of {} is out of range",
+                n,
+                self.name(),
+                time
+            )
+        };
+        let num = self
+            .relative_num(time)
+            .div_euclid(n)
+            .checked_mul(n)
+            .ok_or_else(out_of_range)?;
+        match self {
+            DateTimeUnit::Month | DateTimeUnit::Quarter | DateTimeUnit::Year => {
+                let months = num.checked_mul(self.months()).ok_or_else(out_of_range)?;
+                date_time_of_month(months.div_euclid(12), months.rem_euclid(12) as u32 + 1, 1)
+            }
+            DateTimeUnit::Week => num
+                .checked_mul(7)
+                .and_then(|days| days.checked_sub(3))
+                .and_then(|days| days.checked_mul(86400))
+                .and_then(|seconds| NaiveDateTime::from_timestamp_opt(seconds, 0))
+                .ok_or_else(out_of_range),
+            _ => num
+                .checked_mul(self.seconds())
+                .and_then(|seconds| NaiveDateTime::from_timestamp_opt(seconds, 0))
+                .ok_or_else(out_of_range),
+        }
+    }
+
+    /// Add the n units to the time, the day of the month is clamped to the last day of the month.
+    pub fn add(&self, time: &NaiveDateTime, n: i64) -> Result<NaiveDateTime> {
+        let out_of_range = || {
+            anyhow!(
+                "Function Error: Adding {} {}s to {} is out of range",
+                n,
+                self.name(),
+                time
+            )
+        };
+        match self {
+            DateTimeUnit::Month | DateTimeUnit::Quarter | DateTimeUnit::Year => {
+                let months = n
+                    .checked_mul(self.months())
+                    .and_then(|v| v.checked_add(time.year() as i64 * 12 + time.month0() as i64))
+                    .ok_or_else(out_of_range)?;
+                let (year, month) = (months.div_euclid(12), months.rem_euclid(12) as u32 + 1);
+                let day = time.day().min(days_of_month(year, month)?);
+                Ok(date_time_of_month(year, month, day)?
+                    .date()
+                    .and_time(time.time()))
+            }
+            // The milliseconds never overflow the duration, the seconds may do.
+            DateTimeUnit::Week => n
+                .checked_mul(7 * 86400 * 1000)
+                .and_then(|millis| time.checked_add_signed(Duration::milliseconds(millis)))
+                .ok_or_else(out_of_range),
+            _ => n
+                .checked_mul(self.seconds() * 1000)
+                .and_then(|millis| time.checked_add_signed(Duration::milliseconds(millis)))
+                .ok_or_else(out_of_range),
+        }
+    }
+
+    /// The result type of the unit on the temporal type, the date is kept for the day or
+    /// the larger units, the others are the date time.
+    pub fn result_type(&self, data_type: &DataType) -> DataType {
+        match data_type {
+            DataType::Date32 if *self >= DateTimeUnit::Day => DataType::Date32,
+            DataType::Timestamp(unit, tz) => DataType::Timestamp(unit.clone(), tz.clone()),
+            _ => DataType::Timestamp(TimeUnit::Second, None),
+        }
+    }
+}
+
+fn date_time_of_month(year: i64, month: u32, day: u32) -> Result<NaiveDateTime> {
+    let date = i32::try_from(year)
+        .ok()
+        .and_then(|year| NaiveDate::from_ymd_opt(year, month, day));
+    match date {
+        Some(date) => Ok(date.and_hms(0, 0, 0)),
+        None => bail!(
+            "Function Error: Date {}-{}-{} is out of range",
+            year,
+            month,
+            day
+        ),
+    }
+}
+
+fn days_of_month(year: i64, month: u32) -> Result<u32> {
+    let next = match month {
+        12 => date_time_of_month(year + 1, 1, 1)?,
+        _ => date_time_of_month(year, month + 1, 1)?,
+    };
+    Ok(next.date().pred().day())
+}
+
+/// Check the argument is a temporal type or a string which is parsed as the timestamp.
+pub fn check_temporal_type(name: &str, data_type: &DataType) -> Result<()> {
+    if !is_temporal(data_type) && data_type != &DataType::Utf8 {
+        bail!(
+            "Function Error: {} expects a date or time argument, but got {:?}",
+            name,
+            data_type
+        );
+    }
+    Ok(())
+}
+
+/// The temporal type of the argument, the strings are parsed as the date time.
+pub fn temporal_type(data_type: &DataType) -> DataType {
+    match data_type {
+        DataType::Utf8 => DataType::Timestamp(TimeUnit::Second, None),
+        other => other.clone(),
+    }
+}
+
+/// The argument which must be a constant string, like the unit, the format and the time zone.
+/// It's evaluated when the function is created.
+pub fn constant_string(func: &dyn IFunction) -> Result<String> {
+    match func.eval(&DataBlock::empty()) {
+        Ok(DataColumnarValue::Scalar(DataValue::String(Some(v)))) => Ok(v),
+        _ => bail!(
+            "Function Error: The argument {} must be a constant string",
+            func
+        ),
+    }
+}
+
+/// The time zone of the optional argument.
+pub fn time_zone_arg(func: Option<&Box<dyn IFunction>>) -> Result<Option<Tz>> {
+    func.map(|func| parse_time_zone(&constant_string(func.as_ref())?))
+        .transpose()
+}
+
+/// The time zone of the argument, or of the timestamp type, UTC by default.
+pub fn time_zone(tz: &Option<Tz>, data_type: &DataType) -> Result<Tz> {
+    match (tz, data_type) {
+        (Some(tz), _) => Ok(*tz),
+        (None, DataType::Timestamp(_, Some(tz))) => parse_time_zone(tz),
+        _ => Ok(Tz::UTC),
+    }
+}
+
+pub fn parse_time_zone(name: &str) -> Result<Tz> {
+    match name.parse::<Tz>() {
+        Ok(tz) => Ok(tz),
+        Err(_) => bail!("Function Error: Unknown time zone '{}'", name),
+    }
+}
+
+/// The constant integer argument, like the interval.
+pub fn constant_integer(func: &dyn IFunction) -> Result<i64> {
+    let value = match func.eval(&DataBlock::empty()) {
+        Ok(DataColumnarValue::Scalar(v)) if !v.is_null() => v,
+        _ => bail!(
+            "Function Error: The argument {} must be a constant integer",
+            func
+        ),
+    };
+    match integer_values(&value.to_array(1)?)?[0] {
+        Some(v) => Ok(v),
+        None => bail!("Function Error: The argument {} must be an integer", func),
+    }
+}
+
+/// The values of the integer array as i64.
+pub fn integer_values(array: &DataArrayRef) -> Result<Vec<Option<i64>>> {
+    let array = data_array_cast(array, &DataType::Int64)?;
+    let array = array
+        .as_any()
+        .downcast_ref::<Int64Array>()
+        .ok_or_else(|| anyhow!("Function Error: Cannot downcast the array to Int64Array"))?;
+    Ok(array.iter().collect())
+}
+
+/// The local date times of the temporal array in the time zone, the dates are at midnight.
+pub fn local_values(array: &DataArrayRef, tz: &Tz) -> Result<Vec<Option<NaiveDateTime>>> {
+    let data_type = temporal_type(array.data_type());
+    let array = data_array_cast(array, &data_type)?;
+    temporal_values(&array)?
+        .into_iter()
+        .map(|v| {
+            v.map(|v| {
+                let time = naive_from_micros(temporal_to_micros(v, &data_type)?)?;
+                Ok(match data_type {
+                    DataType::Date32 => time,
+                    _ => tz.from_utc_datetime(&time).naive_local(),
+                })
+            })
+            .transpose()
+        })
+        .collect()
+}
+
+/// Build the temporal array from the local date times in the time zone.
+pub fn local_array(
+    values: Vec<Option<NaiveDateTime>>,
+    data_type: &DataType,
+    tz: &Tz,
+) -> Result<DataArrayRef> {
+    let values = values
+        .into_iter()
+        .map(|v| {
+            v.map(|v| {
+                let time = match data_type {
+                    DataType::Date32 => v,
+                    _ => local_to_utc(&v, tz),
+                };
+                temporal_from_micros(naive_to_micros(&time), data_type)
+            })
+            .transpose()
+        })
+        .collect::<Result<Vec<_>>>()?;
+    temporal_array(values, data_type)
+}
+
+pub fn string_array(values: Vec<Option<String>>) -> DataArrayRef {
+    Arc::new(StringArray::from(
+        values.iter().map(|v| v.as_deref()).collect::<Vec<_>>(),
+    ))
+}
+
+/// The UTC time of the local time in the time zone, the earlier one if it's ambiguous.
+/// The local time skipped by the daylight saving time is moved forward.
+pub fn local_to_utc(time: &NaiveDateTime, tz: &Tz) -> NaiveDateTime {
+    let mut local = *time;
+    for _ in 0..4 {
+        if let Some(v) = tz.from_local_datetime(&local).earliest() {
+            return v.naive_utc();
+        }
+        local += Duration::minutes(30);
+    }
+    tz.from_utc_datetime(time).naive_utc()
+}
+
+pub fn naive_from_micros(micros: i64) -> Result<NaiveDateTime> {
+    NaiveDateTime::from_timestamp_opt(
+        micros.div_euclid(1_000_000),
+        (micros.rem_euclid(1_000_000) * 1000) as u32,
+    )
+    .ok_or_else(|| {
+        anyhow!(
+            "Function Error: {} microseconds is out of the time range",
+            micros
+        )
+    })
+}
+
+pub fn naive_to_micros(time: &NaiveDateTime) -> i64 {
+    time.timestamp() * 1_000_000 + time.timestamp_subsec_micros() as i64
+}
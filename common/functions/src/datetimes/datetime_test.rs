@@ -0,0 +1,424 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+#[test]
+fn test_datetime_function() -> anyhow::Result<()> {
+    use std::sync::Arc;
+
+    use common_arrow::arrow::datatypes::TimeUnit;
+    use common_datablocks::*;
+    use common_datavalues::*;
+    use pretty_assertions::assert_eq;
+
+    use crate::*;
+
+    #[allow(dead_code)]
+    struct Test {
+        name: &'static str,
+        display: &'static str,
+        nullable: bool,
+        block: DataBlock,
+        expect: DataArrayRef,
+        error: &'static str,
+        func: Box<dyn IFunction>,
+    }
+
+    let schema = Arc::new(DataSchema::new(vec![
+        DataField::new("a", DataType::Timestamp(TimeUnit::Second, None), false),
+        DataField::new("b", DataType::Date32, false),
+        DataField::new("c", DataType::Int64, false),
+    ]));
+    // 2021-01-01 12:30:00 and 2021-03-01 00:00:00 in UTC.
+    let block = DataBlock::create(
+        schema,
+        vec![
+            Arc::new(TimestampSecondArray::from_vec(
+                vec![1609504200, 1614556800],
+                None,
+            )),
+            Arc::new(Date32Array::from(vec![18628, 18687])),
+            Arc::new(Int64Array::from(vec![1, 2])),
+        ],
+    );
+
+    let field_a = ColumnFunction::try_create("a")?;
+    let field_b = ColumnFunction::try_create("b")?;
+    let field_c = ColumnFunction::try_create("c")?;
+    let literal = |v: &str| LiteralFunction::try_create(DataValue::String(Some(v.to_string())));
+    let shanghai = literal("Asia/Shanghai")?;
+
+    let tests = vec![
+        Test {
+            name: "to-year-passed",
+            display: "toYear(a)",
+            nullable: false,
+            func: FunctionFactory::get("toYear", &[field_a.clone()])?,
+            block: block.clone(),
+            expect: Arc::new(UInt16Array::from(vec![2021, 2021])),
+            error: "",
+        },
+        Test {
+            name: "to-day-of-month-time-zone-passed",
+            display: "toDayOfMonth(a, America/New_York)",
+            nullable: false,
+            func: FunctionFactory::get(
+                "toDayOfMonth",
+                &[field_a.clone(), literal("America/New_York")?],
+            )?,
+            block: block.clone(),
+            expect: Arc::new(UInt8Array::from(vec![1, 28])),
+            error: "",
+        },
+        Test {
+            name: "to-day-of-week-date-passed",
+            display: "toDayOfWeek(b)",
+            nullable: false,
+            func: FunctionFactory::get("toDayOfWeek", &[field_b.clone()])?,
+            block: block.clone(),
+            expect: Arc::new(UInt8Array::from(vec![5, 1])),
+            error: "",
+        },
+        Test {
+            name: "to-hour-time-zone-passed",
+            display: "toHour(a, Asia/Shanghai)",
+            nullable: false,
+            func: FunctionFactory::get("toHour", &[field_a.clone(), shanghai.clone()])?,
+            block: block.clone(),
+            expect: Arc::new(UInt8Array::from(vec![20, 8])),
+            error: "",
+        },
+        Test {
+            name: "to-start-of-month-passed",
+            display: "toStartOfMonth(a)",
+            nullable: false,
+            func: FunctionFactory::get("toStartOfMonth", &[field_a.clone()])?,
+            block: block.clone(),
+            expect: Arc::new(Date32Array::from(vec![18628, 18687])),
+            error: "",
+        },
+        Test {
+            name: "to-start-of-hour-passed",
+            display: "toStartOfHour(a)",
+            nullable: false,
+            func: FunctionFactory::get("toStartOfHour", &[field_a.clone()])?,
+            block: block.clone(),
+            expect: Arc::new(TimestampSecondArray::from_vec(
+                vec![1609502400, 1614556800],
+                None,
+            )),
+            error: "",
+        },
+        Test {
+            name: "to-start-of-day-time-zone-passed",
+            display: "toStartOfDay(a, Asia/Shanghai)",
+            nullable: false,
+            func: FunctionFactory::get("toStartOfDay", &[field_a.clone(), shanghai.clone()])?,
+            block: block.clone(),
+            expect: Arc::new(TimestampSecondArray::from_vec(
+                vec![1609430400, 1614528000],
+                None,
+            )),
+            error: "",
+        },
+        Test {
+            name: "to-start-of-interval-passed",
+            display: "toStartOfInterval(a, 20, minute)",
+            nullable: false,
+            func: FunctionFactory::get(
+                "toStartOfInterval",
+                &[
+                    field_a.clone(),
+                    LiteralFunction::try_create(DataValue::Int64(Some(20)))?,
+                    literal("minute")?,
+                ],
+            )?,
+            block: block.clone(),
+            expect: Arc::new(TimestampSecondArray::from_vec(
+                vec![1609503600, 1614556800],
+                None,
+            )),
+            error: "",
+        },
+        Test {
+            name: "date-trunc-week-passed",
+            display: "date_trunc(week, b)",
+            nullable: false,
+            func: FunctionFactory::get("date_trunc", &[literal("week")?, field_b.clone()])?,
+            block: block.clone(),
+            expect: Arc::new(Date32Array::from(vec![18624, 18687])),
+            error: "",
+        },
+        Test {
+            name: "date-diff-passed",
+            display: "dateDiff(day, 2020-12-25, a)",
+            nullable: false,
+            func: FunctionFactory::get(
+                "dateDiff",
+                &[literal("day")?, literal("2020-12-25")?, field_a.clone()],
+            )?,
+            block: block.clone(),
+            expect: Arc::new(Int64Array::from(vec![7, 66])),
+            error: "",
+        },
+        Test {
+            name: "add-months-passed",
+            display: "addMonths(b, c)",
+            nullable: false,
+            func: FunctionFactory::get("addMonths", &[field_b.clone(), field_c.clone()])?,
+            block: block.clone(),
+            expect: Arc::new(Date32Array::from(vec![18659, 18748])),
+            error: "",
+        },
+        Test {
+            name: "add-months-last-day-passed",
+            display: "addMonths(2021-01-31, 1)",
+            nullable: false,
+            func: FunctionFactory::get(
+                "addMonths",
+                &[
+                    literal("2021-01-31")?,
+                    LiteralFunction::try_create(DataValue::Int64(Some(1)))?,
+                ],
+            )?,
+            block: block.clone(),
+            expect: Arc::new(TimestampSecondArray::from_vec(
+                vec![1614470400, 1614470400],
+                None,
+            )),
+            error: "",
+        },
+        Test {
+            name: "subtract-hours-date-passed",
+            display: "subtractHours(b, c)",
+            nullable: false,
+            func: FunctionFactory::get("subtractHours", &[field_b.clone(), field_c.clone()])?,
+            block: block.clone(),
+            expect: Arc::new(TimestampSecondArray::from_vec(
+                vec![1609455600, 1614549600],
+                None,
+            )),
+            error: "",
+        },
+        Test {
+            name: "add-days-out-of-range-error",
+            display: "addDays(2020-01-01, 10000000000)",
+            nullable: false,
+            func: FunctionFactory::get(
+                "addDays",
+                &[
+                    literal("2020-01-01")?,
+                    LiteralFunction::try_create(DataValue::Int64(Some(10_000_000_000)))?,
+                ],
+            )?,
+            block: block.clone(),
+            expect: Arc::new(TimestampSecondArray::from_vec(vec![0], None)),
+            error: "Function Error: Adding 10000000000 days to 2020-01-01 00:00:00 is out of range",
+        },
+        Test {
+            name: "add-weeks-overflow-error",
+            display: "addWeeks(2020-01-01, 9223372036854775807)",
+            nullable: false,
+            func: FunctionFactory::get(
+                "addWeeks",
+                &[
+                    literal("2020-01-01")?,
+                    LiteralFunction::try_create(DataValue::Int64(Some(i64::MAX)))?,
+                ],
+            )?,
+            block: block.clone(),
+            expect: Arc::new(TimestampSecondArray::from_vec(vec![0], None)),
+            error: "Function Error: Adding 9223372036854775807 weeks to 2020-01-01 00:00:00 is out of range",
+        },
+        Test {
+            name: "add-years-overflow-error",
+            display: "addYears(2020-01-01, 1000000000000000000)",
+            nullable: false,
+            func: FunctionFactory::get(
+                "addYears",
+                &[
+                    literal("2020-01-01")?,
+                    LiteralFunction::try_create(DataValue::Int64(Some(1_000_000_000_000_000_000)))?,
+                ],
+            )?,
+            block: block.clone(),
+            expect: Arc::new(TimestampSecondArray::from_vec(vec![0], None)),
+            error: "Function Error: Adding 1000000000000000000 years to 2020-01-01 00:00:00 is out of range",
+        },
+        Test {
+            name: "subtract-days-overflow-error",
+            display: "subtractDays(2020-01-01, -9223372036854775808)",
+            nullable: false,
+            func: FunctionFactory::get(
+                "subtractDays",
+                &[
+                    literal("2020-01-01")?,
+                    LiteralFunction::try_create(DataValue::Int64(Some(i64::MIN)))?,
+                ],
+            )?,
+            block: block.clone(),
+            expect: Arc::new(TimestampSecondArray::from_vec(vec![0], None)),
+            error: "Function Error: subtractDays(2020-01-01 00:00:00, -9223372036854775808) is out of range",
+        },
+        Test {
+            name: "to-start-of-interval-overflow-error",
+            display: "toStartOfInterval(1960-01-01, 9223372036854775807, minute)",
+            nullable: false,
+            func: FunctionFactory::get(
+                "toStartOfInterval",
+                &[
+                    literal("1960-01-01")?,
+                    LiteralFunction::try_create(DataValue::Int64(Some(i64::MAX)))?,
+                    literal("minute")?,
+                ],
+            )?,
+            block: block.clone(),
+            expect: Arc::new(TimestampSecondArray::from_vec(vec![0], None)),
+            error: "Function Error: The start of 9223372036854775807 minutes of 1960-01-01 00:00:00 is out of range",
+        },
+        Test {
+            name: "format-date-time-passed",
+            display: "formatDateTime(a, %Y/%m/%d %H:%M, Asia/Shanghai)",
+            nullable: false,
+            func: FunctionFactory::get(
+                "formatDateTime",
+                &[
+                    field_a.clone(),
+                    literal("%Y/%m/%d %H:%M")?,
+                    shanghai.clone(),
+                ],
+            )?,
+            block: block.clone(),
+            expect: Arc::new(StringArray::from(vec![
+                "2021/01/01 20:30",
+                "2021/03/01 08:00",
+            ])),
+            error: "",
+        },
+        Test {
+            name: "parse-date-time-passed",
+            display: "parseDateTime(01/03/2021 08:00, %d/%m/%Y %H:%M, Asia/Shanghai)",
+            nullable: false,
+            func: FunctionFactory::get(
+                "parseDateTime",
+                &[
+                    literal("01/03/2021 08:00")?,
+                    literal("%d/%m/%Y %H:%M")?,
+                    shanghai.clone(),
+                ],
+            )?,
+            block: block.clone(),
+            expect: Arc::new(TimestampSecondArray::from_vec(
+                vec![1614556800, 1614556800],
+                None,
+            )),
+            error: "",
+        },
+        Test {
+            name: "to-year-not-temporal-error",
+            display: "toYear(c)",
+            nullable: false,
+            func: FunctionFactory::get("toYear", &[field_c.clone()])?,
+            block: block.clone(),
+            expect: Arc::new(UInt16Array::from(vec![0])),
+            error: "Function Error: toYear expects a date or time argument, but got Int64",
+        },
+        Test {
+            name: "parse-date-time-error",
+            display: "parseDateTime(2021-13-01, %Y-%m-%d)",
+            nullable: false,
+            func: FunctionFactory::get(
+                "parseDateTime",
+                &[literal("2021-13-01")?, literal("%Y-%m-%d")?],
+            )?,
+            block: block.clone(),
+            expect: Arc::new(TimestampSecondArray::from_vec(vec![0], None)),
+            error: "Function Error: Cannot parse '2021-13-01' with format '%Y-%m-%d'",
+        },
+    ];
+
+    for t in tests {
+        let func = t.func;
+
+        // Display check.
+        let expect_display = t.display.to_string();
+        let actual_display = format!("{}", func);
+        assert_eq!(expect_display, actual_display, "{}", t.name);
+
+        // Nullable check.
+        let expect_null = t.nullable;
+        let actual_null = func.nullable(t.block.schema())?;
+        assert_eq!(expect_null, actual_null, "{}", t.name);
+
+        let v = match func.eval(&t.block) {
+            Ok(v) => v,
+            Err(e) => {
+                assert_eq!(t.error, e.to_string(), "{}", t.name);
+                continue;
+            }
+        };
+        // Type check.
+        let expect_type = func.return_type(t.block.schema())?;
+        let actual_type = v.data_type();
+        assert_eq!(expect_type, actual_type, "{}", t.name);
+        assert_eq!(
+            v.to_array(t.block.num_rows())?.as_ref(),
+            t.expect.as_ref(),
+            "{}",
+            t.name
+        );
+    }
+
+    // The arguments are checked when the functions are created.
+    let field_a = ColumnFunction::try_create("a")?;
+    let errors = vec![
+        (
+            FunctionFactory::get("date_trunc", &[field_a.clone(), field_a.clone()]),
+            "Function Error: The argument a must be a constant string",
+        ),
+        (
+            FunctionFactory::get("date_trunc", &[literal("decade")?, field_a.clone()]),
+            "Function Error: Unknown date time unit 'decade'",
+        ),
+        (
+            FunctionFactory::get("toYear", &[field_a.clone(), literal("Mars/Olympus")?]),
+            "Function Error: Unknown time zone 'Mars/Olympus'",
+        ),
+        (
+            FunctionFactory::get("formatDateTime", &[field_a.clone(), literal("%Y-%")?]),
+            "Function Error: Invalid date time format '%Y-%'",
+        ),
+        (
+            FunctionFactory::get(
+                "toStartOfInterval",
+                &[
+                    field_a.clone(),
+                    LiteralFunction::try_create(DataValue::Int64(Some(0)))?,
+                    literal("day")?,
+                ],
+            ),
+            "",
+        ),
+    ];
+    for (result, expect) in errors {
+        match result {
+            Ok(func) => assert_eq!(
+                "Function Error: The interval must be positive, but got 0",
+                func.eval(&block).err().unwrap().to_string()
+            ),
+            Err(e) => assert_eq!(expect, e.to_string()),
+        }
+    }
+
+    // The current time is the same in the whole query.
+    let now = FunctionFactory::get("now", &[])?;
+    assert_eq!(
+        DataType::Timestamp(TimeUnit::Second, None),
+        now.return_type(block.schema())?
+    );
+    assert_eq!(
+        format!("{:?}", now.eval(&block)?),
+        format!("{:?}", now.eval(&block)?)
+    );
+    Ok(())
+}
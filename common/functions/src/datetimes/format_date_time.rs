@@ -0,0 +1,103 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::fmt;
+
+use anyhow::{bail, ensure, Result};
+use chrono::format::{Item, StrftimeItems};
+use chrono::TimeZone;
+use chrono_tz::Tz;
+use common_datablocks::DataBlock;
+use common_datavalues::{
+    data_array_cast, temporal_to_micros, temporal_values, DataColumnarValue, DataSchema, DataType,
+};
+
+use crate::datetimes::{
+    check_temporal_type, constant_string, naive_from_micros, string_array, temporal_type,
+    time_zone, time_zone_arg,
+};
+use crate::function_common::{eval_arrays, to_columnar};
+use crate::IFunction;
+
+/// Format the date or time in the optional time zone by the strftime format,
+/// like `formatDateTime(t, '%Y-%m-%d %H:%M:%S'[, tz])`.
+#[derive(Clone)]
+pub struct FormatDateTimeFunction {
+    arg: Box<dyn IFunction>,
+    format: String,
+    tz: Option<Tz>,
+    args: Vec<Box<dyn IFunction>>,
+}
+
+impl FormatDateTimeFunction {
+    pub fn try_create(args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+        ensure!(
+            args.len() == 2 || args.len() == 3,
+            "Function Error: Function formatDateTime expects the date time, the format and the optional time zone arguments"
+        );
+
+        let format = constant_string(args[1].as_ref())?;
+        check_format(&format)?;
+        Ok(Box::new(FormatDateTimeFunction {
+            arg: args[0].clone(),
+            format,
+            tz: time_zone_arg(args.get(2))?,
+            args: args.to_vec(),
+        }))
+    }
+}
+
+/// The invalid format can't be displayed, it's checked before formatting.
+pub fn check_format(format: &str) -> Result<()> {
+    if StrftimeItems::new(format).any(|item| matches!(item, Item::Error)) {
+        bail!("Function Error: Invalid date time format '{}'", format);
+    }
+    Ok(())
+}
+
+impl IFunction for FormatDateTimeFunction {
+    fn return_type(&self, input_schema: &DataSchema) -> Result<DataType> {
+        check_temporal_type("formatDateTime", &self.arg.return_type(input_schema)?)?;
+        Ok(DataType::Utf8)
+    }
+
+    fn nullable(&self, input_schema: &DataSchema) -> Result<bool> {
+        self.arg.nullable(input_schema)
+    }
+
+    fn eval(&self, block: &DataBlock) -> Result<DataColumnarValue> {
+        let (arrays, scalar) = eval_arrays(&[self.arg.as_ref()], block)?;
+        check_temporal_type("formatDateTime", arrays[0].data_type())?;
+        let tz = time_zone(&self.tz, arrays[0].data_type())?;
+        let data_type = temporal_type(arrays[0].data_type());
+        let array = data_array_cast(&arrays[0], &data_type)?;
+
+        let values = temporal_values(&array)?
+            .into_iter()
+            .map(|v| {
+                v.map(|v| {
+                    let time = naive_from_micros(temporal_to_micros(v, &data_type)?)?;
+                    // The date has no time zone.
+                    Ok(match data_type {
+                        DataType::Date32 => time.format(&self.format).to_string(),
+                        _ => tz.from_utc_datetime(&time).format(&self.format).to_string(),
+                    })
+                })
+                .transpose()
+            })
+            .collect::<Result<Vec<_>>>()?;
+        to_columnar(string_array(values), scalar)
+    }
+}
+
+impl fmt::Display for FormatDateTimeFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let args = self
+            .args
+            .iter()
+            .map(|arg| arg.to_string())
+            .collect::<Vec<_>>();
+        write!(f, "formatDateTime({})", args.join(", "))
+    }
+}
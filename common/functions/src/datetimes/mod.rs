@@ -0,0 +1,26 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+#[cfg(test)]
+mod datetime_test;
+
+mod date_add;
+mod date_diff;
+mod date_part;
+mod datetime;
+mod format_date_time;
+mod now;
+mod parse_date_time;
+mod start_of;
+mod today;
+
+pub use date_add::DateAddFunction;
+pub use date_diff::DateDiffFunction;
+pub use date_part::{DatePart, DatePartFunction};
+pub use datetime::*;
+pub use format_date_time::{check_format, FormatDateTimeFunction};
+pub use now::NowFunction;
+pub use parse_date_time::ParseDateTimeFunction;
+pub use start_of::StartOfFunction;
+pub use today::TodayFunction;
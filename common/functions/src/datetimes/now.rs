@@ -0,0 +1,54 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::fmt;
+
+use anyhow::{ensure, Result};
+use chrono::Utc;
+use common_arrow::arrow::datatypes::TimeUnit;
+use common_datablocks::DataBlock;
+use common_datavalues::{DataColumnarValue, DataSchema, DataType, DataValue};
+
+use crate::IFunction;
+
+/// The current date time, it's the same in the whole query.
+#[derive(Clone)]
+pub struct NowFunction {
+    seconds: i64,
+}
+
+impl NowFunction {
+    pub fn try_create(args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+        ensure!(
+            args.is_empty(),
+            "Function Error: Function now expects no arguments"
+        );
+
+        Ok(Box::new(NowFunction {
+            seconds: Utc::now().timestamp(),
+        }))
+    }
+}
+
+impl IFunction for NowFunction {
+    fn return_type(&self, _input_schema: &DataSchema) -> Result<DataType> {
+        Ok(DataType::Timestamp(TimeUnit::Second, None))
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, _block: &DataBlock) -> Result<DataColumnarValue> {
+        Ok(DataColumnarValue::Scalar(DataValue::TimestampSecond(Some(
+            self.seconds,
+        ))))
+    }
+}
+
+impl fmt::Display for NowFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "now()")
+    }
+}
@@ -0,0 +1,114 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::fmt;
+use std::sync::Arc;
+
+use anyhow::{bail, ensure, Result};
+use chrono::{DateTime, NaiveDate, NaiveDateTime};
+use chrono_tz::Tz;
+use common_arrow::arrow::datatypes::TimeUnit;
+use common_datablocks::DataBlock;
+use common_datavalues::{
+    DataColumnarValue, DataSchema, DataType, StringArray, TimestampSecondArray,
+};
+
+use crate::datetimes::{check_format, constant_string, local_to_utc, time_zone, time_zone_arg};
+use crate::function_common::{eval_arrays, to_columnar};
+use crate::IFunction;
+
+/// Parse the string by the strftime format into the date time, the time without the offset
+/// is in the optional time zone, like `parseDateTime('2021-01-01 12:00', '%Y-%m-%d %H:%M'[, tz])`.
+#[derive(Clone)]
+pub struct ParseDateTimeFunction {
+    arg: Box<dyn IFunction>,
+    format: String,
+    tz: Option<Tz>,
+    args: Vec<Box<dyn IFunction>>,
+}
+
+impl ParseDateTimeFunction {
+    pub fn try_create(args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+        ensure!(
+            args.len() == 2 || args.len() == 3,
+            "Function Error: Function parseDateTime expects the string, the format and the optional time zone arguments"
+        );
+
+        let format = constant_string(args[1].as_ref())?;
+        check_format(&format)?;
+        Ok(Box::new(ParseDateTimeFunction {
+            arg: args[0].clone(),
+            format,
+            tz: time_zone_arg(args.get(2))?,
+            args: args.to_vec(),
+        }))
+    }
+
+    fn parse(&self, value: &str, tz: &Tz) -> Result<i64> {
+        if let Ok(v) = DateTime::parse_from_str(value, &self.format) {
+            return Ok(v.timestamp());
+        }
+        let local = match NaiveDateTime::parse_from_str(value, &self.format) {
+            Ok(v) => v,
+            Err(_) => match NaiveDate::parse_from_str(value, &self.format) {
+                Ok(v) => v.and_hms(0, 0, 0),
+                Err(_) => bail!(
+                    "Function Error: Cannot parse '{}' with format '{}'",
+                    value,
+                    self.format
+                ),
+            },
+        };
+        Ok(local_to_utc(&local, tz).timestamp())
+    }
+}
+
+impl IFunction for ParseDateTimeFunction {
+    fn return_type(&self, input_schema: &DataSchema) -> Result<DataType> {
+        let data_type = self.arg.return_type(input_schema)?;
+        if data_type != DataType::Utf8 {
+            bail!(
+                "Function Error: parseDateTime expects a string argument, but got {:?}",
+                data_type
+            );
+        }
+        Ok(DataType::Timestamp(TimeUnit::Second, None))
+    }
+
+    fn nullable(&self, input_schema: &DataSchema) -> Result<bool> {
+        self.arg.nullable(input_schema)
+    }
+
+    fn eval(&self, block: &DataBlock) -> Result<DataColumnarValue> {
+        let (arrays, scalar) = eval_arrays(&[self.arg.as_ref()], block)?;
+        let strings = match arrays[0].as_any().downcast_ref::<StringArray>() {
+            Some(strings) => strings,
+            None => bail!(
+                "Function Error: parseDateTime expects a string argument, but got {:?}",
+                arrays[0].data_type()
+            ),
+        };
+        let tz = time_zone(&self.tz, &DataType::Utf8)?;
+
+        let values = strings
+            .iter()
+            .map(|v| v.map(|v| self.parse(v, &tz)).transpose())
+            .collect::<Result<Vec<_>>>()?;
+        to_columnar(
+            Arc::new(TimestampSecondArray::from_opt_vec(values, None)),
+            scalar,
+        )
+    }
+}
+
+impl fmt::Display for ParseDateTimeFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let args = self
+            .args
+            .iter()
+            .map(|arg| arg.to_string())
+            .collect::<Vec<_>>();
+        write!(f, "parseDateTime({})", args.join(", "))
+    }
+}
@@ -0,0 +1,163 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::fmt;
+
+use anyhow::{ensure, Result};
+use chrono_tz::Tz;
+use common_datablocks::DataBlock;
+use common_datavalues::{DataColumnarValue, DataSchema, DataType};
+
+use crate::datetimes::{
+    check_temporal_type, constant_integer, constant_string, local_array, local_values, time_zone,
+    time_zone_arg, DateTimeUnit,
+};
+use crate::function_common::{eval_arrays, to_columnar};
+use crate::IFunction;
+
+/// Round down the date or time to the start of the unit in the optional time zone:
+/// `toStartOfDay(t[, tz])`, `toStartOfInterval(t, n, unit[, tz])` and `date_trunc(unit, t[, tz])`.
+/// The year, quarter, month and week of `toStartOfX` are dates, the others are date times.
+/// The intervals of the days and the smaller units are counted from 1970-01-01 00:00:00.
+#[derive(Clone)]
+pub struct StartOfFunction {
+    display_name: &'static str,
+    unit: DateTimeUnit,
+    interval: i64,
+    /// The unit is the given one, otherwise it's fixed by the function name.
+    unit_arg: bool,
+    arg: Box<dyn IFunction>,
+    tz: Option<Tz>,
+    args: Vec<Box<dyn IFunction>>,
+}
+
+impl StartOfFunction {
+    fn try_create_fixed(
+        display_name: &'static str,
+        unit: DateTimeUnit,
+        args: &[Box<dyn IFunction>],
+    ) -> Result<Box<dyn IFunction>> {
+        ensure!(
+            args.len() == 1 || args.len() == 2,
+            "Function Error: Function {} expects the date time and the optional time zone arguments",
+            display_name
+        );
+
+        Ok(Box::new(StartOfFunction {
+            display_name,
+            unit,
+            interval: 1,
+            unit_arg: false,
+            arg: args[0].clone(),
+            tz: time_zone_arg(args.get(1))?,
+            args: args.to_vec(),
+        }))
+    }
+
+    pub fn try_create_year(args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+        Self::try_create_fixed("toStartOfYear", DateTimeUnit::Year, args)
+    }
+
+    pub fn try_create_quarter(args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+        Self::try_create_fixed("toStartOfQuarter", DateTimeUnit::Quarter, args)
+    }
+
+    pub fn try_create_month(args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+        Self::try_create_fixed("toStartOfMonth", DateTimeUnit::Month, args)
+    }
+
+    pub fn try_create_week(args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+        Self::try_create_fixed("toStartOfWeek", DateTimeUnit::Week, args)
+    }
+
+    pub fn try_create_day(args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+        Self::try_create_fixed("toStartOfDay", DateTimeUnit::Day, args)
+    }
+
+    pub fn try_create_hour(args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+        Self::try_create_fixed("toStartOfHour", DateTimeUnit::Hour, args)
+    }
+
+    pub fn try_create_minute(args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+        Self::try_create_fixed("toStartOfMinute", DateTimeUnit::Minute, args)
+    }
+
+    pub fn try_create_interval(args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+        ensure!(
+            args.len() == 3 || args.len() == 4,
+            "Function Error: Function toStartOfInterval expects the date time, the interval, the unit and the optional time zone arguments"
+        );
+
+        Ok(Box::new(StartOfFunction {
+            display_name: "toStartOfInterval",
+            unit: DateTimeUnit::try_from_name(&constant_string(args[2].as_ref())?)?,
+            interval: constant_integer(args[1].as_ref())?,
+            unit_arg: true,
+            arg: args[0].clone(),
+            tz: time_zone_arg(args.get(3))?,
+            args: args.to_vec(),
+        }))
+    }
+
+    pub fn try_create_date_trunc(args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+        ensure!(
+            args.len() == 2 || args.len() == 3,
+            "Function Error: Function date_trunc expects the unit, the date time and the optional time zone arguments"
+        );
+
+        Ok(Box::new(StartOfFunction {
+            display_name: "date_trunc",
+            unit: DateTimeUnit::try_from_name(&constant_string(args[0].as_ref())?)?,
+            interval: 1,
+            unit_arg: true,
+            arg: args[1].clone(),
+            tz: time_zone_arg(args.get(2))?,
+            args: args.to_vec(),
+        }))
+    }
+
+    fn result_type(&self, data_type: &DataType) -> DataType {
+        match (self.unit_arg, self.unit) {
+            (true, unit) => unit.result_type(data_type),
+            (false, unit) if unit >= DateTimeUnit::Week => DataType::Date32,
+            (false, _) => DateTimeUnit::Second.result_type(data_type),
+        }
+    }
+}
+
+impl IFunction for StartOfFunction {
+    fn return_type(&self, input_schema: &DataSchema) -> Result<DataType> {
+        let data_type = self.arg.return_type(input_schema)?;
+        check_temporal_type(self.display_name, &data_type)?;
+        Ok(self.result_type(&data_type))
+    }
+
+    fn nullable(&self, input_schema: &DataSchema) -> Result<bool> {
+        self.arg.nullable(input_schema)
+    }
+
+    fn eval(&self, block: &DataBlock) -> Result<DataColumnarValue> {
+        let (arrays, scalar) = eval_arrays(&[self.arg.as_ref()], block)?;
+        let array = &arrays[0];
+        check_temporal_type(self.display_name, array.data_type())?;
+        let tz = time_zone(&self.tz, array.data_type())?;
+        let values = local_values(array, &tz)?
+            .into_iter()
+            .map(|v| v.map(|v| self.unit.truncate(&v, self.interval)).transpose())
+            .collect::<Result<Vec<_>>>()?;
+        let result = local_array(values, &self.result_type(array.data_type()), &tz)?;
+        to_columnar(result, scalar)
+    }
+}
+
+impl fmt::Display for StartOfFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let args = self
+            .args
+            .iter()
+            .map(|arg| arg.to_string())
+            .collect::<Vec<_>>();
+        write!(f, "{}({})", self.display_name, args.join(", "))
+    }
+}
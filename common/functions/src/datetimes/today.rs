@@ -0,0 +1,63 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::fmt;
+
+use anyhow::{ensure, Result};
+use chrono::{NaiveDate, Utc};
+use chrono_tz::Tz;
+use common_datablocks::DataBlock;
+use common_datavalues::{DataColumnarValue, DataSchema, DataType, DataValue};
+
+use crate::datetimes::{time_zone, time_zone_arg};
+use crate::IFunction;
+
+/// The current date in the optional time zone, UTC by default.
+#[derive(Clone)]
+pub struct TodayFunction {
+    tz: Option<Tz>,
+}
+
+impl TodayFunction {
+    pub fn try_create(args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+        ensure!(
+            args.len() <= 1,
+            "Function Error: Function today expects the optional time zone argument"
+        );
+
+        Ok(Box::new(TodayFunction {
+            tz: time_zone_arg(args.get(0))?,
+        }))
+    }
+}
+
+impl IFunction for TodayFunction {
+    fn return_type(&self, _input_schema: &DataSchema) -> Result<DataType> {
+        Ok(DataType::Date32)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, _block: &DataBlock) -> Result<DataColumnarValue> {
+        let tz = time_zone(&self.tz, &DataType::Date32)?;
+        let today = Utc::now().with_timezone(&tz).naive_local().date();
+        let days = today
+            .signed_duration_since(NaiveDate::from_ymd(1970, 1, 1))
+            .num_days();
+        Ok(DataColumnarValue::Scalar(DataValue::Date32(Some(
+            days as i32,
+        ))))
+    }
+}
+
+impl fmt::Display for TodayFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.tz {
+            Some(tz) => write!(f, "today({})", tz.name()),
+            None => write!(f, "today()"),
+        }
+    }
+}
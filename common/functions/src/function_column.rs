@@ -6,12 +6,13 @@ use std::fmt;
 
 use anyhow::Result;
 use common_datablocks::DataBlock;
-use common_datavalues::{DataColumnarValue, DataSchema, DataType};
+use common_datavalues::{DataColumnarValue, DataSchema, DataType, DataValue};
 
 use crate::IFunction;
 
 #[derive(Clone, Debug)]
 pub struct ColumnFunction {
+    depth: usize,
     value: String,
     saved: Option<DataColumnarValue>,
 }
@@ -19,6 +20,7 @@ pub struct ColumnFunction {
 impl ColumnFunction {
     pub fn try_create(value: &str) -> Result<Box<dyn IFunction>> {
         Ok(Box::new(ColumnFunction {
+            depth: 0,
             value: value.to_string(),
             saved: None,
         }))
@@ -50,6 +52,40 @@ impl IFunction for ColumnFunction {
             block.column_by_name(self.value.as_str())?.clone(),
         ))
     }
+
+    fn set_depth(&mut self, depth: usize) {
+        self.depth = depth;
+    }
+
+    // For group by column: all the rows of the group have the same value,
+    // keep the first one as the group representative.
+    fn accumulate(&mut self, block: &DataBlock) -> Result<()> {
+        if self.saved.is_none() && block.num_rows() > 0 {
+            let column = block.column_by_name(self.value.as_str())?;
+            self.saved = Some(DataColumnarValue::Scalar(DataValue::try_from_array(
+                column, 0,
+            )?));
+        }
+        Ok(())
+    }
+
+    fn accumulate_result(&self) -> Result<Vec<DataValue>> {
+        Ok(vec![self.merge_result()?])
+    }
+
+    fn merge(&mut self, states: &[DataValue]) -> Result<()> {
+        if self.saved.is_none() {
+            self.saved = Some(DataColumnarValue::Scalar(states[self.depth].clone()));
+        }
+        Ok(())
+    }
+
+    fn merge_result(&self) -> Result<DataValue> {
+        Ok(match &self.saved {
+            Some(DataColumnarValue::Scalar(value)) => value.clone(),
+            _ => DataValue::Null,
+        })
+    }
 }
 
 impl fmt::Display for ColumnFunction {
@@ -0,0 +1,38 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use anyhow::Result;
+use common_datablocks::DataBlock;
+use common_datavalues::{DataArrayRef, DataColumnarValue, DataValue};
+
+use crate::IFunction;
+
+/// The arrays of the arguments. If all of them are scalars, they are converted to the arrays of
+/// one value and the result should be a scalar as well, otherwise to the arrays of the block rows.
+pub fn eval_arrays(
+    funcs: &[&dyn IFunction],
+    block: &DataBlock,
+) -> Result<(Vec<DataArrayRef>, bool)> {
+    let values = funcs
+        .iter()
+        .map(|func| func.eval(block))
+        .collect::<Result<Vec<_>>>()?;
+    let scalar = values
+        .iter()
+        .all(|v| matches!(v, DataColumnarValue::Scalar(_)));
+    let rows = if scalar { 1 } else { block.num_rows() };
+    let arrays = values
+        .iter()
+        .map(|v| v.to_array(rows))
+        .collect::<Result<Vec<_>>>()?;
+    Ok((arrays, scalar))
+}
+
+/// The result of the array, the first value if the arguments are scalars.
+pub fn to_columnar(array: DataArrayRef, scalar: bool) -> Result<DataColumnarValue> {
+    Ok(match scalar {
+        true => DataColumnarValue::Scalar(DataValue::try_from_array(&array, 0)?),
+        false => DataColumnarValue::Array(array),
+    })
+}
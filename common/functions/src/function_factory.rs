@@ -12,7 +12,12 @@ use lazy_static::lazy_static;
 use crate::aggregators::AggregatorFunction;
 use crate::arithmetics::ArithmeticFunction;
 use crate::comparisons::ComparisonFunction;
+use crate::conditionals::ConditionalFunction;
+use crate::conversions::ConversionFunction;
+use crate::datetimes::DateTimeFunction;
 use crate::logics::LogicFunction;
+use crate::nulls::NullFunction;
+use crate::strings::StringFunction;
 use crate::udfs::UdfFunction;
 use crate::IFunction;
 
@@ -26,7 +31,12 @@ lazy_static! {
         AggregatorFunction::register(map.clone()).unwrap();
         ArithmeticFunction::register(map.clone()).unwrap();
         ComparisonFunction::register(map.clone()).unwrap();
+        ConditionalFunction::register(map.clone()).unwrap();
+        ConversionFunction::register(map.clone()).unwrap();
+        DateTimeFunction::register(map.clone()).unwrap();
         LogicFunction::register(map.clone()).unwrap();
+        NullFunction::register(map.clone()).unwrap();
+        StringFunction::register(map.clone()).unwrap();
         UdfFunction::register(map.clone()).unwrap();
         map
     };
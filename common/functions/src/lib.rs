@@ -5,17 +5,24 @@
 mod aggregators;
 mod arithmetics;
 mod comparisons;
+mod conditionals;
+mod conversions;
+mod datetimes;
 mod function;
 mod function_alias;
 mod function_column;
+mod function_common;
 mod function_factory;
 mod function_literal;
 mod logics;
+mod nulls;
+mod strings;
 mod udfs;
 
 pub use common_datablocks;
 pub use common_datavalues;
 
+pub use crate::conversions::CastFunction;
 pub use crate::function::IFunction;
 pub use crate::function_alias::AliasFunction;
 pub use crate::function_column::ColumnFunction;
@@ -10,7 +10,7 @@ use common_datavalues::{
     self as datavalues, DataColumnarValue, DataSchema, DataType, DataValueLogicOperator,
 };
 
-use crate::logics::{LogicAndFunction, LogicOrFunction};
+use crate::logics::{LogicAndFunction, LogicNotFunction, LogicOrFunction};
 use crate::{FactoryFuncRef, IFunction};
 
 #[derive(Clone)]
@@ -27,6 +27,7 @@ impl LogicFunction {
         let mut map = map.write();
         map.insert("and", LogicAndFunction::try_create_func);
         map.insert("or", LogicOrFunction::try_create_func);
+        map.insert("not", LogicNotFunction::try_create_func);
         Ok(())
     }
 
@@ -55,8 +56,8 @@ impl IFunction for LogicFunction {
         Ok(DataType::Boolean)
     }
 
-    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
-        Ok(false)
+    fn nullable(&self, input_schema: &DataSchema) -> Result<bool> {
+        Ok(self.left.nullable(input_schema)? || self.right.nullable(input_schema)?)
     }
 
     fn eval(&self, block: &DataBlock) -> Result<DataColumnarValue> {
@@ -0,0 +1,62 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::fmt;
+
+use anyhow::{ensure, Result};
+use common_datablocks::DataBlock;
+use common_datavalues::{self as datavalues, DataColumnarValue, DataSchema, DataType};
+
+use crate::IFunction;
+
+/// `NOT v`, which is NULL if v is NULL.
+#[derive(Clone)]
+pub struct LogicNotFunction {
+    depth: usize,
+    arg: Box<dyn IFunction>,
+}
+
+impl LogicNotFunction {
+    pub fn try_create_func(args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+        ensure!(
+            args.len() == 1,
+            "Function Error: Logic function not args length must be 1"
+        );
+
+        Ok(Box::new(LogicNotFunction {
+            depth: 0,
+            arg: args[0].clone(),
+        }))
+    }
+}
+
+impl IFunction for LogicNotFunction {
+    fn return_type(&self, _input_schema: &DataSchema) -> Result<DataType> {
+        Ok(DataType::Boolean)
+    }
+
+    fn nullable(&self, input_schema: &DataSchema) -> Result<bool> {
+        self.arg.nullable(input_schema)
+    }
+
+    fn eval(&self, block: &DataBlock) -> Result<DataColumnarValue> {
+        Ok(DataColumnarValue::Array(datavalues::data_array_logic_not(
+            &self.arg.eval(block)?,
+        )?))
+    }
+
+    fn set_depth(&mut self, depth: usize) {
+        self.depth = depth;
+    }
+
+    fn is_aggregator(&self) -> bool {
+        self.arg.is_aggregator()
+    }
+}
+
+impl fmt::Display for LogicNotFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "not({})", self.arg)
+    }
+}
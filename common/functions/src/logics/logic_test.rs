@@ -29,6 +29,10 @@ fn test_logic_function() -> anyhow::Result<()> {
         DataField::new("a", DataType::Boolean, false),
         DataField::new("b", DataType::Boolean, false),
     ]));
+    let nullable_schema = Arc::new(DataSchema::new(vec![
+        DataField::new("a", DataType::Boolean, true),
+        DataField::new("b", DataType::Boolean, true),
+    ]));
 
     let field_a = ColumnFunction::try_create("a").unwrap();
     let field_b = ColumnFunction::try_create("b").unwrap();
@@ -66,6 +70,79 @@ fn test_logic_function() -> anyhow::Result<()> {
             expect: Arc::new(BooleanArray::from(vec![true, true, true, true])),
             error: "",
         },
+        Test {
+            name: "and-null-passed",
+            func_name: "AndFunction",
+            display: "a and b",
+            nullable: true,
+            func: LogicAndFunction::try_create_func(&[field_a.clone(), field_b.clone()])?,
+            block: DataBlock::create(
+                nullable_schema.clone(),
+                vec![
+                    Arc::new(BooleanArray::from(vec![
+                        Some(true),
+                        None,
+                        None,
+                        Some(false),
+                    ])),
+                    Arc::new(BooleanArray::from(vec![None, Some(false), None, None])),
+                ],
+            ),
+            expect: Arc::new(BooleanArray::from(vec![
+                None,
+                Some(false),
+                None,
+                Some(false),
+            ])),
+            error: "",
+        },
+        Test {
+            name: "or-null-passed",
+            func_name: "OrFunction",
+            display: "a or b",
+            nullable: true,
+            func: LogicOrFunction::try_create_func(&[field_a.clone(), field_b.clone()])?,
+            block: DataBlock::create(
+                nullable_schema.clone(),
+                vec![
+                    Arc::new(BooleanArray::from(vec![
+                        Some(true),
+                        None,
+                        None,
+                        Some(false),
+                    ])),
+                    Arc::new(BooleanArray::from(vec![None, Some(true), None, None])),
+                ],
+            ),
+            expect: Arc::new(BooleanArray::from(vec![Some(true), Some(true), None, None])),
+            error: "",
+        },
+        Test {
+            name: "not-null-passed",
+            func_name: "NotFunction",
+            display: "not(a)",
+            nullable: true,
+            func: LogicNotFunction::try_create_func(&[field_a.clone()])?,
+            block: DataBlock::create(
+                nullable_schema.clone(),
+                vec![
+                    Arc::new(BooleanArray::from(vec![
+                        Some(true),
+                        None,
+                        None,
+                        Some(false),
+                    ])),
+                    Arc::new(BooleanArray::from(vec![None, Some(true), None, None])),
+                ],
+            ),
+            expect: Arc::new(BooleanArray::from(vec![
+                Some(false),
+                None,
+                None,
+                Some(true),
+            ])),
+            error: "",
+        },
     ];
 
     for t in tests {
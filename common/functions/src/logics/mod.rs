@@ -7,8 +7,10 @@ mod logic_test;
 
 mod logic;
 mod logic_and;
+mod logic_not;
 mod logic_or;
 
 pub use logic::LogicFunction;
 pub use logic_and::LogicAndFunction;
+pub use logic_not::LogicNotFunction;
 pub use logic_or::LogicOrFunction;
@@ -0,0 +1,92 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::fmt;
+
+use anyhow::{ensure, Result};
+use common_datablocks::DataBlock;
+use common_datavalues::{self as datavalues, DataColumnarValue, DataSchema, DataType};
+
+use crate::function_common::{eval_arrays, to_columnar};
+use crate::IFunction;
+
+/// `coalesce(a, b, ...)` is the first argument not NULL, `ifNull(a, b)` is the two arguments one.
+/// The arguments are casted to their common type.
+#[derive(Clone)]
+pub struct CoalesceFunction {
+    display_name: &'static str,
+    args: Vec<Box<dyn IFunction>>,
+}
+
+impl CoalesceFunction {
+    pub fn try_create_coalesce(args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+        ensure!(
+            !args.is_empty(),
+            "Function Error: Function coalesce args length must be at least 1"
+        );
+        Self::try_create("coalesce", args)
+    }
+
+    pub fn try_create_if_null(args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+        ensure!(
+            args.len() == 2,
+            "Function Error: Function ifNull args length must be 2"
+        );
+        Self::try_create("ifNull", args)
+    }
+
+    fn try_create(
+        display_name: &'static str,
+        args: &[Box<dyn IFunction>],
+    ) -> Result<Box<dyn IFunction>> {
+        Ok(Box::new(CoalesceFunction {
+            display_name,
+            args: args.to_vec(),
+        }))
+    }
+}
+
+impl IFunction for CoalesceFunction {
+    fn return_type(&self, input_schema: &DataSchema) -> Result<DataType> {
+        let mut data_type = self.args[0].return_type(input_schema)?;
+        for arg in &self.args[1..] {
+            data_type = datavalues::equal_coercion(&data_type, &arg.return_type(input_schema)?)?;
+        }
+        Ok(data_type)
+    }
+
+    fn nullable(&self, input_schema: &DataSchema) -> Result<bool> {
+        for arg in &self.args {
+            if !arg.nullable(input_schema)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    fn eval(&self, block: &DataBlock) -> Result<DataColumnarValue> {
+        let data_type = self.return_type(block.schema())?;
+        let funcs = self.args.iter().map(|arg| arg.as_ref()).collect::<Vec<_>>();
+        let (arrays, scalar) = eval_arrays(&funcs, block)?;
+        let arrays = arrays
+            .iter()
+            .map(|array| datavalues::data_array_cast(array, &data_type))
+            .collect::<Result<Vec<_>>>()?;
+        let indices = (0..arrays[0].len())
+            .map(|row| arrays.iter().position(|array| !array.is_null(row)))
+            .collect::<Vec<_>>();
+        to_columnar(datavalues::data_array_select(&arrays, &indices)?, scalar)
+    }
+}
+
+impl fmt::Display for CoalesceFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let args = self
+            .args
+            .iter()
+            .map(|arg| format!("{}", arg))
+            .collect::<Vec<_>>();
+        write!(f, "{}({})", self.display_name, args.join(", "))
+    }
+}
@@ -0,0 +1,75 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::fmt;
+use std::sync::Arc;
+
+use anyhow::{ensure, Result};
+use common_datablocks::DataBlock;
+use common_datavalues::{BooleanArray, DataColumnarValue, DataSchema, DataType, DataValue};
+
+use crate::IFunction;
+
+/// `IS NULL` and `IS NOT NULL`, the result is never NULL.
+#[derive(Clone)]
+pub struct IsNullFunction {
+    negated: bool,
+    arg: Box<dyn IFunction>,
+}
+
+impl IsNullFunction {
+    pub fn try_create_is_null(args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+        Self::try_create(false, args)
+    }
+
+    pub fn try_create_is_not_null(args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+        Self::try_create(true, args)
+    }
+
+    fn try_create(negated: bool, args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+        ensure!(
+            args.len() == 1,
+            "Function Error: Function {} args length must be 1",
+            if negated { "isNotNull" } else { "isNull" }
+        );
+
+        Ok(Box::new(IsNullFunction {
+            negated,
+            arg: args[0].clone(),
+        }))
+    }
+}
+
+impl IFunction for IsNullFunction {
+    fn return_type(&self, _input_schema: &DataSchema) -> Result<DataType> {
+        Ok(DataType::Boolean)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, block: &DataBlock) -> Result<DataColumnarValue> {
+        Ok(match self.arg.eval(block)? {
+            DataColumnarValue::Scalar(value) => {
+                DataColumnarValue::Scalar(DataValue::Boolean(Some(value.is_null() != self.negated)))
+            }
+            DataColumnarValue::Array(array) => DataColumnarValue::Array(Arc::new(
+                (0..array.len())
+                    .map(|i| Some(array.is_null(i) != self.negated))
+                    .collect::<BooleanArray>(),
+            )),
+        })
+    }
+}
+
+impl fmt::Display for IsNullFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.negated {
+            write!(f, "isNotNull({})", self.arg)
+        } else {
+            write!(f, "isNull({})", self.arg)
+        }
+    }
+}
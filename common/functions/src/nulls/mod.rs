@@ -0,0 +1,16 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+#[cfg(test)]
+mod null_test;
+
+mod coalesce;
+mod is_null;
+mod null;
+mod null_if;
+
+pub use coalesce::CoalesceFunction;
+pub use is_null::IsNullFunction;
+pub use null::NullFunction;
+pub use null_if::NullIfFunction;
@@ -0,0 +1,23 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use anyhow::Result;
+
+use crate::nulls::{CoalesceFunction, IsNullFunction, NullIfFunction};
+use crate::FactoryFuncRef;
+
+#[derive(Clone)]
+pub struct NullFunction;
+
+impl NullFunction {
+    pub fn register(map: FactoryFuncRef) -> Result<()> {
+        let mut map = map.write();
+        map.insert("isnull", IsNullFunction::try_create_is_null);
+        map.insert("isnotnull", IsNullFunction::try_create_is_not_null);
+        map.insert("coalesce", CoalesceFunction::try_create_coalesce);
+        map.insert("ifnull", CoalesceFunction::try_create_if_null);
+        map.insert("nullif", NullIfFunction::try_create);
+        Ok(())
+    }
+}
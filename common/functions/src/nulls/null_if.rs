@@ -0,0 +1,80 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::fmt;
+
+use anyhow::{anyhow, ensure, Result};
+use common_datablocks::DataBlock;
+use common_datavalues::{
+    self as datavalues, BooleanArray, DataColumnarValue, DataSchema, DataType,
+    DataValueComparisonOperator,
+};
+
+use crate::function_common::{eval_arrays, to_columnar};
+use crate::IFunction;
+
+/// `nullIf(a, b)` is NULL if `a = b`, otherwise it is `a`.
+#[derive(Clone)]
+pub struct NullIfFunction {
+    left: Box<dyn IFunction>,
+    right: Box<dyn IFunction>,
+}
+
+impl NullIfFunction {
+    pub fn try_create(args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+        ensure!(
+            args.len() == 2,
+            "Function Error: Function nullIf args length must be 2"
+        );
+
+        Ok(Box::new(NullIfFunction {
+            left: args[0].clone(),
+            right: args[1].clone(),
+        }))
+    }
+}
+
+impl IFunction for NullIfFunction {
+    fn return_type(&self, input_schema: &DataSchema) -> Result<DataType> {
+        self.left.return_type(input_schema)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, block: &DataBlock) -> Result<DataColumnarValue> {
+        let (arrays, scalar) = eval_arrays(&[self.left.as_ref(), self.right.as_ref()], block)?;
+        let equals = datavalues::data_array_comparison_op(
+            DataValueComparisonOperator::Eq,
+            &DataColumnarValue::Array(arrays[0].clone()),
+            &DataColumnarValue::Array(arrays[1].clone()),
+        )?;
+        let equals = equals
+            .as_any()
+            .downcast_ref::<BooleanArray>()
+            .ok_or_else(|| anyhow!("Function Error: Cannot downcast the array to BooleanArray"))?;
+
+        // The NULL comparison is not equal.
+        let indices = (0..equals.len())
+            .map(|row| {
+                if equals.is_valid(row) && equals.value(row) {
+                    None
+                } else {
+                    Some(0)
+                }
+            })
+            .collect::<Vec<_>>();
+        to_columnar(
+            datavalues::data_array_select(&arrays[0..1], &indices)?,
+            scalar,
+        )
+    }
+}
+
+impl fmt::Display for NullIfFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "nullIf({}, {})", self.left, self.right)
+    }
+}
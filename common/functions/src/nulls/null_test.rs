@@ -0,0 +1,136 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+#[test]
+fn test_null_function() -> anyhow::Result<()> {
+    use std::sync::Arc;
+
+    use common_datablocks::*;
+    use common_datavalues::*;
+    use pretty_assertions::assert_eq;
+
+    use crate::nulls::*;
+    use crate::*;
+
+    #[allow(dead_code)]
+    struct Test {
+        name: &'static str,
+        display: &'static str,
+        nullable: bool,
+        expect_type: DataType,
+        expect: DataArrayRef,
+        func: Box<dyn IFunction>,
+    }
+
+    let schema = Arc::new(DataSchema::new(vec![
+        DataField::new("a", DataType::Int32, true),
+        DataField::new("b", DataType::Int64, true),
+        DataField::new("c", DataType::Int64, false),
+    ]));
+    let block = DataBlock::create(
+        schema.clone(),
+        vec![
+            Arc::new(Int32Array::from(vec![Some(1), None, None, Some(4)])),
+            Arc::new(Int64Array::from(vec![None, Some(2), None, Some(4)])),
+            Arc::new(Int64Array::from(vec![5, 6, 7, 8])),
+        ],
+    );
+
+    let field_a = ColumnFunction::try_create("a")?;
+    let field_b = ColumnFunction::try_create("b")?;
+    let field_c = ColumnFunction::try_create("c")?;
+
+    let tests = vec![
+        Test {
+            name: "isnull-passed",
+            display: "isNull(a)",
+            nullable: false,
+            expect_type: DataType::Boolean,
+            func: IsNullFunction::try_create_is_null(&[field_a.clone()])?,
+            expect: Arc::new(BooleanArray::from(vec![false, true, true, false])),
+        },
+        Test {
+            name: "isnotnull-passed",
+            display: "isNotNull(a)",
+            nullable: false,
+            expect_type: DataType::Boolean,
+            func: IsNullFunction::try_create_is_not_null(&[field_a.clone()])?,
+            expect: Arc::new(BooleanArray::from(vec![true, false, false, true])),
+        },
+        Test {
+            name: "coalesce-passed",
+            display: "coalesce(a, b, c)",
+            nullable: false,
+            expect_type: DataType::Int64,
+            func: CoalesceFunction::try_create_coalesce(&[
+                field_a.clone(),
+                field_b.clone(),
+                field_c.clone(),
+            ])?,
+            expect: Arc::new(Int64Array::from(vec![1, 2, 7, 4])),
+        },
+        Test {
+            name: "ifnull-passed",
+            display: "ifNull(a, b)",
+            nullable: true,
+            expect_type: DataType::Int64,
+            func: CoalesceFunction::try_create_if_null(&[field_a.clone(), field_b.clone()])?,
+            expect: Arc::new(Int64Array::from(vec![Some(1), Some(2), None, Some(4)])),
+        },
+        Test {
+            name: "ifnull-literal-passed",
+            display: "ifNull(a, Null)",
+            nullable: true,
+            expect_type: DataType::Int32,
+            func: CoalesceFunction::try_create_if_null(&[
+                field_a.clone(),
+                LiteralFunction::try_create(DataValue::Null)?,
+            ])?,
+            expect: Arc::new(Int32Array::from(vec![Some(1), None, None, Some(4)])),
+        },
+        Test {
+            name: "nullif-passed",
+            display: "nullIf(b, a)",
+            nullable: true,
+            expect_type: DataType::Int64,
+            func: NullIfFunction::try_create(&[field_b.clone(), field_a.clone()])?,
+            expect: Arc::new(Int64Array::from(vec![None, Some(2), None, None])),
+        },
+    ];
+
+    for t in tests {
+        let func = t.func;
+        assert_eq!(t.display, format!("{}", func), "{}", t.name);
+        assert_eq!(t.nullable, func.nullable(&schema)?, "{}", t.name);
+        assert_eq!(t.expect_type, func.return_type(&schema)?, "{}", t.name);
+
+        let v = func.eval(&block)?;
+        assert_eq!(t.expect_type, v.data_type(), "{}", t.name);
+        assert_eq!(
+            v.to_array(block.num_rows())?.as_ref(),
+            t.expect.as_ref(),
+            "{}",
+            t.name
+        );
+    }
+
+    // The scalars result in a scalar.
+    let func = CoalesceFunction::try_create_coalesce(&[
+        LiteralFunction::try_create(DataValue::Null)?,
+        LiteralFunction::try_create(DataValue::Int8(Some(3)))?,
+    ])?;
+    assert_eq!(
+        DataColumnarValue::Scalar(DataValue::Int8(Some(3)))
+            .to_array(1)?
+            .as_ref(),
+        func.eval(&block)?.to_array(1)?.as_ref()
+    );
+
+    let func = FunctionFactory::get("coalesce", &[]);
+    assert_eq!(
+        "Function Error: Function coalesce args length must be at least 1",
+        func.err().unwrap().to_string()
+    );
+    Ok(())
+}
@@ -0,0 +1,79 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::fmt;
+use std::sync::Arc;
+
+use anyhow::{ensure, Result};
+use common_datablocks::DataBlock;
+use common_datavalues::{DataColumnarValue, DataSchema, DataType, StringArray};
+
+use crate::function_common::to_columnar;
+use crate::strings::{args_nullable, eval_args, ArgValues};
+use crate::IFunction;
+
+/// `concat(a, b, ...)`, the arguments are casted to strings, the result is NULL if any of them is.
+#[derive(Clone)]
+pub struct ConcatFunction {
+    args: Vec<Box<dyn IFunction>>,
+}
+
+impl ConcatFunction {
+    pub fn try_create(args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+        ensure!(
+            !args.is_empty(),
+            "Function Error: Function concat args length must be at least 1"
+        );
+
+        Ok(Box::new(ConcatFunction {
+            args: args.to_vec(),
+        }))
+    }
+}
+
+impl IFunction for ConcatFunction {
+    fn return_type(&self, _input_schema: &DataSchema) -> Result<DataType> {
+        Ok(DataType::Utf8)
+    }
+
+    fn nullable(&self, input_schema: &DataSchema) -> Result<bool> {
+        let funcs = self.args.iter().map(|arg| arg.as_ref()).collect::<Vec<_>>();
+        args_nullable(&funcs, input_schema)
+    }
+
+    fn eval(&self, block: &DataBlock) -> Result<DataColumnarValue> {
+        let funcs = self
+            .args
+            .iter()
+            .map(|arg| (arg.as_ref(), DataType::Utf8))
+            .collect::<Vec<_>>();
+        let (args, rows, scalar) = eval_args(&funcs, block)?;
+        let values = args
+            .iter()
+            .map(ArgValues::try_create)
+            .collect::<Result<Vec<_>>>()?;
+
+        let result = (0..rows)
+            .map(|row| {
+                let mut result = String::new();
+                for value in &values {
+                    result.push_str(value.string(row)?);
+                }
+                Some(result)
+            })
+            .collect::<StringArray>();
+        to_columnar(Arc::new(result), scalar)
+    }
+}
+
+impl fmt::Display for ConcatFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let args = self
+            .args
+            .iter()
+            .map(|arg| format!("{}", arg))
+            .collect::<Vec<_>>();
+        write!(f, "concat({})", args.join(", "))
+    }
+}
@@ -0,0 +1,58 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::fmt;
+use std::sync::Arc;
+
+use anyhow::{ensure, Result};
+use common_datablocks::DataBlock;
+use common_datavalues::{DataColumnarValue, DataSchema, DataType, UInt64Array};
+
+use crate::function_common::to_columnar;
+use crate::strings::{eval_args, ArgValues};
+use crate::IFunction;
+
+/// The length of the string in bytes.
+#[derive(Clone)]
+pub struct LengthFunction {
+    arg: Box<dyn IFunction>,
+}
+
+impl LengthFunction {
+    pub fn try_create(args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+        ensure!(
+            args.len() == 1,
+            "Function Error: Function length args length must be 1"
+        );
+
+        Ok(Box::new(LengthFunction {
+            arg: args[0].clone(),
+        }))
+    }
+}
+
+impl IFunction for LengthFunction {
+    fn return_type(&self, _input_schema: &DataSchema) -> Result<DataType> {
+        Ok(DataType::UInt64)
+    }
+
+    fn nullable(&self, input_schema: &DataSchema) -> Result<bool> {
+        self.arg.nullable(input_schema)
+    }
+
+    fn eval(&self, block: &DataBlock) -> Result<DataColumnarValue> {
+        let (args, rows, scalar) = eval_args(&[(self.arg.as_ref(), DataType::Utf8)], block)?;
+        let values = ArgValues::try_create(&args[0])?;
+        let result = (0..rows)
+            .map(|row| values.string(row).map(|v| v.len() as u64))
+            .collect::<UInt64Array>();
+        to_columnar(Arc::new(result), scalar)
+    }
+}
+
+impl fmt::Display for LengthFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "length({})", self.arg)
+    }
+}
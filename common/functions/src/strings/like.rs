@@ -0,0 +1,194 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::fmt;
+use std::sync::Arc;
+
+use anyhow::{anyhow, ensure, Result};
+use common_datablocks::DataBlock;
+use common_datavalues::{BooleanArray, DataColumnarValue, DataSchema, DataType};
+use regex::{Regex, RegexBuilder};
+
+use crate::function_common::to_columnar;
+use crate::strings::{args_nullable, constant_pattern, eval_args, ArgValues, PatternCache};
+use crate::IFunction;
+
+/// `s LIKE pattern`, `s NOT LIKE pattern`, `s ILIKE pattern` and `s NOT ILIKE pattern`.
+/// `%` matches any sequence of characters, `_` matches any single character, and `\` escapes them.
+#[derive(Clone)]
+pub struct LikeFunction {
+    negated: bool,
+    case_insensitive: bool,
+    value: Box<dyn IFunction>,
+    pattern: Box<dyn IFunction>,
+    constant: Option<LikePattern>,
+}
+
+impl LikeFunction {
+    pub fn try_create_like(args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+        Self::try_create(false, false, args)
+    }
+
+    pub fn try_create_not_like(args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+        Self::try_create(true, false, args)
+    }
+
+    pub fn try_create_ilike(args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+        Self::try_create(false, true, args)
+    }
+
+    pub fn try_create_not_ilike(args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+        Self::try_create(true, true, args)
+    }
+
+    fn try_create(
+        negated: bool,
+        case_insensitive: bool,
+        args: &[Box<dyn IFunction>],
+    ) -> Result<Box<dyn IFunction>> {
+        ensure!(
+            args.len() == 2,
+            "Function Error: Function {} args length must be 2",
+            like_op(negated, case_insensitive)
+        );
+
+        let constant = constant_pattern(args[1].as_ref())
+            .map(|pattern| LikePattern::try_create(&pattern, case_insensitive))
+            .transpose()?;
+        Ok(Box::new(LikeFunction {
+            negated,
+            case_insensitive,
+            value: args[0].clone(),
+            pattern: args[1].clone(),
+            constant,
+        }))
+    }
+}
+
+impl IFunction for LikeFunction {
+    fn return_type(&self, _input_schema: &DataSchema) -> Result<DataType> {
+        Ok(DataType::Boolean)
+    }
+
+    fn nullable(&self, input_schema: &DataSchema) -> Result<bool> {
+        args_nullable(&[self.value.as_ref(), self.pattern.as_ref()], input_schema)
+    }
+
+    fn eval(&self, block: &DataBlock) -> Result<DataColumnarValue> {
+        let (args, rows, scalar) = eval_args(
+            &[
+                (self.value.as_ref(), DataType::Utf8),
+                (self.pattern.as_ref(), DataType::Utf8),
+            ],
+            block,
+        )?;
+        let values = ArgValues::try_create(&args[0])?;
+        let patterns = ArgValues::try_create(&args[1])?;
+        let case_insensitive = self.case_insensitive;
+        let mut cache = PatternCache::create(self.constant.as_ref(), |pattern: &str| {
+            LikePattern::try_create(pattern, case_insensitive)
+        });
+
+        let mut result = Vec::with_capacity(rows);
+        for row in 0..rows {
+            result.push(match (values.string(row), patterns.string(row)) {
+                (Some(value), Some(pattern)) => {
+                    Some(cache.get(pattern)?.is_match(value) != self.negated)
+                }
+                _ => None,
+            });
+        }
+        to_columnar(Arc::new(BooleanArray::from(result)), scalar)
+    }
+}
+
+impl fmt::Display for LikeFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {}",
+            self.value,
+            like_op(self.negated, self.case_insensitive),
+            self.pattern
+        )
+    }
+}
+
+fn like_op(negated: bool, case_insensitive: bool) -> &'static str {
+    match (negated, case_insensitive) {
+        (false, false) => "LIKE",
+        (true, false) => "NOT LIKE",
+        (false, true) => "ILIKE",
+        (true, true) => "NOT ILIKE",
+    }
+}
+
+/// The compiled LIKE pattern, the patterns without `_` and with `%` only at the ends are matched
+/// by the plain string searches instead of the regular expression.
+#[derive(Clone)]
+enum LikePattern {
+    Equals(String),
+    StartsWith(String),
+    EndsWith(String),
+    Contains(String),
+    Regex(Regex),
+}
+
+impl LikePattern {
+    fn try_create(pattern: &str, case_insensitive: bool) -> Result<Self> {
+        if !case_insensitive && !pattern.contains(|c: char| c == '_' || c == '\\') {
+            let (starts, rest) = match pattern.strip_prefix('%') {
+                Some(rest) => (false, rest),
+                None => (true, pattern),
+            };
+            let (ends, rest) = match rest.strip_suffix('%') {
+                Some(rest) => (false, rest),
+                None => (true, rest),
+            };
+            if !rest.contains('%') {
+                let rest = rest.to_string();
+                return Ok(match (starts, ends) {
+                    (true, true) => LikePattern::Equals(rest),
+                    (true, false) => LikePattern::StartsWith(rest),
+                    (false, true) => LikePattern::EndsWith(rest),
+                    (false, false) => LikePattern::Contains(rest),
+                });
+            }
+        }
+
+        let mut regex = String::with_capacity(pattern.len() * 2 + 2);
+        regex.push('^');
+        let mut chars = pattern.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '%' => regex.push_str(".*"),
+                '_' => regex.push('.'),
+                // The trailing backslash matches itself.
+                '\\' => {
+                    let escaped = chars.next().unwrap_or('\\');
+                    regex.push_str(&regex::escape(&escaped.to_string()));
+                }
+                c => regex.push_str(&regex::escape(&c.to_string())),
+            }
+        }
+        regex.push('$');
+
+        RegexBuilder::new(&regex)
+            .case_insensitive(case_insensitive)
+            .dot_matches_new_line(true)
+            .build()
+            .map(LikePattern::Regex)
+            .map_err(|e| anyhow!("Function Error: Invalid LIKE pattern '{}': {}", pattern, e))
+    }
+
+    fn is_match(&self, value: &str) -> bool {
+        match self {
+            LikePattern::Equals(s) => value == s,
+            LikePattern::StartsWith(s) => value.starts_with(s.as_str()),
+            LikePattern::EndsWith(s) => value.ends_with(s.as_str()),
+            LikePattern::Contains(s) => value.contains(s.as_str()),
+            LikePattern::Regex(regex) => regex.is_match(value),
+        }
+    }
+}
@@ -0,0 +1,30 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+#[cfg(test)]
+mod string_test;
+
+mod concat;
+mod length;
+mod like;
+mod position;
+mod regexp;
+mod replace;
+mod split_by_char;
+mod starts_with;
+mod string;
+mod string_transform;
+mod substring;
+
+pub use concat::ConcatFunction;
+pub use length::LengthFunction;
+pub use like::LikeFunction;
+pub use position::PositionFunction;
+pub use regexp::RegexpFunction;
+pub use replace::ReplaceFunction;
+pub use split_by_char::SplitByCharFunction;
+pub use starts_with::StartsWithFunction;
+pub use string::*;
+pub use string_transform::StringTransformFunction;
+pub use substring::SubstringFunction;
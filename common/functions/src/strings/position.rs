@@ -0,0 +1,78 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::fmt;
+use std::sync::Arc;
+
+use anyhow::{ensure, Result};
+use common_datablocks::DataBlock;
+use common_datavalues::{DataColumnarValue, DataSchema, DataType, UInt64Array};
+
+use crate::function_common::to_columnar;
+use crate::strings::{args_nullable, eval_args, ArgValues};
+use crate::IFunction;
+
+/// `position(haystack, needle)` is the character position of the first `needle` from 1,
+/// 0 if it is not found.
+#[derive(Clone)]
+pub struct PositionFunction {
+    haystack: Box<dyn IFunction>,
+    needle: Box<dyn IFunction>,
+}
+
+impl PositionFunction {
+    pub fn try_create(args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+        ensure!(
+            args.len() == 2,
+            "Function Error: Function position args length must be 2"
+        );
+
+        Ok(Box::new(PositionFunction {
+            haystack: args[0].clone(),
+            needle: args[1].clone(),
+        }))
+    }
+}
+
+impl IFunction for PositionFunction {
+    fn return_type(&self, _input_schema: &DataSchema) -> Result<DataType> {
+        Ok(DataType::UInt64)
+    }
+
+    fn nullable(&self, input_schema: &DataSchema) -> Result<bool> {
+        args_nullable(
+            &[self.haystack.as_ref(), self.needle.as_ref()],
+            input_schema,
+        )
+    }
+
+    fn eval(&self, block: &DataBlock) -> Result<DataColumnarValue> {
+        let (args, rows, scalar) = eval_args(
+            &[
+                (self.haystack.as_ref(), DataType::Utf8),
+                (self.needle.as_ref(), DataType::Utf8),
+            ],
+            block,
+        )?;
+        let haystacks = ArgValues::try_create(&args[0])?;
+        let needles = ArgValues::try_create(&args[1])?;
+
+        let result = (0..rows)
+            .map(|row| {
+                let haystack = haystacks.string(row)?;
+                Some(match haystack.find(needles.string(row)?) {
+                    Some(pos) => haystack[..pos].chars().count() as u64 + 1,
+                    None => 0,
+                })
+            })
+            .collect::<UInt64Array>();
+        to_columnar(Arc::new(result), scalar)
+    }
+}
+
+impl fmt::Display for PositionFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "position({}, {})", self.haystack, self.needle)
+    }
+}
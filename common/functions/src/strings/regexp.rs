@@ -0,0 +1,193 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::fmt;
+use std::sync::Arc;
+
+use anyhow::{anyhow, ensure, Result};
+use common_datablocks::DataBlock;
+use common_datavalues::{BooleanArray, DataColumnarValue, DataSchema, DataType, StringArray};
+use regex::Regex;
+
+use crate::function_common::to_columnar;
+use crate::strings::{args_nullable, constant_pattern, eval_args, ArgValues, PatternCache};
+use crate::IFunction;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum RegexpOp {
+    Match,
+    Extract,
+    ReplaceAll,
+}
+
+/// The regular expression functions, the pattern is the second argument:
+/// `match(s, pattern)` checks whether the string matches the pattern,
+/// `extract(s, pattern)` extracts the first subpattern, or the whole match if there is no
+/// subpattern, or the empty string if not matched,
+/// `replaceRegexpAll(s, pattern, replacement)` replaces all the matches, `\0`-`\9` in the
+/// replacement are substituted with the subpatterns.
+#[derive(Clone)]
+pub struct RegexpFunction {
+    op: RegexpOp,
+    args: Vec<Box<dyn IFunction>>,
+    constant: Option<Regex>,
+}
+
+impl RegexpFunction {
+    pub fn try_create_match(args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+        Self::try_create(RegexpOp::Match, args)
+    }
+
+    pub fn try_create_extract(args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+        Self::try_create(RegexpOp::Extract, args)
+    }
+
+    pub fn try_create_replace_all(args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+        Self::try_create(RegexpOp::ReplaceAll, args)
+    }
+
+    fn try_create(op: RegexpOp, args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+        let (name, args_len) = regexp_name(op);
+        ensure!(
+            args.len() == args_len,
+            "Function Error: Function {} args length must be {}",
+            name,
+            args_len
+        );
+
+        let constant = constant_pattern(args[1].as_ref())
+            .map(|pattern| compile_regex(&pattern))
+            .transpose()?;
+        Ok(Box::new(RegexpFunction {
+            op,
+            args: args.to_vec(),
+            constant,
+        }))
+    }
+}
+
+impl IFunction for RegexpFunction {
+    fn return_type(&self, _input_schema: &DataSchema) -> Result<DataType> {
+        match self.op {
+            RegexpOp::Match => Ok(DataType::Boolean),
+            RegexpOp::Extract | RegexpOp::ReplaceAll => Ok(DataType::Utf8),
+        }
+    }
+
+    fn nullable(&self, input_schema: &DataSchema) -> Result<bool> {
+        let funcs = self.args.iter().map(|arg| arg.as_ref()).collect::<Vec<_>>();
+        args_nullable(&funcs, input_schema)
+    }
+
+    fn eval(&self, block: &DataBlock) -> Result<DataColumnarValue> {
+        let funcs = self
+            .args
+            .iter()
+            .map(|arg| (arg.as_ref(), DataType::Utf8))
+            .collect::<Vec<_>>();
+        let (args, rows, scalar) = eval_args(&funcs, block)?;
+        let values = ArgValues::try_create(&args[0])?;
+        let patterns = ArgValues::try_create(&args[1])?;
+        let mut cache = PatternCache::create(self.constant.as_ref(), compile_regex);
+
+        if self.op == RegexpOp::Match {
+            let mut result = Vec::with_capacity(rows);
+            for row in 0..rows {
+                result.push(match (values.string(row), patterns.string(row)) {
+                    (Some(value), Some(pattern)) => Some(cache.get(pattern)?.is_match(value)),
+                    _ => None,
+                });
+            }
+            return to_columnar(Arc::new(BooleanArray::from(result)), scalar);
+        }
+
+        let replacements = match self.op {
+            RegexpOp::ReplaceAll => Some(ArgValues::try_create(&args[2])?),
+            _ => None,
+        };
+        let mut result: Vec<Option<String>> = Vec::with_capacity(rows);
+        for row in 0..rows {
+            let (value, pattern) = match (values.string(row), patterns.string(row)) {
+                (Some(value), Some(pattern)) => (value, pattern),
+                _ => {
+                    result.push(None);
+                    continue;
+                }
+            };
+            let regex = cache.get(pattern)?;
+            result.push(match &replacements {
+                Some(replacements) => replacements.string(row).map(|replacement| {
+                    regex
+                        .replace_all(value, replacement_template(replacement).as_str())
+                        .into_owned()
+                }),
+                None => Some(extract(regex, value).to_string()),
+            });
+        }
+        let result = result.iter().map(|v| v.as_deref()).collect::<StringArray>();
+        to_columnar(Arc::new(result), scalar)
+    }
+}
+
+impl fmt::Display for RegexpFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let args = self
+            .args
+            .iter()
+            .map(|arg| format!("{}", arg))
+            .collect::<Vec<_>>();
+        write!(f, "{}({})", regexp_name(self.op).0, args.join(", "))
+    }
+}
+
+fn regexp_name(op: RegexpOp) -> (&'static str, usize) {
+    match op {
+        RegexpOp::Match => ("match", 2),
+        RegexpOp::Extract => ("extract", 2),
+        RegexpOp::ReplaceAll => ("replaceRegexpAll", 3),
+    }
+}
+
+fn compile_regex(pattern: &str) -> Result<Regex> {
+    Regex::new(pattern).map_err(|e| {
+        anyhow!(
+            "Function Error: Invalid regular expression '{}': {}",
+            pattern,
+            e
+        )
+    })
+}
+
+fn extract<'a>(regex: &Regex, value: &'a str) -> &'a str {
+    match regex.captures(value) {
+        Some(captures) => captures
+            .get(if captures.len() > 1 { 1 } else { 0 })
+            .map_or("", |m| m.as_str()),
+        None => "",
+    }
+}
+
+/// Converts the `\N` substitutions to the `${N}` of the regex crate and escapes the `$`.
+fn replacement_template(replacement: &str) -> String {
+    let mut template = String::with_capacity(replacement.len());
+    let mut chars = replacement.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.peek() {
+                Some(&d) if d.is_ascii_digit() => {
+                    template.push_str(&format!("${{{}}}", d));
+                    chars.next();
+                }
+                Some(&'\\') => {
+                    template.push('\\');
+                    chars.next();
+                }
+                _ => template.push('\\'),
+            },
+            '$' => template.push_str("$$"),
+            c => template.push(c),
+        }
+    }
+    template
+}
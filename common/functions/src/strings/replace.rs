@@ -0,0 +1,78 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::fmt;
+use std::sync::Arc;
+
+use anyhow::{ensure, Result};
+use common_datablocks::DataBlock;
+use common_datavalues::{DataColumnarValue, DataSchema, DataType, StringArray};
+
+use crate::function_common::to_columnar;
+use crate::strings::{args_nullable, eval_args, ArgValues};
+use crate::IFunction;
+
+/// `replace(s, from, to)` replaces all the occurrences of `from` with `to`.
+#[derive(Clone)]
+pub struct ReplaceFunction {
+    args: Vec<Box<dyn IFunction>>,
+}
+
+impl ReplaceFunction {
+    pub fn try_create(args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+        ensure!(
+            args.len() == 3,
+            "Function Error: Function replace args length must be 3"
+        );
+
+        Ok(Box::new(ReplaceFunction {
+            args: args.to_vec(),
+        }))
+    }
+}
+
+impl IFunction for ReplaceFunction {
+    fn return_type(&self, _input_schema: &DataSchema) -> Result<DataType> {
+        Ok(DataType::Utf8)
+    }
+
+    fn nullable(&self, input_schema: &DataSchema) -> Result<bool> {
+        let funcs = self.args.iter().map(|arg| arg.as_ref()).collect::<Vec<_>>();
+        args_nullable(&funcs, input_schema)
+    }
+
+    fn eval(&self, block: &DataBlock) -> Result<DataColumnarValue> {
+        let funcs = self
+            .args
+            .iter()
+            .map(|arg| (arg.as_ref(), DataType::Utf8))
+            .collect::<Vec<_>>();
+        let (args, rows, scalar) = eval_args(&funcs, block)?;
+        let values = ArgValues::try_create(&args[0])?;
+        let froms = ArgValues::try_create(&args[1])?;
+        let tos = ArgValues::try_create(&args[2])?;
+
+        let result = (0..rows)
+            .map(|row| {
+                let (value, from, to) = (values.string(row)?, froms.string(row)?, tos.string(row)?);
+                // Nothing is replaced by the empty string.
+                match from.is_empty() {
+                    true => Some(value.to_string()),
+                    false => Some(value.replace(from, to)),
+                }
+            })
+            .collect::<StringArray>();
+        to_columnar(Arc::new(result), scalar)
+    }
+}
+
+impl fmt::Display for ReplaceFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "replace({}, {}, {})",
+            self.args[0], self.args[1], self.args[2]
+        )
+    }
+}
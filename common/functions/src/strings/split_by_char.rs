@@ -0,0 +1,88 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::fmt;
+
+use anyhow::{bail, ensure, Result};
+use common_datablocks::DataBlock;
+use common_datavalues::{string_list_array, DataColumnarValue, DataField, DataSchema, DataType};
+
+use crate::function_common::to_columnar;
+use crate::strings::{args_nullable, eval_args, ArgValues};
+use crate::IFunction;
+
+/// `splitByChar(separator, s)` splits the string into the list of strings by the character.
+#[derive(Clone)]
+pub struct SplitByCharFunction {
+    separator: Box<dyn IFunction>,
+    value: Box<dyn IFunction>,
+}
+
+impl SplitByCharFunction {
+    pub fn try_create(args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+        ensure!(
+            args.len() == 2,
+            "Function Error: Function splitByChar args length must be 2"
+        );
+
+        Ok(Box::new(SplitByCharFunction {
+            separator: args[0].clone(),
+            value: args[1].clone(),
+        }))
+    }
+}
+
+impl IFunction for SplitByCharFunction {
+    fn return_type(&self, _input_schema: &DataSchema) -> Result<DataType> {
+        Ok(DataType::List(Box::new(DataField::new(
+            "item",
+            DataType::Utf8,
+            true,
+        ))))
+    }
+
+    fn nullable(&self, input_schema: &DataSchema) -> Result<bool> {
+        args_nullable(
+            &[self.separator.as_ref(), self.value.as_ref()],
+            input_schema,
+        )
+    }
+
+    fn eval(&self, block: &DataBlock) -> Result<DataColumnarValue> {
+        let (args, rows, scalar) = eval_args(
+            &[
+                (self.separator.as_ref(), DataType::Utf8),
+                (self.value.as_ref(), DataType::Utf8),
+            ],
+            block,
+        )?;
+        let separators = ArgValues::try_create(&args[0])?;
+        let values = ArgValues::try_create(&args[1])?;
+
+        let mut lists = Vec::with_capacity(rows);
+        for row in 0..rows {
+            lists.push(match (separators.string(row), values.string(row)) {
+                (Some(separator), Some(value)) => {
+                    let mut chars = separator.chars();
+                    let separator = match (chars.next(), chars.next()) {
+                        (Some(c), None) => c,
+                        _ => bail!(
+                            "Function Error: splitByChar separator must be a single character, but got '{}'",
+                            separator
+                        ),
+                    };
+                    Some(value.split(separator).map(Some).collect::<Vec<_>>())
+                }
+                _ => None,
+            });
+        }
+        to_columnar(string_list_array(&lists)?, scalar)
+    }
+}
+
+impl fmt::Display for SplitByCharFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "splitByChar({}, {})", self.separator, self.value)
+    }
+}
@@ -0,0 +1,88 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::fmt;
+use std::sync::Arc;
+
+use anyhow::{ensure, Result};
+use common_datablocks::DataBlock;
+use common_datavalues::{BooleanArray, DataColumnarValue, DataSchema, DataType};
+
+use crate::function_common::to_columnar;
+use crate::strings::{args_nullable, eval_args, ArgValues};
+use crate::IFunction;
+
+/// `startsWith(s, prefix)` and `endsWith(s, suffix)`.
+#[derive(Clone)]
+pub struct StartsWithFunction {
+    ends: bool,
+    value: Box<dyn IFunction>,
+    pattern: Box<dyn IFunction>,
+}
+
+impl StartsWithFunction {
+    pub fn try_create_starts_with(args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+        Self::try_create(false, args)
+    }
+
+    pub fn try_create_ends_with(args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+        Self::try_create(true, args)
+    }
+
+    fn try_create(ends: bool, args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+        ensure!(
+            args.len() == 2,
+            "Function Error: Function {} args length must be 2",
+            if ends { "endsWith" } else { "startsWith" }
+        );
+
+        Ok(Box::new(StartsWithFunction {
+            ends,
+            value: args[0].clone(),
+            pattern: args[1].clone(),
+        }))
+    }
+}
+
+impl IFunction for StartsWithFunction {
+    fn return_type(&self, _input_schema: &DataSchema) -> Result<DataType> {
+        Ok(DataType::Boolean)
+    }
+
+    fn nullable(&self, input_schema: &DataSchema) -> Result<bool> {
+        args_nullable(&[self.value.as_ref(), self.pattern.as_ref()], input_schema)
+    }
+
+    fn eval(&self, block: &DataBlock) -> Result<DataColumnarValue> {
+        let (args, rows, scalar) = eval_args(
+            &[
+                (self.value.as_ref(), DataType::Utf8),
+                (self.pattern.as_ref(), DataType::Utf8),
+            ],
+            block,
+        )?;
+        let values = ArgValues::try_create(&args[0])?;
+        let patterns = ArgValues::try_create(&args[1])?;
+
+        let result = (0..rows)
+            .map(|row| {
+                let (value, pattern) = (values.string(row)?, patterns.string(row)?);
+                Some(match self.ends {
+                    true => value.ends_with(pattern),
+                    false => value.starts_with(pattern),
+                })
+            })
+            .collect::<BooleanArray>();
+        to_columnar(Arc::new(result), scalar)
+    }
+}
+
+impl fmt::Display for StartsWithFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.ends {
+            true => write!(f, "endsWith({}, {})", self.value, self.pattern),
+            false => write!(f, "startsWith({}, {})", self.value, self.pattern),
+        }
+    }
+}
@@ -0,0 +1,176 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use anyhow::{bail, Result};
+use common_arrow::arrow::array::Array;
+use common_datablocks::DataBlock;
+use common_datavalues::{
+    data_array_cast, DataColumnarValue, DataSchema, DataType, DataValue, Int64Array, StringArray,
+};
+
+use crate::strings::{
+    ConcatFunction, LengthFunction, LikeFunction, PositionFunction, RegexpFunction,
+    ReplaceFunction, SplitByCharFunction, StartsWithFunction, StringTransformFunction,
+    SubstringFunction,
+};
+use crate::{FactoryFuncRef, IFunction};
+
+#[derive(Clone)]
+pub struct StringFunction;
+
+impl StringFunction {
+    pub fn register(map: FactoryFuncRef) -> Result<()> {
+        let mut map = map.write();
+        map.insert("length", LengthFunction::try_create);
+        map.insert("lower", StringTransformFunction::try_create_lower);
+        map.insert("upper", StringTransformFunction::try_create_upper);
+        map.insert("trim", StringTransformFunction::try_create_trim);
+        map.insert("ltrim", StringTransformFunction::try_create_ltrim);
+        map.insert("rtrim", StringTransformFunction::try_create_rtrim);
+        map.insert("reverse", StringTransformFunction::try_create_reverse);
+        map.insert("substring", SubstringFunction::try_create);
+        map.insert("substr", SubstringFunction::try_create);
+        map.insert("concat", ConcatFunction::try_create);
+        map.insert("replace", ReplaceFunction::try_create);
+        map.insert("position", PositionFunction::try_create);
+        map.insert("startswith", StartsWithFunction::try_create_starts_with);
+        map.insert("endswith", StartsWithFunction::try_create_ends_with);
+        map.insert("splitbychar", SplitByCharFunction::try_create);
+        map.insert("like", LikeFunction::try_create_like);
+        map.insert("not like", LikeFunction::try_create_not_like);
+        map.insert("ilike", LikeFunction::try_create_ilike);
+        map.insert("not ilike", LikeFunction::try_create_not_ilike);
+        map.insert("match", RegexpFunction::try_create_match);
+        map.insert("extract", RegexpFunction::try_create_extract);
+        map.insert("regexpextract", RegexpFunction::try_create_extract);
+        map.insert("replaceregexpall", RegexpFunction::try_create_replace_all);
+        Ok(())
+    }
+}
+
+/// Evaluate the arguments casted to the types, the scalars are kept as scalars rather than being
+/// expanded to the arrays of the block rows. If all the arguments are scalars, the rows are 1
+/// and the result should be a scalar as well.
+pub fn eval_args(
+    funcs: &[(&dyn IFunction, DataType)],
+    block: &DataBlock,
+) -> Result<(Vec<DataColumnarValue>, usize, bool)> {
+    let mut args = Vec::with_capacity(funcs.len());
+    for (func, data_type) in funcs {
+        args.push(match func.eval(block)? {
+            DataColumnarValue::Scalar(value) => {
+                let array = data_array_cast(&value.to_array(1)?, data_type)?;
+                DataColumnarValue::Scalar(DataValue::try_from_array(&array, 0)?)
+            }
+            DataColumnarValue::Array(array) => {
+                DataColumnarValue::Array(data_array_cast(&array, data_type)?)
+            }
+        });
+    }
+    let scalar = args
+        .iter()
+        .all(|arg| matches!(arg, DataColumnarValue::Scalar(_)));
+    let rows = if scalar { 1 } else { block.num_rows() };
+    Ok((args, rows, scalar))
+}
+
+/// The values of the argument evaluated by `eval_args`, the scalar is the value of all the rows.
+pub enum ArgValues<'a> {
+    Strings(&'a StringArray),
+    String(Option<&'a str>),
+    Integers(&'a Int64Array),
+    Integer(Option<i64>),
+}
+
+impl<'a> ArgValues<'a> {
+    pub fn try_create(value: &'a DataColumnarValue) -> Result<Self> {
+        Ok(match value {
+            DataColumnarValue::Scalar(DataValue::String(v)) => ArgValues::String(v.as_deref()),
+            DataColumnarValue::Scalar(DataValue::Int64(v)) => ArgValues::Integer(*v),
+            DataColumnarValue::Array(array) => {
+                if let Some(strings) = array.as_any().downcast_ref::<StringArray>() {
+                    ArgValues::Strings(strings)
+                } else if let Some(integers) = array.as_any().downcast_ref::<Int64Array>() {
+                    ArgValues::Integers(integers)
+                } else {
+                    bail!(
+                        "Function Error: Unsupported argument type {:?}",
+                        array.data_type()
+                    )
+                }
+            }
+            DataColumnarValue::Scalar(other) => bail!(
+                "Function Error: Unsupported argument type {:?}",
+                other.data_type()
+            ),
+        })
+    }
+
+    pub fn string(&self, row: usize) -> Option<&'a str> {
+        match self {
+            ArgValues::Strings(array) if array.is_valid(row) => Some(array.value(row)),
+            ArgValues::String(value) => *value,
+            _ => None,
+        }
+    }
+
+    pub fn integer(&self, row: usize) -> Option<i64> {
+        match self {
+            ArgValues::Integers(array) if array.is_valid(row) => Some(array.value(row)),
+            ArgValues::Integer(value) => *value,
+            _ => None,
+        }
+    }
+}
+
+/// The result is nullable if any of the arguments is nullable.
+pub fn args_nullable(funcs: &[&dyn IFunction], input_schema: &DataSchema) -> Result<bool> {
+    for func in funcs {
+        if func.nullable(input_schema)? {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/// The pattern argument if it's a constant string, so that it can be compiled only once when the
+/// function is created.
+pub fn constant_pattern(func: &dyn IFunction) -> Option<String> {
+    match func.eval(&DataBlock::empty()) {
+        Ok(DataColumnarValue::Scalar(DataValue::String(Some(v)))) => Some(v),
+        _ => None,
+    }
+}
+
+/// The compiled patterns of the rows. The constant pattern is compiled once when the function is
+/// created, the others are recompiled only if the pattern differs from the one of the last row.
+pub struct PatternCache<'a, T, F: Fn(&str) -> Result<T>> {
+    constant: Option<&'a T>,
+    last: Option<(String, T)>,
+    compile: F,
+}
+
+impl<'a, T, F: Fn(&str) -> Result<T>> PatternCache<'a, T, F> {
+    pub fn create(constant: Option<&'a T>, compile: F) -> Self {
+        PatternCache {
+            constant,
+            last: None,
+            compile,
+        }
+    }
+
+    pub fn get(&mut self, pattern: &str) -> Result<&T> {
+        if let Some(compiled) = self.constant {
+            return Ok(compiled);
+        }
+        let hit = matches!(&self.last, Some((last, _)) if last == pattern);
+        if !hit {
+            self.last = Some((pattern.to_string(), (self.compile)(pattern)?));
+        }
+        match &self.last {
+            Some((_, compiled)) => Ok(compiled),
+            None => bail!("Function Error: The pattern {} is not compiled", pattern),
+        }
+    }
+}
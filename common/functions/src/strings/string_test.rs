@@ -0,0 +1,335 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+#[test]
+fn test_string_function() -> anyhow::Result<()> {
+    use std::sync::Arc;
+
+    use common_datablocks::*;
+    use common_datavalues::*;
+    use pretty_assertions::assert_eq;
+
+    use crate::*;
+
+    #[allow(dead_code)]
+    struct Test {
+        name: &'static str,
+        display: &'static str,
+        nullable: bool,
+        block: DataBlock,
+        expect: DataArrayRef,
+        error: &'static str,
+        func: Box<dyn IFunction>,
+    }
+
+    let schema = Arc::new(DataSchema::new(vec![
+        DataField::new("a", DataType::Utf8, true),
+        DataField::new("b", DataType::Utf8, false),
+        DataField::new("c", DataType::Int64, false),
+    ]));
+    let block = DataBlock::create(
+        schema,
+        vec![
+            Arc::new(StringArray::from(vec![
+                Some(" Hello, World "),
+                None,
+                Some("数据库"),
+            ])),
+            Arc::new(StringArray::from(vec!["l", "x", "据"])),
+            Arc::new(Int64Array::from(vec![2, 1, -1])),
+        ],
+    );
+
+    let field_a = ColumnFunction::try_create("a")?;
+    let field_b = ColumnFunction::try_create("b")?;
+    let field_c = ColumnFunction::try_create("c")?;
+    let literal = |v: &str| LiteralFunction::try_create(DataValue::String(Some(v.to_string())));
+    let number = |v: i64| LiteralFunction::try_create(DataValue::Int64(Some(v)));
+
+    let tests = vec![
+        Test {
+            name: "length-passed",
+            display: "length(a)",
+            nullable: true,
+            func: FunctionFactory::get("length", &[field_a.clone()])?,
+            block: block.clone(),
+            expect: Arc::new(UInt64Array::from(vec![Some(14), None, Some(9)])),
+            error: "",
+        },
+        Test {
+            name: "upper-passed",
+            display: "upper(a)",
+            nullable: true,
+            func: FunctionFactory::get("upper", &[field_a.clone()])?,
+            block: block.clone(),
+            expect: Arc::new(StringArray::from(vec![
+                Some(" HELLO, WORLD "),
+                None,
+                Some("数据库"),
+            ])),
+            error: "",
+        },
+        Test {
+            name: "trim-reverse-passed",
+            display: "reverse(trim(a))",
+            nullable: true,
+            func: FunctionFactory::get(
+                "reverse",
+                &[FunctionFactory::get("trim", &[field_a.clone()])?],
+            )?,
+            block: block.clone(),
+            expect: Arc::new(StringArray::from(vec![
+                Some("dlroW ,olleH"),
+                None,
+                Some("库据数"),
+            ])),
+            error: "",
+        },
+        Test {
+            name: "substring-passed",
+            display: "substring(a, c, 3)",
+            nullable: true,
+            func: FunctionFactory::get(
+                "substring",
+                &[field_a.clone(), field_c.clone(), number(3)?],
+            )?,
+            block: block.clone(),
+            expect: Arc::new(StringArray::from(vec![Some("Hel"), None, Some("库")])),
+            error: "",
+        },
+        Test {
+            name: "substr-no-length-passed",
+            display: "substring(b, 1)",
+            nullable: false,
+            func: FunctionFactory::get("substr", &[field_b.clone(), number(1)?])?,
+            block: block.clone(),
+            expect: Arc::new(StringArray::from(vec!["l", "x", "据"])),
+            error: "",
+        },
+        Test {
+            name: "concat-passed",
+            display: "concat(b, -, c)",
+            nullable: false,
+            func: FunctionFactory::get(
+                "concat",
+                &[field_b.clone(), literal("-")?, field_c.clone()],
+            )?,
+            block: block.clone(),
+            expect: Arc::new(StringArray::from(vec!["l-2", "x-1", "据--1"])),
+            error: "",
+        },
+        Test {
+            name: "replace-passed",
+            display: "replace(a, b, _)",
+            nullable: true,
+            func: FunctionFactory::get(
+                "replace",
+                &[field_a.clone(), field_b.clone(), literal("_")?],
+            )?,
+            block: block.clone(),
+            expect: Arc::new(StringArray::from(vec![
+                Some(" He__o, Wor_d "),
+                None,
+                Some("数_库"),
+            ])),
+            error: "",
+        },
+        Test {
+            name: "position-passed",
+            display: "position(a, b)",
+            nullable: true,
+            func: FunctionFactory::get("position", &[field_a.clone(), field_b.clone()])?,
+            block: block.clone(),
+            expect: Arc::new(UInt64Array::from(vec![Some(4), None, Some(2)])),
+            error: "",
+        },
+        Test {
+            name: "starts-with-passed",
+            display: "startsWith(a,  H)",
+            nullable: true,
+            func: FunctionFactory::get("startsWith", &[field_a.clone(), literal(" H")?])?,
+            block: block.clone(),
+            expect: Arc::new(BooleanArray::from(vec![Some(true), None, Some(false)])),
+            error: "",
+        },
+        Test {
+            name: "ends-with-passed",
+            display: "endsWith(a, 库)",
+            nullable: true,
+            func: FunctionFactory::get("endsWith", &[field_a.clone(), literal("库")?])?,
+            block: block.clone(),
+            expect: Arc::new(BooleanArray::from(vec![Some(false), None, Some(true)])),
+            error: "",
+        },
+        Test {
+            name: "split-by-char-passed",
+            display: "splitByChar(,, a)",
+            nullable: true,
+            func: FunctionFactory::get("splitByChar", &[literal(",")?, field_a.clone()])?,
+            block: block.clone(),
+            expect: string_list_array(&[
+                Some(vec![Some(" Hello"), Some(" World ")]),
+                None,
+                Some(vec![Some("数据库")]),
+            ])?,
+            error: "",
+        },
+        Test {
+            name: "split-by-char-separator-error",
+            display: "splitByChar(, , a)",
+            nullable: true,
+            func: FunctionFactory::get("splitByChar", &[literal(", ")?, field_a.clone()])?,
+            block: block.clone(),
+            expect: Arc::new(NullArray::new(0)),
+            error: "Function Error: splitByChar separator must be a single character, but got ', '",
+        },
+        Test {
+            name: "like-passed",
+            display: "a LIKE %World%",
+            nullable: true,
+            func: FunctionFactory::get("like", &[field_a.clone(), literal("%World%")?])?,
+            block: block.clone(),
+            expect: Arc::new(BooleanArray::from(vec![Some(true), None, Some(false)])),
+            error: "",
+        },
+        Test {
+            name: "not-like-underscore-passed",
+            display: "a NOT LIKE _Hello%",
+            nullable: true,
+            func: FunctionFactory::get("not like", &[field_a.clone(), literal("_Hello%")?])?,
+            block: block.clone(),
+            expect: Arc::new(BooleanArray::from(vec![Some(false), None, Some(true)])),
+            error: "",
+        },
+        Test {
+            name: "like-escape-passed",
+            display: r"a LIKE %o\,%",
+            nullable: true,
+            func: FunctionFactory::get("like", &[field_a.clone(), literal(r"%o\,%")?])?,
+            block: block.clone(),
+            expect: Arc::new(BooleanArray::from(vec![Some(true), None, Some(false)])),
+            error: "",
+        },
+        Test {
+            name: "ilike-passed",
+            display: "a ILIKE %hello%",
+            nullable: true,
+            func: FunctionFactory::get("ilike", &[field_a.clone(), literal("%hello%")?])?,
+            block: block.clone(),
+            expect: Arc::new(BooleanArray::from(vec![Some(true), None, Some(false)])),
+            error: "",
+        },
+        Test {
+            name: "like-column-pattern-passed",
+            display: "a LIKE concat(%, b, %)",
+            nullable: true,
+            func: FunctionFactory::get(
+                "like",
+                &[
+                    field_a.clone(),
+                    FunctionFactory::get(
+                        "concat",
+                        &[literal("%")?, field_b.clone(), literal("%")?],
+                    )?,
+                ],
+            )?,
+            block: block.clone(),
+            expect: Arc::new(BooleanArray::from(vec![Some(true), None, Some(true)])),
+            error: "",
+        },
+        Test {
+            name: "match-passed",
+            display: r"match(a, W\w+d)",
+            nullable: true,
+            func: FunctionFactory::get("match", &[field_a.clone(), literal(r"W\w+d")?])?,
+            block: block.clone(),
+            expect: Arc::new(BooleanArray::from(vec![Some(true), None, Some(false)])),
+            error: "",
+        },
+        Test {
+            name: "extract-passed",
+            display: r"extract(a, (\w+),)",
+            nullable: true,
+            func: FunctionFactory::get("extract", &[field_a.clone(), literal(r"(\w+),")?])?,
+            block: block.clone(),
+            expect: Arc::new(StringArray::from(vec![Some("Hello"), None, Some("")])),
+            error: "",
+        },
+        Test {
+            name: "replace-regexp-all-passed",
+            display: r"replaceRegexpAll(a, (\w+), (\w+), \2 \1)",
+            nullable: true,
+            func: FunctionFactory::get(
+                "replaceRegexpAll",
+                &[
+                    field_a.clone(),
+                    literal(r"(\w+), (\w+)")?,
+                    literal(r"\2 \1")?,
+                ],
+            )?,
+            block: block.clone(),
+            expect: Arc::new(StringArray::from(vec![
+                Some(" World Hello "),
+                None,
+                Some("数据库"),
+            ])),
+            error: "",
+        },
+    ];
+
+    for t in tests {
+        let func = t.func;
+
+        // Display check.
+        let expect_display = t.display.to_string();
+        let actual_display = format!("{}", func);
+        assert_eq!(expect_display, actual_display, "{}", t.name);
+
+        // Nullable check.
+        let expect_null = t.nullable;
+        let actual_null = func.nullable(t.block.schema())?;
+        assert_eq!(expect_null, actual_null, "{}", t.name);
+
+        let v = match func.eval(&t.block) {
+            Ok(v) => v,
+            Err(e) => {
+                assert_eq!(t.error, e.to_string(), "{}", t.name);
+                continue;
+            }
+        };
+        // Type check.
+        let expect_type = func.return_type(t.block.schema())?;
+        let actual_type = v.data_type();
+        assert_eq!(expect_type, actual_type, "{}", t.name);
+        assert_eq!(
+            v.to_array(t.block.num_rows())?.as_ref(),
+            t.expect.as_ref(),
+            "{}",
+            t.name
+        );
+    }
+
+    // The literals result in a literal.
+    let func = FunctionFactory::get("upper", &[literal("abc")?])?;
+    let v = func.eval(&block)?;
+    assert!(matches!(v, DataColumnarValue::Scalar(_)));
+    assert_eq!(
+        DataValue::String(Some("ABC".to_string())),
+        DataValue::try_from_array(&v.to_array(1)?, 0)?
+    );
+    let func = FunctionFactory::get("splitByChar", &[literal("-")?, literal("a-b")?])?;
+    let v = func.eval(&block)?;
+    assert!(matches!(v, DataColumnarValue::Scalar(_)));
+    assert_eq!(
+        "['a', 'b']",
+        DataValue::try_from_array(&v.to_array(1)?, 0)?.to_string()
+    );
+
+    // The literal patterns are compiled when the function is created.
+    let result = FunctionFactory::get("match", &[field_a.clone(), literal("(")?]);
+    assert!(matches!(result, Err(e) if e.to_string().starts_with(
+        "Function Error: Invalid regular expression '('"
+    )));
+    Ok(())
+}
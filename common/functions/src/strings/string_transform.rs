@@ -0,0 +1,91 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::fmt;
+use std::sync::Arc;
+
+use anyhow::{ensure, Result};
+use common_datablocks::DataBlock;
+use common_datavalues::{DataColumnarValue, DataSchema, DataType, StringArray};
+
+use crate::function_common::to_columnar;
+use crate::strings::{eval_args, ArgValues};
+use crate::IFunction;
+
+/// The function transforms every string to a new one, like `lower` and `trim`.
+#[derive(Clone)]
+pub struct StringTransformFunction {
+    display_name: &'static str,
+    transform: fn(&str) -> String,
+    arg: Box<dyn IFunction>,
+}
+
+impl StringTransformFunction {
+    pub fn try_create_lower(args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+        Self::try_create("lower", |v| v.to_lowercase(), args)
+    }
+
+    pub fn try_create_upper(args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+        Self::try_create("upper", |v| v.to_uppercase(), args)
+    }
+
+    pub fn try_create_trim(args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+        Self::try_create("trim", |v| v.trim().to_string(), args)
+    }
+
+    pub fn try_create_ltrim(args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+        Self::try_create("ltrim", |v| v.trim_start().to_string(), args)
+    }
+
+    pub fn try_create_rtrim(args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+        Self::try_create("rtrim", |v| v.trim_end().to_string(), args)
+    }
+
+    pub fn try_create_reverse(args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+        Self::try_create("reverse", |v| v.chars().rev().collect(), args)
+    }
+
+    fn try_create(
+        display_name: &'static str,
+        transform: fn(&str) -> String,
+        args: &[Box<dyn IFunction>],
+    ) -> Result<Box<dyn IFunction>> {
+        ensure!(
+            args.len() == 1,
+            "Function Error: Function {} args length must be 1",
+            display_name
+        );
+
+        Ok(Box::new(StringTransformFunction {
+            display_name,
+            transform,
+            arg: args[0].clone(),
+        }))
+    }
+}
+
+impl IFunction for StringTransformFunction {
+    fn return_type(&self, _input_schema: &DataSchema) -> Result<DataType> {
+        Ok(DataType::Utf8)
+    }
+
+    fn nullable(&self, input_schema: &DataSchema) -> Result<bool> {
+        self.arg.nullable(input_schema)
+    }
+
+    fn eval(&self, block: &DataBlock) -> Result<DataColumnarValue> {
+        let (args, rows, scalar) = eval_args(&[(self.arg.as_ref(), DataType::Utf8)], block)?;
+        let values = ArgValues::try_create(&args[0])?;
+        let result = (0..rows)
+            .map(|row| values.string(row).map(self.transform))
+            .collect::<StringArray>();
+        to_columnar(Arc::new(result), scalar)
+    }
+}
+
+impl fmt::Display for StringTransformFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}({})", self.display_name, self.arg)
+    }
+}
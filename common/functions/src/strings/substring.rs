@@ -0,0 +1,105 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::fmt;
+use std::sync::Arc;
+
+use anyhow::{ensure, Result};
+use common_datablocks::DataBlock;
+use common_datavalues::{DataColumnarValue, DataSchema, DataType, StringArray};
+
+use crate::function_common::to_columnar;
+use crate::strings::{args_nullable, eval_args, ArgValues};
+use crate::IFunction;
+
+/// `substring(s, offset[, length])`, the offset of the first character is 1 and the negative
+/// offset counts from the end. The string is to the end without the length.
+#[derive(Clone)]
+pub struct SubstringFunction {
+    args: Vec<Box<dyn IFunction>>,
+}
+
+impl SubstringFunction {
+    pub fn try_create(args: &[Box<dyn IFunction>]) -> Result<Box<dyn IFunction>> {
+        ensure!(
+            args.len() == 2 || args.len() == 3,
+            "Function Error: Function substring args length must be 2 or 3"
+        );
+
+        Ok(Box::new(SubstringFunction {
+            args: args.to_vec(),
+        }))
+    }
+}
+
+fn substring(value: &str, offset: i64, length: Option<i64>) -> String {
+    let chars = value.chars().count() as i64;
+    let start = match offset {
+        0 => return String::new(),
+        offset if offset > 0 => offset - 1,
+        offset => (chars + offset).max(0),
+    };
+    let length = match length {
+        Some(length) => length.max(0),
+        None => chars,
+    };
+    value
+        .chars()
+        .skip(start as usize)
+        .take(length as usize)
+        .collect()
+}
+
+impl IFunction for SubstringFunction {
+    fn return_type(&self, _input_schema: &DataSchema) -> Result<DataType> {
+        Ok(DataType::Utf8)
+    }
+
+    fn nullable(&self, input_schema: &DataSchema) -> Result<bool> {
+        let funcs = self.args.iter().map(|arg| arg.as_ref()).collect::<Vec<_>>();
+        args_nullable(&funcs, input_schema)
+    }
+
+    fn eval(&self, block: &DataBlock) -> Result<DataColumnarValue> {
+        let funcs = self
+            .args
+            .iter()
+            .enumerate()
+            .map(|(i, arg)| match i {
+                0 => (arg.as_ref(), DataType::Utf8),
+                _ => (arg.as_ref(), DataType::Int64),
+            })
+            .collect::<Vec<_>>();
+        let (args, rows, scalar) = eval_args(&funcs, block)?;
+        let values = ArgValues::try_create(&args[0])?;
+        let offsets = ArgValues::try_create(&args[1])?;
+        let lengths = args.get(2).map(ArgValues::try_create).transpose()?;
+
+        let result = (0..rows)
+            .map(|row| {
+                let length = match &lengths {
+                    Some(lengths) => Some(lengths.integer(row)?),
+                    None => None,
+                };
+                Some(substring(
+                    values.string(row)?,
+                    offsets.integer(row)?,
+                    length,
+                ))
+            })
+            .collect::<StringArray>();
+        to_columnar(Arc::new(result), scalar)
+    }
+}
+
+impl fmt::Display for SubstringFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let args = self
+            .args
+            .iter()
+            .map(|arg| format!("{}", arg))
+            .collect::<Vec<_>>();
+        write!(f, "substring({})", args.join(", "))
+    }
+}
@@ -11,6 +11,10 @@ mod plan_expression_test;
 #[cfg(test)]
 mod plan_filter_test;
 #[cfg(test)]
+mod plan_having_test;
+#[cfg(test)]
+mod plan_join_test;
+#[cfg(test)]
 mod plan_limit_test;
 #[cfg(test)]
 mod plan_projection_test;
@@ -19,6 +23,8 @@ mod plan_rewriter_test;
 #[cfg(test)]
 mod plan_select_test;
 #[cfg(test)]
+mod plan_sort_test;
+#[cfg(test)]
 mod plan_stage_test;
 #[cfg(test)]
 mod plan_walker_test;
@@ -29,6 +35,8 @@ mod plan_builder;
 mod plan_create_database;
 mod plan_create_table;
 mod plan_display;
+mod plan_drop_database;
+mod plan_drop_table;
 mod plan_empty;
 mod plan_explain;
 mod plan_explain_test;
@@ -36,7 +44,11 @@ mod plan_expression;
 mod plan_expression_column;
 mod plan_expression_function;
 mod plan_expression_literal;
+mod plan_extras;
 mod plan_filter;
+mod plan_having;
+mod plan_insert_into;
+mod plan_join;
 mod plan_limit;
 mod plan_node;
 mod plan_partition;
@@ -46,8 +58,10 @@ mod plan_rewriter;
 mod plan_scan;
 mod plan_select;
 mod plan_setting;
+mod plan_sort;
 mod plan_stage;
 mod plan_statistics;
+mod plan_truncate_table;
 mod plan_walker;
 mod test;
 
@@ -59,13 +73,19 @@ pub use crate::plan_aggregator_partial::AggregatorPartialPlan;
 pub use crate::plan_builder::PlanBuilder;
 pub use crate::plan_create_database::{CreateDatabasePlan, DatabaseEngineType, DatabaseOptions};
 pub use crate::plan_create_table::{CreateTablePlan, TableEngineType, TableOptions};
+pub use crate::plan_drop_database::DropDatabasePlan;
+pub use crate::plan_drop_table::DropTablePlan;
 pub use crate::plan_empty::EmptyPlan;
 pub use crate::plan_explain::{ExplainPlan, ExplainType};
 pub use crate::plan_expression::ExpressionPlan;
 pub use crate::plan_expression_column::col;
 pub use crate::plan_expression_function::{add, sum};
 pub use crate::plan_expression_literal::lit;
+pub use crate::plan_extras::Extras;
 pub use crate::plan_filter::FilterPlan;
+pub use crate::plan_having::HavingPlan;
+pub use crate::plan_insert_into::InsertIntoPlan;
+pub use crate::plan_join::{JoinPlan, JoinShuffle, JoinType};
 pub use crate::plan_limit::LimitPlan;
 pub use crate::plan_node::PlanNode;
 pub use crate::plan_partition::{Partition, Partitions};
@@ -75,6 +95,8 @@ pub use crate::plan_rewriter::PlanRewriter;
 pub use crate::plan_scan::ScanPlan;
 pub use crate::plan_select::SelectPlan;
 pub use crate::plan_setting::{SettingPlan, VarValue};
+pub use crate::plan_sort::SortPlan;
 pub use crate::plan_stage::{StagePlan, StageState};
 pub use crate::plan_statistics::Statistics;
+pub use crate::plan_truncate_table::TruncateTablePlan;
 pub use crate::test::Test;
@@ -4,13 +4,13 @@
 
 use std::sync::Arc;
 
-use anyhow::Result;
+use anyhow::{bail, Result};
 use common_datavalues::{DataField, DataSchema, DataSchemaRef};
 
 use crate::{
     col, AggregatorFinalPlan, AggregatorPartialPlan, EmptyPlan, ExplainPlan, ExplainType,
-    ExpressionPlan, FilterPlan, LimitPlan, PlanNode, PlanRewriter, ProjectionPlan, ScanPlan,
-    SelectPlan, StagePlan, StageState,
+    ExpressionPlan, FilterPlan, HavingPlan, JoinPlan, JoinType, LimitPlan, PlanNode, PlanRewriter,
+    ProjectionPlan, ScanPlan, SelectPlan, SortPlan, StagePlan, StageState,
 };
 
 pub enum AggregateMode {
@@ -89,11 +89,9 @@ impl PlanBuilder {
         aggr_expr: Vec<ExpressionPlan>,
         group_expr: Vec<ExpressionPlan>,
     ) -> Result<Self> {
-        let mut all_expr: Vec<ExpressionPlan> = group_expr.clone();
-        aggr_expr.iter().for_each(|x| all_expr.push(x.clone()));
-
+        // The group by columns are output by the aggregator expressions if they are selected.
         let input_schema = self.plan.schema();
-        let aggr_fields = self.exprs_to_fields(&all_expr, &input_schema)?;
+        let aggr_fields = self.exprs_to_fields(&aggr_expr, &input_schema)?;
 
         Ok(match mode {
             AggregateMode::Partial => {
@@ -166,6 +164,65 @@ impl PlanBuilder {
         })))
     }
 
+    /// Apply a having filter on the aggregator output
+    pub fn having(&self, expr: ExpressionPlan) -> Result<Self> {
+        Ok(Self::from(&PlanNode::Having(HavingPlan {
+            predicate: expr,
+            input: Arc::new(self.plan.clone()),
+        })))
+    }
+
+    /// Apply a hash join, the current plan probes the hash table loaded from the build plan
+    pub fn join(
+        &self,
+        join_type: JoinType,
+        build: &PlanNode,
+        probe_keys: Vec<ExpressionPlan>,
+        build_keys: Vec<ExpressionPlan>,
+    ) -> Result<Self> {
+        if probe_keys.is_empty() || probe_keys.len() != build_keys.len() {
+            bail!(
+                "Join keys are not matched, probe keys: {:?}, build keys: {:?}",
+                probe_keys,
+                build_keys
+            );
+        }
+
+        let probe_schema = self.plan.schema();
+        let mut fields = probe_schema.fields().clone();
+        for field in build.schema().fields() {
+            if probe_schema.field_with_name(field.name()).is_ok() {
+                bail!("Join has duplicate column name: {}", field.name());
+            }
+            // The unmatched rows of the left join output NULL for the build side.
+            let nullable = field.is_nullable() || join_type == JoinType::Left;
+            fields.push(DataField::new(
+                field.name(),
+                field.data_type().clone(),
+                nullable,
+            ));
+        }
+
+        Ok(Self::from(&PlanNode::Join(JoinPlan {
+            join_type,
+            probe_keys,
+            build_keys,
+            schema: Arc::new(DataSchema::new(fields)),
+            shuffle: None,
+            build: Arc::new(build.clone()),
+            input: Arc::new(self.plan.clone()),
+        })))
+    }
+
+    /// Apply a sort, the limit is set if only the first N rows are required
+    pub fn sort(&self, exprs: Vec<ExpressionPlan>, limit: Option<usize>) -> Result<Self> {
+        Ok(Self::from(&PlanNode::Sort(SortPlan {
+            order_by: exprs,
+            limit,
+            input: Arc::new(self.plan.clone()),
+        })))
+    }
+
     /// Apply a limit
     pub fn limit(&self, n: usize) -> Result<Self> {
         Ok(Self::from(&PlanNode::Limit(LimitPlan {
@@ -17,6 +17,10 @@ pub enum TableEngineType {
     Csv,
     /// Null ENGINE
     Null,
+    /// Memory ENGINE
+    Memory,
+    /// Table stored in FuseStore
+    Remote,
 }
 
 impl ToString for TableEngineType {
@@ -26,6 +30,8 @@ impl ToString for TableEngineType {
             TableEngineType::Parquet => "Parquet".into(),
             TableEngineType::Csv => "CSV".into(),
             TableEngineType::Null => "Null".into(),
+            TableEngineType::Memory => "Memory".into(),
+            TableEngineType::Remote => "Remote".into(),
         }
     }
 }
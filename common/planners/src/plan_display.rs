@@ -26,9 +26,11 @@ impl PlanNode {
                     Ok(())
                 };
 
+                let mut depth = 0;
                 self.0
                     .walk_preorder(|node| {
                         write_indent(f)?;
+                        depth += 1;
                         match node {
                             PlanNode::Stage(plan) => {
                                 write!(
@@ -73,6 +75,41 @@ impl PlanNode {
                                 write!(f, "Filter: {:?}", plan.predicate)?;
                                 Ok(true)
                             }
+                            PlanNode::Having(plan) => {
+                                write!(f, "Having: {:?}", plan.predicate)?;
+                                Ok(true)
+                            }
+                            PlanNode::Join(plan) => {
+                                write!(
+                                    f,
+                                    "Join: {:?}, probe keys: {:?}, build keys: {:?}",
+                                    plan.join_type, plan.probe_keys, plan.build_keys
+                                )?;
+                                if let Some(shuffle) = &plan.shuffle {
+                                    write!(
+                                        f,
+                                        ", shuffle: [bucket: {}, executors: {}]",
+                                        shuffle.index,
+                                        shuffle.executors.len()
+                                    )?;
+                                }
+                                // The build side is displayed before the probe side, at the same depth.
+                                for line in format!("{:?}", plan.build).lines() {
+                                    writeln!(f)?;
+                                    for _ in 0..depth {
+                                        write!(f, "  ")?;
+                                    }
+                                    write!(f, "{}", line)?;
+                                }
+                                Ok(true)
+                            }
+                            PlanNode::Sort(plan) => {
+                                write!(f, "Sort: {:?}", plan.order_by)?;
+                                if let Some(limit) = plan.limit {
+                                    write!(f, ", limit: {}", limit)?;
+                                }
+                                Ok(true)
+                            }
                             PlanNode::Limit(plan) => {
                                 write!(f, "Limit: {}", plan.n)?;
                                 Ok(true)
@@ -105,6 +142,42 @@ impl PlanNode {
                                 write!(f, " option: {:?}", plan.options)?;
                                 Ok(false)
                             }
+                            PlanNode::InsertInto(plan) => {
+                                write!(
+                                    f,
+                                    "Insert into {:}.{:} {:}",
+                                    plan.db,
+                                    plan.table,
+                                    PlanNode::display_schema(plan.schema.as_ref())
+                                )?;
+                                match &plan.select {
+                                    Some(select) => {
+                                        for line in format!("{:?}", select).lines() {
+                                            writeln!(f)?;
+                                            for _ in 0..depth {
+                                                write!(f, "  ")?;
+                                            }
+                                            write!(f, "{}", line)?;
+                                        }
+                                    }
+                                    None => write!(f, ", values: {} rows", plan.values.len())?,
+                                }
+                                Ok(false)
+                            }
+                            PlanNode::DropTable(plan) => {
+                                write!(f, "Drop table {:}.{:},", plan.db, plan.table)?;
+                                write!(f, " if_exists:{:}", plan.if_exists)?;
+                                Ok(false)
+                            }
+                            PlanNode::DropDatabase(plan) => {
+                                write!(f, "Drop database {:},", plan.db)?;
+                                write!(f, " if_exists:{:}", plan.if_exists)?;
+                                Ok(false)
+                            }
+                            PlanNode::TruncateTable(plan) => {
+                                write!(f, "Truncate table {:}.{:}", plan.db, plan.table)?;
+                                Ok(false)
+                            }
                             _ => Ok(false),
                         }
                     })
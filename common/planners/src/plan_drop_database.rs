@@ -0,0 +1,18 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use common_datavalues::DataSchemaRef;
+
+#[derive(serde::Serialize, serde::Deserialize, Clone)]
+pub struct DropDatabasePlan {
+    pub if_exists: bool,
+    pub db: String,
+    pub schema: DataSchemaRef,
+}
+
+impl DropDatabasePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        self.schema.clone()
+    }
+}
@@ -5,9 +5,9 @@
 use std::fmt;
 
 use anyhow::Result;
-use common_datavalues::{DataField, DataSchemaRef, DataValue};
+use common_datavalues::{DataField, DataSchemaRef, DataType, DataValue};
 use common_functions::{
-    AliasFunction, ColumnFunction, FunctionFactory, IFunction, LiteralFunction,
+    AliasFunction, CastFunction, ColumnFunction, FunctionFactory, IFunction, LiteralFunction,
 };
 
 #[derive(serde::Serialize, serde::Deserialize, Clone)]
@@ -24,13 +24,42 @@ pub enum ExpressionPlan {
         op: String,
         right: Box<ExpressionPlan>,
     },
+    /// A unary expression such as "NOT a" and "-a", the op is the function name.
+    UnaryExpression {
+        op: String,
+        expr: Box<ExpressionPlan>,
+    },
+    /// The expression in or not in the list, such as "a IN (1, 2, 3)".
+    InList {
+        expr: Box<ExpressionPlan>,
+        list: Vec<ExpressionPlan>,
+        negated: bool,
+    },
+    /// The expression between or not between the bounds, such as "a BETWEEN 1 AND 3".
+    Between {
+        expr: Box<ExpressionPlan>,
+        low: Box<ExpressionPlan>,
+        high: Box<ExpressionPlan>,
+        negated: bool,
+    },
     /// Functions with a set of arguments.
     Function {
         op: String,
         args: Vec<ExpressionPlan>,
     },
+    /// Cast the expression to the data type, such as "CAST(a AS BIGINT)".
+    Cast {
+        expr: Box<ExpressionPlan>,
+        data_type: DataType,
+    },
     /// All fields(*) in a schema.
     Wildcard,
+    /// The order by expression with the direction and nulls order.
+    Sort {
+        expr: Box<ExpressionPlan>,
+        asc: bool,
+        nulls_first: bool,
+    },
 }
 
 impl ExpressionPlan {
@@ -48,26 +77,68 @@ impl ExpressionPlan {
                 func.set_depth(depth);
                 Ok(func)
             }
-            ExpressionPlan::Function { op, args } => {
-                let mut funcs = Vec::with_capacity(args.len());
-                for arg in args {
-                    let mut func = arg.to_function_with_depth(depth + 1)?;
-                    func.set_depth(depth);
-                    funcs.push(func);
-                }
-                let mut func = FunctionFactory::get(op, &funcs)?;
+            ExpressionPlan::UnaryExpression { op, expr } => {
+                let arg = expr.to_function_with_depth(depth)?;
+                let mut func = FunctionFactory::get(op, &[arg])?;
                 func.set_depth(depth);
                 Ok(func)
             }
+            ExpressionPlan::InList {
+                expr,
+                list,
+                negated,
+            } => {
+                let op = if *negated { "not in" } else { "in" };
+                let args = [vec![expr.as_ref().clone()], list.clone()].concat();
+                Self::function_with_depth(op, &args, depth)
+            }
+            ExpressionPlan::Between {
+                expr,
+                low,
+                high,
+                negated,
+            } => {
+                let op = if *negated { "not between" } else { "between" };
+                let args = vec![
+                    expr.as_ref().clone(),
+                    low.as_ref().clone(),
+                    high.as_ref().clone(),
+                ];
+                Self::function_with_depth(op, &args, depth)
+            }
+            ExpressionPlan::Function { op, args } => Self::function_with_depth(op, args, depth),
             ExpressionPlan::Alias(alias, expr) => {
                 let mut func = expr.to_function_with_depth(depth)?;
                 func.set_depth(depth);
                 Ok(AliasFunction::try_create(alias.clone(), func)?)
             }
+            ExpressionPlan::Cast { expr, data_type } => {
+                let arg = expr.to_function_with_depth(depth + 1)?;
+                let mut func = CastFunction::try_create_cast(arg, data_type.clone())?;
+                func.set_depth(depth);
+                Ok(func)
+            }
             ExpressionPlan::Wildcard => Ok(ColumnFunction::try_create("*")?),
+            ExpressionPlan::Sort { expr, .. } => expr.to_function_with_depth(depth),
         }
     }
 
+    fn function_with_depth(
+        op: &str,
+        args: &[ExpressionPlan],
+        depth: usize,
+    ) -> Result<Box<dyn IFunction>> {
+        let mut funcs = Vec::with_capacity(args.len());
+        for arg in args {
+            let mut func = arg.to_function_with_depth(depth + 1)?;
+            func.set_depth(depth);
+            funcs.push(func);
+        }
+        let mut func = FunctionFactory::get(op, &funcs)?;
+        func.set_depth(depth);
+        Ok(func)
+    }
+
     pub fn to_function(&self) -> Result<Box<dyn IFunction>> {
         self.to_function_with_depth(0)
     }
@@ -95,8 +166,44 @@ impl fmt::Debug for ExpressionPlan {
             ExpressionPlan::BinaryExpression { left, op, right } => {
                 write!(f, "({:?} {} {:?})", left, op, right,)
             }
+            ExpressionPlan::UnaryExpression { op, expr } => write!(f, "{}({:?})", op, expr),
+            ExpressionPlan::InList {
+                expr,
+                list,
+                negated,
+            } => {
+                let list = list.iter().map(|e| format!("{:?}", e)).collect::<Vec<_>>();
+                let op = if *negated { "NOT IN" } else { "IN" };
+                write!(f, "({:?} {} ({}))", expr, op, list.join(", "))
+            }
+            ExpressionPlan::Between {
+                expr,
+                low,
+                high,
+                negated,
+            } => {
+                let op = if *negated { "NOT BETWEEN" } else { "BETWEEN" };
+                write!(f, "({:?} {} {:?} AND {:?})", expr, op, low, high)
+            }
             ExpressionPlan::Function { op, args } => write!(f, "{}({:?})", op, args),
+            ExpressionPlan::Cast { expr, data_type } => {
+                write!(f, "CAST({:?} AS {:?})", expr, data_type)
+            }
             ExpressionPlan::Wildcard => write!(f, "*"),
+            ExpressionPlan::Sort {
+                expr,
+                asc,
+                nulls_first,
+            } => {
+                write!(f, "{:?}", expr)?;
+                if !*asc {
+                    write!(f, " DESC")?;
+                }
+                if *nulls_first {
+                    write!(f, " NULLS FIRST")?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -0,0 +1,15 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use crate::ExpressionPlan;
+
+/// The items pushed down to the source, the source may ignore them.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Default)]
+pub struct Extras {
+    /// Optional column indices of the table schema to use as a projection
+    pub projection: Option<Vec<usize>>,
+
+    /// The filters on the source data, the source may skip the data not matched.
+    pub filters: Vec<ExpressionPlan>,
+}
@@ -0,0 +1,28 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::sync::Arc;
+
+use common_datavalues::DataSchemaRef;
+
+use crate::{ExpressionPlan, PlanNode};
+
+#[derive(serde::Serialize, serde::Deserialize, Clone)]
+pub struct InsertIntoPlan {
+    pub db: String,
+    /// The table name
+    pub table: String,
+    /// The inserted columns of the table, in the order of the values.
+    pub schema: DataSchemaRef,
+    /// The rows of INSERT INTO ... VALUES, one expression for each column.
+    pub values: Vec<Vec<ExpressionPlan>>,
+    /// The plan of INSERT INTO ... SELECT, None for VALUES.
+    pub select: Option<Arc<PlanNode>>,
+}
+
+impl InsertIntoPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        self.schema.clone()
+    }
+}
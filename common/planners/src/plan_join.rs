@@ -0,0 +1,64 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::sync::Arc;
+
+use anyhow::Result;
+use common_datavalues::DataSchemaRef;
+
+use crate::{ExpressionPlan, PlanNode};
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq)]
+pub enum JoinType {
+    /// Only the rows matched on both sides
+    Inner,
+    /// All the rows of the left side, the unmatched right columns are NULL
+    Left,
+}
+
+/// The join is executed in the cluster by repartitioning both sides with the hash of the join keys,
+/// every executor joins the rows of its own bucket.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+pub struct JoinShuffle {
+    /// The id of the join exchanges in the cluster.
+    pub id: String,
+    /// The executor addresses, the bucket of an executor is its index.
+    pub executors: Vec<String>,
+    /// The bucket of the current executor.
+    pub index: usize,
+}
+
+/// Hash join: the build side is loaded into a hash table, the input is the probe side
+/// whose blocks are streamed through the hash table.
+#[derive(serde::Serialize, serde::Deserialize, Clone)]
+pub struct JoinPlan {
+    pub join_type: JoinType,
+    /// The equi-join keys of the probe side.
+    pub probe_keys: Vec<ExpressionPlan>,
+    /// The equi-join keys of the build side.
+    pub build_keys: Vec<ExpressionPlan>,
+    /// The probe side fields followed by the build side fields.
+    pub schema: DataSchemaRef,
+    /// Set by the scheduler if the join is shuffled in the cluster.
+    pub shuffle: Option<JoinShuffle>,
+    /// The build side logical plan
+    pub build: Arc<PlanNode>,
+    /// The probe side logical plan
+    pub input: Arc<PlanNode>,
+}
+
+impl JoinPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        self.schema.clone()
+    }
+
+    pub fn input(&self) -> Arc<PlanNode> {
+        self.input.clone()
+    }
+
+    pub fn set_input(&mut self, input: &PlanNode) -> Result<()> {
+        self.input = Arc::new(input.clone());
+        Ok(())
+    }
+}
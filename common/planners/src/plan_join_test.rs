@@ -0,0 +1,44 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+#[test]
+fn test_join_plan() -> anyhow::Result<()> {
+    use pretty_assertions::assert_eq;
+
+    use crate::*;
+
+    let probe = Test::create().generate_source_plan_for_test(10000)?;
+    let build = PlanBuilder::from(&Test::create().generate_source_plan_for_test(100)?)
+        .project(vec![col("number").alias("id")])?
+        .build()?;
+    let plan = PlanBuilder::from(&probe)
+        .join(JoinType::Left, &build, vec![col("number")], vec![col("id")])?
+        .project(vec![col("number"), col("id")])?
+        .build()?;
+
+    let expect = "\
+    Projection: number:UInt64, id:UInt64\
+    \n  Join: Left, probe keys: [number], build keys: [id]\
+    \n    Projection: number as id:UInt64\
+    \n      ReadDataSource: scan partitions: [8], scan schema: [number:UInt64], statistics: [read_rows: 100, read_bytes: 800]\
+    \n    ReadDataSource: scan partitions: [8], scan schema: [number:UInt64], statistics: [read_rows: 10000, read_bytes: 80000]";
+    let actual = format!("{:?}", plan);
+    assert_eq!(expect, actual);
+
+    // The build side columns are nullable for the left join.
+    assert!(plan.schema().field_with_name("id")?.is_nullable());
+
+    // The duplicate column name must be renamed.
+    let result = PlanBuilder::from(&probe).join(
+        JoinType::Inner,
+        &probe,
+        vec![col("number")],
+        vec![col("number")],
+    );
+    assert_eq!(
+        "Join has duplicate column name: number",
+        format!("{}", result.err().unwrap())
+    );
+    Ok(())
+}
@@ -8,9 +8,10 @@ use anyhow::Result;
 use common_datavalues::{DataSchema, DataSchemaRef};
 
 use crate::{
-    AggregatorFinalPlan, AggregatorPartialPlan, CreateDatabasePlan, CreateTablePlan, EmptyPlan,
-    ExplainPlan, FilterPlan, LimitPlan, ProjectionPlan, ReadDataSourcePlan, ScanPlan, SelectPlan,
-    SettingPlan, StagePlan,
+    AggregatorFinalPlan, AggregatorPartialPlan, CreateDatabasePlan, CreateTablePlan,
+    DropDatabasePlan, DropTablePlan, EmptyPlan, ExplainPlan, FilterPlan, HavingPlan,
+    InsertIntoPlan, JoinPlan, LimitPlan, ProjectionPlan, ReadDataSourcePlan, ScanPlan, SelectPlan,
+    SettingPlan, SortPlan, StagePlan, TruncateTablePlan,
 };
 
 #[derive(serde::Serialize, serde::Deserialize, Clone)]
@@ -21,6 +22,9 @@ pub enum PlanNode {
     AggregatorPartial(AggregatorPartialPlan),
     AggregatorFinal(AggregatorFinalPlan),
     Filter(FilterPlan),
+    Having(HavingPlan),
+    Join(JoinPlan),
+    Sort(SortPlan),
     Limit(LimitPlan),
     Scan(ScanPlan),
     ReadSource(ReadDataSourcePlan),
@@ -29,6 +33,10 @@ pub enum PlanNode {
     CreateTable(CreateTablePlan),
     CreateDatabase(CreateDatabasePlan),
     SetVariable(SettingPlan),
+    InsertInto(InsertIntoPlan),
+    DropTable(DropTablePlan),
+    DropDatabase(DropDatabasePlan),
+    TruncateTable(TruncateTablePlan),
 }
 
 impl PlanNode {
@@ -42,6 +50,9 @@ impl PlanNode {
             PlanNode::AggregatorPartial(v) => v.schema(),
             PlanNode::AggregatorFinal(v) => v.schema(),
             PlanNode::Filter(v) => v.schema(),
+            PlanNode::Having(v) => v.schema(),
+            PlanNode::Join(v) => v.schema(),
+            PlanNode::Sort(v) => v.schema(),
             PlanNode::Limit(v) => v.schema(),
             PlanNode::ReadSource(v) => v.schema(),
             PlanNode::Select(v) => v.schema(),
@@ -49,6 +60,10 @@ impl PlanNode {
             PlanNode::CreateDatabase(v) => v.schema(),
             PlanNode::CreateTable(v) => v.schema(),
             PlanNode::SetVariable(v) => v.schema(),
+            PlanNode::InsertInto(v) => v.schema(),
+            PlanNode::DropTable(v) => v.schema(),
+            PlanNode::DropDatabase(v) => v.schema(),
+            PlanNode::TruncateTable(v) => v.schema(),
         }
     }
 
@@ -61,6 +76,9 @@ impl PlanNode {
             PlanNode::AggregatorPartial(_) => "AggregatorPartialPlan",
             PlanNode::AggregatorFinal(_) => "AggregatorFinalPlan",
             PlanNode::Filter(_) => "FilterPlan",
+            PlanNode::Having(_) => "HavingPlan",
+            PlanNode::Join(_) => "JoinPlan",
+            PlanNode::Sort(_) => "SortPlan",
             PlanNode::Limit(_) => "LimitPlan",
             PlanNode::ReadSource(_) => "ReadSourcePlan",
             PlanNode::Select(_) => "SelectPlan",
@@ -68,6 +86,10 @@ impl PlanNode {
             PlanNode::CreateTable(_) => "CreateTablePlan",
             PlanNode::CreateDatabase(_) => "CreateDatabasePlan",
             PlanNode::SetVariable(_) => "SetVariablePlan",
+            PlanNode::InsertInto(_) => "InsertIntoPlan",
+            PlanNode::DropTable(_) => "DropTablePlan",
+            PlanNode::DropDatabase(_) => "DropDatabasePlan",
+            PlanNode::TruncateTable(_) => "TruncateTablePlan",
         }
     }
 
@@ -78,6 +100,9 @@ impl PlanNode {
             PlanNode::AggregatorPartial(v) => v.input(),
             PlanNode::AggregatorFinal(v) => v.input(),
             PlanNode::Filter(v) => v.input(),
+            PlanNode::Having(v) => v.input(),
+            PlanNode::Join(v) => v.input(),
+            PlanNode::Sort(v) => v.input(),
             PlanNode::Limit(v) => v.input(),
             PlanNode::Explain(v) => v.input(),
             PlanNode::Select(v) => v.input(),
@@ -95,6 +120,9 @@ impl PlanNode {
             PlanNode::AggregatorPartial(v) => v.set_input(node),
             PlanNode::AggregatorFinal(v) => v.set_input(node),
             PlanNode::Filter(v) => v.set_input(node),
+            PlanNode::Having(v) => v.set_input(node),
+            PlanNode::Join(v) => v.set_input(node),
+            PlanNode::Sort(v) => v.set_input(node),
             PlanNode::Limit(v) => v.set_input(node),
             PlanNode::Explain(v) => v.set_input(node),
             PlanNode::Select(v) => v.set_input(node),
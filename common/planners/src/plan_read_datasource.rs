@@ -6,7 +6,7 @@ use std::sync::Arc;
 
 use common_datavalues::{DataSchema, DataSchemaRef};
 
-use crate::{Partitions, Statistics};
+use crate::{Extras, Partitions, Statistics};
 
 #[derive(serde::Serialize, serde::Deserialize, Clone)]
 pub struct ReadDataSourcePlan {
@@ -16,6 +16,8 @@ pub struct ReadDataSourcePlan {
     pub partitions: Partitions,
     pub statistics: Statistics,
     pub description: String,
+    /// The projection and filters pushed down to the table.
+    pub push_downs: Extras,
 }
 
 impl ReadDataSourcePlan {
@@ -27,6 +29,7 @@ impl ReadDataSourcePlan {
             partitions: vec![],
             statistics: Statistics::default(),
             description: "".to_string(),
+            push_downs: Extras::default(),
         }
     }
 
@@ -130,6 +130,51 @@ impl PlanRewriter {
                 Ok(ExpressionPlan::Alias(alias.clone(), Box::new(new_expr)))
             }
 
+            ExpressionPlan::UnaryExpression { op, expr } => Ok(ExpressionPlan::UnaryExpression {
+                op: op.clone(),
+                expr: Box::new(PlanRewriter::expr_rewrite_alias(expr, data)?),
+            }),
+
+            ExpressionPlan::InList {
+                expr,
+                list,
+                negated,
+            } => Ok(ExpressionPlan::InList {
+                expr: Box::new(PlanRewriter::expr_rewrite_alias(expr, data)?),
+                list: list
+                    .iter()
+                    .map(|v| PlanRewriter::expr_rewrite_alias(v, data))
+                    .collect::<Result<Vec<_>>>()?,
+                negated: *negated,
+            }),
+
+            ExpressionPlan::Between {
+                expr,
+                low,
+                high,
+                negated,
+            } => Ok(ExpressionPlan::Between {
+                expr: Box::new(PlanRewriter::expr_rewrite_alias(expr, data)?),
+                low: Box::new(PlanRewriter::expr_rewrite_alias(low, data)?),
+                high: Box::new(PlanRewriter::expr_rewrite_alias(high, data)?),
+                negated: *negated,
+            }),
+
+            ExpressionPlan::Cast { expr, data_type } => Ok(ExpressionPlan::Cast {
+                expr: Box::new(PlanRewriter::expr_rewrite_alias(expr, data)?),
+                data_type: data_type.clone(),
+            }),
+
+            ExpressionPlan::Sort {
+                expr,
+                asc,
+                nulls_first,
+            } => Ok(ExpressionPlan::Sort {
+                expr: Box::new(PlanRewriter::expr_rewrite_alias(expr, data)?),
+                asc: *asc,
+                nulls_first: *nulls_first,
+            }),
+
             ExpressionPlan::Wildcard | ExpressionPlan::Literal(_) => Ok(expr.clone()),
         }
     }
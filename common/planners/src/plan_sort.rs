@@ -0,0 +1,35 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::sync::Arc;
+
+use anyhow::Result;
+use common_datavalues::DataSchemaRef;
+
+use crate::{ExpressionPlan, PlanNode};
+
+#[derive(serde::Serialize, serde::Deserialize, Clone)]
+pub struct SortPlan {
+    /// The expressions to sort by, each one is an ExpressionPlan::Sort
+    pub order_by: Vec<ExpressionPlan>,
+    /// Only the first `limit` rows are required(ORDER BY ... LIMIT N), sort as top-N
+    pub limit: Option<usize>,
+    /// The logical plan
+    pub input: Arc<PlanNode>,
+}
+
+impl SortPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        self.input.schema()
+    }
+
+    pub fn input(&self) -> Arc<PlanNode> {
+        self.input.clone()
+    }
+
+    pub fn set_input(&mut self, input: &PlanNode) -> Result<()> {
+        self.input = Arc::new(input.clone());
+        Ok(())
+    }
+}
@@ -0,0 +1,40 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+#[test]
+fn test_sort_plan() -> anyhow::Result<()> {
+    use std::sync::Arc;
+
+    use pretty_assertions::assert_eq;
+
+    use crate::*;
+
+    let source = Test::create().generate_source_plan_for_test(10000)?;
+    let plan = PlanBuilder::from(&source)
+        .sort(
+            vec![
+                ExpressionPlan::Sort {
+                    expr: Box::new(col("number")),
+                    asc: false,
+                    nulls_first: false,
+                },
+                ExpressionPlan::Sort {
+                    expr: Box::new(add(col("number"), lit(1))),
+                    asc: true,
+                    nulls_first: true,
+                },
+            ],
+            Some(3),
+        )?
+        .build()?;
+    let explain = PlanNode::Explain(ExplainPlan {
+        typ: ExplainType::Syntax,
+        input: Arc::new(plan),
+    });
+    let expect = "Sort: [number DESC, (number + 1) NULLS FIRST], limit: 3\
+    \n  ReadDataSource: scan partitions: [8], scan schema: [number:UInt64], statistics: [read_rows: 10000, read_bytes: 80000]";
+    let actual = format!("{:?}", explain);
+    assert_eq!(expect, actual);
+    Ok(())
+}
@@ -16,6 +16,7 @@ pub enum StageState {
     SortMerge,
     GroupByMerge,
     AggregatorMerge,
+    JoinShuffle,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone)]
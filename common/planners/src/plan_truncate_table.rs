@@ -0,0 +1,19 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use common_datavalues::DataSchemaRef;
+
+#[derive(serde::Serialize, serde::Deserialize, Clone)]
+pub struct TruncateTablePlan {
+    pub db: String,
+    /// The table name
+    pub table: String,
+    pub schema: DataSchemaRef,
+}
+
+impl TruncateTablePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        self.schema.clone()
+    }
+}
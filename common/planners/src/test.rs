@@ -8,7 +8,7 @@ use anyhow::Result;
 use common_datavalues::{DataField, DataSchema, DataType};
 
 use crate::plan_partition::Partition;
-use crate::{Partitions, PlanNode, ReadDataSourcePlan, Statistics};
+use crate::{Extras, Partitions, PlanNode, ReadDataSourcePlan, Statistics};
 
 pub struct Test {}
 
@@ -39,6 +39,7 @@ impl Test {
                 "(Read from system.numbers_mt table, Read Rows:{}, Read Bytes:{})",
                 statistics.read_rows, statistics.read_bytes
             ),
+            push_downs: Extras::default(),
         }))
     }
 
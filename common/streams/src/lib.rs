@@ -10,6 +10,7 @@ mod stream_csv;
 mod stream_datablock;
 mod stream_expression;
 mod stream_limit;
+mod stream_ndjson;
 mod stream_parquet;
 
 pub use common_datablocks;
@@ -21,4 +22,5 @@ pub use crate::stream_csv::CsvStream;
 pub use crate::stream_datablock::DataBlockStream;
 pub use crate::stream_expression::ExpressionStream;
 pub use crate::stream_limit::LimitStream;
+pub use crate::stream_ndjson::NdJsonStream;
 pub use crate::stream_parquet::ParquetStream;
@@ -4,23 +4,69 @@
 
 use std::convert::TryInto;
 use std::fs::File;
+use std::sync::Arc;
 use std::task::{Context, Poll};
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use common_arrow::arrow::csv;
 use common_datablocks::DataBlock;
-use common_datavalues::DataSchemaRef;
+use common_datavalues::{
+    data_array_cast, is_decimal, is_temporal, DataArrayRef, DataField, DataSchema, DataSchemaRef,
+    DataType, StringArray,
+};
 use csv as csv_crate;
 use futures::stream::Stream;
 
 pub struct CsvStream {
     reader: csv_crate::Reader<File>,
+    schema: DataSchemaRef,
 }
 
 impl CsvStream {
     pub fn try_create(schema: DataSchemaRef, r: File) -> Result<Self> {
-        let reader = csv::Reader::new(r, schema, false, None, 1024, None, None);
-        Ok(CsvStream { reader })
+        // The temporal and decimal fields are read as strings and parsed like the literals,
+        // the decimals keep all their digits.
+        let read_schema = Arc::new(DataSchema::new(
+            schema
+                .fields()
+                .iter()
+                .map(|f| match Self::read_as_string(f.data_type()) {
+                    true => DataField::new(f.name(), DataType::Utf8, f.is_nullable()),
+                    false => f.clone(),
+                })
+                .collect(),
+        ));
+        let reader = csv::Reader::new(r, read_schema, false, None, 1024, None, None);
+        Ok(CsvStream { reader, schema })
+    }
+
+    fn try_convert(&self, block: DataBlock) -> Result<DataBlock> {
+        let mut columns = Vec::with_capacity(self.schema.fields().len());
+        for (i, field) in self.schema.fields().iter().enumerate() {
+            let column = block.column(i);
+            columns.push(match Self::read_as_string(field.data_type()) {
+                true => {
+                    // The empty values are NULL.
+                    let strings = column
+                        .as_any()
+                        .downcast_ref::<StringArray>()
+                        .ok_or_else(|| anyhow!("CSV Error: field {} is not read as string", i))?;
+                    let strings: DataArrayRef = Arc::new(
+                        strings
+                            .iter()
+                            .map(|v| v.filter(|v| !v.is_empty()))
+                            .collect::<StringArray>(),
+                    );
+                    data_array_cast(&strings, field.data_type())?
+                }
+                false => column.clone(),
+            });
+        }
+        Ok(DataBlock::create(self.schema.clone(), columns))
+    }
+
+    fn read_as_string(data_type: &DataType) -> bool {
+        is_temporal(data_type) || is_decimal(data_type)
     }
 }
 
@@ -36,7 +82,7 @@ impl Stream for CsvStream {
             Some(Ok(batch)) => {
                 let block = batch.try_into();
                 match block {
-                    Ok(block) => Poll::Ready(Some(Ok(block))),
+                    Ok(block) => Poll::Ready(Some(self.try_convert(block))),
                     _ => Poll::Ready(None),
                 }
             }
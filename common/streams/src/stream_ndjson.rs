@@ -0,0 +1,263 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::convert::TryFrom;
+use std::fmt;
+use std::io::BufRead;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use anyhow::{bail, Result};
+use common_arrow::arrow::array::{
+    BooleanBuilder, Date32Builder, DecimalBuilder, Float32Builder, Float64Builder, Int16Builder,
+    Int32Builder, Int64Builder, Int8Builder, StringBuilder, TimestampMicrosecondBuilder,
+    TimestampSecondBuilder, UInt16Builder, UInt32Builder, UInt64Builder, UInt8Builder,
+};
+use common_arrow::arrow::datatypes::TimeUnit;
+use common_datablocks::DataBlock;
+use common_datavalues::{
+    parse_decimal, parse_temporal, DataArrayRef, DataField, DataSchemaRef, DataType,
+};
+use futures::stream::Stream;
+use serde_json::{Map, Value};
+
+type JsonRow = (LinePosition, Map<String, Value>);
+
+/// The position of a line for the error messages, the row number counted from the first line
+/// of the file, or the byte offset of the line if the lines before the reader are unknown.
+#[derive(Clone, Copy, Debug)]
+enum LinePosition {
+    Row(usize),
+    Offset(u64),
+}
+
+impl fmt::Display for LinePosition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LinePosition::Row(row) => write!(f, "row {}", row),
+            LinePosition::Offset(offset) => write!(f, "line at byte {}", offset),
+        }
+    }
+}
+
+macro_rules! build_json_array {
+    ($FIELD:expr, $ROWS:expr, $BUILDER:ident, $CONVERT:expr) => {{
+        let mut builder = $BUILDER::new($ROWS.len());
+        for (row, object) in $ROWS {
+            match object.get($FIELD.name()) {
+                None | Some(Value::Null) => {
+                    check_nullable($FIELD, row)?;
+                    builder.append_null()?
+                }
+                Some(value) => match $CONVERT(value) {
+                    Some(v) => builder.append_value(v)?,
+                    None => bail!(
+                        "JSONEachRow Error: {}, field {} expects {:?}, but got {}",
+                        row,
+                        $FIELD.name(),
+                        $FIELD.data_type(),
+                        value
+                    ),
+                },
+            }
+        }
+        Arc::new(builder.finish()) as DataArrayRef
+    }};
+}
+
+/// Newline-delimited JSON stream, every line is a JSON object whose fields are mapped to the
+/// schema fields by name, the missing and null fields are NULL, which are rejected by the
+/// non-nullable fields.
+pub struct NdJsonStream<R> {
+    reader: R,
+    schema: DataSchemaRef,
+    /// The position of the next line, for the error messages.
+    position: LinePosition,
+    block_size: usize,
+}
+
+impl<R: BufRead + Unpin> NdJsonStream<R> {
+    /// The row is the row number of the first line of the reader, starts from 1.
+    pub fn try_create(
+        schema: DataSchemaRef,
+        reader: R,
+        row: usize,
+        block_size: usize,
+    ) -> Result<Self> {
+        Ok(NdJsonStream {
+            reader,
+            schema,
+            position: LinePosition::Row(row),
+            block_size,
+        })
+    }
+
+    /// The offset is the byte offset of the first line of the reader in the file, it's used
+    /// when the reader starts in the middle of the file, such as a partition.
+    pub fn try_create_at_offset(
+        schema: DataSchemaRef,
+        reader: R,
+        offset: u64,
+        block_size: usize,
+    ) -> Result<Self> {
+        Ok(NdJsonStream {
+            reader,
+            schema,
+            position: LinePosition::Offset(offset),
+            block_size,
+        })
+    }
+
+    fn try_read_block(&mut self) -> Result<Option<DataBlock>> {
+        let mut rows: Vec<JsonRow> = Vec::with_capacity(self.block_size);
+        let mut line = String::new();
+        while rows.len() < self.block_size {
+            line.clear();
+            let n = self.reader.read_line(&mut line)?;
+            if n == 0 {
+                break;
+            }
+            let row = self.position;
+            self.position = match row {
+                LinePosition::Row(row) => LinePosition::Row(row + 1),
+                LinePosition::Offset(offset) => LinePosition::Offset(offset + n as u64),
+            };
+
+            if line.trim().is_empty() {
+                continue;
+            }
+            match serde_json::from_str::<Value>(&line) {
+                Ok(Value::Object(object)) => rows.push((row, object)),
+                Ok(_) => bail!("JSONEachRow Error: {} is not a JSON object", row),
+                Err(e) => bail!("JSONEachRow Error: {} is not valid JSON: {}", row, e),
+            }
+        }
+
+        if rows.is_empty() {
+            return Ok(None);
+        }
+
+        let mut columns = Vec::with_capacity(self.schema.fields().len());
+        for field in self.schema.fields() {
+            columns.push(Self::build_array(field, &rows)?);
+        }
+        Ok(Some(DataBlock::create(self.schema.clone(), columns)))
+    }
+
+    fn build_array(field: &DataField, rows: &[JsonRow]) -> Result<DataArrayRef> {
+        Ok(match field.data_type() {
+            DataType::Boolean => build_json_array!(field, rows, BooleanBuilder, Value::as_bool),
+            DataType::Int8 => build_json_array!(field, rows, Int8Builder, |v: &Value| v
+                .as_i64()
+                .and_then(|v| i8::try_from(v).ok())),
+            DataType::Int16 => build_json_array!(field, rows, Int16Builder, |v: &Value| v
+                .as_i64()
+                .and_then(|v| i16::try_from(v).ok())),
+            DataType::Int32 => build_json_array!(field, rows, Int32Builder, |v: &Value| v
+                .as_i64()
+                .and_then(|v| i32::try_from(v).ok())),
+            DataType::Int64 => build_json_array!(field, rows, Int64Builder, Value::as_i64),
+            DataType::UInt8 => build_json_array!(field, rows, UInt8Builder, |v: &Value| v
+                .as_u64()
+                .and_then(|v| u8::try_from(v).ok())),
+            DataType::UInt16 => build_json_array!(field, rows, UInt16Builder, |v: &Value| v
+                .as_u64()
+                .and_then(|v| u16::try_from(v).ok())),
+            DataType::UInt32 => build_json_array!(field, rows, UInt32Builder, |v: &Value| v
+                .as_u64()
+                .and_then(|v| u32::try_from(v).ok())),
+            DataType::UInt64 => build_json_array!(field, rows, UInt64Builder, Value::as_u64),
+            DataType::Float32 => build_json_array!(field, rows, Float32Builder, |v: &Value| v
+                .as_f64()
+                .map(|v| v as f32)),
+            DataType::Float64 => build_json_array!(field, rows, Float64Builder, Value::as_f64),
+            DataType::Utf8 => build_json_array!(field, rows, StringBuilder, Value::as_str),
+            DataType::Date32 => build_json_array!(field, rows, Date32Builder, |v: &Value| {
+                Self::temporal_value(v, field.data_type()).and_then(|v| i32::try_from(v).ok())
+            }),
+            DataType::Timestamp(TimeUnit::Second, _) => {
+                build_json_array!(field, rows, TimestampSecondBuilder, |v: &Value| {
+                    Self::temporal_value(v, field.data_type())
+                })
+            }
+            DataType::Timestamp(TimeUnit::Microsecond, _) => {
+                build_json_array!(field, rows, TimestampMicrosecondBuilder, |v: &Value| {
+                    Self::temporal_value(v, field.data_type())
+                })
+            }
+            DataType::Decimal(precision, scale) => {
+                Self::build_decimal_array(field, rows, *precision, *scale)?
+            }
+            other => bail!(
+                "JSONEachRow Error: unsupported type {:?} of field {}",
+                other,
+                field.name()
+            ),
+        })
+    }
+
+    /// The decimal value is a string to keep all the digits, or a number.
+    fn build_decimal_array(
+        field: &DataField,
+        rows: &[JsonRow],
+        precision: usize,
+        scale: usize,
+    ) -> Result<DataArrayRef> {
+        let mut builder = DecimalBuilder::new(rows.len(), precision, scale);
+        for (row, object) in rows {
+            let value = match object.get(field.name()) {
+                None | Some(Value::Null) => {
+                    check_nullable(field, row)?;
+                    None
+                }
+                Some(Value::String(v)) => Some(parse_decimal(v, precision, scale)),
+                Some(v @ Value::Number(_)) => Some(parse_decimal(&v.to_string(), precision, scale)),
+                Some(v) => bail!(
+                    "JSONEachRow Error: {}, field {} expects {:?}, but got {}",
+                    row,
+                    field.name(),
+                    field.data_type(),
+                    v
+                ),
+            };
+            match value {
+                None => builder.append_null()?,
+                Some(Ok(v)) => builder.append_value(v)?,
+                Some(Err(e)) => bail!("JSONEachRow Error: {}, field {}: {}", row, field.name(), e),
+            }
+        }
+        Ok(Arc::new(builder.finish()))
+    }
+
+    /// The temporal value is a string like the literals, or the number in the unit of the type.
+    fn temporal_value(value: &Value, data_type: &DataType) -> Option<i64> {
+        match value {
+            Value::String(v) => parse_temporal(v, data_type).ok(),
+            v => v.as_i64(),
+        }
+    }
+}
+
+/// The null or missing value is only accepted by the nullable field.
+fn check_nullable(field: &DataField, row: &LinePosition) -> Result<()> {
+    if !field.is_nullable() {
+        bail!(
+            "JSONEachRow Error: {}, field {} is not nullable, but got null or nothing",
+            row,
+            field.name()
+        );
+    }
+    Ok(())
+}
+
+impl<R: BufRead + Unpin> Stream for NdJsonStream<R> {
+    type Item = Result<DataBlock>;
+
+    fn poll_next(
+        mut self: std::pin::Pin<&mut Self>,
+        _: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        Poll::Ready(self.try_read_block().transpose())
+    }
+}
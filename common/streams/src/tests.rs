@@ -19,3 +19,173 @@ async fn test_chunk_stream() {
     );
     while let Some(_) = s1.next().await {}
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_ndjson_stream() -> anyhow::Result<()> {
+    use std::io::Cursor;
+    use std::sync::Arc;
+
+    use common_datavalues::*;
+    use futures::stream::TryStreamExt;
+    use pretty_assertions::assert_eq;
+
+    use crate::*;
+
+    let schema = Arc::new(DataSchema::new(vec![
+        DataField::new("a", DataType::Int32, true),
+        DataField::new("b", DataType::Utf8, true),
+    ]));
+
+    // The missing and null fields are NULL, the unknown fields are ignored.
+    let data = "{\"a\": 1, \"b\": \"x\"}\n\n{\"a\": null, \"c\": 2}\n{\"b\": \"z\", \"a\": 3}\n";
+    let stream = NdJsonStream::try_create(schema.clone(), Cursor::new(data), 1, 2)?;
+    let blocks = stream.try_collect::<Vec<_>>().await?;
+    assert_eq!(2, blocks.len());
+
+    let mut actual = vec![];
+    for block in blocks {
+        for row in 0..block.num_rows() {
+            actual.push(format!(
+                "{:?}|{:?}",
+                DataValue::try_from_array(block.column(0), row)?,
+                DataValue::try_from_array(block.column(1), row)?,
+            ));
+        }
+    }
+    assert_eq!(vec!["1|x", "NULL|NULL", "3|z"], actual);
+
+    // The errors have the row number.
+    let data = "{\"a\": 1}\n{\"a\": \"x\"}\n";
+    let stream = NdJsonStream::try_create(schema.clone(), Cursor::new(data), 10, 100)?;
+    let result = stream.try_collect::<Vec<_>>().await;
+    assert_eq!(
+        "JSONEachRow Error: row 11, field a expects Int32, but got \"x\"",
+        format!("{}", result.err().unwrap())
+    );
+
+    let data = "{\"a\": 1}\n[1]\n";
+    let stream = NdJsonStream::try_create(schema.clone(), Cursor::new(data), 1, 100)?;
+    let result = stream.try_collect::<Vec<_>>().await;
+    assert_eq!(
+        "JSONEachRow Error: row 2 is not a JSON object",
+        format!("{}", result.err().unwrap())
+    );
+
+    // The reader starts in the middle of the file, the errors have the byte offset of the line.
+    let data = "{\"a\": 1}\n{\"a\": \"x\"}\n";
+    let stream = NdJsonStream::try_create_at_offset(schema, Cursor::new(data), 100, 100)?;
+    let result = stream.try_collect::<Vec<_>>().await;
+    assert_eq!(
+        "JSONEachRow Error: line at byte 109, field a expects Int32, but got \"x\"",
+        format!("{}", result.err().unwrap())
+    );
+
+    // The null and missing values are rejected by the non-nullable fields.
+    let schema = Arc::new(DataSchema::new(vec![
+        DataField::new("a", DataType::Int32, false),
+        DataField::new("b", DataType::Utf8, true),
+    ]));
+    for data in &[
+        "{\"a\": 1}\n{\"b\": \"x\"}\n",
+        "{\"a\": 1}\n{\"a\": null}\n",
+    ] {
+        let stream = NdJsonStream::try_create(schema.clone(), Cursor::new(*data), 1, 100)?;
+        let result = stream.try_collect::<Vec<_>>().await;
+        assert_eq!(
+            "JSONEachRow Error: row 2, field a is not nullable, but got null or nothing",
+            format!("{}", result.err().unwrap())
+        );
+    }
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_ndjson_stream_temporal() -> anyhow::Result<()> {
+    use std::io::Cursor;
+    use std::sync::Arc;
+
+    use common_arrow::arrow::datatypes::TimeUnit;
+    use common_datavalues::*;
+    use futures::stream::TryStreamExt;
+    use pretty_assertions::assert_eq;
+
+    use crate::*;
+
+    let schema = Arc::new(DataSchema::new(vec![
+        DataField::new("d", DataType::Date32, true),
+        DataField::new("t", DataType::Timestamp(TimeUnit::Second, None), true),
+    ]));
+
+    // The strings are parsed like the literals, the numbers are in the unit of the type.
+    let data =
+        "{\"d\": \"2021-01-01\", \"t\": \"2021-01-01 12:30:00\"}\n{\"d\": 18629, \"t\": 0}\n";
+    let stream = NdJsonStream::try_create(schema.clone(), Cursor::new(data), 1, 100)?;
+    let blocks = stream.try_collect::<Vec<_>>().await?;
+
+    let mut actual = vec![];
+    for block in blocks {
+        for row in 0..block.num_rows() {
+            actual.push(format!(
+                "{:?}|{:?}",
+                DataValue::try_from_array(block.column(0), row)?,
+                DataValue::try_from_array(block.column(1), row)?,
+            ));
+        }
+    }
+    assert_eq!(
+        vec![
+            "2021-01-01|2021-01-01 12:30:00",
+            "2021-01-02|1970-01-01 00:00:00"
+        ],
+        actual
+    );
+
+    let data = "{\"d\": \"2021-02-30\"}\n";
+    let stream = NdJsonStream::try_create(schema, Cursor::new(data), 1, 100)?;
+    let result = stream.try_collect::<Vec<_>>().await;
+    assert_eq!(
+        "JSONEachRow Error: row 1, field d expects Date32, but got \"2021-02-30\"",
+        format!("{}", result.err().unwrap())
+    );
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_ndjson_stream_decimal() -> anyhow::Result<()> {
+    use std::io::Cursor;
+    use std::sync::Arc;
+
+    use common_datavalues::*;
+    use futures::stream::TryStreamExt;
+    use pretty_assertions::assert_eq;
+
+    use crate::*;
+
+    let schema = Arc::new(DataSchema::new(vec![DataField::new(
+        "price",
+        DataType::Decimal(38, 10),
+        true,
+    )]));
+
+    // The strings keep all the digits.
+    let data = "{\"price\": \"1234567890123456789012345678.0123456789\"}\n{\"price\": 0.1}\n{}\n";
+    let stream = NdJsonStream::try_create(schema.clone(), Cursor::new(data), 1, 100)?;
+    let blocks = stream.try_collect::<Vec<_>>().await?;
+    assert_eq!(
+        vec![
+            Some(12345678901234567890123456780123456789_i128),
+            Some(1000000000),
+            None
+        ],
+        decimal_values(blocks[0].column(0))?
+    );
+
+    let data = "{\"price\": \"abc\"}\n";
+    let stream = NdJsonStream::try_create(schema, Cursor::new(data), 1, 100)?;
+    let result = stream.try_collect::<Vec<_>>().await;
+    assert_eq!(
+        "JSONEachRow Error: row 1, field price: DataValue Error: Cannot parse 'abc' as Decimal",
+        format!("{}", result.err().unwrap())
+    );
+    Ok(())
+}
@@ -6,15 +6,20 @@ use std::convert::{TryFrom, TryInto};
 use std::sync::Arc;
 
 use anyhow::{bail, Result};
+use common_arrow::arrow;
+use common_arrow::arrow::record_batch::RecordBatch;
 use common_arrow::arrow_flight::flight_service_client::FlightServiceClient;
-use common_arrow::arrow_flight::utils::flight_data_to_arrow_batch;
-use common_arrow::arrow_flight::{Action, Ticket};
-use common_datavalues::DataSchema;
+use common_arrow::arrow_flight::utils::{
+    flight_data_from_arrow_batch, flight_data_from_arrow_schema, flight_data_to_arrow_batch,
+};
+use common_arrow::arrow_flight::{Action, FlightData, Ticket};
+use common_datavalues::{DataSchema, DataSchemaRef};
 use common_flights::query_do_action::{DoActionAction, FetchPartitionAction};
+use common_flights::query_do_exchange::{DoExchangeAction, ShuffleAction};
 use common_flights::query_do_get::{DoGetAction, ExecutePlanAction};
 use common_planners::{Partitions, PlanNode};
 use common_streams::SendableDataBlockStream;
-use tokio_stream::StreamExt;
+use tokio_stream::{Stream, StreamExt};
 use tonic::Request;
 
 pub struct FlightClient {
@@ -48,6 +53,18 @@ impl FlightClient {
         Ok(parts)
     }
 
+    /// Send the batches to the exchange of the remote executor, the exchange of the executor
+    /// ends with the stream.
+    pub async fn shuffle_exchange_action(
+        &mut self,
+        id: String,
+        schema: &DataSchemaRef,
+        batches: impl Stream<Item = RecordBatch> + Send + Sync + 'static,
+    ) -> Result<()> {
+        let action = DoExchangeAction::Shuffle(ShuffleAction { id });
+        self.do_exchange(&action, schema, batches).await
+    }
+
     // Execute do_get.
     async fn do_get(&mut self, action: &DoGetAction) -> Result<SendableDataBlockStream> {
         let request: Request<Ticket> = action.try_into()?;
@@ -82,4 +99,31 @@ impl FlightClient {
             Some(resp) => Ok(resp.body),
         }
     }
+
+    // Execute do_exchange, the first flight data is the schema with the action descriptor.
+    async fn do_exchange(
+        &mut self,
+        action: &DoExchangeAction,
+        schema: &DataSchemaRef,
+        batches: impl Stream<Item = RecordBatch> + Send + Sync + 'static,
+    ) -> Result<()> {
+        let options = arrow::ipc::writer::IpcWriteOptions::default();
+        let mut schema_flight_data = flight_data_from_arrow_schema(schema, &options);
+        schema_flight_data.flight_descriptor = Some(action.try_into()?);
+
+        let flights = futures::StreamExt::flat_map(batches, move |batch| {
+            let (flight_dicts, flight_batch) = flight_data_from_arrow_batch(&batch, &options);
+            futures::stream::iter(
+                flight_dicts
+                    .into_iter()
+                    .chain(std::iter::once(flight_batch)),
+            )
+        });
+        let flights = futures::stream::iter(vec![schema_flight_data]).chain(flights);
+
+        let request = Request::new(flights);
+        let mut stream = self.client.do_exchange(request).await?.into_inner();
+        while stream.message().await?.is_some() {}
+        Ok(())
+    }
 }
@@ -2,8 +2,9 @@
 //
 // SPDX-License-Identifier: Apache-2.0.
 
-use std::convert::TryInto;
+use std::convert::{TryFrom, TryInto};
 use std::pin::Pin;
+use std::sync::Arc;
 use std::time::Instant;
 
 use common_arrow::arrow;
@@ -13,7 +14,9 @@ use common_arrow::arrow_flight::{
     Action, ActionType, Criteria, Empty, FlightData, FlightDescriptor, FlightInfo,
     HandshakeRequest, HandshakeResponse, PutResult, SchemaResult, Ticket,
 };
+use common_datavalues::DataSchema;
 use common_flights::query_do_action::DoActionAction;
+use common_flights::query_do_exchange::DoExchangeAction;
 use common_flights::query_do_get::DoGetAction;
 use futures::{Stream, StreamExt};
 use log::info;
@@ -186,9 +189,59 @@ impl Flight for FlightService {
     type DoExchangeStream = FlightStream<FlightData>;
     async fn do_exchange(
         &self,
-        _request: Request<Streaming<FlightData>>,
+        request: Request<Streaming<FlightData>>,
     ) -> Result<Response<Self::DoExchangeStream>, Status> {
-        unimplemented!()
+        let mut stream = request.into_inner();
+
+        // The first flight data is the schema with the action descriptor.
+        let flight_data = stream
+            .message()
+            .await?
+            .ok_or_else(|| Status::invalid_argument("Exchange stream is empty"))?;
+        let action: DoExchangeAction = (&flight_data).try_into()?;
+        match action {
+            DoExchangeAction::Shuffle(action) => {
+                let schema = Arc::new(
+                    DataSchema::try_from(&flight_data)
+                        .map_err(|e| Status::internal(e.to_string()))?,
+                );
+                let sender = self
+                    .cluster
+                    .get_exchange_sender(&action.id)
+                    .map_err(|e| Status::internal(e.to_string()))?;
+
+                let received: anyhow::Result<()> = async {
+                    while let Some(flight_data) = stream.message().await? {
+                        let batch = arrow_flight::utils::flight_data_to_arrow_batch(
+                            &flight_data,
+                            schema.clone(),
+                            &[],
+                        )?;
+                        sender.send(Ok(Some(batch.try_into()?))).await?;
+                    }
+                    // All the blocks of the executor are received.
+                    sender.send(Ok(None)).await?;
+                    Ok(())
+                }
+                .await;
+
+                // The executor stream or the receiver failed, fail the exchange and remove it.
+                // It's removed first, the untaken receiver is dropped and never blocks the send.
+                if let Err(e) = received {
+                    self.cluster.remove_exchange(&action.id).ok();
+                    sender
+                        .send(Err(anyhow::anyhow!("Exchange {} failed: {}", action.id, e)))
+                        .await
+                        .ok();
+                    return Err(Status::internal(e.to_string()));
+                }
+
+                let flights: Vec<Result<FlightData, Status>> = vec![];
+                Ok(Response::new(
+                    Box::pin(futures::stream::iter(flights)) as Self::DoExchangeStream
+                ))
+            }
+        }
     }
 
     type DoActionStream = FlightStream<arrow_flight::Result>;
@@ -22,7 +22,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     info!("FuseQuery v-{}", conf.version);
 
     let cluster = Cluster::create(conf.clone());
-    let session_manager = Session::create();
+    let session_manager = Session::create(conf.clone());
 
     // MySQL handler.
     {
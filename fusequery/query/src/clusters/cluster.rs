@@ -4,18 +4,59 @@
 
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
-use anyhow::Result;
+use anyhow::{bail, Result};
+use common_datablocks::DataBlock;
 use common_infallible::Mutex;
+use tokio::sync::mpsc::{channel, Receiver, Sender};
 
 use crate::clusters::node::Node;
 use crate::configs::Config;
 
 pub type ClusterRef = Arc<Cluster>;
 
+/// The blocks shuffled from the other executors, None marks the end of one executor,
+/// and the error fails the exchange.
+pub type ExchangeSender = Sender<Result<Option<DataBlock>>>;
+
+/// The exchange whose receiver is not taken in this time is dropped, it's left by the sender
+/// of the query failed on this executor.
+const EXCHANGE_UNTAKEN_TIMEOUT: Duration = Duration::from_secs(600);
+
+/// The blocks buffered by an exchange, the senders wait when it's full, so a fast executor
+/// doesn't buffer its whole shuffled input in the memory of a slow one.
+const EXCHANGE_CHANNEL_CAPACITY: usize = 8;
+
+struct Exchange {
+    created: Instant,
+    sender: ExchangeSender,
+    receiver: Option<Receiver<Result<Option<DataBlock>>>>,
+}
+
+/// The receiver of an exchange, the exchange is removed from the cluster when it's dropped.
+pub struct ExchangeReceiver {
+    id: String,
+    cluster: ClusterRef,
+    receiver: Receiver<Result<Option<DataBlock>>>,
+}
+
+impl ExchangeReceiver {
+    pub async fn recv(&mut self) -> Option<Result<Option<DataBlock>>> {
+        self.receiver.recv().await
+    }
+}
+
+impl Drop for ExchangeReceiver {
+    fn drop(&mut self) {
+        self.cluster.remove_exchange(&self.id).ok();
+    }
+}
+
 pub struct Cluster {
     cfg: Config,
     nodes: Mutex<HashMap<String, Node>>,
+    exchanges: Mutex<HashMap<String, Exchange>>,
 }
 
 impl Cluster {
@@ -23,6 +64,7 @@ impl Cluster {
         Arc::new(Cluster {
             cfg,
             nodes: Mutex::new(HashMap::new()),
+            exchanges: Mutex::new(HashMap::new()),
         })
     }
 
@@ -30,6 +72,7 @@ impl Cluster {
         Arc::new(Cluster {
             cfg: Config::default(),
             nodes: Mutex::new(HashMap::new()),
+            exchanges: Mutex::new(HashMap::new()),
         })
     }
 
@@ -70,4 +113,46 @@ impl Cluster {
         nodes.sort_by(|a, b| b.name.cmp(&a.name));
         Ok(nodes)
     }
+
+    /// Get the sender of the exchange, the exchange is created by the first sender or receiver.
+    pub fn get_exchange_sender(&self, id: &str) -> Result<ExchangeSender> {
+        let mut exchanges = self.exchanges.lock();
+        exchanges.retain(|_, exchange| {
+            exchange.receiver.is_none() || exchange.created.elapsed() < EXCHANGE_UNTAKEN_TIMEOUT
+        });
+        let exchange = exchanges
+            .entry(id.to_string())
+            .or_insert_with(Self::create_exchange);
+        Ok(exchange.sender.clone())
+    }
+
+    /// Take the receiver of the exchange, an exchange has only one receiver.
+    pub fn take_exchange_receiver(self: &Arc<Self>, id: &str) -> Result<ExchangeReceiver> {
+        let mut exchanges = self.exchanges.lock();
+        let exchange = exchanges
+            .entry(id.to_string())
+            .or_insert_with(Self::create_exchange);
+        match exchange.receiver.take() {
+            Some(receiver) => Ok(ExchangeReceiver {
+                id: id.to_string(),
+                cluster: self.clone(),
+                receiver,
+            }),
+            None => bail!("Exchange receiver {} is already taken", id),
+        }
+    }
+
+    pub fn remove_exchange(&self, id: &str) -> Result<()> {
+        self.exchanges.lock().remove(id);
+        Ok(())
+    }
+
+    fn create_exchange() -> Exchange {
+        let (sender, receiver) = channel(EXCHANGE_CHANNEL_CAPACITY);
+        Exchange {
+            created: Instant::now(),
+            sender,
+            receiver: Some(receiver),
+        }
+    }
 }
@@ -38,3 +38,37 @@ fn test_cluster() -> anyhow::Result<()> {
     assert_eq!(0, nodes.len());
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_cluster_exchange() -> anyhow::Result<()> {
+    use pretty_assertions::assert_eq;
+
+    use crate::clusters::cluster::Cluster;
+
+    let cluster = Cluster::empty();
+
+    // The sender comes first, the receiver gets the blocks sent before it.
+    let sender = cluster.get_exchange_sender("shuffle-0")?;
+    sender.send(Ok(None)).await?;
+    let mut receiver = cluster.take_exchange_receiver("shuffle-0")?;
+    assert!(receiver.recv().await.unwrap()?.is_none());
+
+    // Only one receiver.
+    let actual = cluster.take_exchange_receiver("shuffle-0");
+    let expect = "Exchange receiver shuffle-0 is already taken";
+    assert_eq!(expect, format!("{}", actual.err().unwrap()));
+
+    // The exchange is removed with its receiver.
+    drop(receiver);
+    let mut receiver = cluster.take_exchange_receiver("shuffle-0")?;
+
+    // The failure of the exchange is received.
+    let sender = cluster.get_exchange_sender("shuffle-0")?;
+    sender
+        .send(Err(anyhow::anyhow!("Exchange shuffle-0 failed")))
+        .await?;
+    let actual = receiver.recv().await.unwrap();
+    let expect = "Exchange shuffle-0 failed";
+    assert_eq!(expect, format!("{}", actual.err().unwrap()));
+    Ok(())
+}
@@ -8,5 +8,5 @@ mod cluster_test;
 mod cluster;
 mod node;
 
-pub use cluster::{Cluster, ClusterRef};
+pub use cluster::{Cluster, ClusterRef, ExchangeReceiver, ExchangeSender};
 pub use node::Node;
@@ -5,7 +5,7 @@
 use std::sync::Arc;
 
 use anyhow::Result;
-use common_planners::CreateTablePlan;
+use common_planners::{CreateTablePlan, DropTablePlan};
 
 use crate::datasources::{ITable, ITableFunction};
 
@@ -27,4 +27,5 @@ pub trait IDatabase: Sync + Send {
 
     // DDL
     fn create_table(&self, plan: CreateTablePlan) -> Result<()>;
+    fn drop_table(&self, plan: DropTablePlan) -> Result<()>;
 }
@@ -5,14 +5,15 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
-use anyhow::{anyhow, Result};
-use common_planners::{CreateDatabasePlan, DatabaseEngineType};
+use anyhow::{anyhow, bail, Result};
+use common_planners::{CreateDatabasePlan, DatabaseEngineType, DropDatabasePlan};
 
 use crate::configs::Config;
 use crate::datasources::local::{LocalDatabase, LocalFactory};
-use crate::datasources::remote::RemoteFactory;
+use crate::datasources::remote::{RemoteDatabase, RemoteFactory};
 use crate::datasources::system::SystemFactory;
 use crate::datasources::{IDatabase, ITable, ITableFunction};
+use crate::rpcs::store::{block_on, StoreClient};
 
 pub trait IDataSource: Sync + Send {
     fn get_database(&self, db_name: &str) -> Result<Arc<dyn IDatabase>>;
@@ -20,6 +21,7 @@ pub trait IDataSource: Sync + Send {
     fn get_all_tables(&self) -> Result<Vec<(String, Arc<dyn ITable>)>>;
     fn get_table_function(&self, name: &str) -> Result<Arc<dyn ITableFunction>>;
     fn create_database(&mut self, plan: CreateDatabasePlan) -> Result<()>;
+    fn drop_database(&mut self, plan: DropDatabasePlan) -> Result<()>;
 }
 
 // Maintain all the databases of user.
@@ -40,13 +42,14 @@ impl DataSource {
         datasource.register_system_database()?;
         datasource.register_local_database()?;
         datasource.register_default_database()?;
-        datasource.register_remote_database()?;
         Ok(datasource)
     }
 
+    // The databases of the store in the config are registered too.
     pub fn try_create_with_config(conf: Config) -> Result<Self> {
         let mut ds = Self::try_create()?;
         ds.conf = conf;
+        ds.register_remote_database()?;
         Ok(ds)
     }
 
@@ -131,13 +134,57 @@ impl IDataSource for DataSource {
     }
 
     fn create_database(&mut self, plan: CreateDatabasePlan) -> Result<()> {
+        if self.databases.contains_key(&plan.db) {
+            if plan.if_not_exists {
+                return Ok(());
+            }
+            bail!("DataSource Error: Database '{}' already exists", plan.db);
+        }
+
         match plan.engine {
             DatabaseEngineType::Local => {
                 let database = LocalDatabase::create();
                 self.databases.insert(plan.db, Arc::new(database));
             }
-            DatabaseEngineType::Remote => {}
+            DatabaseEngineType::Remote => {
+                let database = RemoteDatabase::create(self.conf.clone(), plan.db.as_str());
+                let addr = self.conf.storage_api_address.clone();
+                block_on(async move {
+                    let mut client = StoreClient::try_create(addr).await?;
+                    client.create_database(plan).await
+                })?;
+                self.databases
+                    .insert(database.name().to_string(), Arc::new(database));
+            }
         }
         Ok(())
     }
+
+    fn drop_database(&mut self, plan: DropDatabasePlan) -> Result<()> {
+        if plan.db.to_lowercase() == "system" {
+            bail!("DataSource Error: cannot drop system database");
+        }
+
+        if let Some(database) = self.databases.get(&plan.db) {
+            if database.engine() == "remote" {
+                let addr = self.conf.storage_api_address.clone();
+                let plan = plan.clone();
+                block_on(async move {
+                    let mut client = StoreClient::try_create(addr).await?;
+                    client.drop_database(plan).await
+                })?;
+            }
+        }
+
+        match self.databases.remove(&plan.db) {
+            Some(database) => {
+                for tbl_func in database.get_table_functions()? {
+                    self.table_functions.remove(tbl_func.name());
+                }
+                Ok(())
+            }
+            None if plan.if_exists => Ok(()),
+            None => bail!("DataSource Error: Unknown database: '{}'", plan.db),
+        }
+    }
 }
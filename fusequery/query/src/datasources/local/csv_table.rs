@@ -3,13 +3,18 @@
 // SPDX-License-Identifier: Apache-2.0.
 
 use std::any::Any;
-use std::fs::File;
+use std::convert::TryInto;
+use std::fs::{File, OpenOptions};
+use std::path::Path;
 
 use anyhow::{bail, Result};
 use async_trait::async_trait;
+use common_arrow::arrow::csv;
+use common_arrow::arrow::record_batch::RecordBatch;
 use common_datavalues::DataSchemaRef;
-use common_planners::{Partition, PlanNode, ReadDataSourcePlan, Statistics, TableOptions};
+use common_planners::{Extras, Partition, PlanNode, ReadDataSourcePlan, Statistics, TableOptions};
 use common_streams::{CsvStream, SendableDataBlockStream};
+use futures::stream::StreamExt;
 
 use crate::datasources::ITable;
 use crate::sessions::FuseQueryContextRef;
@@ -42,6 +47,17 @@ impl CsvTable {
             _ => bail!("CSV Engine must contains file location options"),
         };
     }
+
+    async fn write_tmp_file(tmp_file: &Path, mut stream: SendableDataBlockStream) -> Result<()> {
+        let mut writer = csv::WriterBuilder::new()
+            .has_headers(false)
+            .build(File::create(tmp_file)?);
+        while let Some(block) = stream.next().await {
+            let batch: RecordBatch = block?.try_into()?;
+            writer.write(&batch)?;
+        }
+        Ok(())
+    }
 }
 
 #[async_trait]
@@ -77,14 +93,39 @@ impl ITable for CsvTable {
             }],
             statistics: Statistics::default(),
             description: format!("(Read from CSV Engine table  {}.{})", self.db, self.name),
+            push_downs: Extras::default(),
         })
     }
 
-    async fn read(&self, _ctx: FuseQueryContextRef) -> Result<SendableDataBlockStream> {
+    async fn read(
+        &self,
+        _ctx: FuseQueryContextRef,
+        _source_plan: &ReadDataSourcePlan,
+    ) -> Result<SendableDataBlockStream> {
         let reader = File::open(self.file.clone())?;
         Ok(Box::pin(CsvStream::try_create(
             self.schema.clone(),
             reader,
         )?))
     }
+
+    async fn append_data(
+        &self,
+        _ctx: FuseQueryContextRef,
+        stream: SendableDataBlockStream,
+    ) -> Result<()> {
+        // The rows are written to a temporary file first, they are appended to the table file
+        // only if the whole stream is written, the failed insert leaves the table unchanged.
+        let tmp_file = std::env::temp_dir().join(format!("{}.csv", uuid::Uuid::new_v4()));
+        let written = Self::write_tmp_file(&tmp_file, stream).await.and_then(|_| {
+            let mut file = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(self.file.clone())?;
+            std::io::copy(&mut File::open(&tmp_file)?, &mut file)?;
+            Ok(())
+        });
+        std::fs::remove_file(&tmp_file).ok();
+        written
+    }
 }
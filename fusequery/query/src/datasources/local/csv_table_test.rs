@@ -30,9 +30,9 @@ async fn test_csv_table() -> anyhow::Result<()> {
         DataSchema::new(vec![DataField::new("a", DataType::UInt64, false)]).into(),
         options,
     )?;
-    table.read_plan(ctx.clone(), PlanBuilder::empty().build()?)?;
+    let source_plan = table.read_plan(ctx.clone(), PlanBuilder::empty().build()?)?;
 
-    let stream = table.read(ctx).await?;
+    let stream = table.read(ctx, &source_plan).await?;
     let blocks = stream.try_collect::<Vec<_>>().await?;
     let rows: usize = blocks.iter().map(|block| block.num_rows()).sum();
 
@@ -0,0 +1,220 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::any::Any;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read, Seek, SeekFrom};
+
+use anyhow::{anyhow, bail, Result};
+use async_trait::async_trait;
+use common_datablocks::DataBlock;
+use common_datavalues::DataSchemaRef;
+use common_planners::{
+    Extras, Partition, Partitions, PlanNode, ReadDataSourcePlan, Statistics, TableOptions,
+};
+use common_streams::{NdJsonStream, SendableDataBlockStream};
+use futures::stream::{Stream, TryStreamExt};
+
+use crate::datasources::ITable;
+use crate::sessions::FuseQueryContextRef;
+
+/// The bytes at the start of the file read to estimate the rows.
+const ROWS_SAMPLE_BYTES: u64 = 64 * 1024;
+
+pub struct JsonTable {
+    db: String,
+    name: String,
+    schema: DataSchemaRef,
+    file: String,
+}
+
+impl JsonTable {
+    pub fn try_create(
+        db: String,
+        name: String,
+        schema: DataSchemaRef,
+        options: TableOptions,
+    ) -> Result<Box<dyn ITable>> {
+        let file = options.get("location");
+        return match file {
+            Some(file) => {
+                let table = JsonTable {
+                    db,
+                    name,
+                    schema,
+                    file: file.trim_matches(|s| s == '\'' || s == '"').to_string(),
+                };
+                Ok(Box::new(table))
+            }
+            _ => bail!("JSONEachRaw Engine must contains file location options"),
+        };
+    }
+
+    /// Split the file into byte ranges for the workers, the partition name is
+    /// `{begin offset}-{end offset}`. The lines are not scanned here, a line belongs to
+    /// the range its first byte falls into.
+    fn generate_parts(&self, workers: usize) -> Result<(Partitions, Statistics)> {
+        let len = File::open(self.file.clone())?.metadata()?.len();
+        let workers = workers.max(1) as u64;
+        let bytes_per_part = ((len + workers - 1) / workers).max(1);
+
+        let mut partitions = Vec::with_capacity(workers as usize);
+        let mut begin = 0;
+        while begin < len {
+            let end = (begin + bytes_per_part).min(len);
+            partitions.push(Partition {
+                name: format!("{}-{}", begin, end),
+                version: 0,
+            });
+            begin = end;
+        }
+
+        let statistics = Statistics {
+            read_rows: self.estimate_rows(len)?,
+            read_bytes: len as usize,
+        };
+        Ok((partitions, statistics))
+    }
+
+    /// The rows are estimated by the lines in the first bytes of the file, they are counted
+    /// if the whole file is sampled.
+    fn estimate_rows(&self, len: u64) -> Result<usize> {
+        let mut sample = vec![];
+        File::open(self.file.clone())?
+            .take(ROWS_SAMPLE_BYTES)
+            .read_to_end(&mut sample)?;
+        // The last line of the sample is cut unless it's the end of the file.
+        let sampled = match sample.len() as u64 == len {
+            true => sample.len(),
+            false => match sample.iter().rposition(|b| *b == b'\n') {
+                Some(i) => i + 1,
+                // The lines are longer than the sample.
+                None => return Ok((len / ROWS_SAMPLE_BYTES) as usize),
+            },
+        };
+        if sampled == 0 {
+            return Ok(0);
+        }
+        let lines = sample[..sampled]
+            .split(|b| *b == b'\n')
+            .filter(|line| !line.iter().all(u8::is_ascii_whitespace))
+            .count();
+        Ok((len as u128 * lines as u128 / sampled as u128) as usize)
+    }
+
+    /// The offset of the first line starts at or after the offset.
+    fn next_line_start(file: &mut File, offset: u64) -> Result<u64> {
+        if offset == 0 {
+            return Ok(0);
+        }
+        // The line starts at the offset if the previous byte ends a line.
+        file.seek(SeekFrom::Start(offset - 1))?;
+        let mut skipped = vec![];
+        let n = BufReader::new(file).read_until(b'\n', &mut skipped)?;
+        Ok(offset - 1 + n as u64)
+    }
+
+    fn read_partition(
+        schema: DataSchemaRef,
+        file: &str,
+        block_size: usize,
+        partition: &Partition,
+    ) -> Result<impl Stream<Item = Result<DataBlock>>> {
+        let names: Vec<_> = partition.name.split('-').collect();
+        if names.len() != 2 {
+            bail!("Invalid JSONEachRaw partition: {}", partition.name);
+        }
+        let begin: u64 = names[0].parse()?;
+        let end: u64 = names[1].parse()?;
+
+        // Move the both ends forward to the line starts, so the line crosses the end is read
+        // by this partition and skipped by the next one.
+        let mut reader = File::open(file)?;
+        let begin = Self::next_line_start(&mut reader, begin)?;
+        let end = Self::next_line_start(&mut reader, end)?.max(begin);
+        reader.seek(SeekFrom::Start(begin))?;
+
+        // The lines before the range are not counted, the errors have the byte offsets.
+        let stream = NdJsonStream::try_create_at_offset(
+            schema,
+            BufReader::new(reader.take(end - begin)),
+            begin,
+            block_size,
+        )?;
+        let name = partition.name.clone();
+        Ok(stream.map_err(move |e| anyhow!("{} (in JSONEachRaw partition {})", e, name)))
+    }
+}
+
+#[async_trait]
+impl ITable for JsonTable {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn engine(&self) -> &str {
+        "JSON"
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> Result<DataSchemaRef> {
+        Ok(self.schema.clone())
+    }
+
+    fn read_plan(
+        &self,
+        ctx: FuseQueryContextRef,
+        _push_down_plan: PlanNode,
+    ) -> Result<ReadDataSourcePlan> {
+        let (partitions, statistics) = self.generate_parts(ctx.get_max_threads()? as usize)?;
+        ctx.try_set_statistics(&statistics)?;
+
+        Ok(ReadDataSourcePlan {
+            db: self.db.clone(),
+            table: self.name().to_string(),
+            schema: self.schema.clone(),
+            partitions,
+            statistics: statistics.clone(),
+            description: format!(
+                "(Read from JSONEachRaw Engine table  {}.{}, Read Rows:{}, Read Bytes:{})",
+                self.db, self.name, statistics.read_rows, statistics.read_bytes
+            ),
+            push_downs: Extras::default(),
+        })
+    }
+
+    async fn read(
+        &self,
+        ctx: FuseQueryContextRef,
+        _source_plan: &ReadDataSourcePlan,
+    ) -> Result<SendableDataBlockStream> {
+        let schema = self.schema.clone();
+        let file = self.file.clone();
+        let block_size = ctx.get_max_block_size()? as usize;
+
+        // Every stream reads the chunks of the partitions fetched from the context.
+        let stream = futures::stream::try_unfold(ctx, |ctx| async move {
+            let partitions = ctx.try_get_partitions(1)?;
+            Ok::<_, anyhow::Error>(
+                partitions
+                    .into_iter()
+                    .next()
+                    .map(|partition| (partition, ctx)),
+            )
+        })
+        .and_then(move |partition| {
+            futures::future::ready(Self::read_partition(
+                schema.clone(),
+                &file,
+                block_size,
+                &partition,
+            ))
+        })
+        .try_flatten();
+        Ok(Box::pin(stream))
+    }
+}
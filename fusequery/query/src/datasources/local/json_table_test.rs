@@ -0,0 +1,104 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_json_table() -> anyhow::Result<()> {
+    use std::env;
+
+    use common_datavalues::*;
+    use common_planners::*;
+    use futures::TryStreamExt;
+    use pretty_assertions::assert_eq;
+
+    use crate::datasources::local::*;
+
+    let options: TableOptions = [(
+        "location".to_string(),
+        env::current_dir()?
+            .join("../../tests/data/sample.ndjson")
+            .display()
+            .to_string(),
+    )]
+    .iter()
+    .cloned()
+    .collect();
+
+    let location = options["location"].clone();
+    let schema = DataSchema::new(vec![
+        DataField::new("a", DataType::UInt64, true),
+        DataField::new("b", DataType::Utf8, true),
+    ]);
+    let table =
+        JsonTable::try_create("default".into(), "test_json".into(), schema.into(), options)?;
+    assert_eq!(table.engine(), "JSON");
+
+    // The ranges of 8 workers end in the middle of the lines, every line is read once.
+    for (threads, parts) in vec![(2, 2), (8, 8)] {
+        let ctx = crate::tests::try_create_context()?;
+        ctx.set_max_threads(threads)?;
+
+        let source_plan = table.read_plan(ctx.clone(), PlanBuilder::empty().build()?)?;
+        assert_eq!(source_plan.partitions.len(), parts);
+        assert_eq!(source_plan.statistics.read_rows, 5);
+        assert_eq!(
+            source_plan.statistics.read_bytes as u64,
+            std::fs::metadata(&location)?.len()
+        );
+        ctx.try_set_partitions(source_plan.partitions.clone())?;
+
+        let stream = table.read(ctx, &source_plan).await?;
+        let blocks = stream.try_collect::<Vec<_>>().await?;
+        let mut actual = vec![];
+        for block in blocks {
+            for row in 0..block.num_rows() {
+                actual.push(format!(
+                    "{:?}|{:?}",
+                    DataValue::try_from_array(block.column(0), row)?,
+                    DataValue::try_from_array(block.column(1), row)?,
+                ));
+            }
+        }
+        assert_eq!(vec!["1|x", "2|NULL", "3|z", "4|NULL", "5|y"], actual);
+    }
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_json_table_partition_error() -> anyhow::Result<()> {
+    use common_datavalues::*;
+    use common_planners::*;
+    use futures::TryStreamExt;
+    use pretty_assertions::assert_eq;
+
+    use crate::datasources::local::*;
+
+    // The bad line is in the second partition, it starts at the byte 90.
+    let file = std::env::temp_dir().join(format!("{}.ndjson", uuid::Uuid::new_v4()));
+    let mut data = "{\"a\": 1}\n".repeat(10);
+    data.push_str("{\"a\": \"x\"}\n");
+    std::fs::write(&file, data)?;
+
+    let options: TableOptions = [("location".to_string(), file.display().to_string())]
+        .iter()
+        .cloned()
+        .collect();
+    let schema = DataSchema::new(vec![DataField::new("a", DataType::UInt64, false)]);
+    let table =
+        JsonTable::try_create("default".into(), "test_json".into(), schema.into(), options)?;
+
+    let ctx = crate::tests::try_create_context()?;
+    ctx.set_max_threads(2)?;
+    let source_plan = table.read_plan(ctx.clone(), PlanBuilder::empty().build()?)?;
+    assert_eq!(source_plan.statistics.read_rows, 11);
+    ctx.try_set_partitions(source_plan.partitions.clone())?;
+
+    let stream = table.read(ctx, &source_plan).await?;
+    let result = stream.try_collect::<Vec<_>>().await;
+    std::fs::remove_file(&file)?;
+    assert_eq!(
+        "JSONEachRow Error: line at byte 90, field a expects UInt64, but got \"x\" (in JSONEachRaw partition 51-101)",
+        format!("{}", result.err().unwrap())
+    );
+    Ok(())
+}
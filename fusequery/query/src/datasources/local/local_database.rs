@@ -7,9 +7,9 @@ use std::sync::Arc;
 
 use anyhow::{anyhow, bail, Result};
 use common_infallible::RwLock;
-use common_planners::{CreateTablePlan, TableEngineType};
+use common_planners::{CreateTablePlan, DropTablePlan, TableEngineType};
 
-use crate::datasources::local::{CsvTable, NullTable, ParquetTable};
+use crate::datasources::local::{CsvTable, JsonTable, MemoryTable, NullTable, ParquetTable};
 use crate::datasources::{IDatabase, ITable, ITableFunction};
 
 pub struct LocalDatabase {
@@ -62,10 +62,26 @@ impl IDatabase for LocalDatabase {
             TableEngineType::Null => {
                 NullTable::try_create(plan.db, plan.table, plan.schema, plan.options)?
             }
-            _ => bail!("Unsupported engine: {:?}", plan.engine),
+            TableEngineType::Memory => {
+                MemoryTable::try_create(plan.db, plan.table, plan.schema, plan.options)?
+            }
+            TableEngineType::JsonEachRaw => {
+                JsonTable::try_create(plan.db, plan.table, plan.schema, plan.options)?
+            }
+            TableEngineType::Remote => {
+                bail!("Remote table can only be created in a remote database")
+            }
         };
 
         self.tables.write().insert(table_name, Arc::from(table));
         Ok(())
     }
+
+    fn drop_table(&self, plan: DropTablePlan) -> Result<()> {
+        let table = self.tables.write().remove(&plan.table);
+        if table.is_none() && !plan.if_exists {
+            bail!("DataSource Error: Unknown table: '{}'", plan.table);
+        }
+        Ok(())
+    }
 }
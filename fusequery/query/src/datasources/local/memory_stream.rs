@@ -0,0 +1,47 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use anyhow::{anyhow, Result};
+use common_datablocks::DataBlock;
+use common_infallible::RwLock;
+use futures::stream::Stream;
+
+use crate::sessions::FuseQueryContextRef;
+
+/// Read the memory table blocks of the partitions fetched from the context.
+pub struct MemoryStream {
+    ctx: FuseQueryContextRef,
+    blocks: Arc<RwLock<Vec<DataBlock>>>,
+}
+
+impl MemoryStream {
+    pub fn create(ctx: FuseQueryContextRef, blocks: Arc<RwLock<Vec<DataBlock>>>) -> Self {
+        MemoryStream { ctx, blocks }
+    }
+
+    fn try_get_one_block(&self) -> Result<Option<DataBlock>> {
+        let partitions = self.ctx.try_get_partitions(1)?;
+        if partitions.is_empty() {
+            return Ok(None);
+        }
+
+        let index: usize = partitions[0].name.parse()?;
+        let blocks = self.blocks.read();
+        let block = blocks
+            .get(index)
+            .ok_or_else(|| anyhow!("Memory table partition {} is not found", index))?;
+        Ok(Some(block.clone()))
+    }
+}
+
+impl Stream for MemoryStream {
+    type Item = Result<DataBlock>;
+
+    fn poll_next(self: std::pin::Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Poll::Ready(self.try_get_one_block().transpose())
+    }
+}
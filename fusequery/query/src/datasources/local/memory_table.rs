@@ -0,0 +1,148 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use common_datablocks::DataBlock;
+use common_datavalues::DataSchemaRef;
+use common_infallible::RwLock;
+use common_planners::{Extras, Partition, PlanNode, ReadDataSourcePlan, Statistics, TableOptions};
+use common_streams::SendableDataBlockStream;
+use futures::stream::StreamExt;
+
+use crate::datasources::local::MemoryStream;
+use crate::datasources::ITable;
+use crate::sessions::FuseQueryContextRef;
+
+pub struct MemoryTable {
+    db: String,
+    name: String,
+    schema: DataSchemaRef,
+    blocks: Arc<RwLock<Vec<DataBlock>>>,
+    /// The memory size of the appended blocks before they are sliced, the slices share the
+    /// buffers of their block. It's updated under the write lock of the blocks.
+    bytes: RwLock<usize>,
+}
+
+impl MemoryTable {
+    pub fn try_create(
+        db: String,
+        name: String,
+        schema: DataSchemaRef,
+        _options: TableOptions,
+    ) -> Result<Box<dyn ITable>> {
+        let table = Self {
+            db,
+            name,
+            schema,
+            blocks: Arc::new(RwLock::new(vec![])),
+            bytes: RwLock::new(0),
+        };
+        Ok(Box::new(table))
+    }
+}
+
+#[async_trait]
+impl ITable for MemoryTable {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn engine(&self) -> &str {
+        "Memory"
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> Result<DataSchemaRef> {
+        Ok(self.schema.clone())
+    }
+
+    fn read_plan(
+        &self,
+        ctx: FuseQueryContextRef,
+        _push_down_plan: PlanNode,
+    ) -> Result<ReadDataSourcePlan> {
+        let blocks = self.blocks.read();
+
+        // Every block is one partition, the partition name is the block index.
+        let mut partitions = Vec::with_capacity(blocks.len());
+        let mut statistics = Statistics {
+            read_rows: 0,
+            read_bytes: *self.bytes.read(),
+        };
+        for (i, block) in blocks.iter().enumerate() {
+            partitions.push(Partition {
+                name: format!("{}", i),
+                version: 0,
+            });
+            statistics.read_rows += block.num_rows();
+        }
+        ctx.try_set_statistics(&statistics)?;
+
+        Ok(ReadDataSourcePlan {
+            db: self.db.clone(),
+            table: self.name().to_string(),
+            schema: self.schema.clone(),
+            partitions,
+            statistics: statistics.clone(),
+            description: format!(
+                "(Read from Memory Engine table {}.{}, Read Rows:{}, Read Bytes:{})",
+                self.db, self.name, statistics.read_rows, statistics.read_bytes
+            ),
+            push_downs: Extras::default(),
+        })
+    }
+
+    async fn read(
+        &self,
+        ctx: FuseQueryContextRef,
+        _source_plan: &ReadDataSourcePlan,
+    ) -> Result<SendableDataBlockStream> {
+        Ok(Box::pin(MemoryStream::create(ctx, self.blocks.clone())))
+    }
+
+    async fn append_data(
+        &self,
+        ctx: FuseQueryContextRef,
+        mut stream: SendableDataBlockStream,
+    ) -> Result<()> {
+        // The big blocks are sliced by the max block size for the parallel reading.
+        let block_size = ctx.get_max_block_size()? as usize;
+        let mut blocks = vec![];
+        let mut bytes = 0;
+        while let Some(block) = stream.next().await {
+            let block = block?;
+            for i in 0..block.num_columns() {
+                bytes += block.column(i).get_array_memory_size();
+            }
+            let rows = block.num_rows();
+            let mut offset = 0;
+            while offset < rows {
+                let len = block_size.min(rows - offset);
+                let columns = (0..block.num_columns())
+                    .map(|i| block.column(i).slice(offset, len))
+                    .collect();
+                blocks.push(DataBlock::create(self.schema.clone(), columns));
+                offset += len;
+            }
+        }
+        let mut table_blocks = self.blocks.write();
+        table_blocks.extend(blocks);
+        *self.bytes.write() += bytes;
+        Ok(())
+    }
+
+    async fn truncate(&self, _ctx: FuseQueryContextRef) -> Result<()> {
+        let mut blocks = self.blocks.write();
+        blocks.clear();
+        *self.bytes.write() = 0;
+        Ok(())
+    }
+}
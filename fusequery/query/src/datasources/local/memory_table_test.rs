@@ -0,0 +1,116 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_memory_table() -> anyhow::Result<()> {
+    use std::sync::Arc;
+
+    use common_datablocks::*;
+    use common_datavalues::*;
+    use common_planners::*;
+    use common_streams::*;
+    use futures::TryStreamExt;
+    use pretty_assertions::assert_eq;
+
+    use crate::datasources::local::*;
+
+    let ctx = crate::tests::try_create_context()?;
+    ctx.set_max_block_size(2)?;
+    let schema = Arc::new(DataSchema::new(vec![DataField::new(
+        "a",
+        DataType::UInt64,
+        false,
+    )]));
+    let table = MemoryTable::try_create(
+        "default".into(),
+        "a".into(),
+        schema.clone(),
+        TableOptions::default(),
+    )?;
+    assert_eq!(table.engine(), "Memory");
+
+    // The block of 5 rows is sliced into 3 blocks.
+    let block = DataBlock::create(
+        schema.clone(),
+        vec![Arc::new(UInt64Array::from(vec![1, 2, 3, 4, 5]))],
+    );
+    let stream = DataBlockStream::create(schema.clone(), None, vec![block]);
+    table.append_data(ctx.clone(), Box::pin(stream)).await?;
+
+    let source_plan = table.read_plan(ctx.clone(), PlanBuilder::empty().build()?)?;
+    assert_eq!(source_plan.partitions.len(), 3);
+    assert_eq!(source_plan.statistics.read_rows, 5);
+    ctx.try_set_partitions(source_plan.partitions.clone())?;
+
+    let stream = table.read(ctx, &source_plan).await?;
+    let blocks = stream.try_collect::<Vec<_>>().await?;
+    let mut actual = vec![];
+    for block in blocks {
+        for row in 0..block.num_rows() {
+            actual.push(format!(
+                "{:?}",
+                DataValue::try_from_array(block.column(0), row)?
+            ));
+        }
+    }
+    actual.sort();
+    assert_eq!(vec!["1", "2", "3", "4", "5"], actual);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_memory_table_statistics() -> anyhow::Result<()> {
+    use std::sync::Arc;
+
+    use common_datablocks::*;
+    use common_datavalues::*;
+    use common_planners::*;
+    use common_streams::*;
+    use pretty_assertions::assert_eq;
+
+    use crate::datasources::local::*;
+
+    let ctx = crate::tests::try_create_context()?;
+    ctx.set_max_block_size(2)?;
+    let schema = Arc::new(DataSchema::new(vec![DataField::new(
+        "a",
+        DataType::UInt64,
+        false,
+    )]));
+    let table = MemoryTable::try_create(
+        "default".into(),
+        "a".into(),
+        schema.clone(),
+        TableOptions::default(),
+    )?;
+
+    // The slices share the buffers of their blocks, the bytes are counted once per block.
+    let blocks = vec![
+        DataBlock::create(
+            schema.clone(),
+            vec![Arc::new(UInt64Array::from(vec![1, 2, 3, 4, 5]))],
+        ),
+        DataBlock::create(
+            schema.clone(),
+            vec![Arc::new(UInt64Array::from(vec![6, 7, 8]))],
+        ),
+    ];
+    let expect_bytes = blocks
+        .iter()
+        .map(|block| block.column(0).get_array_memory_size())
+        .sum::<usize>();
+    let stream = DataBlockStream::create(schema.clone(), None, blocks);
+    table.append_data(ctx.clone(), Box::pin(stream)).await?;
+
+    let source_plan = table.read_plan(ctx.clone(), PlanBuilder::empty().build()?)?;
+    assert_eq!(source_plan.partitions.len(), 5);
+    assert_eq!(source_plan.statistics.read_rows, 8);
+    assert_eq!(source_plan.statistics.read_bytes, expect_bytes);
+
+    table.truncate(ctx.clone()).await?;
+    let source_plan = table.read_plan(ctx, PlanBuilder::empty().build()?)?;
+    assert_eq!(source_plan.statistics.read_rows, 0);
+    assert_eq!(source_plan.statistics.read_bytes, 0);
+    Ok(())
+}
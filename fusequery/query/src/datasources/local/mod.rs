@@ -5,18 +5,28 @@
 #[cfg(test)]
 mod csv_table_test;
 #[cfg(test)]
+mod json_table_test;
+#[cfg(test)]
+mod memory_table_test;
+#[cfg(test)]
 mod null_table_test;
 #[cfg(test)]
 mod parquet_table_test;
 
 mod csv_table;
+mod json_table;
 mod local_database;
 mod local_factory;
+mod memory_stream;
+mod memory_table;
 mod null_table;
 mod parquet_table;
 
 pub use csv_table::CsvTable;
+pub use json_table::JsonTable;
 pub use local_database::LocalDatabase;
 pub use local_factory::LocalFactory;
+pub use memory_stream::MemoryStream;
+pub use memory_table::MemoryTable;
 pub use null_table::NullTable;
 pub use parquet_table::ParquetTable;
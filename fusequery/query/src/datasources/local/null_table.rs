@@ -8,8 +8,9 @@ use anyhow::Result;
 use async_trait::async_trait;
 use common_datablocks::DataBlock;
 use common_datavalues::DataSchemaRef;
-use common_planners::{Partition, PlanNode, ReadDataSourcePlan, Statistics, TableOptions};
+use common_planners::{Extras, Partition, PlanNode, ReadDataSourcePlan, Statistics, TableOptions};
 use common_streams::{DataBlockStream, SendableDataBlockStream};
+use futures::stream::StreamExt;
 
 use crate::datasources::ITable;
 use crate::sessions::FuseQueryContextRef;
@@ -65,10 +66,15 @@ impl ITable for NullTable {
             }],
             statistics: Statistics::default(),
             description: format!("(Read from Null Engine table  {}.{})", self.db, self.name),
+            push_downs: Extras::default(),
         })
     }
 
-    async fn read(&self, _ctx: FuseQueryContextRef) -> Result<SendableDataBlockStream> {
+    async fn read(
+        &self,
+        _ctx: FuseQueryContextRef,
+        _source_plan: &ReadDataSourcePlan,
+    ) -> Result<SendableDataBlockStream> {
         let block = DataBlock::empty_with_schema(self.schema.clone());
 
         Ok(Box::pin(DataBlockStream::create(
@@ -77,4 +83,20 @@ impl ITable for NullTable {
             vec![block],
         )))
     }
+
+    async fn append_data(
+        &self,
+        _ctx: FuseQueryContextRef,
+        mut stream: SendableDataBlockStream,
+    ) -> Result<()> {
+        // The blocks are discarded.
+        while let Some(block) = stream.next().await {
+            block?;
+        }
+        Ok(())
+    }
+
+    async fn truncate(&self, _ctx: FuseQueryContextRef) -> Result<()> {
+        Ok(())
+    }
 }
@@ -17,10 +17,10 @@ async fn test_null_table() -> anyhow::Result<()> {
         DataSchema::new(vec![DataField::new("a", DataType::UInt64, false)]).into(),
         TableOptions::default(),
     )?;
-    table.read_plan(ctx.clone(), PlanBuilder::empty().build()?)?;
+    let source_plan = table.read_plan(ctx.clone(), PlanBuilder::empty().build()?)?;
     assert_eq!(table.engine(), "Null");
 
-    let stream = table.read(ctx).await?;
+    let stream = table.read(ctx, &source_plan).await?;
     let blocks = stream.try_collect::<Vec<_>>().await?;
     let rows: usize = blocks.iter().map(|block| block.num_rows()).sum();
 
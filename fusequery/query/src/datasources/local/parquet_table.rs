@@ -5,17 +5,23 @@
 use std::any::Any;
 use std::convert::TryInto;
 use std::fs::File;
+use std::path::Path;
 use std::sync::Arc;
 
 use anyhow::{anyhow, bail, Result};
 use async_trait::async_trait;
-use common_arrow::parquet::arrow::{ArrowReader, ParquetFileArrowReader};
+use common_arrow::arrow::record_batch::RecordBatch;
+use common_arrow::parquet::arrow::{ArrowReader, ArrowWriter, ParquetFileArrowReader};
 use common_arrow::parquet::file::reader::SerializedFileReader;
 use common_datablocks::DataBlock;
-use common_datavalues::DataSchemaRef;
-use common_planners::{Partition, PlanNode, ReadDataSourcePlan, Statistics, TableOptions};
+use common_datavalues::{
+    data_array_cast, decimal_array, is_integer, DataSchemaRef, DataType, DataValue,
+};
+use common_planners::{Extras, Partition, PlanNode, ReadDataSourcePlan, Statistics, TableOptions};
 use common_streams::{ParquetStream, SendableDataBlockStream};
 use crossbeam::channel::{bounded, Receiver, Sender};
+use futures::stream::StreamExt;
+use tokio::sync::mpsc;
 use tokio::task;
 
 use crate::datasources::ITable;
@@ -51,8 +57,35 @@ impl ParquetTable {
     }
 }
 
+/// Cast the columns read from the file to the types of the table schema.
+/// The decimals stored as INT32 or INT64 are read as their unscaled integers, they are
+/// converted to the decimals without going through the floats.
+fn convert_block(schema: &DataSchemaRef, batch: RecordBatch) -> Result<DataBlock> {
+    let block: DataBlock = batch.try_into()?;
+    let mut columns = Vec::with_capacity(block.num_columns());
+    for (i, field) in schema.fields().iter().enumerate() {
+        let column = block.column(i);
+        columns.push(match (column.data_type(), field.data_type()) {
+            (from, to) if from == to => column.clone(),
+            (from, DataType::Decimal(precision, scale)) if is_integer(from) => {
+                let values = (0..column.len())
+                    .map(|row| match DataValue::try_from_array(column, row)? {
+                        DataValue::Int32(v) => Ok(v.map(|v| v as i128)),
+                        DataValue::Int64(v) => Ok(v.map(|v| v as i128)),
+                        other => bail!("Parquet Error: {:?} is not a decimal", other),
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                decimal_array(values, *precision, *scale)?
+            }
+            (_, to) => data_array_cast(column, to)?,
+        });
+    }
+    Ok(DataBlock::create(schema.clone(), columns))
+}
+
 fn read_file(
     file: &str,
+    schema: DataSchemaRef,
     tx: Sender<Option<Result<DataBlock>>>,
     projection: &[usize],
 ) -> Result<()> {
@@ -68,7 +101,7 @@ fn read_file(
     loop {
         match batch_reader.next() {
             Some(Ok(batch)) => {
-                tx.send(Some(Ok(batch.try_into()?)))
+                tx.send(Some(convert_block(&schema, batch)))
                     .map_err(|e| anyhow!(e.to_string()))?;
             }
             None => {
@@ -86,6 +119,51 @@ fn read_file(
     Ok(())
 }
 
+/// Parquet file can't be appended, every append rewrites the whole file: the existing rows are
+/// copied batch by batch to a temporary file followed by the new blocks, and the temporary file
+/// replaces the table file only if all the rows are written. The memory is bounded by a few
+/// batches, but the cost grows with the file, the engine is not for the frequent small inserts.
+fn write_file(
+    file: &str,
+    schema: DataSchemaRef,
+    receiver: mpsc::Receiver<Option<RecordBatch>>,
+) -> Result<()> {
+    let tmp_file = format!("{}.tmp", file);
+    match write_tmp_file(file, &tmp_file, schema, receiver) {
+        Ok(_) => Ok(std::fs::rename(&tmp_file, file)?),
+        Err(e) => {
+            std::fs::remove_file(&tmp_file).ok();
+            Err(e)
+        }
+    }
+}
+
+/// The new blocks are received until the None, the closed channel aborts the append.
+fn write_tmp_file(
+    file: &str,
+    tmp_file: &str,
+    schema: DataSchemaRef,
+    mut receiver: mpsc::Receiver<Option<RecordBatch>>,
+) -> Result<()> {
+    let mut writer = ArrowWriter::try_new(File::create(tmp_file)?, schema, None)?;
+    if Path::new(file).exists() {
+        let file_reader = SerializedFileReader::new(File::open(file)?)?;
+        let mut arrow_reader = ParquetFileArrowReader::new(Arc::new(file_reader));
+        for batch in arrow_reader.get_record_reader(2048)? {
+            writer.write(&batch?)?;
+        }
+    }
+    loop {
+        match receiver.blocking_recv() {
+            Some(Some(batch)) => writer.write(&batch)?,
+            Some(None) => break,
+            None => bail!("Parquet append to {} is aborted", file),
+        }
+    }
+    writer.close()?;
+    Ok(())
+}
+
 #[async_trait]
 impl ITable for ParquetTable {
     fn name(&self) -> &str {
@@ -122,23 +200,58 @@ impl ITable for ParquetTable {
                 "(Read from Parquet Engine table  {}.{})",
                 self.db, self.name
             ),
+            push_downs: Extras::default(),
         })
     }
 
-    async fn read(&self, _ctx: FuseQueryContextRef) -> Result<SendableDataBlockStream> {
+    async fn read(
+        &self,
+        _ctx: FuseQueryContextRef,
+        _source_plan: &ReadDataSourcePlan,
+    ) -> Result<SendableDataBlockStream> {
         type BlockSender = Sender<Option<Result<DataBlock>>>;
         type BlockReceiver = Receiver<Option<Result<DataBlock>>>;
 
         let (response_tx, response_rx): (BlockSender, BlockReceiver) = bounded(2);
 
         let file = self.file.clone();
+        let schema = self.schema.clone();
         let projection: Vec<usize> = (0..self.schema.fields().len()).collect();
         task::spawn_blocking(move || {
-            if let Err(e) = read_file(&file, response_tx, &projection) {
+            if let Err(e) = read_file(&file, schema, response_tx, &projection) {
                 println!("Parquet reader thread terminated due to error: {:?}", e);
             }
         });
 
         Ok(Box::pin(ParquetStream::try_create(response_rx)?))
     }
+
+    async fn append_data(
+        &self,
+        _ctx: FuseQueryContextRef,
+        mut stream: SendableDataBlockStream,
+    ) -> Result<()> {
+        // The blocks are written by the blocking task while they are read.
+        let (sender, receiver) = mpsc::channel(1);
+        let file = self.file.clone();
+        let schema = self.schema.clone();
+        let writer = task::spawn_blocking(move || write_file(&file, schema, receiver));
+
+        let sent: Result<()> = async {
+            while let Some(block) = stream.next().await {
+                let batch: RecordBatch = block?.try_into()?;
+                // The writer failed, its error is returned below.
+                if sender.send(Some(batch)).await.is_err() {
+                    return Ok(());
+                }
+            }
+            sender.send(None).await.ok();
+            Ok(())
+        }
+        .await;
+        // The sender is dropped before the None if the stream failed, the append is aborted.
+        drop(sender);
+        let written = writer.await?;
+        sent.and(written)
+    }
 }
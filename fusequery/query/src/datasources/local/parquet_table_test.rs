@@ -30,12 +30,72 @@ async fn test_parquet_table() -> anyhow::Result<()> {
         DataSchema::new(vec![DataField::new("id", DataType::Int32, false)]).into(),
         options,
     )?;
-    table.read_plan(ctx.clone(), PlanBuilder::empty().build()?)?;
+    let source_plan = table.read_plan(ctx.clone(), PlanBuilder::empty().build()?)?;
 
-    let stream = table.read(ctx).await?;
+    let stream = table.read(ctx, &source_plan).await?;
     let blocks = stream.try_collect::<Vec<_>>().await?;
     let rows: usize = blocks.iter().map(|block| block.num_rows()).sum();
 
     assert_eq!(rows, 8);
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_parquet_table_append_data() -> anyhow::Result<()> {
+    use std::sync::Arc;
+
+    use common_datablocks::*;
+    use common_datavalues::*;
+    use common_planners::*;
+    use common_streams::*;
+    use futures::TryStreamExt;
+
+    use crate::datasources::local::*;
+
+    let file = std::env::temp_dir().join(format!("{}.parquet", uuid::Uuid::new_v4()));
+    let options: TableOptions = [("location".to_string(), file.display().to_string())]
+        .iter()
+        .cloned()
+        .collect();
+
+    let ctx = crate::tests::try_create_context()?;
+    let schema = Arc::new(DataSchema::new(vec![DataField::new(
+        "id",
+        DataType::Int32,
+        false,
+    )]));
+    let table = ParquetTable::try_create(
+        "default".into(),
+        "test_parquet".into(),
+        schema.clone(),
+        options,
+    )?;
+
+    // Every append rewrites the file with the existing rows.
+    for _ in 0..2 {
+        let block = DataBlock::create(
+            schema.clone(),
+            vec![Arc::new(Int32Array::from(vec![1, 2, 3]))],
+        );
+        let stream = DataBlockStream::create(schema.clone(), None, vec![block]);
+        table.append_data(ctx.clone(), Box::pin(stream)).await?;
+    }
+
+    // The failed stream aborts the append, the file is unchanged.
+    let block = DataBlock::create(
+        schema.clone(),
+        vec![Arc::new(Int32Array::from(vec![4, 5, 6]))],
+    );
+    let stream = futures::stream::iter(vec![Ok(block), Err(anyhow::anyhow!("Stream failed"))]);
+    let result = table.append_data(ctx.clone(), Box::pin(stream)).await;
+    assert_eq!("Stream failed", format!("{}", result.err().unwrap()));
+
+    let source_plan = table.read_plan(ctx.clone(), PlanBuilder::empty().build()?)?;
+    let stream = table.read(ctx, &source_plan).await?;
+    let blocks = stream.try_collect::<Vec<_>>().await?;
+    let rows: usize = blocks.iter().map(|block| block.num_rows()).sum();
+
+    assert_eq!(rows, 6);
+    std::fs::remove_file(file)?;
+    Ok(())
+}
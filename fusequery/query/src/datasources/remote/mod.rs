@@ -1,9 +1,14 @@
 // Copyright 2020-2021 The Datafuse Authors.
 //
 // SPDX-License-Identifier: Apache-2.0.
+
+#[cfg(test)]
+mod remote_table_test;
+
 mod remote_database;
 mod remote_factory;
 mod remote_table;
 
 pub use remote_database::RemoteDatabase;
 pub use remote_factory::RemoteFactory;
+pub use remote_table::RemoteTable;
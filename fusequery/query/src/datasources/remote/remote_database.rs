@@ -2,26 +2,27 @@
 //
 // SPDX-License-Identifier: Apache-2.0.
 
-use std::collections::HashMap;
 use std::sync::Arc;
 
 use anyhow::{bail, Result};
-use common_infallible::RwLock;
-use common_planners::CreateTablePlan;
+use common_planners::{CreateTablePlan, DropTablePlan, TableEngineType};
 
 use crate::configs::Config;
+use crate::datasources::remote::RemoteTable;
 use crate::datasources::{IDatabase, ITable, ITableFunction};
+use crate::rpcs::store::{block_on, StoreClient};
 
+/// The database and its tables are stored in FuseStore, every call goes to the store.
 pub struct RemoteDatabase {
+    conf: Config,
     name: String,
-    tables: RwLock<HashMap<String, Arc<dyn ITable>>>,
 }
 
 impl RemoteDatabase {
-    pub fn create(_conf: Config, name: &str) -> Self {
+    pub fn create(conf: Config, name: &str) -> Self {
         RemoteDatabase {
+            conf,
             name: name.to_string(),
-            tables: RwLock::new(HashMap::default()),
         }
     }
 }
@@ -32,22 +33,70 @@ impl IDatabase for RemoteDatabase {
     }
 
     fn engine(&self) -> &str {
-        "local"
+        "remote"
     }
 
-    fn get_table(&self, _table_name: &str) -> Result<Arc<dyn ITable>> {
-        bail!("RemoteDatabase get_table not yet implemented")
+    fn get_table(&self, table_name: &str) -> Result<Arc<dyn ITable>> {
+        let addr = self.conf.storage_api_address.clone();
+        let (db, table) = (self.name.clone(), table_name.to_string());
+        let result = block_on(async move {
+            let mut client = StoreClient::try_create(addr).await?;
+            client.get_table(db, table).await
+        })?;
+
+        let table = RemoteTable::try_create(
+            self.conf.clone(),
+            result.db,
+            result.name,
+            result.schema,
+            result.options,
+        )?;
+        Ok(Arc::from(table))
     }
 
     fn get_tables(&self) -> Result<Vec<Arc<dyn ITable>>> {
-        Ok(self.tables.read().values().cloned().collect())
+        let addr = self.conf.storage_api_address.clone();
+        let db = self.name.clone();
+        let result = block_on(async move {
+            let mut client = StoreClient::try_create(addr).await?;
+            client.get_tables(db).await
+        })?;
+
+        let mut tables = vec![];
+        for v in result.tables {
+            let table =
+                RemoteTable::try_create(self.conf.clone(), v.db, v.name, v.schema, v.options)?;
+            tables.push(Arc::from(table));
+        }
+        Ok(tables)
     }
 
     fn get_table_functions(&self) -> Result<Vec<Arc<dyn ITableFunction>>> {
         Ok(vec![])
     }
 
-    fn create_table(&self, _plan: CreateTablePlan) -> Result<()> {
-        bail!("RemoteDatabase create_table not yet implemented")
+    fn create_table(&self, plan: CreateTablePlan) -> Result<()> {
+        if plan.engine != TableEngineType::Remote {
+            bail!(
+                "RemoteDatabase only supports the Remote table engine, but got {}",
+                plan.engine.to_string()
+            );
+        }
+
+        let addr = self.conf.storage_api_address.clone();
+        block_on(async move {
+            let mut client = StoreClient::try_create(addr).await?;
+            client.create_table(plan).await
+        })?;
+        Ok(())
+    }
+
+    fn drop_table(&self, plan: DropTablePlan) -> Result<()> {
+        let addr = self.conf.storage_api_address.clone();
+        block_on(async move {
+            let mut client = StoreClient::try_create(addr).await?;
+            client.drop_table(plan).await
+        })?;
+        Ok(())
     }
 }
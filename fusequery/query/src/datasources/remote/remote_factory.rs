@@ -5,10 +5,12 @@
 use std::sync::Arc;
 
 use anyhow::Result;
+use log::warn;
 
 use crate::configs::Config;
 use crate::datasources::remote::RemoteDatabase;
 use crate::datasources::IDatabase;
+use crate::rpcs::store::{block_on, StoreClient};
 
 pub struct RemoteFactory {
     conf: Config,
@@ -20,11 +22,28 @@ impl RemoteFactory {
     }
 
     pub fn load_databases(&self) -> Result<Vec<Arc<dyn IDatabase>>> {
-        // Load databases from remote.
-        let databases: Vec<Arc<dyn IDatabase>> = vec![Arc::new(RemoteDatabase::create(
-            self.conf.clone(),
-            "for_test",
-        ))];
-        Ok(databases)
+        // Load databases from remote, the query still works with the local databases if the store is down.
+        let addr = self.conf.storage_api_address.clone();
+        let result = block_on(async move {
+            let mut client = StoreClient::try_create(addr).await?;
+            client.get_databases().await
+        });
+
+        match result {
+            Ok(result) => {
+                let mut databases: Vec<Arc<dyn IDatabase>> = vec![];
+                for v in result.databases {
+                    databases.push(Arc::new(RemoteDatabase::create(self.conf.clone(), &v.db)));
+                }
+                Ok(databases)
+            }
+            Err(e) => {
+                warn!(
+                    "Load databases from store {} error: {:?}",
+                    self.conf.storage_api_address, e
+                );
+                Ok(vec![])
+            }
+        }
     }
 }
@@ -4,31 +4,39 @@
 
 use std::any::Any;
 
-use anyhow::{bail, Result};
+use anyhow::Result;
 use async_trait::async_trait;
 use common_datavalues::DataSchemaRef;
-use common_planners::{PlanNode, ReadDataSourcePlan, TableOptions};
+use common_planners::{Extras, PlanNode, ReadDataSourcePlan, TableOptions};
 use common_streams::SendableDataBlockStream;
+use futures::stream::TryStreamExt;
 
+use crate::configs::Config;
 use crate::datasources::ITable;
+use crate::rpcs::store::{block_on, StoreClient};
 use crate::sessions::FuseQueryContextRef;
 
-#[allow(dead_code)]
 pub struct RemoteTable {
+    conf: Config,
     db: String,
     name: String,
     schema: DataSchemaRef,
 }
 
 impl RemoteTable {
-    #[allow(dead_code)]
     pub fn try_create(
+        conf: Config,
         db: String,
         name: String,
         schema: DataSchemaRef,
         _options: TableOptions,
     ) -> Result<Box<dyn ITable>> {
-        let table = Self { db, name, schema };
+        let table = Self {
+            conf,
+            db,
+            name,
+            schema,
+        };
         Ok(Box::new(table))
     }
 }
@@ -40,7 +48,7 @@ impl ITable for RemoteTable {
     }
 
     fn engine(&self) -> &str {
-        "remote"
+        "Remote"
     }
 
     fn as_any(&self) -> &dyn Any {
@@ -53,13 +61,78 @@ impl ITable for RemoteTable {
 
     fn read_plan(
         &self,
-        _ctx: FuseQueryContextRef,
-        _push_down_plan: PlanNode,
+        ctx: FuseQueryContextRef,
+        push_down_plan: PlanNode,
     ) -> Result<ReadDataSourcePlan> {
-        bail!("RemoteTable read_plan not yet implemented")
+        let addr = self.conf.storage_api_address.clone();
+        let (db, table) = (self.db.clone(), self.name.clone());
+        let result = block_on(async move {
+            let mut client = StoreClient::try_create(addr).await?;
+            client.read_plan(db, table, push_down_plan).await
+        })?;
+
+        let statistics = result.statistics;
+        ctx.try_set_statistics(&statistics)?;
+
+        Ok(ReadDataSourcePlan {
+            db: self.db.clone(),
+            table: self.name().to_string(),
+            schema: self.schema.clone(),
+            partitions: result.partitions,
+            statistics: statistics.clone(),
+            description: format!(
+                "(Read from Remote Engine table {}.{}, Read Rows:{}, Read Bytes:{})",
+                self.db, self.name, statistics.read_rows, statistics.read_bytes
+            ),
+            push_downs: Extras::default(),
+        })
     }
 
-    async fn read(&self, _ctx: FuseQueryContextRef) -> Result<SendableDataBlockStream> {
-        bail!("RemoteTable read not yet implemented")
+    async fn read(
+        &self,
+        ctx: FuseQueryContextRef,
+        source_plan: &ReadDataSourcePlan,
+    ) -> Result<SendableDataBlockStream> {
+        let client = StoreClient::try_create(self.conf.storage_api_address.clone()).await?;
+        let (db, table) = (self.db.clone(), self.name.clone());
+        let push_downs = source_plan.push_downs.clone();
+
+        // Every stream reads the partitions fetched from the context one by one.
+        let stream = futures::stream::try_unfold((ctx, client), move |(ctx, mut client)| {
+            let (db, table, push_downs) = (db.clone(), table.clone(), push_downs.clone());
+            async move {
+                let partitions = ctx.try_get_partitions(1)?;
+                let blocks = match partitions.into_iter().next() {
+                    None => return Ok(None),
+                    Some(partition) => {
+                        client
+                            .read_partition(db, table, partition, push_downs)
+                            .await?
+                    }
+                };
+                Ok::<_, anyhow::Error>(Some((blocks, (ctx, client))))
+            }
+        })
+        .try_flatten();
+        Ok(Box::pin(stream))
+    }
+
+    async fn append_data(
+        &self,
+        _ctx: FuseQueryContextRef,
+        stream: SendableDataBlockStream,
+    ) -> Result<()> {
+        // Collect all the blocks first, a failed stream must not commit a part of the insert.
+        let blocks = stream.try_collect::<Vec<_>>().await?;
+        let mut client = StoreClient::try_create(self.conf.storage_api_address.clone()).await?;
+        client
+            .append_data(
+                self.db.clone(),
+                self.name.clone(),
+                self.schema.clone(),
+                blocks,
+            )
+            .await?;
+        Ok(())
     }
 }
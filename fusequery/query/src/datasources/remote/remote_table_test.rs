@@ -0,0 +1,99 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_remote_table() -> anyhow::Result<()> {
+    use common_datavalues::*;
+    use common_planners::*;
+    use futures::TryStreamExt;
+    use pretty_assertions::assert_eq;
+
+    use crate::configs::Config;
+    use crate::interpreters::*;
+    use crate::sessions::FuseQueryContext;
+    use crate::sql::*;
+
+    let mut conf = Config::default();
+    conf.storage_api_address = crate::tests::try_start_store_service().await?;
+    let ctx = FuseQueryContext::try_create_with_conf(conf.clone())?;
+
+    for sql in vec![
+        "create database db1",
+        "create table db1.t(a bigint, b varchar) Engine = Remote",
+    ] {
+        let plan = PlanParser::create(ctx.clone()).build_from_sql(sql)?;
+        InterpreterFactory::get(ctx.clone(), plan)?
+            .execute()
+            .await?;
+    }
+
+    // Only the Remote engine is supported in the remote database.
+    let plan = PlanParser::create(ctx.clone())
+        .build_from_sql("create table db1.t2(a bigint) Engine = Memory")?;
+    if let Err(e) = InterpreterFactory::get(ctx.clone(), plan)?.execute().await {
+        assert_eq!(
+            "RemoteDatabase only supports the Remote table engine, but got Memory",
+            format!("{:?}", e)
+        );
+    } else {
+        assert!(false)
+    }
+
+    // The catalog is loaded from the store by a new context.
+    let ctx = FuseQueryContext::try_create_with_conf(conf)?;
+    let table = ctx.get_table("db1", "t")?;
+    assert_eq!(table.engine(), "Remote");
+    assert_eq!(
+        table.schema()?.field_with_name("b")?.data_type(),
+        &DataType::Utf8
+    );
+    if let Err(e) = ctx.get_table("db1", "t2") {
+        assert_eq!("Store Error: Unknown table: 'db1.t2'", format!("{:?}", e));
+    } else {
+        assert!(false)
+    }
+
+    // The table has no blocks yet.
+    let plan = PlanParser::create(ctx.clone()).build_from_sql("select * from db1.t")?;
+    let stream = InterpreterFactory::get(ctx.clone(), plan)?
+        .execute()
+        .await?;
+    let blocks = stream.try_collect::<Vec<_>>().await?;
+    let rows: usize = blocks.iter().map(|block| block.num_rows()).sum();
+    assert_eq!(0, rows);
+
+    // The inserted blocks are written to the store.
+    let plan = PlanParser::create(ctx.clone())
+        .build_from_sql("insert into db1.t values(1, 'x'), (2, 'y')")?;
+    InterpreterFactory::get(ctx.clone(), plan)?
+        .execute()
+        .await?;
+
+    // The blocks are read back with the projection and the filter pushed down.
+    let tests = vec![
+        ("select * from db1.t", 2, 2),
+        ("select b from db1.t where a > 1", 1, 1),
+        ("select count() from db1.t", 1, 1),
+    ];
+    for (sql, expect_rows, expect_columns) in tests {
+        let plan = PlanParser::create(ctx.clone()).build_from_sql(sql)?;
+        let stream = InterpreterFactory::get(ctx.clone(), plan)?
+            .execute()
+            .await?;
+        let blocks = stream.try_collect::<Vec<_>>().await?;
+        let rows: usize = blocks.iter().map(|block| block.num_rows()).sum();
+        assert_eq!(expect_rows, rows, "{}", sql);
+        assert_eq!(expect_columns, blocks[0].num_columns(), "{}", sql);
+    }
+
+    for sql in vec!["drop table db1.t", "drop database db1"] {
+        let plan = PlanParser::create(ctx.clone()).build_from_sql(sql)?;
+        InterpreterFactory::get(ctx.clone(), plan)?
+            .execute()
+            .await?;
+    }
+    assert!(ctx.get_datasource().read().get_database("db1").is_err());
+
+    Ok(())
+}
@@ -9,7 +9,7 @@ use anyhow::Result;
 use async_trait::async_trait;
 use common_datablocks::DataBlock;
 use common_datavalues::{DataField, DataSchema, DataSchemaRef, DataType, StringArray, UInt32Array};
-use common_planners::{Partition, PlanNode, ReadDataSourcePlan, Statistics};
+use common_planners::{Extras, Partition, PlanNode, ReadDataSourcePlan, Statistics};
 use common_streams::{DataBlockStream, SendableDataBlockStream};
 
 use crate::datasources::ITable;
@@ -64,10 +64,15 @@ impl ITable for ClustersTable {
             }],
             statistics: Statistics::default(),
             description: "(Read from system.clusters table)".to_string(),
+            push_downs: Extras::default(),
         })
     }
 
-    async fn read(&self, ctx: FuseQueryContextRef) -> Result<SendableDataBlockStream> {
+    async fn read(
+        &self,
+        ctx: FuseQueryContextRef,
+        _source_plan: &ReadDataSourcePlan,
+    ) -> Result<SendableDataBlockStream> {
         let nodes = ctx.try_get_cluster()?.get_nodes()?;
         let names: Vec<&str> = nodes.iter().map(|x| x.name.as_str()).collect();
         let addresses: Vec<&str> = nodes.iter().map(|x| x.address.as_str()).collect();
@@ -12,8 +12,8 @@ async fn test_clusters_table() -> anyhow::Result<()> {
 
     let ctx = crate::tests::try_create_context()?;
     let table = ClustersTable::create();
-    table.read_plan(ctx.clone(), PlanBuilder::empty().build()?)?;
-    let stream = table.read(ctx).await?;
+    let source_plan = table.read_plan(ctx.clone(), PlanBuilder::empty().build()?)?;
+    let stream = table.read(ctx, &source_plan).await?;
     let blocks = stream.try_collect::<Vec<_>>().await?;
     let rows: usize = blocks.iter().map(|block| block.num_rows()).sum();
     assert!(rows == 0);
@@ -10,7 +10,7 @@ use async_trait::async_trait;
 use common_datablocks::DataBlock;
 use common_datavalues::{DataField, DataSchema, DataSchemaRef, DataType, StringArray};
 use common_functions::FunctionFactory;
-use common_planners::{Partition, PlanNode, ReadDataSourcePlan, Statistics};
+use common_planners::{Extras, Partition, PlanNode, ReadDataSourcePlan, Statistics};
 use common_streams::{DataBlockStream, SendableDataBlockStream};
 
 use crate::datasources::ITable;
@@ -65,10 +65,15 @@ impl ITable for FunctionsTable {
             }],
             statistics: Statistics::default(),
             description: "(Read from system.functions table)".to_string(),
+            push_downs: Extras::default(),
         })
     }
 
-    async fn read(&self, _ctx: FuseQueryContextRef) -> Result<SendableDataBlockStream> {
+    async fn read(
+        &self,
+        _ctx: FuseQueryContextRef,
+        _source_plan: &ReadDataSourcePlan,
+    ) -> Result<SendableDataBlockStream> {
         let func_names = FunctionFactory::registered_names();
         let names: Vec<&str> = func_names.iter().map(|x| x.as_ref()).collect();
         let block = DataBlock::create(
@@ -11,7 +11,8 @@ use anyhow::{bail, Result};
 use async_trait::async_trait;
 use common_datavalues::{DataField, DataSchema, DataSchemaRef, DataType, DataValue};
 use common_planners::{
-    ExpressionPlan, Partition, Partitions, PlanNode, ReadDataSourcePlan, ScanPlan, Statistics,
+    ExpressionPlan, Extras, Partition, Partitions, PlanNode, ReadDataSourcePlan, ScanPlan,
+    Statistics,
 };
 use common_streams::SendableDataBlockStream;
 
@@ -127,10 +128,15 @@ impl ITable for NumbersTable {
                 "(Read from system.{} table, Read Rows:{}, Read Bytes:{})",
                 self.table, statistics.read_rows, statistics.read_bytes
             ),
+            push_downs: Extras::default(),
         })
     }
 
-    async fn read(&self, ctx: FuseQueryContextRef) -> Result<SendableDataBlockStream> {
+    async fn read(
+        &self,
+        ctx: FuseQueryContextRef,
+        _source_plan: &ReadDataSourcePlan,
+    ) -> Result<SendableDataBlockStream> {
         Ok(Box::pin(NumbersStream::create(ctx, self.schema.clone())))
     }
 }
@@ -9,7 +9,7 @@ use anyhow::Result;
 use async_trait::async_trait;
 use common_datablocks::DataBlock;
 use common_datavalues::{DataField, DataSchema, DataSchemaRef, DataType, UInt8Array};
-use common_planners::{Partition, PlanNode, ReadDataSourcePlan, Statistics};
+use common_planners::{Extras, Partition, PlanNode, ReadDataSourcePlan, Statistics};
 use common_streams::{DataBlockStream, SendableDataBlockStream};
 
 use crate::datasources::ITable;
@@ -64,10 +64,15 @@ impl ITable for OneTable {
             }],
             statistics: Statistics::default(),
             description: "(Read from system.one table)".to_string(),
+            push_downs: Extras::default(),
         })
     }
 
-    async fn read(&self, _: FuseQueryContextRef) -> Result<SendableDataBlockStream> {
+    async fn read(
+        &self,
+        _: FuseQueryContextRef,
+        _source_plan: &ReadDataSourcePlan,
+    ) -> Result<SendableDataBlockStream> {
         let block = DataBlock::create(
             self.schema.clone(),
             vec![Arc::new(UInt8Array::from(vec![1u8]))],
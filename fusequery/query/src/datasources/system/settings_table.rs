@@ -9,7 +9,7 @@ use anyhow::Result;
 use async_trait::async_trait;
 use common_datablocks::DataBlock;
 use common_datavalues::{DataField, DataSchema, DataSchemaRef, DataType, DataValue, StringArray};
-use common_planners::{Partition, PlanNode, ReadDataSourcePlan, Statistics};
+use common_planners::{Extras, Partition, PlanNode, ReadDataSourcePlan, Statistics};
 use common_streams::{DataBlockStream, SendableDataBlockStream};
 
 use crate::datasources::ITable;
@@ -65,10 +65,15 @@ impl ITable for SettingsTable {
             }],
             statistics: Statistics::default(),
             description: "(Read from system.settings table)".to_string(),
+            push_downs: Extras::default(),
         })
     }
 
-    async fn read(&self, ctx: FuseQueryContextRef) -> Result<SendableDataBlockStream> {
+    async fn read(
+        &self,
+        ctx: FuseQueryContextRef,
+        _source_plan: &ReadDataSourcePlan,
+    ) -> Result<SendableDataBlockStream> {
         let settings = ctx.get_settings()?;
 
         let mut names: Vec<String> = vec![];
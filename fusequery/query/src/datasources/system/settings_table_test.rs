@@ -13,8 +13,8 @@ async fn test_settings_table() -> anyhow::Result<()> {
 
     let ctx = crate::tests::try_create_context()?;
     let table = SettingsTable::create();
-    table.read_plan(ctx.clone(), PlanBuilder::empty().build()?)?;
-    let stream = table.read(ctx).await?;
+    let source_plan = table.read_plan(ctx.clone(), PlanBuilder::empty().build()?)?;
+    let stream = table.read(ctx, &source_plan).await?;
     let blocks = stream.try_collect::<Vec<_>>().await?;
     let rows: usize = blocks.iter().map(|block| block.num_rows()).sum();
     assert_eq!(3, rows);
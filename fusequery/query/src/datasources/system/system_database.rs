@@ -6,7 +6,7 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use anyhow::{anyhow, bail, Result};
-use common_planners::CreateTablePlan;
+use common_planners::{CreateTablePlan, DropTablePlan};
 
 use crate::datasources::{system, IDatabase, ITable, ITableFunction};
 
@@ -77,4 +77,8 @@ impl IDatabase for SystemDatabase {
     fn create_table(&self, _plan: CreateTablePlan) -> Result<()> {
         bail!("DataSource Error: cannot create table for system database")
     }
+
+    fn drop_table(&self, _plan: DropTablePlan) -> Result<()> {
+        bail!("DataSource Error: cannot drop table for system database")
+    }
 }
@@ -9,7 +9,7 @@ use anyhow::Result;
 use async_trait::async_trait;
 use common_datablocks::DataBlock;
 use common_datavalues::{DataField, DataSchema, DataSchemaRef, DataType, StringArray};
-use common_planners::{Partition, PlanNode, ReadDataSourcePlan, Statistics};
+use common_planners::{Extras, Partition, PlanNode, ReadDataSourcePlan, Statistics};
 use common_streams::{DataBlockStream, SendableDataBlockStream};
 
 use crate::datasources::ITable;
@@ -64,10 +64,15 @@ impl ITable for TablesTable {
             }],
             statistics: Statistics::default(),
             description: "(Read from system.functions table)".to_string(),
+            push_downs: Extras::default(),
         })
     }
 
-    async fn read(&self, ctx: FuseQueryContextRef) -> Result<SendableDataBlockStream> {
+    async fn read(
+        &self,
+        ctx: FuseQueryContextRef,
+        _source_plan: &ReadDataSourcePlan,
+    ) -> Result<SendableDataBlockStream> {
         let database_tables = ctx.get_datasource().read().get_all_tables()?;
 
         let databases: Vec<&str> = database_tables.iter().map(|(d, _)| d.as_str()).collect();
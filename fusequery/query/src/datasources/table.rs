@@ -4,7 +4,7 @@
 
 use std::any::Any;
 
-use anyhow::Result;
+use anyhow::{bail, Result};
 use async_trait::async_trait;
 use common_datavalues::DataSchemaRef;
 use common_planners::{PlanNode, ReadDataSourcePlan};
@@ -26,6 +26,24 @@ pub trait ITable: Sync + Send {
         push_down_plan: PlanNode,
     ) -> Result<ReadDataSourcePlan>;
 
-    // Read block datas from the underfling.
-    async fn read(&self, ctx: FuseQueryContextRef) -> Result<SendableDataBlockStream>;
+    // Read block datas from the underfling, the source plan carries the push downs.
+    async fn read(
+        &self,
+        ctx: FuseQueryContextRef,
+        source_plan: &ReadDataSourcePlan,
+    ) -> Result<SendableDataBlockStream>;
+
+    // Append the blocks of the stream into the underlying, the blocks have the table schema.
+    async fn append_data(
+        &self,
+        _ctx: FuseQueryContextRef,
+        _stream: SendableDataBlockStream,
+    ) -> Result<()> {
+        bail!("Table engine {} does not support INSERT", self.engine())
+    }
+
+    // Remove all the data of the table, the schema is kept.
+    async fn truncate(&self, _ctx: FuseQueryContextRef) -> Result<()> {
+        bail!("Table engine {} does not support TRUNCATE", self.engine())
+    }
 }
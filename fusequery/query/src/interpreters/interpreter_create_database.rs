@@ -0,0 +1,45 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::sync::Arc;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use common_planners::CreateDatabasePlan;
+use common_streams::{DataBlockStream, SendableDataBlockStream};
+
+use crate::interpreters::IInterpreter;
+use crate::sessions::FuseQueryContextRef;
+
+pub struct CreateDatabaseInterpreter {
+    ctx: FuseQueryContextRef,
+    plan: CreateDatabasePlan,
+}
+
+impl CreateDatabaseInterpreter {
+    pub fn try_create(
+        ctx: FuseQueryContextRef,
+        plan: CreateDatabasePlan,
+    ) -> Result<Arc<dyn IInterpreter>> {
+        Ok(Arc::new(CreateDatabaseInterpreter { ctx, plan }))
+    }
+}
+
+#[async_trait]
+impl IInterpreter for CreateDatabaseInterpreter {
+    fn name(&self) -> &str {
+        "CreateDatabaseInterpreter"
+    }
+
+    async fn execute(&self) -> Result<SendableDataBlockStream> {
+        let datasource = self.ctx.get_datasource();
+        datasource.write().create_database(self.plan.clone())?;
+
+        Ok(Box::pin(DataBlockStream::create(
+            self.plan.schema.clone(),
+            None,
+            vec![],
+        )))
+    }
+}
@@ -0,0 +1,44 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_create_database_interpreter() -> anyhow::Result<()> {
+    use common_planners::*;
+    use pretty_assertions::assert_eq;
+
+    use crate::interpreters::*;
+    use crate::sql::*;
+
+    let ctx = crate::tests::try_create_context()?;
+
+    if let PlanNode::CreateDatabase(plan) =
+        PlanParser::create(ctx.clone()).build_from_sql("create database db1 Engine = Local")?
+    {
+        let executor = CreateDatabaseInterpreter::try_create(ctx.clone(), plan)?;
+        assert_eq!(executor.name(), "CreateDatabaseInterpreter");
+        executor.execute().await?;
+    } else {
+        assert!(false)
+    }
+    ctx.get_datasource().read().get_database("db1")?;
+
+    // Create again.
+    let plan =
+        PlanParser::create(ctx.clone()).build_from_sql("create database db1 Engine = Local")?;
+    if let Err(e) = InterpreterFactory::get(ctx.clone(), plan)?.execute().await {
+        assert_eq!(
+            "DataSource Error: Database 'db1' already exists",
+            format!("{:?}", e)
+        );
+    } else {
+        assert!(false)
+    }
+    let plan = PlanParser::create(ctx.clone())
+        .build_from_sql("create database if not exists db1 Engine = Local")?;
+    InterpreterFactory::get(ctx.clone(), plan)?
+        .execute()
+        .await?;
+
+    Ok(())
+}
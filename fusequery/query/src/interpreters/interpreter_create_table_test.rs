@@ -4,6 +4,7 @@
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_explain_interpreter() -> anyhow::Result<()> {
+    use common_arrow::arrow::datatypes::TimeUnit;
     use common_datavalues::DataType;
     use common_planners::*;
     use futures::stream::StreamExt;
@@ -15,7 +16,7 @@ async fn test_explain_interpreter() -> anyhow::Result<()> {
     let ctx = crate::tests::try_create_context()?;
 
     if let PlanNode::CreateTable(plan) = PlanParser::create(ctx.clone())
-        .build_from_sql("create table default.a(a bigint, b int, c varchar(255), d smallint, e Date ) Engine = Null")?
+        .build_from_sql("create table default.a(a bigint, b int, c varchar(255), d smallint, e Date, f DateTime, g Timestamp ) Engine = Null")?
     {
         let executor = CreateTableInterpreter::try_create(ctx, plan.clone())?;
         assert_eq!(executor.name(), "CreateInterpreter");
@@ -25,6 +26,8 @@ async fn test_explain_interpreter() -> anyhow::Result<()> {
         assert_eq!(plan.schema().field_with_name("c")?.data_type(), &DataType::Utf8);
         assert_eq!(plan.schema().field_with_name("d")?.data_type(), &DataType::Int16);
         assert_eq!(plan.schema().field_with_name("e")?.data_type(), &DataType::Date32);
+        assert_eq!(plan.schema().field_with_name("f")?.data_type(), &DataType::Timestamp(TimeUnit::Second, None));
+        assert_eq!(plan.schema().field_with_name("g")?.data_type(), &DataType::Timestamp(TimeUnit::Microsecond, None));
 
         let mut stream = executor.execute().await?;
         while let Some(_block) = stream.next().await {}
@@ -34,3 +37,32 @@ async fn test_explain_interpreter() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_create_table_nullable_columns() -> anyhow::Result<()> {
+    use common_planners::*;
+    use pretty_assertions::assert_eq;
+
+    use crate::sql::*;
+
+    let ctx = crate::tests::try_create_context()?;
+
+    if let PlanNode::CreateTable(plan) = PlanParser::create(ctx.clone()).build_from_sql(
+        "create table default.b(a bigint null, b int not null, c varchar(255)) Engine = Null",
+    )? {
+        assert_eq!(plan.schema().field_with_name("a")?.is_nullable(), true);
+        assert_eq!(plan.schema().field_with_name("b")?.is_nullable(), false);
+        // The columns are NOT NULL by default.
+        assert_eq!(plan.schema().field_with_name("c")?.is_nullable(), false);
+    } else {
+        assert!(false)
+    }
+
+    let result = PlanParser::create(ctx)
+        .build_from_sql("create table default.b(a bigint unique) Engine = Null");
+    assert_eq!(
+        "Unsupported column option UNIQUE of column a",
+        result.err().unwrap().to_string()
+    );
+    Ok(())
+}
@@ -0,0 +1,45 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::sync::Arc;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use common_planners::DropDatabasePlan;
+use common_streams::{DataBlockStream, SendableDataBlockStream};
+
+use crate::interpreters::IInterpreter;
+use crate::sessions::FuseQueryContextRef;
+
+pub struct DropDatabaseInterpreter {
+    ctx: FuseQueryContextRef,
+    plan: DropDatabasePlan,
+}
+
+impl DropDatabaseInterpreter {
+    pub fn try_create(
+        ctx: FuseQueryContextRef,
+        plan: DropDatabasePlan,
+    ) -> Result<Arc<dyn IInterpreter>> {
+        Ok(Arc::new(DropDatabaseInterpreter { ctx, plan }))
+    }
+}
+
+#[async_trait]
+impl IInterpreter for DropDatabaseInterpreter {
+    fn name(&self) -> &str {
+        "DropDatabaseInterpreter"
+    }
+
+    async fn execute(&self) -> Result<SendableDataBlockStream> {
+        let datasource = self.ctx.get_datasource();
+        datasource.write().drop_database(self.plan.clone())?;
+
+        Ok(Box::pin(DataBlockStream::create(
+            self.plan.schema.clone(),
+            None,
+            vec![],
+        )))
+    }
+}
@@ -0,0 +1,67 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_drop_database_interpreter() -> anyhow::Result<()> {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    use common_datavalues::DataSchema;
+    use common_planners::*;
+    use pretty_assertions::assert_eq;
+
+    use crate::interpreters::*;
+    use crate::sql::*;
+
+    let ctx = crate::tests::try_create_context()?;
+    ctx.get_datasource()
+        .write()
+        .create_database(CreateDatabasePlan {
+            if_not_exists: false,
+            db: "db1".to_string(),
+            engine: DatabaseEngineType::Local,
+            options: HashMap::new(),
+            schema: Arc::new(DataSchema::empty()),
+        })?;
+
+    if let PlanNode::DropDatabase(plan) =
+        PlanParser::create(ctx.clone()).build_from_sql("drop database db1")?
+    {
+        let executor = DropDatabaseInterpreter::try_create(ctx.clone(), plan)?;
+        assert_eq!(executor.name(), "DropDatabaseInterpreter");
+        executor.execute().await?;
+    } else {
+        assert!(false)
+    }
+    assert!(ctx.get_datasource().read().get_database("db1").is_err());
+
+    // Drop again.
+    let plan = PlanParser::create(ctx.clone()).build_from_sql("drop database db1")?;
+    if let Err(e) = InterpreterFactory::get(ctx.clone(), plan)?.execute().await {
+        assert_eq!(
+            "DataSource Error: Unknown database: 'db1'",
+            format!("{:?}", e)
+        );
+    } else {
+        assert!(false)
+    }
+    let plan = PlanParser::create(ctx.clone()).build_from_sql("drop database if exists db1")?;
+    InterpreterFactory::get(ctx.clone(), plan)?
+        .execute()
+        .await?;
+
+    // The system database is protected.
+    let plan = PlanParser::create(ctx.clone()).build_from_sql("drop database if exists system")?;
+    if let Err(e) = InterpreterFactory::get(ctx.clone(), plan)?.execute().await {
+        assert_eq!(
+            "DataSource Error: cannot drop system database",
+            format!("{:?}", e)
+        );
+    } else {
+        assert!(false)
+    }
+    ctx.get_table("system", "one")?;
+
+    Ok(())
+}
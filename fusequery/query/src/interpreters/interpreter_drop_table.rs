@@ -0,0 +1,50 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::sync::Arc;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use common_planners::DropTablePlan;
+use common_streams::{DataBlockStream, SendableDataBlockStream};
+
+use crate::interpreters::IInterpreter;
+use crate::sessions::FuseQueryContextRef;
+
+pub struct DropTableInterpreter {
+    ctx: FuseQueryContextRef,
+    plan: DropTablePlan,
+}
+
+impl DropTableInterpreter {
+    pub fn try_create(
+        ctx: FuseQueryContextRef,
+        plan: DropTablePlan,
+    ) -> Result<Arc<dyn IInterpreter>> {
+        Ok(Arc::new(DropTableInterpreter { ctx, plan }))
+    }
+}
+
+#[async_trait]
+impl IInterpreter for DropTableInterpreter {
+    fn name(&self) -> &str {
+        "DropTableInterpreter"
+    }
+
+    async fn execute(&self) -> Result<SendableDataBlockStream> {
+        let datasource = self.ctx.get_datasource();
+        let database = datasource.read().get_database(self.plan.db.as_str());
+        match database {
+            Ok(database) => database.drop_table(self.plan.clone())?,
+            Err(e) if !self.plan.if_exists => return Err(e),
+            Err(_) => {}
+        }
+
+        Ok(Box::pin(DataBlockStream::create(
+            self.plan.schema.clone(),
+            None,
+            vec![],
+        )))
+    }
+}
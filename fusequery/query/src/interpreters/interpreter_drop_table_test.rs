@@ -0,0 +1,56 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_drop_table_interpreter() -> anyhow::Result<()> {
+    use common_planners::*;
+    use pretty_assertions::assert_eq;
+
+    use crate::interpreters::*;
+    use crate::sql::*;
+
+    let ctx = crate::tests::try_create_context()?;
+
+    let plan = PlanParser::create(ctx.clone())
+        .build_from_sql("create table default.a(a bigint, b int) Engine = Null")?;
+    InterpreterFactory::get(ctx.clone(), plan)?
+        .execute()
+        .await?;
+
+    if let PlanNode::DropTable(plan) =
+        PlanParser::create(ctx.clone()).build_from_sql("drop table default.a")?
+    {
+        let executor = DropTableInterpreter::try_create(ctx.clone(), plan)?;
+        assert_eq!(executor.name(), "DropTableInterpreter");
+        executor.execute().await?;
+    } else {
+        assert!(false)
+    }
+    assert!(ctx.get_table("default", "a").is_err());
+
+    // Drop again.
+    let plan = PlanParser::create(ctx.clone()).build_from_sql("drop table default.a")?;
+    if let Err(e) = InterpreterFactory::get(ctx.clone(), plan)?.execute().await {
+        assert_eq!("DataSource Error: Unknown table: 'a'", format!("{:?}", e));
+    } else {
+        assert!(false)
+    }
+    let plan = PlanParser::create(ctx.clone()).build_from_sql("drop table if exists default.a")?;
+    InterpreterFactory::get(ctx.clone(), plan)?
+        .execute()
+        .await?;
+
+    // System tables can't be dropped.
+    let plan = PlanParser::create(ctx.clone()).build_from_sql("drop table system.one")?;
+    if let Err(e) = InterpreterFactory::get(ctx.clone(), plan)?.execute().await {
+        assert_eq!(
+            "DataSource Error: cannot drop table for system database",
+            format!("{:?}", e)
+        );
+    } else {
+        assert!(false)
+    }
+
+    Ok(())
+}
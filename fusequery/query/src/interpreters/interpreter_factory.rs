@@ -9,7 +9,9 @@ use common_planners::PlanNode;
 
 use crate::interpreters::interpreter_create_table::CreateTableInterpreter;
 use crate::interpreters::{
-    ExplainInterpreter, IInterpreter, SelectInterpreter, SettingInterpreter,
+    CreateDatabaseInterpreter, DropDatabaseInterpreter, DropTableInterpreter, ExplainInterpreter,
+    IInterpreter, InsertIntoInterpreter, SelectInterpreter, SettingInterpreter,
+    TruncateTableInterpreter,
 };
 use crate::sessions::FuseQueryContextRef;
 
@@ -19,9 +21,14 @@ impl InterpreterFactory {
     pub fn get(ctx: FuseQueryContextRef, plan: PlanNode) -> Result<Arc<dyn IInterpreter>> {
         match plan {
             PlanNode::Select(v) => SelectInterpreter::try_create(ctx, v),
+            PlanNode::CreateDatabase(v) => CreateDatabaseInterpreter::try_create(ctx, v),
             PlanNode::CreateTable(v) => CreateTableInterpreter::try_create(ctx, v),
             PlanNode::Explain(v) => ExplainInterpreter::try_create(ctx, v),
             PlanNode::SetVariable(v) => SettingInterpreter::try_create(ctx, v),
+            PlanNode::InsertInto(v) => InsertIntoInterpreter::try_create(ctx, v),
+            PlanNode::DropTable(v) => DropTableInterpreter::try_create(ctx, v),
+            PlanNode::DropDatabase(v) => DropDatabaseInterpreter::try_create(ctx, v),
+            PlanNode::TruncateTable(v) => TruncateTableInterpreter::try_create(ctx, v),
             _ => bail!("Can't get the interpreter by plan:{}", plan.name()),
         }
     }
@@ -0,0 +1,133 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::sync::Arc;
+
+use anyhow::{ensure, Result};
+use async_trait::async_trait;
+use common_arrow::arrow;
+use common_datablocks::DataBlock;
+use common_datavalues::{DataField, DataSchema, DataSchemaRef, DataType, UInt8Array};
+use common_functions::CastFunction;
+use common_planners::InsertIntoPlan;
+use common_streams::{DataBlockStream, SendableDataBlockStream};
+use futures::stream::StreamExt;
+
+use crate::interpreters::IInterpreter;
+use crate::optimizers::Optimizer;
+use crate::pipelines::processors::PipelineBuilder;
+use crate::sessions::FuseQueryContextRef;
+
+pub struct InsertIntoInterpreter {
+    ctx: FuseQueryContextRef,
+    plan: InsertIntoPlan,
+}
+
+impl InsertIntoInterpreter {
+    pub fn try_create(
+        ctx: FuseQueryContextRef,
+        plan: InsertIntoPlan,
+    ) -> Result<Arc<dyn IInterpreter>> {
+        Ok(Arc::new(InsertIntoInterpreter { ctx, plan }))
+    }
+
+    /// Evaluate the VALUES rows into one block of the inserted columns.
+    fn values_block(&self) -> Result<DataBlock> {
+        let schema = self.plan.schema();
+        // The expressions are evaluated on a block of one row.
+        let dummy = DataBlock::create(
+            Arc::new(DataSchema::new(vec![DataField::new(
+                "dummy",
+                DataType::UInt8,
+                false,
+            )])),
+            vec![Arc::new(UInt8Array::from(vec![0]))],
+        );
+
+        let mut columns = Vec::with_capacity(schema.fields().len());
+        for (i, field) in schema.fields().iter().enumerate() {
+            let mut arrays = Vec::with_capacity(self.plan.values.len());
+            for row in self.plan.values.iter() {
+                let array = row[i].to_function()?.eval(&dummy)?.to_array(1)?;
+                arrays.push(CastFunction::strict_cast(&array, field.data_type())?);
+            }
+            let arrays = arrays
+                .iter()
+                .map(|array| array.as_ref())
+                .collect::<Vec<_>>();
+            columns.push(arrow::compute::concat(&arrays)?);
+        }
+        Ok(DataBlock::create(schema, columns))
+    }
+
+    /// Convert the block of the inserted columns to the table schema, the other columns are NULL.
+    /// The values can't be casted are errors rather than NULLs, and the NULLs are rejected by
+    /// the NOT NULL columns.
+    fn table_block(
+        table_schema: &DataSchemaRef,
+        insert_schema: &DataSchemaRef,
+        block: DataBlock,
+    ) -> Result<DataBlock> {
+        let rows = block.num_rows();
+        let mut columns = Vec::with_capacity(table_schema.fields().len());
+        for field in table_schema.fields() {
+            let column = match insert_schema.index_of(field.name()) {
+                Ok(i) if block.column(i).data_type() == field.data_type() => {
+                    block.column(i).clone()
+                }
+                Ok(i) => CastFunction::strict_cast(block.column(i), field.data_type())?,
+                Err(_) => arrow::array::new_null_array(field.data_type(), rows),
+            };
+            ensure!(
+                field.is_nullable() || column.null_count() == 0,
+                "Column {} is NOT NULL, but NULL is inserted",
+                field.name()
+            );
+            columns.push(column);
+        }
+        Ok(DataBlock::create(table_schema.clone(), columns))
+    }
+}
+
+#[async_trait]
+impl IInterpreter for InsertIntoInterpreter {
+    fn name(&self) -> &str {
+        "InsertIntoInterpreter"
+    }
+
+    async fn execute(&self) -> Result<SendableDataBlockStream> {
+        let table = self.ctx.get_table(&self.plan.db, &self.plan.table)?;
+
+        let stream: SendableDataBlockStream = match &self.plan.select {
+            Some(select) => {
+                let plan = Optimizer::create(self.ctx.clone()).optimize(select)?;
+                PipelineBuilder::create(self.ctx.clone(), plan)
+                    .build()?
+                    .execute()
+                    .await?
+            }
+            None => Box::pin(DataBlockStream::create(
+                self.plan.schema(),
+                None,
+                vec![self.values_block()?],
+            )),
+        };
+
+        // The select columns are matched to the inserted columns by position.
+        let table_schema = table.schema()?;
+        let insert_schema = self.plan.schema();
+        let stream = stream.map(move |block| {
+            block.and_then(|block| Self::table_block(&table_schema, &insert_schema, block))
+        });
+        table
+            .append_data(self.ctx.clone(), Box::pin(stream))
+            .await?;
+
+        Ok(Box::pin(DataBlockStream::create(
+            self.plan.schema(),
+            None,
+            vec![],
+        )))
+    }
+}
@@ -0,0 +1,231 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_insert_into_interpreter() -> anyhow::Result<()> {
+    use common_datavalues::*;
+    use common_planners::*;
+    use futures::TryStreamExt;
+    use pretty_assertions::assert_eq;
+
+    use crate::interpreters::*;
+    use crate::sql::*;
+
+    let ctx = crate::tests::try_create_context()?;
+    let file = std::env::temp_dir().join(format!("{}.csv", uuid::Uuid::new_v4()));
+
+    let plan = PlanParser::create(ctx.clone()).build_from_sql(&format!(
+        "create table default.t(a bigint, b int) Engine = CSV location = '{}'",
+        file.display()
+    ))?;
+    InterpreterFactory::get(ctx.clone(), plan)?
+        .execute()
+        .await?;
+
+    // Insert values.
+    if let PlanNode::InsertInto(plan) = PlanParser::create(ctx.clone())
+        .build_from_sql("insert into default.t values (1, 10), (2, 20)")?
+    {
+        let executor = InsertIntoInterpreter::try_create(ctx.clone(), plan)?;
+        assert_eq!(executor.name(), "InsertIntoInterpreter");
+        executor.execute().await?;
+    } else {
+        assert!(false)
+    }
+
+    // Insert select, the columns are matched by position.
+    let plan = PlanParser::create(ctx.clone())
+        .build_from_sql("insert into default.t(b, a) select number, number * 100 from numbers_mt(3) where number > 0")?;
+    InterpreterFactory::get(ctx.clone(), plan)?
+        .execute()
+        .await?;
+
+    let plan = PlanParser::create(ctx.clone()).build_from_sql("select a, b from default.t")?;
+    let stream = InterpreterFactory::get(ctx.clone(), plan)?
+        .execute()
+        .await?;
+    let blocks = stream.try_collect::<Vec<_>>().await?;
+    let mut actual = vec![];
+    for block in blocks {
+        for row in 0..block.num_rows() {
+            actual.push(format!(
+                "{:?}|{:?}",
+                DataValue::try_from_array(block.column(0), row)?,
+                DataValue::try_from_array(block.column(1), row)?,
+            ));
+        }
+    }
+    actual.sort();
+    assert_eq!(vec!["1|10", "100|1", "2|20", "200|2"], actual);
+
+    // The columns don't match.
+    let result = PlanParser::create(ctx.clone()).build_from_sql("insert into default.t values (1)");
+    assert_eq!(
+        "Insert values has 1 columns, but 2 columns are expected",
+        format!("{}", result.err().unwrap())
+    );
+    let result = PlanParser::create(ctx.clone())
+        .build_from_sql("insert into default.t(a) select number, number from numbers_mt(3)");
+    assert_eq!(
+        "Insert select has 2 columns, but 1 columns are expected",
+        format!("{}", result.err().unwrap())
+    );
+
+    // The NOT NULL column rejects the NULLs.
+    let plan =
+        PlanParser::create(ctx.clone()).build_from_sql("insert into default.t values (3, NULL)")?;
+    let result = InterpreterFactory::get(ctx.clone(), plan)?.execute().await;
+    assert_eq!(
+        "Column b is NOT NULL, but NULL is inserted",
+        format!("{}", result.err().unwrap())
+    );
+    let plan =
+        PlanParser::create(ctx.clone()).build_from_sql("insert into default.t(a) values (3)")?;
+    let result = InterpreterFactory::get(ctx.clone(), plan)?.execute().await;
+    assert_eq!(
+        "Column b is NOT NULL, but NULL is inserted",
+        format!("{}", result.err().unwrap())
+    );
+
+    // The system table is read only.
+    let plan = PlanParser::create(ctx.clone())
+        .build_from_sql("insert into system.settings(name) values ('a')")?;
+    let result = InterpreterFactory::get(ctx.clone(), plan)?.execute().await;
+    assert_eq!(
+        "Table engine SystemSettings does not support INSERT",
+        format!("{}", result.err().unwrap())
+    );
+
+    std::fs::remove_file(file)?;
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_insert_into_failed_interpreter() -> anyhow::Result<()> {
+    use common_datavalues::*;
+    use futures::TryStreamExt;
+    use pretty_assertions::assert_eq;
+
+    use crate::interpreters::*;
+    use crate::sql::*;
+
+    let ctx = crate::tests::try_create_context()?;
+    ctx.set_max_block_size(2)?;
+    let file = std::env::temp_dir().join(format!("{}.csv", uuid::Uuid::new_v4()));
+
+    let plan = PlanParser::create(ctx.clone()).build_from_sql(&format!(
+        "create table default.t(a bigint null, b int) Engine = CSV location = '{}'",
+        file.display()
+    ))?;
+    InterpreterFactory::get(ctx.clone(), plan)?
+        .execute()
+        .await?;
+    let plan = PlanParser::create(ctx.clone()).build_from_sql("insert into t values (1, 10)")?;
+    InterpreterFactory::get(ctx.clone(), plan)?
+        .execute()
+        .await?;
+
+    // The value can't be casted is an error, rather than a NULL of the nullable column.
+    let plan = PlanParser::create(ctx.clone()).build_from_sql("insert into t values ('x', 20)")?;
+    let result = InterpreterFactory::get(ctx.clone(), plan)?.execute().await;
+    assert_eq!(
+        "Function Error: Cannot cast x to Int64",
+        format!("{}", result.err().unwrap())
+    );
+
+    // The NULL of the later block fails the insert, the rows of the earlier blocks are not
+    // appended either.
+    let plan = PlanParser::create(ctx.clone())
+        .build_from_sql("insert into t select number, nullif(number, 5) from numbers_mt(6)")?;
+    let result = InterpreterFactory::get(ctx.clone(), plan)?.execute().await;
+    assert_eq!(
+        "Column b is NOT NULL, but NULL is inserted",
+        format!("{}", result.err().unwrap())
+    );
+
+    let plan = PlanParser::create(ctx.clone()).build_from_sql("select a, b from t")?;
+    let stream = InterpreterFactory::get(ctx.clone(), plan)?
+        .execute()
+        .await?;
+    let blocks = stream.try_collect::<Vec<_>>().await?;
+    let mut actual = vec![];
+    for block in blocks {
+        for row in 0..block.num_rows() {
+            actual.push(format!(
+                "{:?}|{:?}",
+                DataValue::try_from_array(block.column(0), row)?,
+                DataValue::try_from_array(block.column(1), row)?,
+            ));
+        }
+    }
+    assert_eq!(vec!["1|10"], actual);
+
+    std::fs::remove_file(file)?;
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_insert_into_temporal_interpreter() -> anyhow::Result<()> {
+    use common_datavalues::*;
+    use futures::TryStreamExt;
+    use pretty_assertions::assert_eq;
+
+    use crate::interpreters::*;
+    use crate::sql::*;
+
+    let ctx = crate::tests::try_create_context()?;
+    let file = std::env::temp_dir().join(format!("{}.csv", uuid::Uuid::new_v4()));
+
+    let plan = PlanParser::create(ctx.clone()).build_from_sql(&format!(
+        "create table default.t(d date, dt datetime, ts timestamp) Engine = CSV location = '{}'",
+        file.display()
+    ))?;
+    InterpreterFactory::get(ctx.clone(), plan)?
+        .execute()
+        .await?;
+
+    // The strings are parsed, a time without the time zone is in UTC.
+    let plan = PlanParser::create(ctx.clone()).build_from_sql(
+        "insert into default.t values \
+         ('2021-01-01', '2021-01-01 12:30:00', '2021-01-01 12:30:00.5'), \
+         ('2021-03-01', '2021-03-01T08:00:00+08:00', '2021-03-01')",
+    )?;
+    InterpreterFactory::get(ctx.clone(), plan)?
+        .execute()
+        .await?;
+
+    let plan = PlanParser::create(ctx.clone())
+        .build_from_sql("select d, dt, ts from default.t where d > '2021-02-01'")?;
+    let stream = InterpreterFactory::get(ctx.clone(), plan)?
+        .execute()
+        .await?;
+    let blocks = stream.try_collect::<Vec<_>>().await?;
+    let mut actual = vec![];
+    for block in blocks {
+        for row in 0..block.num_rows() {
+            actual.push(format!(
+                "{:?}|{:?}|{:?}",
+                DataValue::try_from_array(block.column(0), row)?,
+                DataValue::try_from_array(block.column(1), row)?,
+                DataValue::try_from_array(block.column(2), row)?,
+            ));
+        }
+    }
+    assert_eq!(
+        vec!["2021-03-01|2021-03-01 00:00:00|2021-03-01 00:00:00.000000"],
+        actual
+    );
+
+    // The invalid value is an error.
+    let plan = PlanParser::create(ctx.clone())
+        .build_from_sql("insert into default.t(d) values ('2021-02-30')")?;
+    let result = InterpreterFactory::get(ctx.clone(), plan)?.execute().await;
+    assert_eq!(
+        "DataValue Error: Cannot parse '2021-02-30' as Date",
+        format!("{}", result.err().unwrap())
+    );
+
+    std::fs::remove_file(file)?;
+    Ok(())
+}
@@ -55,3 +55,227 @@ async fn test_select_interpreter() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_select_interpreter_with_group_by() -> anyhow::Result<()> {
+    use common_datavalues::*;
+    use common_planners::*;
+    use futures::stream::StreamExt;
+    use pretty_assertions::assert_eq;
+
+    use crate::interpreters::*;
+    use crate::sql::*;
+
+    let ctx = crate::tests::try_create_context()?;
+
+    if let PlanNode::Select(plan) = PlanParser::create(ctx.clone()).build_from_sql(
+        "select number%3 as k, count(number), max(number) from numbers_mt(100) group by k",
+    )? {
+        let executor = SelectInterpreter::try_create(ctx.clone(), plan)?;
+        let mut stream = executor.execute().await?;
+
+        let mut actual = vec![];
+        while let Some(block) = stream.next().await {
+            let block = block?;
+            assert_eq!(3, block.num_columns());
+            for row in 0..block.num_rows() {
+                actual.push(format!(
+                    "{:?}|{:?}|{:?}",
+                    DataValue::try_from_array(block.column(0), row)?,
+                    DataValue::try_from_array(block.column(1), row)?,
+                    DataValue::try_from_array(block.column(2), row)?,
+                ));
+            }
+        }
+        actual.sort();
+        assert_eq!(vec!["0|34|99", "1|33|97", "2|33|98"], actual);
+    } else {
+        assert!(false)
+    }
+
+    // The non-aggregate column must be in the group by.
+    let result = PlanParser::create(ctx.clone())
+        .build_from_sql("select number, count(number) from numbers_mt(100) group by number%3");
+    assert_eq!(
+        "Column number is not under aggregate function and not in GROUP BY",
+        format!("{}", result.err().unwrap())
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_select_interpreter_with_having() -> anyhow::Result<()> {
+    use common_datavalues::*;
+    use common_planners::*;
+    use futures::stream::StreamExt;
+    use pretty_assertions::assert_eq;
+
+    use crate::interpreters::*;
+    use crate::sql::*;
+
+    let ctx = crate::tests::try_create_context()?;
+
+    // The count() is not in the projection.
+    if let PlanNode::Select(plan) = PlanParser::create(ctx.clone()).build_from_sql(
+        "select number%3 as k, max(number) from numbers_mt(100) group by k having count() = 33 and k > 1",
+    )? {
+        let executor = SelectInterpreter::try_create(ctx.clone(), plan)?;
+        let mut stream = executor.execute().await?;
+
+        let mut actual = vec![];
+        while let Some(block) = stream.next().await {
+            let block = block?;
+            assert_eq!(2, block.num_columns());
+            for row in 0..block.num_rows() {
+                actual.push(format!(
+                    "{:?}|{:?}",
+                    DataValue::try_from_array(block.column(0), row)?,
+                    DataValue::try_from_array(block.column(1), row)?,
+                ));
+            }
+        }
+        assert_eq!(vec!["2|98"], actual);
+    } else {
+        assert!(false)
+    }
+
+    // The non-aggregate column in HAVING must be in the group by.
+    let result = PlanParser::create(ctx.clone()).build_from_sql(
+        "select number%3 as k, count(number) from numbers_mt(100) group by k having number > 1",
+    );
+    assert_eq!(
+        "Column number is not under aggregate function and not in GROUP BY",
+        format!("{}", result.err().unwrap())
+    );
+
+    let result = PlanParser::create(ctx.clone())
+        .build_from_sql("select number from numbers_mt(100) having number > 1");
+    assert_eq!(
+        "HAVING clause requires GROUP BY or aggregate functions",
+        format!("{}", result.err().unwrap())
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_select_interpreter_with_order_by() -> anyhow::Result<()> {
+    use common_datavalues::*;
+    use common_planners::*;
+    use futures::stream::StreamExt;
+    use pretty_assertions::assert_eq;
+
+    use crate::interpreters::*;
+    use crate::sql::*;
+
+    // The ORDER BY columns are not selected, they are dropped after the sort.
+    let tests = vec![
+        (
+            "select number + 1 as n from numbers_mt(6) order by number % 3, number desc",
+            vec!["4", "1", "5", "2", "6", "3"],
+        ),
+        (
+            "select number % 3 as k from numbers_mt(11) group by k order by count(), k",
+            vec!["2", "0", "1"],
+        ),
+    ];
+
+    for (query, expect) in tests {
+        let ctx = crate::tests::try_create_context()?;
+        if let PlanNode::Select(plan) = PlanParser::create(ctx.clone()).build_from_sql(query)? {
+            let executor = SelectInterpreter::try_create(ctx.clone(), plan)?;
+            let mut stream = executor.execute().await?;
+
+            let mut actual = vec![];
+            while let Some(block) = stream.next().await {
+                let block = block?;
+                assert_eq!(1, block.num_columns(), "{}", query);
+                for row in 0..block.num_rows() {
+                    actual.push(format!(
+                        "{:?}",
+                        DataValue::try_from_array(block.column(0), row)?
+                    ));
+                }
+            }
+            assert_eq!(expect, actual, "{}", query);
+        } else {
+            assert!(false)
+        }
+    }
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_select_interpreter_with_join() -> anyhow::Result<()> {
+    use common_datavalues::*;
+    use common_planners::*;
+    use futures::stream::StreamExt;
+    use pretty_assertions::assert_eq;
+
+    use crate::interpreters::*;
+    use crate::sql::*;
+
+    #[allow(dead_code)]
+    struct Test {
+        name: &'static str,
+        query: &'static str,
+        expect: Vec<&'static str>,
+    }
+
+    let tests = vec![
+        Test {
+            name: "inner-join-passed",
+            query: "select a.number, b.number from numbers_mt(5) as a join numbers_mt(3) as b on a.number = b.number",
+            expect: vec!["0|0", "1|1", "2|2"],
+        },
+        Test {
+            name: "inner-join-build-left-with-condition-passed",
+            query: "select a.number, b.number from numbers_mt(3) as a join numbers_mt(5) as b on b.number = a.number and b.number > 0",
+            expect: vec!["1|1", "2|2"],
+        },
+        Test {
+            name: "left-join-passed",
+            query: "select a.number, b.number from numbers_mt(5) as a left join numbers_mt(3) as b using (number)",
+            expect: vec!["0|0", "1|1", "2|2", "3|NULL", "4|NULL"],
+        },
+    ];
+
+    for test in tests {
+        let ctx = crate::tests::try_create_context()?;
+        if let PlanNode::Select(plan) =
+            PlanParser::create(ctx.clone()).build_from_sql(test.query)?
+        {
+            let executor = SelectInterpreter::try_create(ctx.clone(), plan)?;
+            let mut stream = executor.execute().await?;
+
+            let mut actual = vec![];
+            while let Some(block) = stream.next().await {
+                let block = block?;
+                assert_eq!(2, block.num_columns());
+                for row in 0..block.num_rows() {
+                    actual.push(format!(
+                        "{:?}|{:?}",
+                        DataValue::try_from_array(block.column(0), row)?,
+                        DataValue::try_from_array(block.column(1), row)?,
+                    ));
+                }
+            }
+            actual.sort();
+            assert_eq!(test.expect, actual, "{}", test.name);
+        } else {
+            assert!(false)
+        }
+    }
+
+    let ctx = crate::tests::try_create_context()?;
+    let result = PlanParser::create(ctx.clone()).build_from_sql(
+        "select * from numbers_mt(5) as a left join numbers_mt(3) as b on a.number > b.number",
+    );
+    assert_eq!(
+        "JOIN must have at least one equality condition between the two sides",
+        format!("{}", result.err().unwrap())
+    );
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::sync::Arc;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use common_planners::TruncateTablePlan;
+use common_streams::{DataBlockStream, SendableDataBlockStream};
+
+use crate::interpreters::IInterpreter;
+use crate::sessions::FuseQueryContextRef;
+
+pub struct TruncateTableInterpreter {
+    ctx: FuseQueryContextRef,
+    plan: TruncateTablePlan,
+}
+
+impl TruncateTableInterpreter {
+    pub fn try_create(
+        ctx: FuseQueryContextRef,
+        plan: TruncateTablePlan,
+    ) -> Result<Arc<dyn IInterpreter>> {
+        Ok(Arc::new(TruncateTableInterpreter { ctx, plan }))
+    }
+}
+
+#[async_trait]
+impl IInterpreter for TruncateTableInterpreter {
+    fn name(&self) -> &str {
+        "TruncateTableInterpreter"
+    }
+
+    async fn execute(&self) -> Result<SendableDataBlockStream> {
+        let table = self
+            .ctx
+            .get_table(self.plan.db.as_str(), self.plan.table.as_str())?;
+        table.truncate(self.ctx.clone()).await?;
+
+        Ok(Box::pin(DataBlockStream::create(
+            self.plan.schema.clone(),
+            None,
+            vec![],
+        )))
+    }
+}
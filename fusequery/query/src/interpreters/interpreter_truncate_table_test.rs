@@ -0,0 +1,76 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_truncate_table_interpreter() -> anyhow::Result<()> {
+    use common_planners::*;
+    use futures::TryStreamExt;
+    use pretty_assertions::assert_eq;
+
+    use crate::interpreters::*;
+    use crate::sql::*;
+
+    let ctx = crate::tests::try_create_context()?;
+
+    for sql in vec![
+        "create table default.a(a bigint, b int) Engine = Memory",
+        "insert into default.a values (1, 10), (2, 20)",
+    ] {
+        let plan = PlanParser::create(ctx.clone()).build_from_sql(sql)?;
+        InterpreterFactory::get(ctx.clone(), plan)?
+            .execute()
+            .await?;
+    }
+
+    if let PlanNode::TruncateTable(plan) =
+        PlanParser::create(ctx.clone()).build_from_sql("truncate table default.a")?
+    {
+        let executor = TruncateTableInterpreter::try_create(ctx.clone(), plan)?;
+        assert_eq!(executor.name(), "TruncateTableInterpreter");
+        executor.execute().await?;
+    } else {
+        assert!(false)
+    }
+
+    // The table is kept but empty.
+    let plan = PlanParser::create(ctx.clone()).build_from_sql("select * from default.a")?;
+    let stream = InterpreterFactory::get(ctx.clone(), plan)?
+        .execute()
+        .await?;
+    let blocks = stream.try_collect::<Vec<_>>().await?;
+    let rows: usize = blocks.iter().map(|block| block.num_rows()).sum();
+    assert_eq!(0, rows);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_truncate_external_table_interpreter() -> anyhow::Result<()> {
+    use pretty_assertions::assert_eq;
+
+    use crate::interpreters::*;
+    use crate::sql::*;
+
+    let ctx = crate::tests::try_create_context()?;
+    let file = std::env::temp_dir().join(format!("{}.csv", uuid::Uuid::new_v4()));
+    std::fs::write(&file, "1,10\n2,20\n")?;
+
+    let plan = PlanParser::create(ctx.clone()).build_from_sql(&format!(
+        "create table default.t(a bigint, b int) Engine = CSV location = '{}'",
+        file.display()
+    ))?;
+    InterpreterFactory::get(ctx.clone(), plan)?
+        .execute()
+        .await?;
+
+    // The external file is not owned by the table, it is kept as it is.
+    let plan = PlanParser::create(ctx.clone()).build_from_sql("truncate table default.t")?;
+    let result = InterpreterFactory::get(ctx.clone(), plan)?.execute().await;
+    let actual = format!("{}", result.err().unwrap());
+    assert_eq!("Table engine CSV does not support TRUNCATE", actual);
+    assert_eq!("1,10\n2,20\n", std::fs::read_to_string(&file)?);
+
+    std::fs::remove_file(&file)?;
+    Ok(())
+}
@@ -2,25 +2,45 @@
 //
 // SPDX-License-Identifier: Apache-2.0.
 
+#[cfg(test)]
+mod interpreter_create_database_test;
 #[cfg(test)]
 mod interpreter_create_table_test;
 #[cfg(test)]
+mod interpreter_drop_database_test;
+#[cfg(test)]
+mod interpreter_drop_table_test;
+#[cfg(test)]
 mod interpreter_explain_test;
 #[cfg(test)]
+mod interpreter_insert_into_test;
+#[cfg(test)]
 mod interpreter_select_test;
 #[cfg(test)]
 mod interpreter_setting_test;
+#[cfg(test)]
+mod interpreter_truncate_table_test;
 
 mod interpreter;
+mod interpreter_create_database;
 mod interpreter_create_table;
+mod interpreter_drop_database;
+mod interpreter_drop_table;
 mod interpreter_explain;
 mod interpreter_factory;
+mod interpreter_insert_into;
 mod interpreter_select;
 mod interpreter_setting;
+mod interpreter_truncate_table;
 
 pub use interpreter::IInterpreter;
+pub use interpreter_create_database::CreateDatabaseInterpreter;
 pub use interpreter_create_table::CreateTableInterpreter;
+pub use interpreter_drop_database::DropDatabaseInterpreter;
+pub use interpreter_drop_table::DropTableInterpreter;
 pub use interpreter_explain::ExplainInterpreter;
 pub use interpreter_factory::InterpreterFactory;
+pub use interpreter_insert_into::InsertIntoInterpreter;
 pub use interpreter_select::SelectInterpreter;
 pub use interpreter_setting::SettingInterpreter;
+pub use interpreter_truncate_table::TruncateTableInterpreter;
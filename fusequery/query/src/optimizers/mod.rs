@@ -4,11 +4,14 @@
 
 mod optimizer_filter_push_down_test;
 mod optimizer_limit_push_down_test;
+mod optimizer_scan_push_down_test;
 
 mod optimizer;
 mod optimizer_filter_push_down;
 mod optimizer_limit_push_down;
+mod optimizer_scan_push_down;
 
 pub use optimizer::{IOptimizer, Optimizer};
 pub use optimizer_filter_push_down::FilterPushDownOptimizer;
 pub use optimizer_limit_push_down::LimitPushDownOptimizer;
+pub use optimizer_scan_push_down::ScanPushDownOptimizer;
@@ -7,7 +7,7 @@ use std::collections::HashMap;
 use anyhow::Result;
 use common_planners::{ExpressionPlan, PlanNode};
 
-use crate::optimizers::FilterPushDownOptimizer;
+use crate::optimizers::{FilterPushDownOptimizer, ScanPushDownOptimizer};
 use crate::sessions::FuseQueryContextRef;
 
 pub trait IOptimizer {
@@ -21,8 +21,10 @@ pub struct Optimizer {
 
 impl Optimizer {
     pub fn create(ctx: FuseQueryContextRef) -> Self {
-        let optimizers: Vec<Box<dyn IOptimizer>> =
-            vec![Box::new(FilterPushDownOptimizer::create(ctx))];
+        let optimizers: Vec<Box<dyn IOptimizer>> = vec![
+            Box::new(FilterPushDownOptimizer::create(ctx.clone())),
+            Box::new(ScanPushDownOptimizer::create(ctx)),
+        ];
         Optimizer { optimizers }
     }
 
@@ -51,6 +53,8 @@ impl Optimizer {
             PlanNode::AggregatorPartial(v) => Self::projections_to_map(v.input.as_ref(), map)?,
             PlanNode::AggregatorFinal(v) => Self::projections_to_map(v.input.as_ref(), map)?,
             PlanNode::Filter(v) => Self::projections_to_map(v.input.as_ref(), map)?,
+            PlanNode::Having(v) => Self::projections_to_map(v.input.as_ref(), map)?,
+            PlanNode::Sort(v) => Self::projections_to_map(v.input.as_ref(), map)?,
             PlanNode::Limit(v) => Self::projections_to_map(v.input.as_ref(), map)?,
             PlanNode::Explain(v) => Self::projections_to_map(v.input.as_ref(), map)?,
             PlanNode::Select(v) => Self::projections_to_map(v.input.as_ref(), map)?,
@@ -73,8 +77,21 @@ impl Optimizer {
             ExpressionPlan::BinaryExpression { left, right, .. } => {
                 vec![left.as_ref().clone(), right.as_ref().clone()]
             }
+            ExpressionPlan::UnaryExpression { expr, .. } => vec![expr.as_ref().clone()],
+            ExpressionPlan::InList { expr, list, .. } => {
+                [vec![expr.as_ref().clone()], list.clone()].concat()
+            }
+            ExpressionPlan::Between {
+                expr, low, high, ..
+            } => vec![
+                expr.as_ref().clone(),
+                low.as_ref().clone(),
+                high.as_ref().clone(),
+            ],
             ExpressionPlan::Function { args, .. } => args.clone(),
+            ExpressionPlan::Cast { expr, .. } => vec![expr.as_ref().clone()],
             ExpressionPlan::Wildcard => vec![],
+            ExpressionPlan::Sort { expr, .. } => vec![expr.as_ref().clone()],
         })
     }
 
@@ -93,10 +110,36 @@ impl Optimizer {
                 op: op.clone(),
                 right: Box::new(expressions[1].clone()),
             },
+            ExpressionPlan::UnaryExpression { op, .. } => ExpressionPlan::UnaryExpression {
+                op: op.clone(),
+                expr: Box::new(expressions[0].clone()),
+            },
+            ExpressionPlan::InList { negated, .. } => ExpressionPlan::InList {
+                expr: Box::new(expressions[0].clone()),
+                list: expressions[1..].to_vec(),
+                negated: *negated,
+            },
+            ExpressionPlan::Between { negated, .. } => ExpressionPlan::Between {
+                expr: Box::new(expressions[0].clone()),
+                low: Box::new(expressions[1].clone()),
+                high: Box::new(expressions[2].clone()),
+                negated: *negated,
+            },
             ExpressionPlan::Function { op, .. } => ExpressionPlan::Function {
                 op: op.clone(),
                 args: expressions.to_vec(),
             },
+            ExpressionPlan::Cast { data_type, .. } => ExpressionPlan::Cast {
+                expr: Box::new(expressions[0].clone()),
+                data_type: data_type.clone(),
+            },
+            ExpressionPlan::Sort {
+                asc, nulls_first, ..
+            } => ExpressionPlan::Sort {
+                expr: Box::new(expressions[0].clone()),
+                asc: *asc,
+                nulls_first: *nulls_first,
+            },
             other => other.clone(),
         }
     }
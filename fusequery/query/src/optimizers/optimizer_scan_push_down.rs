@@ -0,0 +1,113 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use anyhow::Result;
+use common_datavalues::DataSchema;
+use common_planners::{EmptyPlan, ExpressionPlan, PlanNode};
+
+use crate::optimizers::{IOptimizer, Optimizer};
+use crate::sessions::FuseQueryContextRef;
+
+/// Push the referenced columns and the source filters down to the read source,
+/// so the table can skip the columns and the data not needed.
+pub struct ScanPushDownOptimizer {}
+
+impl ScanPushDownOptimizer {
+    pub fn create(_ctx: FuseQueryContextRef) -> Self {
+        ScanPushDownOptimizer {}
+    }
+}
+
+/// The columns referenced by the expression, None if all the columns are referenced.
+fn expr_columns(expr: &ExpressionPlan, columns: &mut Option<HashSet<String>>) -> Result<()> {
+    match expr {
+        ExpressionPlan::Wildcard => *columns = None,
+        ExpressionPlan::Column(name) => {
+            if let Some(columns) = columns {
+                columns.insert(name.clone());
+            }
+        }
+        _ => {
+            for child in Optimizer::expression_plan_children(expr)? {
+                expr_columns(&child, columns)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+impl IOptimizer for ScanPushDownOptimizer {
+    fn name(&self) -> &str {
+        "ScanPushDown"
+    }
+
+    fn optimize(&mut self, plan: &PlanNode) -> Result<PlanNode> {
+        let mut columns = Some(HashSet::new());
+        let mut filters = vec![];
+        let mut has_join = false;
+        plan.walk_preorder(|node| {
+            let exprs = match node {
+                PlanNode::Join(_) => {
+                    has_join = true;
+                    return Ok(false);
+                }
+                PlanNode::Projection(v) => v.expr.clone(),
+                PlanNode::AggregatorPartial(v) => [&v.group_expr[..], &v.aggr_expr[..]].concat(),
+                PlanNode::AggregatorFinal(v) => [&v.group_expr[..], &v.aggr_expr[..]].concat(),
+                PlanNode::Having(v) => vec![v.predicate.clone()],
+                PlanNode::Sort(v) => v.order_by.clone(),
+                PlanNode::Filter(v) => {
+                    if let PlanNode::ReadSource(_) = v.input.as_ref() {
+                        filters.push(v.predicate.clone());
+                    }
+                    vec![v.predicate.clone()]
+                }
+                _ => vec![],
+            };
+            for expr in &exprs {
+                expr_columns(expr, &mut columns)?;
+            }
+            Ok(true)
+        })?;
+
+        // The joined sources are read as they are.
+        if has_join {
+            return Ok(plan.clone());
+        }
+
+        let mut rewritten_node = PlanNode::Empty(EmptyPlan {
+            schema: Arc::new(DataSchema::empty()),
+        });
+        plan.walk_postorder(|node| {
+            if let PlanNode::ReadSource(source) = node {
+                let mut source = source.clone();
+                let fields = source.schema.fields();
+                if let Some(columns) = &columns {
+                    let mut projection: Vec<usize> = (0..fields.len())
+                        .filter(|i| columns.contains(fields[*i].name()))
+                        .collect();
+                    // At least one column is read to keep the rows, such as count().
+                    if projection.is_empty() && !fields.is_empty() {
+                        projection.push(0);
+                    }
+                    if projection.len() < fields.len() {
+                        source.push_downs.projection = Some(projection);
+                    }
+                }
+                source.push_downs.filters = filters.clone();
+                rewritten_node = PlanNode::ReadSource(source);
+            } else {
+                let mut clone_node = node.clone();
+                clone_node.set_input(&rewritten_node)?;
+                rewritten_node = clone_node;
+            }
+            Ok(true)
+        })?;
+
+        Ok(rewritten_node)
+    }
+}
@@ -0,0 +1,53 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_scan_push_down_optimizer() -> anyhow::Result<()> {
+    use common_planners::*;
+    use pretty_assertions::assert_eq;
+
+    use crate::interpreters::*;
+    use crate::optimizers::*;
+    use crate::sql::*;
+
+    let ctx = crate::tests::try_create_context()?;
+    let plan = PlanParser::create(ctx.clone())
+        .build_from_sql("create table default.t(a bigint, b bigint, c bigint) Engine = Memory")?;
+    InterpreterFactory::get(ctx.clone(), plan)?
+        .execute()
+        .await?;
+
+    fn push_downs(plan: &PlanNode) -> anyhow::Result<Extras> {
+        let mut extras = Extras::default();
+        plan.walk_preorder(|node| {
+            if let PlanNode::ReadSource(v) = node {
+                extras = v.push_downs.clone();
+            }
+            Ok(true)
+        })?;
+        Ok(extras)
+    }
+
+    let tests = vec![
+        ("select a from t where b > 1", Some(vec![0, 1]), 1),
+        ("select a, b, c from t", None, 0),
+        ("select * from t where a > 1", None, 1),
+        ("select count() from t", Some(vec![0]), 0),
+        (
+            "select c, sum(b) from t group by c having sum(b) > 1",
+            Some(vec![1, 2]),
+            0,
+        ),
+    ];
+    for (sql, projection, filters) in tests {
+        let plan = PlanParser::create(ctx.clone()).build_from_sql(sql)?;
+        let mut scan_push_down = ScanPushDownOptimizer::create(ctx.clone());
+        let optimized = scan_push_down.optimize(&plan)?;
+        let actual = push_downs(&optimized)?;
+        assert_eq!(projection, actual.projection, "{}", sql);
+        assert_eq!(filters, actual.filters.len(), "{}", sql);
+    }
+
+    Ok(())
+}
@@ -10,8 +10,9 @@ use log::info;
 
 use crate::pipelines::processors::Pipeline;
 use crate::pipelines::transforms::{
-    AggregatorFinalTransform, AggregatorPartialTransform, FilterTransform, LimitTransform,
-    ProjectionTransform, RemoteTransform, SourceTransform,
+    AggregatorFinalTransform, AggregatorPartialTransform, FilterTransform, HashJoinTransform,
+    LimitTransform, ProjectionTransform, RemoteTransform, SortMergeTransform, SortPartialTransform,
+    SourceTransform,
 };
 use crate::planners::PlanScheduler;
 use crate::sessions::FuseQueryContextRef;
@@ -72,6 +73,7 @@ impl PipelineBuilder {
                     Ok(Box::new(AggregatorPartialTransform::try_create(
                         plan.schema(),
                         plan.aggr_expr.clone(),
+                        plan.group_expr.clone(),
                     )?))
                 })?;
                 Ok(true)
@@ -82,6 +84,7 @@ impl PipelineBuilder {
                     Ok(Box::new(AggregatorFinalTransform::try_create(
                         plan.schema(),
                         plan.aggr_expr.clone(),
+                        plan.group_expr.clone(),
                     )?))
                 })?;
                 Ok(true)
@@ -94,6 +97,56 @@ impl PipelineBuilder {
                 })?;
                 Ok(true)
             }
+            PlanNode::Having(plan) => {
+                pipeline.add_simple_transform(|| {
+                    Ok(Box::new(FilterTransform::try_create(
+                        plan.predicate.clone(),
+                    )?))
+                })?;
+                Ok(true)
+            }
+            PlanNode::Join(plan) => {
+                // The build side is loaded once into the hash table, every probe stream shares it.
+                let hash_table = HashJoinTransform::create_hash_table();
+                let probe_schema = plan.input.schema();
+                // The shuffled join exchanges the blocks of all the streams at once.
+                if plan.shuffle.is_some() {
+                    pipeline.merge_processor()?;
+                }
+                pipeline.add_simple_transform(|| {
+                    Ok(Box::new(HashJoinTransform::try_create(
+                        self.ctx.clone(),
+                        plan.schema(),
+                        plan.join_type,
+                        probe_schema.clone(),
+                        plan.build.as_ref().clone(),
+                        plan.probe_keys.clone(),
+                        plan.build_keys.clone(),
+                        plan.shuffle.clone(),
+                        hash_table.clone(),
+                    )?))
+                })?;
+                Ok(true)
+            }
+            PlanNode::Sort(plan) => {
+                // Sort every stream in parallel, then merge the sorted streams into one.
+                pipeline.add_simple_transform(|| {
+                    Ok(Box::new(SortPartialTransform::try_create(
+                        plan.schema(),
+                        plan.order_by.clone(),
+                        plan.limit,
+                    )?))
+                })?;
+                pipeline.merge_processor()?;
+                pipeline.add_simple_transform(|| {
+                    Ok(Box::new(SortMergeTransform::try_create(
+                        plan.schema(),
+                        plan.order_by.clone(),
+                        plan.limit,
+                    )?))
+                })?;
+                Ok(true)
+            }
             PlanNode::Limit(plan) => {
                 pipeline.merge_processor()?;
                 pipeline
@@ -114,11 +167,7 @@ impl PipelineBuilder {
                 };
 
                 for _i in 0..workers {
-                    let source = SourceTransform::try_create(
-                        self.ctx.clone(),
-                        plan.db.as_str(),
-                        plan.table.as_str(),
-                    )?;
+                    let source = SourceTransform::try_create(self.ctx.clone(), plan.clone())?;
                     pipeline.add_source(Arc::new(source))?;
                 }
                 Ok(true)
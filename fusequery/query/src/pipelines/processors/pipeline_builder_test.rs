@@ -26,6 +26,29 @@ async fn test_local_pipeline_build() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_local_pipeline_build_with_order_by() -> anyhow::Result<()> {
+    use pretty_assertions::assert_eq;
+
+    use crate::pipelines::processors::*;
+    use crate::sql::*;
+
+    let ctx = crate::tests::try_create_context()?;
+
+    let plan = PlanParser::create(ctx.clone())
+        .build_from_sql("select number from numbers_mt(80000) order by number desc limit 3")?;
+    let pipeline = PipelineBuilder::create(ctx, plan).build()?;
+    let expect = "LimitTransform × 1 processor\
+    \n  SortMergeTransform × 1 processor\
+    \n    Merge (SortPartialTransform × 8 processors) to (SortMergeTransform × 1)\
+    \n      SortPartialTransform × 8 processors\
+    \n        ProjectionTransform × 8 processors\
+    \n          SourceTransform × 8 processors";
+    let actual = format!("{:?}", pipeline);
+    assert_eq!(expect, actual);
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_distributed_pipeline_build() -> anyhow::Result<()> {
     use pretty_assertions::assert_eq;
@@ -7,26 +7,36 @@ mod transform_aggregator_test;
 #[cfg(test)]
 mod transform_filter_test;
 #[cfg(test)]
+mod transform_hash_join_test;
+#[cfg(test)]
 mod transform_limit_test;
 #[cfg(test)]
 mod transform_projection_test;
 #[cfg(test)]
 mod transform_remote_test;
 #[cfg(test)]
+mod transform_sort_test;
+#[cfg(test)]
 mod transform_source_test;
 
 mod transform_aggregator_final;
 mod transform_aggregator_partial;
 mod transform_filter;
+mod transform_hash_join;
 mod transform_limit;
 mod transform_projection;
 mod transform_remote;
+mod transform_sort_merge;
+mod transform_sort_partial;
 mod transform_source;
 
 pub use transform_aggregator_final::AggregatorFinalTransform;
 pub use transform_aggregator_partial::AggregatorPartialTransform;
 pub use transform_filter::FilterTransform;
+pub use transform_hash_join::HashJoinTransform;
 pub use transform_limit::LimitTransform;
 pub use transform_projection::ProjectionTransform;
 pub use transform_remote::RemoteTransform;
+pub use transform_sort_merge::SortMergeTransform;
+pub use transform_sort_partial::{get_sort_descriptions, SortPartialTransform};
 pub use transform_source::SourceTransform;
@@ -6,37 +6,111 @@ use std::any::Any;
 use std::sync::Arc;
 use std::time::Instant;
 
-use anyhow::Result;
+use anyhow::{bail, Result};
 use async_trait::async_trait;
 use common_datablocks::DataBlock;
-use common_datavalues::{DataSchemaRef, DataValue};
+use common_datavalues::{
+    data_array_cast, DataField, DataSchema, DataSchemaRef, DataType, DataValue,
+};
 use common_functions::IFunction;
 use common_planners::ExpressionPlan;
 use common_streams::{DataBlockStream, SendableDataBlockStream};
 use futures::stream::StreamExt;
+use indexmap::IndexMap;
 use log::info;
 
+use crate::optimizers::Optimizer;
 use crate::pipelines::processors::{EmptyProcessor, IProcessor};
 
 pub struct AggregatorFinalTransform {
     funcs: Vec<Box<dyn IFunction>>,
+    // The expressions without aggregator are evaluated on the group by values, the group by
+    // expressions in them are rewritten to the columns of the values.
+    key_funcs: Vec<Option<Box<dyn IFunction>>>,
+    group_names: Vec<String>,
+    has_group_by: bool,
     schema: DataSchemaRef,
     input: Arc<dyn IProcessor>,
 }
 
 impl AggregatorFinalTransform {
-    pub fn try_create(schema: DataSchemaRef, exprs: Vec<ExpressionPlan>) -> Result<Self> {
+    pub fn try_create(
+        schema: DataSchemaRef,
+        exprs: Vec<ExpressionPlan>,
+        group_exprs: Vec<ExpressionPlan>,
+    ) -> Result<Self> {
+        let group_names = group_exprs
+            .iter()
+            .map(|expr| format!("{:?}", expr))
+            .collect::<Vec<_>>();
+
         let mut funcs = Vec::with_capacity(exprs.len());
+        let mut key_funcs = Vec::with_capacity(exprs.len());
         for expr in &exprs {
-            funcs.push(expr.to_function()?);
+            let func = expr.to_function()?;
+            key_funcs.push(match func.is_aggregator() {
+                true => None,
+                false => Some(Self::rewrite_group_expr(expr, &group_names)?.to_function()?),
+            });
+            funcs.push(func);
         }
 
         Ok(AggregatorFinalTransform {
             funcs,
+            key_funcs,
+            group_names,
+            has_group_by: !group_exprs.is_empty(),
             schema,
             input: Arc::new(EmptyProcessor::create()),
         })
     }
+
+    fn rewrite_group_expr(expr: &ExpressionPlan, group_names: &[String]) -> Result<ExpressionPlan> {
+        let name = format!("{:?}", expr);
+        if group_names.contains(&name) {
+            return Ok(ExpressionPlan::Column(name));
+        }
+
+        let children = Optimizer::expression_plan_children(expr)?
+            .iter()
+            .map(|child| Self::rewrite_group_expr(child, group_names))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Optimizer::rebuild_from_exprs(expr, &children))
+    }
+
+    /// The block of the group by values, deserialized from the group keys.
+    fn group_key_block<'a>(
+        &self,
+        group_keys: impl Iterator<Item = &'a String>,
+    ) -> Result<DataBlock> {
+        let mut rows = 0;
+        let mut columns = vec![Vec::new(); self.group_names.len()];
+        for group_key in group_keys {
+            rows += 1;
+            match serde_json::from_str::<DataValue>(group_key)? {
+                DataValue::Struct(values) if values.len() == columns.len() => {
+                    for (column, value) in columns.iter_mut().zip(values) {
+                        column.push(value);
+                    }
+                }
+                other => bail!("Aggregator final unexpected group key: {:?}", other),
+            }
+        }
+
+        let mut fields = Vec::with_capacity(columns.len());
+        let mut arrays = Vec::with_capacity(columns.len());
+        for (name, column) in self.group_names.iter().zip(&columns) {
+            let array = DataValue::try_into_data_array(column)?;
+            fields.push(DataField::new(name, array.data_type().clone(), true));
+            arrays.push(array);
+        }
+        // Without group by, a NULL column keeps the number of rows for the constants.
+        if arrays.is_empty() {
+            fields.push(DataField::new("", DataType::Null, true));
+            arrays.push(DataValue::Null.to_array(rows)?);
+        }
+        Ok(DataBlock::create(Arc::new(DataSchema::new(fields)), arrays))
+    }
 }
 
 #[async_trait]
@@ -59,18 +133,34 @@ impl IProcessor for AggregatorFinalTransform {
     }
 
     async fn execute(&self) -> Result<SendableDataBlockStream> {
-        let mut funcs = self.funcs.clone();
+        let mut groups: IndexMap<String, Vec<Box<dyn IFunction>>> = IndexMap::new();
         let mut stream = self.input.execute().await?;
 
         let start = Instant::now();
         while let Some(block) = stream.next().await {
             let block = block?;
-            for (i, func) in funcs.iter_mut().enumerate() {
-                if let DataValue::String(Some(ser)) = DataValue::try_from_array(block.column(0), i)?
+            for row in 0..block.num_rows() {
+                let group_key = match DataValue::try_from_array(block.column(0), row)? {
+                    DataValue::String(Some(group_key)) => group_key,
+                    other => bail!("Aggregator final unexpected group key: {:?}", other),
+                };
+                let funcs = groups
+                    .entry(group_key)
+                    .or_insert_with(|| self.funcs.clone());
+
+                if let DataValue::String(Some(ser)) =
+                    DataValue::try_from_array(block.column(1), row)?
                 {
                     let de: DataValue = serde_json::from_str(&ser)?;
-                    if let DataValue::Struct(states) = de {
-                        func.merge(&states)?;
+                    if let DataValue::Struct(func_states) = de {
+                        for (i, func) in funcs.iter_mut().enumerate() {
+                            if self.key_funcs[i].is_some() {
+                                continue;
+                            }
+                            if let DataValue::Struct(states) = &func_states[i] {
+                                func.merge(states)?;
+                            }
+                        }
                     }
                 }
             }
@@ -78,9 +168,39 @@ impl IProcessor for AggregatorFinalTransform {
         let delta = start.elapsed();
         info!("Aggregator final cost: {:?}", delta);
 
-        let mut final_results = Vec::with_capacity(funcs.len());
-        for func in &funcs {
-            final_results.push(func.merge_result()?.to_array(1)?);
+        if groups.is_empty() {
+            if self.has_group_by {
+                return Ok(Box::pin(DataBlockStream::create(
+                    self.schema.clone(),
+                    None,
+                    vec![],
+                )));
+            }
+            let group_key = serde_json::to_string(&DataValue::Struct(vec![]))?;
+            groups.insert(group_key, self.funcs.clone());
+        }
+
+        let key_block = self.group_key_block(groups.keys())?;
+        let mut final_results = Vec::with_capacity(self.funcs.len());
+        for i in 0..self.funcs.len() {
+            let array = match &self.key_funcs[i] {
+                Some(func) => func.eval(&key_block)?.to_array(groups.len())?,
+                None => {
+                    let mut values = Vec::with_capacity(groups.len());
+                    for funcs in groups.values() {
+                        values.push(funcs[i].merge_result()?);
+                    }
+                    DataValue::try_into_data_array(&values)?
+                }
+            };
+
+            // Make sure the result type is the same as the schema.
+            let data_type = self.schema.field(i).data_type();
+            if array.data_type() != data_type && array.data_type() != &DataType::Null {
+                final_results.push(data_array_cast(&array, data_type)?);
+            } else {
+                final_results.push(array);
+            }
         }
         let block = DataBlock::create(self.schema.clone(), final_results);
         Ok(Box::pin(DataBlockStream::create(
@@ -8,35 +8,76 @@ use std::time::Instant;
 
 use anyhow::Result;
 use async_trait::async_trait;
-use common_datablocks::DataBlock;
+use common_datablocks::{block_take_by_indices, DataBlock};
 use common_datavalues::{DataField, DataSchema, DataSchemaRef, DataType, DataValue, StringArray};
 use common_functions::IFunction;
 use common_planners::ExpressionPlan;
 use common_streams::{DataBlockStream, SendableDataBlockStream};
 use futures::stream::StreamExt;
+use indexmap::IndexMap;
 use log::info;
 
 use crate::pipelines::processors::{EmptyProcessor, IProcessor};
 
 pub struct AggregatorPartialTransform {
     funcs: Vec<Box<dyn IFunction>>,
+    group_funcs: Vec<Box<dyn IFunction>>,
     schema: DataSchemaRef,
     input: Arc<dyn IProcessor>,
 }
 
 impl AggregatorPartialTransform {
-    pub fn try_create(schema: DataSchemaRef, exprs: Vec<ExpressionPlan>) -> Result<Self> {
+    pub fn try_create(
+        schema: DataSchemaRef,
+        exprs: Vec<ExpressionPlan>,
+        group_exprs: Vec<ExpressionPlan>,
+    ) -> Result<Self> {
         let mut funcs = Vec::with_capacity(exprs.len());
         for expr in &exprs {
             funcs.push(expr.to_function()?);
         }
 
+        let mut group_funcs = Vec::with_capacity(group_exprs.len());
+        for expr in &group_exprs {
+            group_funcs.push(expr.to_function()?);
+        }
+
         Ok(AggregatorPartialTransform {
             funcs,
+            group_funcs,
             schema,
             input: Arc::new(EmptyProcessor::create()),
         })
     }
+
+    /// The schema of the partial results:
+    /// group_key is the serialized group by values, partial_result is the serialized states of all the funcs.
+    pub fn partial_schema() -> DataSchemaRef {
+        Arc::new(DataSchema::new(vec![
+            DataField::new("group_key", DataType::Utf8, false),
+            DataField::new("partial_result", DataType::Utf8, false),
+        ]))
+    }
+
+    /// Split the block rows into groups by the serialized group by values.
+    fn group_rows(&self, block: &DataBlock) -> Result<IndexMap<String, Vec<u32>>> {
+        let rows = block.num_rows();
+        let mut group_columns = Vec::with_capacity(self.group_funcs.len());
+        for func in &self.group_funcs {
+            group_columns.push(func.eval(block)?.to_array(rows)?);
+        }
+
+        let mut groups: IndexMap<String, Vec<u32>> = IndexMap::new();
+        for row in 0..rows {
+            let mut group_values = Vec::with_capacity(group_columns.len());
+            for column in &group_columns {
+                group_values.push(DataValue::try_from_array(column, row)?);
+            }
+            let group_key = serde_json::to_string(&DataValue::Struct(group_values))?;
+            groups.entry(group_key).or_default().push(row as u32);
+        }
+        Ok(groups)
+    }
 }
 
 #[async_trait]
@@ -59,39 +100,67 @@ impl IProcessor for AggregatorPartialTransform {
     }
 
     async fn execute(&self) -> Result<SendableDataBlockStream> {
-        let mut funcs = self.funcs.clone();
+        let mut groups: IndexMap<String, Vec<Box<dyn IFunction>>> = IndexMap::new();
         let mut stream = self.input.execute().await?;
 
         let start = Instant::now();
         while let Some(block) = stream.next().await {
             let block = block?;
+            if self.group_funcs.is_empty() {
+                let group_key = serde_json::to_string(&DataValue::Struct(vec![]))?;
+                let funcs = groups
+                    .entry(group_key)
+                    .or_insert_with(|| self.funcs.clone());
+                for func in funcs.iter_mut().filter(|func| func.is_aggregator()) {
+                    func.accumulate(&block)?;
+                }
+                continue;
+            }
 
-            for func in funcs.iter_mut() {
-                func.accumulate(&block)?;
+            for (group_key, indices) in self.group_rows(&block)? {
+                let group_block = block_take_by_indices(&block, &indices)?;
+                let funcs = groups
+                    .entry(group_key)
+                    .or_insert_with(|| self.funcs.clone());
+                for func in funcs.iter_mut().filter(|func| func.is_aggregator()) {
+                    func.accumulate(&group_block)?;
+                }
             }
         }
         let delta = start.elapsed();
         info!("Aggregator partial cost: {:?}", delta);
 
-        let mut acc_results = Vec::with_capacity(funcs.len());
-        for func in &funcs {
-            let states = DataValue::Struct(func.accumulate_result()?);
-            let serialized = serde_json::to_string(&states)?;
-            acc_results.push(serialized);
+        // No group by, the aggregator always has one result even if the input is empty.
+        if self.group_funcs.is_empty() && groups.is_empty() {
+            let group_key = serde_json::to_string(&DataValue::Struct(vec![]))?;
+            groups.insert(group_key, self.funcs.clone());
+        }
+
+        let mut group_keys = Vec::with_capacity(groups.len());
+        let mut acc_results = Vec::with_capacity(groups.len());
+        for (group_key, funcs) in &groups {
+            let mut states = Vec::with_capacity(funcs.len());
+            // The expressions without aggregator are evaluated on the group key by the final.
+            for func in funcs {
+                states.push(DataValue::Struct(match func.is_aggregator() {
+                    true => func.accumulate_result()?,
+                    false => vec![],
+                }));
+            }
+            group_keys.push(group_key.as_str());
+            acc_results.push(serde_json::to_string(&DataValue::Struct(states))?);
         }
 
-        let partial_schema = Arc::new(DataSchema::new(vec![DataField::new(
-            "partial_result",
-            DataType::Utf8,
-            false,
-        )]));
         let partial_results = acc_results
             .iter()
             .map(|x| x.as_str())
             .collect::<Vec<&str>>();
         let block = DataBlock::create(
-            partial_schema,
-            vec![Arc::new(StringArray::from(partial_results))],
+            AggregatorPartialTransform::partial_schema(),
+            vec![
+                Arc::new(StringArray::from(group_keys)),
+                Arc::new(StringArray::from(partial_results)),
+            ],
         );
         Ok(Box::pin(DataBlockStream::create(
             self.schema.clone(),
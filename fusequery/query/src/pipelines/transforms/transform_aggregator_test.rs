@@ -33,6 +33,7 @@ async fn test_transform_aggregator() -> anyhow::Result<()> {
         Ok(Box::new(AggregatorPartialTransform::try_create(
             aggr_partial.schema(),
             aggr_exprs.clone(),
+            vec![],
         )?))
     })?;
     pipeline.merge_processor()?;
@@ -40,6 +41,7 @@ async fn test_transform_aggregator() -> anyhow::Result<()> {
         Ok(Box::new(AggregatorFinalTransform::try_create(
             aggr_final.schema(),
             aggr_exprs.clone(),
+            vec![],
         )?))
     })?;
 
@@ -54,3 +56,164 @@ async fn test_transform_aggregator() -> anyhow::Result<()> {
     }
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_transform_aggregator_with_group_by() -> anyhow::Result<()> {
+    use std::sync::Arc;
+
+    use common_datavalues::*;
+    use common_planners::{self, *};
+    use futures::stream::StreamExt;
+    use pretty_assertions::assert_eq;
+
+    use crate::pipelines::processors::*;
+    use crate::pipelines::transforms::*;
+
+    let ctx = crate::tests::try_create_context()?;
+    let test_source = crate::tests::NumberTestData::create(ctx.clone());
+
+    // select number%3 as k, sum(number), count(number) from numbers(16) group by number%3
+    let group_exprs = vec![ExpressionPlan::BinaryExpression {
+        left: Box::new(col("number")),
+        op: "%".to_string(),
+        right: Box::new(lit(3u64)),
+    }];
+    let aggr_exprs = vec![
+        group_exprs[0].alias("k"),
+        sum(col("number")),
+        ExpressionPlan::Function {
+            op: "count".to_string(),
+            args: vec![col("number")],
+        },
+    ];
+
+    let aggr_partial = PlanBuilder::create(test_source.number_schema_for_test()?)
+        .aggregate_partial(aggr_exprs.clone(), group_exprs.clone())?
+        .build()?;
+    let aggr_final = PlanBuilder::create(test_source.number_schema_for_test()?)
+        .aggregate_final(aggr_exprs.clone(), group_exprs.clone())?
+        .build()?;
+
+    let mut pipeline = Pipeline::create();
+    let a = test_source.number_source_transform_for_test(16)?;
+    pipeline.add_source(Arc::new(a))?;
+    pipeline.add_simple_transform(|| {
+        Ok(Box::new(AggregatorPartialTransform::try_create(
+            aggr_partial.schema(),
+            aggr_exprs.clone(),
+            group_exprs.clone(),
+        )?))
+    })?;
+    pipeline.merge_processor()?;
+    pipeline.add_simple_transform(|| {
+        Ok(Box::new(AggregatorFinalTransform::try_create(
+            aggr_final.schema(),
+            aggr_exprs.clone(),
+            group_exprs.clone(),
+        )?))
+    })?;
+
+    let mut actual = vec![];
+    let mut stream = pipeline.execute().await?;
+    while let Some(v) = stream.next().await {
+        let v = v?;
+        assert_eq!(3, v.num_columns());
+        for row in 0..v.num_rows() {
+            actual.push(format!(
+                "{:?}|{:?}|{:?}",
+                DataValue::try_from_array(v.column(0), row)?,
+                DataValue::try_from_array(v.column(1), row)?,
+                DataValue::try_from_array(v.column(2), row)?,
+            ));
+        }
+    }
+    actual.sort();
+
+    let expect = vec!["0|45|6", "1|35|5", "2|40|5"];
+    assert_eq!(expect, actual);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_transform_aggregator_with_group_by_function() -> anyhow::Result<()> {
+    use std::sync::Arc;
+
+    use common_datavalues::*;
+    use common_planners::{self, *};
+    use futures::stream::StreamExt;
+    use pretty_assertions::assert_eq;
+
+    use crate::pipelines::processors::*;
+    use crate::pipelines::transforms::*;
+
+    let ctx = crate::tests::try_create_context()?;
+    let test_source = crate::tests::NumberTestData::create(ctx.clone());
+
+    // select toString(number%3) as k, k > '0', count(number) from numbers(16) group by toString(number%3)
+    let group_exprs = vec![ExpressionPlan::Function {
+        op: "toString".to_string(),
+        args: vec![ExpressionPlan::BinaryExpression {
+            left: Box::new(col("number")),
+            op: "%".to_string(),
+            right: Box::new(lit(3u64)),
+        }],
+    }];
+    let aggr_exprs = vec![
+        group_exprs[0].alias("k"),
+        ExpressionPlan::BinaryExpression {
+            left: Box::new(group_exprs[0].clone()),
+            op: ">".to_string(),
+            right: Box::new(lit("0")),
+        },
+        ExpressionPlan::Function {
+            op: "count".to_string(),
+            args: vec![col("number")],
+        },
+    ];
+
+    let aggr_partial = PlanBuilder::create(test_source.number_schema_for_test()?)
+        .aggregate_partial(aggr_exprs.clone(), group_exprs.clone())?
+        .build()?;
+    let aggr_final = PlanBuilder::create(test_source.number_schema_for_test()?)
+        .aggregate_final(aggr_exprs.clone(), group_exprs.clone())?
+        .build()?;
+
+    let mut pipeline = Pipeline::create();
+    let a = test_source.number_source_transform_for_test(16)?;
+    pipeline.add_source(Arc::new(a))?;
+    pipeline.add_simple_transform(|| {
+        Ok(Box::new(AggregatorPartialTransform::try_create(
+            aggr_partial.schema(),
+            aggr_exprs.clone(),
+            group_exprs.clone(),
+        )?))
+    })?;
+    pipeline.merge_processor()?;
+    pipeline.add_simple_transform(|| {
+        Ok(Box::new(AggregatorFinalTransform::try_create(
+            aggr_final.schema(),
+            aggr_exprs.clone(),
+            group_exprs.clone(),
+        )?))
+    })?;
+
+    let mut actual = vec![];
+    let mut stream = pipeline.execute().await?;
+    while let Some(v) = stream.next().await {
+        let v = v?;
+        assert_eq!(3, v.num_columns());
+        for row in 0..v.num_rows() {
+            actual.push(format!(
+                "{:?}|{:?}|{:?}",
+                DataValue::try_from_array(v.column(0), row)?,
+                DataValue::try_from_array(v.column(1), row)?,
+                DataValue::try_from_array(v.column(2), row)?,
+            ));
+        }
+    }
+    actual.sort();
+
+    let expect = vec!["0|false|6", "1|true|5", "2|true|5"];
+    assert_eq!(expect, actual);
+    Ok(())
+}
@@ -0,0 +1,415 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::convert::TryInto;
+use std::sync::Arc;
+use std::time::Instant;
+
+use anyhow::{bail, Result};
+use async_trait::async_trait;
+use common_arrow::arrow;
+use common_arrow::arrow::record_batch::RecordBatch;
+use common_datablocks::{block_concat, block_take_by_indices, DataBlock};
+use common_datavalues::{
+    data_array_cast, data_hash, equal_coercion, DataArrayRef, DataSchemaRef, DataType, DataValue,
+    UInt32Array,
+};
+use common_functions::IFunction;
+use common_planners::{ExpressionPlan, JoinShuffle, JoinType, PlanNode};
+use common_streams::SendableDataBlockStream;
+use futures::stream::StreamExt;
+use log::info;
+use tokio::sync::Mutex;
+use tokio_stream::wrappers::ReceiverStream;
+
+use crate::api::rpc::FlightClient;
+use crate::pipelines::processors::{EmptyProcessor, IProcessor, PipelineBuilder};
+use crate::sessions::FuseQueryContextRef;
+
+/// The hash table of the build side rows.
+#[derive(Default)]
+pub struct JoinHashTable {
+    /// All the build side rows, None if the build side is empty.
+    block: Option<DataBlock>,
+    /// The serialized join keys to the build side row indices.
+    rows: HashMap<String, Vec<u32>>,
+}
+
+/// The hash table is loaded once by the first probe transform and shared by the others.
+pub type JoinHashTableRef = Arc<Mutex<Option<Arc<JoinHashTable>>>>;
+
+pub struct HashJoinTransform {
+    ctx: FuseQueryContextRef,
+    schema: DataSchemaRef,
+    join_type: JoinType,
+    probe_schema: DataSchemaRef,
+    build: PlanNode,
+    shuffle: Option<JoinShuffle>,
+    probe_funcs: Vec<Box<dyn IFunction>>,
+    build_funcs: Vec<Box<dyn IFunction>>,
+    key_types: Vec<DataType>,
+    hash_table: JoinHashTableRef,
+    input: Arc<dyn IProcessor>,
+}
+
+impl HashJoinTransform {
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_create(
+        ctx: FuseQueryContextRef,
+        schema: DataSchemaRef,
+        join_type: JoinType,
+        probe_schema: DataSchemaRef,
+        build: PlanNode,
+        probe_keys: Vec<ExpressionPlan>,
+        build_keys: Vec<ExpressionPlan>,
+        shuffle: Option<JoinShuffle>,
+        hash_table: JoinHashTableRef,
+    ) -> Result<Self> {
+        let build_schema = build.schema();
+
+        let mut probe_funcs = Vec::with_capacity(probe_keys.len());
+        let mut build_funcs = Vec::with_capacity(build_keys.len());
+        let mut key_types = Vec::with_capacity(probe_keys.len());
+        for (probe_key, build_key) in probe_keys.iter().zip(build_keys.iter()) {
+            let probe_func = probe_key.to_function()?;
+            let build_func = build_key.to_function()?;
+            // Both sides are hashed in the same type, such as Int32 and Int64 keys.
+            key_types.push(equal_coercion(
+                &probe_func.return_type(&probe_schema)?,
+                &build_func.return_type(&build_schema)?,
+            )?);
+            probe_funcs.push(probe_func);
+            build_funcs.push(build_func);
+        }
+
+        Ok(HashJoinTransform {
+            ctx,
+            schema,
+            join_type,
+            probe_schema,
+            build,
+            shuffle,
+            probe_funcs,
+            build_funcs,
+            key_types,
+            hash_table,
+            input: Arc::new(EmptyProcessor::create()),
+        })
+    }
+
+    pub fn create_hash_table() -> JoinHashTableRef {
+        Arc::new(Mutex::new(None))
+    }
+
+    /// Evaluate the join keys of the block, casted to the key types.
+    fn key_columns(
+        block: &DataBlock,
+        funcs: &[Box<dyn IFunction>],
+        key_types: &[DataType],
+    ) -> Result<Vec<DataArrayRef>> {
+        let rows = block.num_rows();
+        let mut columns = Vec::with_capacity(funcs.len());
+        for (func, key_type) in funcs.iter().zip(key_types.iter()) {
+            let column = func.eval(block)?.to_array(rows)?;
+            if column.data_type() == key_type {
+                columns.push(column);
+            } else {
+                columns.push(data_array_cast(&column, key_type)?);
+            }
+        }
+        Ok(columns)
+    }
+
+    /// The serialized join key of the row, None if any key is NULL which never matches.
+    fn row_key(columns: &[DataArrayRef], row: usize) -> Result<Option<String>> {
+        let mut values = Vec::with_capacity(columns.len());
+        for column in columns {
+            if column.is_null(row) {
+                return Ok(None);
+            }
+            values.push(DataValue::try_from_array(column, row)?);
+        }
+        Ok(Some(serde_json::to_string(&DataValue::Struct(values))?))
+    }
+
+    /// Execute the build side plan in a child context and load all the rows into the hash table.
+    async fn load_hash_table(&self) -> Result<Arc<JoinHashTable>> {
+        let mut guard = self.hash_table.lock().await;
+        if let Some(hash_table) = guard.as_ref() {
+            return Ok(hash_table.clone());
+        }
+
+        let start = Instant::now();
+        let ctx = self.ctx.try_create_child()?;
+        let mut pipeline = PipelineBuilder::create(ctx, self.build.clone()).build()?;
+        let mut stream = pipeline.execute().await?;
+        if let Some(shuffle) = &self.shuffle {
+            stream = self.shuffle(
+                shuffle,
+                "build",
+                self.build.schema(),
+                &self.build_funcs,
+                stream,
+            )?;
+        }
+
+        let mut blocks = vec![];
+        while let Some(block) = stream.next().await {
+            let block = block?;
+            if !block.is_empty() {
+                blocks.push(block);
+            }
+        }
+
+        let mut hash_table = JoinHashTable::default();
+        if !blocks.is_empty() {
+            let block = block_concat(&blocks)?;
+            let keys = Self::key_columns(&block, &self.build_funcs, &self.key_types)?;
+            for row in 0..block.num_rows() {
+                if let Some(key) = Self::row_key(&keys, row)? {
+                    hash_table.rows.entry(key).or_default().push(row as u32);
+                }
+            }
+            hash_table.block = Some(block);
+        }
+        info!(
+            "Hash join build side loaded, keys: {}, cost: {:?}",
+            hash_table.rows.len(),
+            start.elapsed()
+        );
+
+        let hash_table = Arc::new(hash_table);
+        *guard = Some(hash_table.clone());
+        Ok(hash_table)
+    }
+
+    /// Shuffle the stream of one side to the executors by the hash of the join keys while it's
+    /// read, the output is the stream of the current bucket received from all the executors.
+    fn shuffle(
+        &self,
+        shuffle: &JoinShuffle,
+        side: &str,
+        schema: DataSchemaRef,
+        funcs: &[Box<dyn IFunction>],
+        input: SendableDataBlockStream,
+    ) -> Result<SendableDataBlockStream> {
+        let buckets = shuffle.executors.len();
+        let cluster = self.ctx.try_get_cluster()?;
+        let exchange_id = format!("{}-{}-{}", shuffle.id, side, shuffle.index);
+        // The exchange is removed when the receiver is dropped, either finished or failed.
+        let receiver = cluster.take_exchange_receiver(&exchange_id)?;
+        let local_sender = cluster.get_exchange_sender(&exchange_id)?;
+
+        let mut bucket_senders = Vec::with_capacity(buckets);
+        let mut sends = Vec::with_capacity(buckets);
+        for (bucket, address) in shuffle.executors.iter().enumerate() {
+            let (sender, bucket_receiver) = tokio::sync::mpsc::channel(2);
+            bucket_senders.push(sender);
+
+            let id = format!("{}-{}-{}", shuffle.id, side, bucket);
+            let address = address.clone();
+            let schema = schema.clone();
+            sends.push(async move {
+                let mut client = FlightClient::try_create(address).await?;
+                client
+                    .shuffle_exchange_action(id, &schema, ReceiverStream::new(bucket_receiver))
+                    .await
+            });
+        }
+
+        let partition = Self::partition(
+            input,
+            funcs.to_vec(),
+            self.key_types.clone(),
+            shuffle.index,
+            bucket_senders,
+        );
+        let side = side.to_string();
+        let index = shuffle.index;
+        tokio::spawn(async move {
+            let start = Instant::now();
+            // The failed partition or send drops the others, which breaks the remote exchanges.
+            match futures::future::try_join(partition, futures::future::try_join_all(sends)).await {
+                Ok(_) => info!(
+                    "Hash join {} side shuffled, bucket: {}, cost: {:?}",
+                    side,
+                    index,
+                    start.elapsed()
+                ),
+                Err(e) => {
+                    local_sender.send(Err(e)).await.ok();
+                }
+            }
+        });
+
+        // Every executor ends its blocks with a None.
+        let stream =
+            futures::stream::try_unfold((receiver, 0), move |(mut receiver, mut finished)| {
+                let exchange_id = exchange_id.clone();
+                async move {
+                    while finished < buckets {
+                        match receiver.recv().await {
+                            Some(Ok(Some(block))) => {
+                                return Ok(Some((block, (receiver, finished))))
+                            }
+                            Some(Ok(None)) => finished += 1,
+                            Some(Err(e)) => return Err(e),
+                            None => bail!("Join shuffle exchange {} is closed", exchange_id),
+                        }
+                    }
+                    Ok(None)
+                }
+            });
+        Ok(Box::pin(stream))
+    }
+
+    /// Split the blocks of the input into the buckets by the hash of the join keys.
+    async fn partition(
+        mut input: SendableDataBlockStream,
+        funcs: Vec<Box<dyn IFunction>>,
+        key_types: Vec<DataType>,
+        index: usize,
+        senders: Vec<tokio::sync::mpsc::Sender<RecordBatch>>,
+    ) -> Result<()> {
+        let buckets = senders.len();
+        while let Some(block) = input.next().await {
+            let block = block?;
+            let keys = Self::key_columns(&block, &funcs, &key_types)?;
+            let mut bucket_indices: Vec<Vec<u32>> = vec![vec![]; buckets];
+            for row in 0..block.num_rows() {
+                let bucket = match Self::row_key(&keys, row)? {
+                    // The hash is fixed, the executors built by the different Rust releases
+                    // must put the same key into the same bucket.
+                    Some(key) => (data_hash(key.as_bytes()) % buckets as u64) as usize,
+                    // The NULL keys never match, they stay in the current bucket.
+                    None => index,
+                };
+                bucket_indices[bucket].push(row as u32);
+            }
+            for (bucket, indices) in bucket_indices.iter().enumerate() {
+                if indices.is_empty() {
+                    continue;
+                }
+                let batch = block_take_by_indices(&block, indices)?.try_into()?;
+                if senders[bucket].send(batch).await.is_err() {
+                    bail!("Join shuffle to bucket {} is closed", bucket);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Probe the hash table with the block rows, the output is the probe columns followed by the build columns.
+    fn probe(
+        schema: &DataSchemaRef,
+        join_type: JoinType,
+        hash_table: &JoinHashTable,
+        probe_funcs: &[Box<dyn IFunction>],
+        key_types: &[DataType],
+        block: DataBlock,
+    ) -> Result<DataBlock> {
+        let keys = Self::key_columns(&block, probe_funcs, key_types)?;
+
+        let mut probe_indices: Vec<u32> = vec![];
+        let mut build_indices: Vec<Option<u32>> = vec![];
+        for row in 0..block.num_rows() {
+            let matched = match Self::row_key(&keys, row)? {
+                Some(key) => hash_table.rows.get(&key),
+                None => None,
+            };
+            match matched {
+                Some(build_rows) => {
+                    for build_row in build_rows {
+                        probe_indices.push(row as u32);
+                        build_indices.push(Some(*build_row));
+                    }
+                }
+                None => {
+                    if join_type == JoinType::Left {
+                        probe_indices.push(row as u32);
+                        build_indices.push(None);
+                    }
+                }
+            }
+        }
+
+        let rows = probe_indices.len();
+        let probe_indices = UInt32Array::from(probe_indices);
+        let build_indices = UInt32Array::from(build_indices);
+
+        let mut columns = Vec::with_capacity(schema.fields().len());
+        for i in 0..block.num_columns() {
+            columns.push(arrow::compute::take(
+                block.column(i).as_ref(),
+                &probe_indices,
+                None,
+            )?);
+        }
+        for (i, field) in schema.fields()[block.num_columns()..].iter().enumerate() {
+            columns.push(match &hash_table.block {
+                // The NULL indices take the NULL values for the unmatched rows.
+                Some(build_block) => {
+                    arrow::compute::take(build_block.column(i).as_ref(), &build_indices, None)?
+                }
+                None => arrow::array::new_null_array(field.data_type(), rows),
+            });
+        }
+        Ok(DataBlock::create(schema.clone(), columns))
+    }
+}
+
+#[async_trait]
+impl IProcessor for HashJoinTransform {
+    fn name(&self) -> &str {
+        "HashJoinTransform"
+    }
+
+    fn connect_to(&mut self, input: Arc<dyn IProcessor>) -> Result<()> {
+        self.input = input;
+        Ok(())
+    }
+
+    fn inputs(&self) -> Vec<Arc<dyn IProcessor>> {
+        vec![self.input.clone()]
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    async fn execute(&self) -> Result<SendableDataBlockStream> {
+        let hash_table = self.load_hash_table().await?;
+
+        let schema = self.schema.clone();
+        let join_type = self.join_type;
+        let probe_funcs = self.probe_funcs.clone();
+        let key_types = self.key_types.clone();
+        let mut input = self.input.execute().await?;
+        if let Some(shuffle) = &self.shuffle {
+            input = self.shuffle(
+                shuffle,
+                "probe",
+                self.probe_schema.clone(),
+                &self.probe_funcs,
+                input,
+            )?;
+        }
+
+        let stream = input.map(move |block| {
+            block.and_then(|block| {
+                Self::probe(
+                    &schema,
+                    join_type,
+                    &hash_table,
+                    &probe_funcs,
+                    &key_types,
+                    block,
+                )
+            })
+        });
+        Ok(Box::pin(stream))
+    }
+}
@@ -0,0 +1,93 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_transform_hash_join() -> anyhow::Result<()> {
+    use std::sync::Arc;
+
+    use common_datavalues::*;
+    use common_planners::*;
+    use futures::TryStreamExt;
+    use pretty_assertions::assert_eq;
+
+    use crate::pipelines::processors::*;
+    use crate::pipelines::transforms::*;
+
+    #[allow(dead_code)]
+    struct Test {
+        name: &'static str,
+        join_type: JoinType,
+        expect: Vec<&'static str>,
+    }
+
+    let tests = vec![
+        Test {
+            name: "inner-join-passed",
+            join_type: JoinType::Inner,
+            expect: vec!["0|0", "0|0", "1|1", "1|1", "2|2", "2|2"],
+        },
+        Test {
+            name: "left-join-passed",
+            join_type: JoinType::Left,
+            expect: vec![
+                "0|0", "0|0", "1|1", "1|1", "2|2", "2|2", "3|NULL", "3|NULL", "4|NULL", "4|NULL",
+            ],
+        },
+    ];
+
+    for test in tests {
+        let ctx = crate::tests::try_create_context()?;
+        let test_source = crate::tests::NumberTestData::create(ctx.clone());
+
+        // Build side: [0, 3), probe side: two streams of [0, 5).
+        let build = PlanBuilder::from(&PlanNode::ReadSource(
+            test_source.number_read_source_plan_for_test(3)?,
+        ))
+        .project(vec![col("number").alias("id")])?
+        .build()?;
+        let probe = PlanNode::ReadSource(test_source.number_read_source_plan_for_test(5)?);
+        let plan = PlanBuilder::from(&probe)
+            .join(test.join_type, &build, vec![col("number")], vec![col("id")])?
+            .build()?;
+
+        let mut pipeline = Pipeline::create();
+        let a = test_source.number_source_transform_for_test(5)?;
+        pipeline.add_source(Arc::new(a))?;
+        let b = test_source.number_source_transform_for_test(5)?;
+        pipeline.add_source(Arc::new(b))?;
+
+        let hash_table = HashJoinTransform::create_hash_table();
+        pipeline.add_simple_transform(|| {
+            Ok(Box::new(HashJoinTransform::try_create(
+                ctx.clone(),
+                plan.schema(),
+                test.join_type,
+                probe.schema(),
+                build.clone(),
+                vec![col("number")],
+                vec![col("id")],
+                None,
+                hash_table.clone(),
+            )?))
+        })?;
+
+        let stream = pipeline.execute().await?;
+        let blocks = stream.try_collect::<Vec<_>>().await?;
+
+        let mut actual = vec![];
+        for block in blocks {
+            assert_eq!(2, block.num_columns());
+            for row in 0..block.num_rows() {
+                actual.push(format!(
+                    "{:?}|{:?}",
+                    DataValue::try_from_array(block.column(0), row)?,
+                    DataValue::try_from_array(block.column(1), row)?,
+                ));
+            }
+        }
+        actual.sort();
+        assert_eq!(test.expect, actual, "{}", test.name);
+    }
+    Ok(())
+}
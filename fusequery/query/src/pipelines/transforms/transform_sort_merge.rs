@@ -0,0 +1,89 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use common_datablocks::{block_merge_sort, DataBlock};
+use common_datavalues::DataSchemaRef;
+use common_planners::ExpressionPlan;
+use common_streams::{DataBlockStream, SendableDataBlockStream};
+use futures::stream::StreamExt;
+
+use crate::pipelines::processors::{EmptyProcessor, IProcessor};
+use crate::pipelines::transforms::get_sort_descriptions;
+
+/// Merge the sorted blocks from the SortPartialTransforms into one sorted block.
+pub struct SortMergeTransform {
+    schema: DataSchemaRef,
+    exprs: Vec<ExpressionPlan>,
+    limit: Option<usize>,
+    input: Arc<dyn IProcessor>,
+}
+
+impl SortMergeTransform {
+    pub fn try_create(
+        schema: DataSchemaRef,
+        exprs: Vec<ExpressionPlan>,
+        limit: Option<usize>,
+    ) -> Result<Self> {
+        Ok(SortMergeTransform {
+            schema,
+            exprs,
+            limit,
+            input: Arc::new(EmptyProcessor::create()),
+        })
+    }
+}
+
+#[async_trait]
+impl IProcessor for SortMergeTransform {
+    fn name(&self) -> &str {
+        "SortMergeTransform"
+    }
+
+    fn connect_to(&mut self, input: Arc<dyn IProcessor>) -> Result<()> {
+        self.input = input;
+        Ok(())
+    }
+
+    fn inputs(&self) -> Vec<Arc<dyn IProcessor>> {
+        vec![self.input.clone()]
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    async fn execute(&self) -> Result<SendableDataBlockStream> {
+        let descriptions = get_sort_descriptions(&self.exprs)?;
+
+        let mut blocks = vec![];
+        let mut stream = self.input.execute().await?;
+        while let Some(block) = stream.next().await {
+            let block = block?;
+            if !block.is_empty() {
+                blocks.push(block);
+            }
+        }
+
+        let mut results = vec![];
+        if !blocks.is_empty() {
+            let block = block_merge_sort(&blocks, &descriptions, self.limit)?;
+
+            // Remove the sort columns appended by the SortPartialTransform.
+            let columns = (0..self.schema.fields().len())
+                .map(|i| block.column(i).clone())
+                .collect::<Vec<_>>();
+            results.push(DataBlock::create(self.schema.clone(), columns));
+        }
+        Ok(Box::pin(DataBlockStream::create(
+            self.schema.clone(),
+            None,
+            results,
+        )))
+    }
+}
@@ -0,0 +1,164 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use anyhow::{bail, Result};
+use async_trait::async_trait;
+use common_datablocks::{block_concat, block_sort, DataBlock, SortColumnDescription};
+use common_datavalues::{DataField, DataSchema, DataSchemaRef};
+use common_functions::IFunction;
+use common_planners::ExpressionPlan;
+use common_streams::{DataBlockStream, SendableDataBlockStream};
+use futures::stream::StreamExt;
+
+use crate::pipelines::processors::{EmptyProcessor, IProcessor};
+
+/// The column name of the sort expression, the expression which isn't a column
+/// is evaluated and appended to the block with this name.
+fn sort_column_name(expr: &ExpressionPlan) -> String {
+    match expr {
+        ExpressionPlan::Column(name) => name.clone(),
+        other => format!("{:?}", other),
+    }
+}
+
+pub fn get_sort_descriptions(exprs: &[ExpressionPlan]) -> Result<Vec<SortColumnDescription>> {
+    let mut descriptions = Vec::with_capacity(exprs.len());
+    for expr in exprs {
+        match expr {
+            ExpressionPlan::Sort {
+                expr,
+                asc,
+                nulls_first,
+            } => descriptions.push(SortColumnDescription {
+                column_name: sort_column_name(expr),
+                asc: *asc,
+                nulls_first: *nulls_first,
+            }),
+            other => bail!(
+                "Sort expression must be ExpressionPlan::Sort, but got {:?}",
+                other
+            ),
+        }
+    }
+    Ok(descriptions)
+}
+
+pub struct SortPartialTransform {
+    schema: DataSchemaRef,
+    exprs: Vec<ExpressionPlan>,
+    limit: Option<usize>,
+    input: Arc<dyn IProcessor>,
+}
+
+impl SortPartialTransform {
+    pub fn try_create(
+        schema: DataSchemaRef,
+        exprs: Vec<ExpressionPlan>,
+        limit: Option<usize>,
+    ) -> Result<Self> {
+        Ok(SortPartialTransform {
+            schema,
+            exprs,
+            limit,
+            input: Arc::new(EmptyProcessor::create()),
+        })
+    }
+
+    /// Evaluate the sort expressions which are not columns and append them to the block.
+    fn append_sort_columns(
+        block: DataBlock,
+        funcs: &[(String, Box<dyn IFunction>)],
+    ) -> Result<DataBlock> {
+        if funcs.is_empty() {
+            return Ok(block);
+        }
+
+        let rows = block.num_rows();
+        let mut fields = block.schema().fields().clone();
+        let mut columns = (0..block.num_columns())
+            .map(|i| block.column(i).clone())
+            .collect::<Vec<_>>();
+        for (name, func) in funcs {
+            let column = func.eval(&block)?.to_array(rows)?;
+            fields.push(DataField::new(name, column.data_type().clone(), true));
+            columns.push(column);
+        }
+        Ok(DataBlock::create(
+            Arc::new(DataSchema::new(fields)),
+            columns,
+        ))
+    }
+}
+
+#[async_trait]
+impl IProcessor for SortPartialTransform {
+    fn name(&self) -> &str {
+        "SortPartialTransform"
+    }
+
+    fn connect_to(&mut self, input: Arc<dyn IProcessor>) -> Result<()> {
+        self.input = input;
+        Ok(())
+    }
+
+    fn inputs(&self) -> Vec<Arc<dyn IProcessor>> {
+        vec![self.input.clone()]
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    async fn execute(&self) -> Result<SendableDataBlockStream> {
+        let descriptions = get_sort_descriptions(&self.exprs)?;
+
+        let mut funcs = vec![];
+        for expr in &self.exprs {
+            if let ExpressionPlan::Sort { expr, .. } = expr {
+                if let ExpressionPlan::Column(_) = expr.as_ref() {
+                    continue;
+                }
+                funcs.push((sort_column_name(expr), expr.to_function()?));
+            }
+        }
+
+        let mut rows = 0;
+        let mut blocks = vec![];
+        let mut stream = self.input.execute().await?;
+        while let Some(block) = stream.next().await {
+            let block = SortPartialTransform::append_sort_columns(block?, &funcs)?;
+            if block.is_empty() {
+                continue;
+            }
+            rows += block.num_rows();
+            blocks.push(block);
+
+            // Top-N: only keep the first N rows in memory.
+            if let Some(limit) = self.limit {
+                if blocks.len() > 1 && rows >= limit * 2 {
+                    let block = block_sort(&block_concat(&blocks)?, &descriptions, Some(limit))?;
+                    rows = block.num_rows();
+                    blocks = vec![block];
+                }
+            }
+        }
+
+        let mut results = vec![];
+        if !blocks.is_empty() {
+            results.push(block_sort(
+                &block_concat(&blocks)?,
+                &descriptions,
+                self.limit,
+            )?);
+        }
+        Ok(Box::pin(DataBlockStream::create(
+            self.schema.clone(),
+            None,
+            results,
+        )))
+    }
+}
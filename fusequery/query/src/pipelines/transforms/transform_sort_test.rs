@@ -0,0 +1,153 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_transform_sort() -> anyhow::Result<()> {
+    use std::sync::Arc;
+
+    use common_datavalues::*;
+    use common_planners::*;
+    use futures::TryStreamExt;
+    use pretty_assertions::assert_eq;
+
+    use crate::pipelines::processors::*;
+    use crate::pipelines::transforms::*;
+
+    #[allow(dead_code)]
+    struct Test {
+        name: &'static str,
+        limit: Option<usize>,
+        expect: Vec<u64>,
+    }
+
+    let tests = vec![
+        Test {
+            name: "sort-desc-passed",
+            limit: None,
+            expect: vec![7, 7, 6, 6, 5, 5, 4, 4, 3, 3, 2, 2, 1, 1, 0, 0],
+        },
+        Test {
+            name: "sort-desc-top-n-passed",
+            limit: Some(3),
+            expect: vec![7, 7, 6],
+        },
+    ];
+
+    for test in tests {
+        let ctx = crate::tests::try_create_context()?;
+        let test_source = crate::tests::NumberTestData::create(ctx.clone());
+
+        // Two streams: [0, 8) and [0, 8).
+        let mut pipeline = Pipeline::create();
+        let a = test_source.number_source_transform_for_test(8)?;
+        pipeline.add_source(Arc::new(a))?;
+        let b = test_source.number_source_transform_for_test(8)?;
+        pipeline.add_source(Arc::new(b))?;
+
+        let schema = test_source.number_schema_for_test()?;
+        let order_by = vec![ExpressionPlan::Sort {
+            expr: Box::new(col("number")),
+            asc: false,
+            nulls_first: false,
+        }];
+        pipeline.add_simple_transform(|| {
+            Ok(Box::new(SortPartialTransform::try_create(
+                schema.clone(),
+                order_by.clone(),
+                test.limit,
+            )?))
+        })?;
+        pipeline.merge_processor()?;
+        pipeline.add_simple_transform(|| {
+            Ok(Box::new(SortMergeTransform::try_create(
+                schema.clone(),
+                order_by.clone(),
+                test.limit,
+            )?))
+        })?;
+
+        let stream = pipeline.execute().await?;
+        let blocks = stream.try_collect::<Vec<_>>().await?;
+
+        let mut actual = vec![];
+        for block in blocks {
+            let column = block
+                .column(0)
+                .as_any()
+                .downcast_ref::<UInt64Array>()
+                .unwrap();
+            actual.extend(column.values().iter().copied());
+        }
+        assert_eq!(test.expect, actual, "{}", test.name);
+    }
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_transform_sort_by_expression() -> anyhow::Result<()> {
+    use std::sync::Arc;
+
+    use common_datavalues::*;
+    use common_planners::*;
+    use futures::TryStreamExt;
+    use pretty_assertions::assert_eq;
+
+    use crate::pipelines::processors::*;
+    use crate::pipelines::transforms::*;
+
+    let ctx = crate::tests::try_create_context()?;
+    let test_source = crate::tests::NumberTestData::create(ctx.clone());
+
+    let mut pipeline = Pipeline::create();
+    let a = test_source.number_source_transform_for_test(6)?;
+    pipeline.add_source(Arc::new(a))?;
+
+    // order by number%3, number desc
+    let schema = test_source.number_schema_for_test()?;
+    let order_by = vec![
+        ExpressionPlan::Sort {
+            expr: Box::new(ExpressionPlan::BinaryExpression {
+                left: Box::new(col("number")),
+                op: "%".to_string(),
+                right: Box::new(lit(3u64)),
+            }),
+            asc: true,
+            nulls_first: false,
+        },
+        ExpressionPlan::Sort {
+            expr: Box::new(col("number")),
+            asc: false,
+            nulls_first: false,
+        },
+    ];
+    pipeline.add_simple_transform(|| {
+        Ok(Box::new(SortPartialTransform::try_create(
+            schema.clone(),
+            order_by.clone(),
+            None,
+        )?))
+    })?;
+    pipeline.merge_processor()?;
+    pipeline.add_simple_transform(|| {
+        Ok(Box::new(SortMergeTransform::try_create(
+            schema.clone(),
+            order_by.clone(),
+            None,
+        )?))
+    })?;
+
+    let stream = pipeline.execute().await?;
+    let blocks = stream.try_collect::<Vec<_>>().await?;
+    assert_eq!(1, blocks.len());
+    assert_eq!(1, blocks[0].num_columns());
+
+    let actual = blocks[0]
+        .column(0)
+        .as_any()
+        .downcast_ref::<UInt64Array>()
+        .unwrap();
+    let expect = UInt64Array::from(vec![3, 0, 4, 1, 5, 2]);
+    assert_eq!(expect, actual.clone());
+    Ok(())
+}
@@ -7,6 +7,7 @@ use std::sync::Arc;
 
 use anyhow::{bail, Result};
 use async_trait::async_trait;
+use common_planners::ReadDataSourcePlan;
 use common_streams::SendableDataBlockStream;
 
 use crate::pipelines::processors::{EmptyProcessor, IProcessor};
@@ -14,17 +15,12 @@ use crate::sessions::FuseQueryContextRef;
 
 pub struct SourceTransform {
     ctx: FuseQueryContextRef,
-    db: String,
-    table: String,
+    source_plan: ReadDataSourcePlan,
 }
 
 impl SourceTransform {
-    pub fn try_create(ctx: FuseQueryContextRef, db: &str, table: &str) -> Result<Self> {
-        Ok(SourceTransform {
-            ctx,
-            db: db.to_string(),
-            table: table.to_string(),
-        })
+    pub fn try_create(ctx: FuseQueryContextRef, source_plan: ReadDataSourcePlan) -> Result<Self> {
+        Ok(SourceTransform { ctx, source_plan })
     }
 }
 
@@ -47,7 +43,10 @@ impl IProcessor for SourceTransform {
     }
 
     async fn execute(&self) -> Result<SendableDataBlockStream> {
-        let table = self.ctx.get_table(self.db.as_str(), self.table.as_str())?;
-        table.read(self.ctx.clone()).await
+        let table = self.ctx.get_table(
+            self.source_plan.db.as_str(),
+            self.source_plan.table.as_str(),
+        )?;
+        table.read(self.ctx.clone(), &self.source_plan).await
     }
 }
@@ -7,15 +7,23 @@ use std::sync::Arc;
 
 use anyhow::Result;
 use common_datavalues::DataSchema;
-use common_planners::{EmptyPlan, PlanNode, ReadDataSourcePlan};
+use common_planners::{EmptyPlan, JoinShuffle, PlanNode, ReadDataSourcePlan};
 use log::info;
+use uuid::Uuid;
 
+use crate::clusters::Node;
 use crate::sessions::FuseQueryContextRef;
 
 pub struct PlanScheduler {}
 
 impl PlanScheduler {
     pub fn schedule(ctx: FuseQueryContextRef, plan: &PlanNode) -> Result<Vec<PlanNode>> {
+        // The join is shuffled to all the nodes by the join keys.
+        let nodes = ctx.try_get_cluster()?.get_nodes()?;
+        if !nodes.is_empty() && Self::has_join(plan)? {
+            return Self::schedule_shuffle_join(plan, &nodes);
+        }
+
         let mut source_plan = ReadDataSourcePlan::empty();
 
         // Get the source plan node from walk.
@@ -88,12 +96,17 @@ impl PlanScheduler {
 
             // Walk and rewrite the plan from the source.
             plan.walk_postorder(|node| {
-                if let PlanNode::ReadSource(_) = node {
-                    rewritten_node = PlanNode::ReadSource(new_source_plan.clone());
-                } else {
-                    let mut clone_node = node.clone();
-                    clone_node.set_input(&rewritten_node)?;
-                    rewritten_node = clone_node;
+                match node {
+                    PlanNode::ReadSource(_) => {
+                        rewritten_node = PlanNode::ReadSource(new_source_plan.clone());
+                    }
+                    // The plan is executed in the executor, the stages below are removed.
+                    PlanNode::Stage(_) => {}
+                    _ => {
+                        let mut clone_node = node.clone();
+                        clone_node.set_input(&rewritten_node)?;
+                        rewritten_node = clone_node;
+                    }
                 }
                 Ok(true)
             })?;
@@ -103,4 +116,106 @@ impl PlanScheduler {
 
         Ok(results)
     }
+
+    fn has_join(plan: &PlanNode) -> Result<bool> {
+        let mut has_join = false;
+        plan.walk_preorder(|node| {
+            has_join = matches!(node, PlanNode::Join(_));
+            Ok(!has_join)
+        })?;
+        Ok(has_join)
+    }
+
+    /// Schedule the plan with join to all the nodes, every node reads a part of the partitions
+    /// of both sides and shuffles the rows to the other nodes by the hash of the join keys.
+    fn schedule_shuffle_join(plan: &PlanNode, nodes: &[Node]) -> Result<Vec<PlanNode>> {
+        let shuffle_id = Uuid::new_v4().to_string();
+        let executors = nodes
+            .iter()
+            .map(|node| node.address.clone())
+            .collect::<Vec<_>>();
+
+        let mut results = Vec::with_capacity(nodes.len());
+        for index in 0..nodes.len() {
+            let shuffle = JoinShuffle {
+                id: shuffle_id.clone(),
+                executors: executors.clone(),
+                index,
+            };
+            results.push(Self::rewrite_for_node(
+                plan,
+                nodes.len(),
+                index,
+                Some(shuffle),
+            )?);
+        }
+        info!(
+            "Schedule shuffle join plans to [{:?}] executors",
+            results.len()
+        );
+
+        Ok(results)
+    }
+
+    /// Rewrite the plan for the node of the index: the source partitions are split to the nodes
+    /// round-robin, the top join is shuffled and the joins below it broadcast their build side.
+    fn rewrite_for_node(
+        plan: &PlanNode,
+        nums: usize,
+        index: usize,
+        mut shuffle: Option<JoinShuffle>,
+    ) -> Result<PlanNode> {
+        let mut joins = 0;
+        plan.walk_preorder(|node| {
+            if let PlanNode::Join(_) = node {
+                joins += 1;
+            }
+            Ok(true)
+        })?;
+
+        let mut rewritten_node = PlanNode::Empty(EmptyPlan {
+            schema: Arc::new(DataSchema::empty()),
+        });
+        plan.walk_postorder(|node| {
+            match node {
+                PlanNode::ReadSource(source) => {
+                    let mut source = source.clone();
+                    source.partitions = source
+                        .partitions
+                        .iter()
+                        .enumerate()
+                        .filter(|(i, _)| i % nums == index)
+                        .map(|(_, partition)| partition.clone())
+                        .collect();
+                    rewritten_node = PlanNode::ReadSource(source);
+                }
+                // The plan is executed in the node, the stages below are removed.
+                PlanNode::Stage(_) => {}
+                PlanNode::Join(join) => {
+                    joins -= 1;
+                    let mut join = join.clone();
+                    match (joins, shuffle.take()) {
+                        (0, Some(shuffle)) => {
+                            join.build =
+                                Arc::new(Self::rewrite_for_node(&join.build, nums, index, None)?);
+                            join.shuffle = Some(shuffle);
+                        }
+                        (_, other) => {
+                            join.build = Arc::new(Self::rewrite_for_node(&join.build, 1, 0, None)?);
+                            shuffle = other;
+                        }
+                    }
+                    join.input = Arc::new(rewritten_node.clone());
+                    rewritten_node = PlanNode::Join(join);
+                }
+                _ => {
+                    let mut clone_node = node.clone();
+                    clone_node.set_input(&rewritten_node)?;
+                    rewritten_node = clone_node;
+                }
+            }
+            Ok(true)
+        })?;
+        Ok(rewritten_node)
+    }
 }
@@ -144,3 +144,62 @@ async fn test_scheduler_plan_with_3_nodes_diff_priority() -> anyhow::Result<()>
     }
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_scheduler_plan_with_join_3_nodes() -> anyhow::Result<()> {
+    use common_planners::*;
+    use pretty_assertions::assert_eq;
+
+    use crate::planners::PlanScheduler;
+
+    let ctx = crate::tests::try_create_context_with_nodes(3).await?;
+
+    // The probe side has 8 partitions, the build side has 4 partitions.
+    let ctx_probe = crate::tests::try_create_context()?;
+    ctx_probe.set_max_threads(8)?;
+    let probe = crate::tests::NumberTestData::create(ctx_probe.clone())
+        .number_read_source_plan_for_test(100000)?;
+    let ctx_build = crate::tests::try_create_context()?;
+    ctx_build.set_max_threads(4)?;
+    let build = crate::tests::NumberTestData::create(ctx_build.clone())
+        .number_read_source_plan_for_test(100)?;
+
+    let build = PlanBuilder::from(&PlanNode::ReadSource(build))
+        .project(vec![col("number").alias("id")])?
+        .build()?;
+    let plan = PlanBuilder::from(&PlanNode::ReadSource(probe))
+        .join(
+            JoinType::Inner,
+            &build,
+            vec![col("number")],
+            vec![col("id")],
+        )?
+        .project(vec![col("number"), col("id")])?
+        .build()?;
+
+    // Every node joins its own bucket of both sides.
+    let plans = PlanScheduler::schedule(ctx, &plan)?;
+    let expects = vec!["Projection: number:UInt64, id:UInt64
+  Join: Inner, probe keys: [number], build keys: [id], shuffle: [bucket: 0, executors: 3]
+    Projection: number as id:UInt64
+      ReadDataSource: scan partitions: [2], scan schema: [number:UInt64], statistics: [read_rows: 100, read_bytes: 800]
+    ReadDataSource: scan partitions: [3], scan schema: [number:UInt64], statistics: [read_rows: 100000, read_bytes: 800000]",
+"Projection: number:UInt64, id:UInt64
+  Join: Inner, probe keys: [number], build keys: [id], shuffle: [bucket: 1, executors: 3]
+    Projection: number as id:UInt64
+      ReadDataSource: scan partitions: [1], scan schema: [number:UInt64], statistics: [read_rows: 100, read_bytes: 800]
+    ReadDataSource: scan partitions: [3], scan schema: [number:UInt64], statistics: [read_rows: 100000, read_bytes: 800000]",
+"Projection: number:UInt64, id:UInt64
+  Join: Inner, probe keys: [number], build keys: [id], shuffle: [bucket: 2, executors: 3]
+    Projection: number as id:UInt64
+      ReadDataSource: scan partitions: [1], scan schema: [number:UInt64], statistics: [read_rows: 100, read_bytes: 800]
+    ReadDataSource: scan partitions: [2], scan schema: [number:UInt64], statistics: [read_rows: 100000, read_bytes: 800000]",
+    ];
+
+    assert_eq!(expects.len(), plans.len());
+    for (i, plan) in plans.iter().enumerate() {
+        let actual = format!("{:?}", plan);
+        assert_eq!(expects[i], actual);
+    }
+    Ok(())
+}
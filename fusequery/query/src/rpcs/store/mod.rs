@@ -2,8 +2,8 @@
 //
 // SPDX-License-Identifier: Apache-2.0.
 
-// The api module only used for internal communication, such as GRPC between cluster and the managed HTTP REST API.
-
 // Flight Client for FuseStore.
 
 mod store_client;
+
+pub use store_client::{block_on, StoreClient};
@@ -1,3 +1,231 @@
 // Copyright 2020-2021 The Datafuse Authors.
 //
 // SPDX-License-Identifier: Apache-2.0.
+
+use std::collections::HashMap;
+use std::convert::{TryFrom, TryInto};
+use std::future::Future;
+use std::sync::Arc;
+
+use anyhow::{anyhow, bail, Result};
+use common_arrow::arrow;
+use common_arrow::arrow_flight::flight_service_client::FlightServiceClient;
+use common_arrow::arrow_flight::utils::{
+    flight_data_from_arrow_batch, flight_data_from_arrow_schema, flight_data_to_arrow_batch,
+};
+use common_arrow::arrow_flight::{Action, FlightData, Ticket};
+use common_datablocks::DataBlock;
+use common_datavalues::{DataSchema, DataSchemaRef};
+use common_flights::store_do_action::{
+    CreateDatabaseAction, CreateDatabaseActionResult, CreateTableAction, CreateTableActionResult,
+    DoActionAction, DropDatabaseAction, DropDatabaseActionResult, DropTableAction,
+    DropTableActionResult, GetDatabasesAction, GetDatabasesActionResult, GetTableAction,
+    GetTableActionResult, GetTablesAction, GetTablesActionResult, ReadPlanAction,
+    ReadPlanActionResult,
+};
+use common_flights::store_do_get::{DoGetAction, ReadAction};
+use common_flights::store_do_put::{AppendAction, AppendActionResult, DoPutAction};
+use common_infallible::Mutex;
+use common_planners::{
+    CreateDatabasePlan, CreateTablePlan, DropDatabasePlan, DropTablePlan, Extras, Partition,
+    PlanNode,
+};
+use common_streams::SendableDataBlockStream;
+use lazy_static::lazy_static;
+use tokio::runtime::{Builder, Handle, Runtime};
+use tokio_stream::StreamExt;
+use tonic::transport::Channel;
+use tonic::Request;
+
+lazy_static! {
+    // The connections are driven by this runtime, so they outlive the runtimes of the callers.
+    static ref STORE_RUNTIME: Runtime = Builder::new_multi_thread()
+        .thread_name("store-client")
+        .enable_all()
+        .build()
+        .expect("Cannot create the store client runtime");
+    // One connection for each store address, the clients are the cheap clones of it.
+    static ref STORE_CLIENTS: Mutex<HashMap<String, FlightServiceClient<Channel>>> =
+        Mutex::new(HashMap::new());
+}
+
+pub struct StoreClient {
+    client: FlightServiceClient<Channel>,
+}
+
+impl StoreClient {
+    /// The client shares the connection to the address, it is only connected at the first time.
+    pub async fn try_create(addr: String) -> Result<Self> {
+        if let Some(client) = STORE_CLIENTS.lock().get(&addr) {
+            return Ok(Self {
+                client: client.clone(),
+            });
+        }
+
+        let url = format!("http://{}", addr);
+        let client = STORE_RUNTIME
+            .spawn(FlightServiceClient::connect(url))
+            .await??;
+        let client = STORE_CLIENTS.lock().entry(addr).or_insert(client).clone();
+        Ok(Self { client })
+    }
+
+    pub async fn create_database(
+        &mut self,
+        plan: CreateDatabasePlan,
+    ) -> Result<CreateDatabaseActionResult> {
+        let action = DoActionAction::CreateDatabase(CreateDatabaseAction { plan });
+        self.do_action(&action).await
+    }
+
+    pub async fn get_databases(&mut self) -> Result<GetDatabasesActionResult> {
+        let action = DoActionAction::GetDatabases(GetDatabasesAction {});
+        self.do_action(&action).await
+    }
+
+    pub async fn drop_database(
+        &mut self,
+        plan: DropDatabasePlan,
+    ) -> Result<DropDatabaseActionResult> {
+        let action = DoActionAction::DropDatabase(DropDatabaseAction { plan });
+        self.do_action(&action).await
+    }
+
+    pub async fn create_table(&mut self, plan: CreateTablePlan) -> Result<CreateTableActionResult> {
+        let action = DoActionAction::CreateTable(CreateTableAction { plan });
+        self.do_action(&action).await
+    }
+
+    pub async fn get_table(&mut self, db: String, table: String) -> Result<GetTableActionResult> {
+        let action = DoActionAction::GetTable(GetTableAction { db, table });
+        self.do_action(&action).await
+    }
+
+    pub async fn get_tables(&mut self, db: String) -> Result<GetTablesActionResult> {
+        let action = DoActionAction::GetTables(GetTablesAction { db });
+        self.do_action(&action).await
+    }
+
+    pub async fn drop_table(&mut self, plan: DropTablePlan) -> Result<DropTableActionResult> {
+        let action = DoActionAction::DropTable(DropTableAction { plan });
+        self.do_action(&action).await
+    }
+
+    /// Get the partitions of the table to read.
+    pub async fn read_plan(
+        &mut self,
+        db: String,
+        table: String,
+        push_down: PlanNode,
+    ) -> Result<ReadPlanActionResult> {
+        let action = DoActionAction::ReadPlan(ReadPlanAction {
+            db,
+            table,
+            push_down,
+        });
+        self.do_action(&action).await
+    }
+
+    /// Read the blocks of one partition, only the projected columns are sent back.
+    pub async fn read_partition(
+        &mut self,
+        db: String,
+        table: String,
+        partition: Partition,
+        push_downs: Extras,
+    ) -> Result<SendableDataBlockStream> {
+        let action = DoGetAction::Read(ReadAction {
+            db,
+            table,
+            partition,
+            push_downs,
+        });
+        let request: Request<Ticket> = (&action).try_into()?;
+        let mut stream = self.client.do_get(request).await?.into_inner();
+        match stream.message().await? {
+            Some(flight_data) => {
+                let schema = Arc::new(DataSchema::try_from(&flight_data)?);
+                let block_stream = stream.map(move |flight_data| {
+                    let batch = flight_data_to_arrow_batch(&flight_data?, schema.clone(), &[])?;
+                    batch.try_into()
+                });
+                Ok(Box::pin(block_stream))
+            }
+            None => bail!("Can not receive data from store flight server"),
+        }
+    }
+
+    /// Append the blocks to the table, they are committed at once after all of them are written.
+    pub async fn append_data(
+        &mut self,
+        db: String,
+        table: String,
+        schema: DataSchemaRef,
+        blocks: Vec<DataBlock>,
+    ) -> Result<AppendActionResult> {
+        let action = DoPutAction::Append(AppendAction { db, table });
+
+        // The first flight data is the schema with the action descriptor.
+        let options = arrow::ipc::writer::IpcWriteOptions::default();
+        let mut schema_flight_data = flight_data_from_arrow_schema(&schema, &options);
+        schema_flight_data.flight_descriptor = Some((&action).try_into()?);
+
+        let mut flights: Vec<FlightData> = vec![schema_flight_data];
+        for block in blocks {
+            if !block.is_empty() {
+                let batch = block.try_into()?;
+                let (flight_dicts, flight_batch) = flight_data_from_arrow_batch(&batch, &options);
+                flights.extend(flight_dicts);
+                flights.push(flight_batch);
+            }
+        }
+
+        let request = Request::new(futures::stream::iter(flights));
+        let mut stream = self
+            .client
+            .do_put(request)
+            .await
+            .map_err(|e| anyhow!("{}", e.message()))?
+            .into_inner();
+        match stream
+            .message()
+            .await
+            .map_err(|e| anyhow!("{}", e.message()))?
+        {
+            None => bail!("Can not receive data from store flight server"),
+            Some(resp) => Ok(serde_json::from_slice(&resp.app_metadata)?),
+        }
+    }
+
+    // Execute do_action, the result body is JSON encoded.
+    async fn do_action<T>(&mut self, action: &DoActionAction) -> Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let request: Request<Action> = action.try_into()?;
+        // The store errors are carried by the status message.
+        let mut stream = self
+            .client
+            .do_action(request)
+            .await
+            .map_err(|e| anyhow!("{}", e.message()))?
+            .into_inner();
+        match stream.message().await? {
+            None => bail!("Can not receive data from store flight server"),
+            Some(resp) => Ok(serde_json::from_slice(&resp.body)?),
+        }
+    }
+}
+
+/// Run the store calls in the synchronous datasource interfaces on the store client runtime.
+/// Inside a tokio runtime, the worker thread hands its tasks over to the other workers while
+/// blocking, so it must be a multi-thread runtime.
+pub fn block_on<F, T>(future: F) -> Result<T>
+where
+    F: Future<Output = Result<T>>,
+{
+    match Handle::try_current() {
+        Ok(_) => tokio::task::block_in_place(|| STORE_RUNTIME.block_on(future)),
+        Err(_) => STORE_RUNTIME.block_on(future),
+    }
+}
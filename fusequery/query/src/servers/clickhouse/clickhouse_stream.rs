@@ -6,11 +6,13 @@ use std::pin::Pin;
 use std::task::{Context, Poll};
 
 use anyhow::{bail, Result};
+use chrono::{Date, DateTime, NaiveDateTime, TimeZone};
+use chrono_tz::Tz;
 use clickhouse_srv::types::Block as ClickHouseBlock;
 use common_arrow::arrow::array::{as_boolean_array, as_primitive_array, as_string_array, Array};
 use common_arrow::arrow::datatypes::*;
 use common_datablocks::DataBlock;
-use common_datavalues::DataArrayRef;
+use common_datavalues::{data_array_cast, temporal_to_micros, DataArrayRef};
 use common_streams::SendableDataBlockStream;
 use futures::stream::Stream;
 use futures::StreamExt;
@@ -87,6 +89,33 @@ impl ClickHouseStream {
                     result = result.column(name, data);
                 }
 
+                DataType::Decimal(_, _) | DataType::List(_) => {
+                    // The decimals are sent as the formatted strings to keep all the digits,
+                    // so are the lists.
+                    let strings = data_array_cast(column, &DataType::Utf8)?;
+                    let data = build_string_column(&strings)?;
+                    result = result.column(name, data);
+                }
+
+                DataType::Date32 => {
+                    let data = build_date_column(column)?;
+                    result = result.column(name, data);
+                }
+                DataType::Timestamp(TimeUnit::Second, _) => {
+                    let data = build_date_time_column::<TimestampSecondType>(column)?;
+                    result = result.column(name, data);
+                }
+                DataType::Timestamp(TimeUnit::Microsecond, _) => {
+                    let data = build_date_time_column::<TimestampMicrosecondType>(column)?;
+                    result = result.column(name, data);
+                }
+
+                DataType::Null => {
+                    // The NULL literal is sent as the NULLs of Nullable(UInt8).
+                    let data = vec![None::<u8>; column.len()];
+                    result = result.column(name, data);
+                }
+
                 _ => bail!("Unsupported column type:{:?}", column.data_type()),
             }
         }
@@ -167,3 +196,32 @@ fn build_string_column(values: &DataArrayRef) -> Result<Vec<Option<&str>>> {
             .collect::<Vec<Option<&str>>>(),
     })
 }
+
+fn build_date_column(values: &DataArrayRef) -> Result<Vec<Option<Date<Tz>>>> {
+    Ok(build_date_time_column::<Date32Type>(values)?
+        .into_iter()
+        .map(|v| v.map(|v| v.date()))
+        .collect())
+}
+
+// The temporal values are in UTC.
+fn build_date_time_column<T>(values: &DataArrayRef) -> Result<Vec<Option<DateTime<Tz>>>>
+where
+    T: ArrowPrimitiveType,
+    T::Native: Into<i64>,
+{
+    build_primitive_column::<T>(values)?
+        .into_iter()
+        .map(|v| {
+            v.map(|v| {
+                let micros = temporal_to_micros(v.into(), values.data_type())?;
+                let time = NaiveDateTime::from_timestamp(
+                    micros.div_euclid(1_000_000),
+                    (micros.rem_euclid(1_000_000) * 1000) as u32,
+                );
+                Ok(Tz::UTC.from_utc_datetime(&time))
+            })
+            .transpose()
+        })
+        .collect()
+}
@@ -6,6 +6,7 @@ use anyhow::{bail, Result};
 use common_arrow::arrow::datatypes::DataType;
 use common_arrow::arrow::util::display::array_value_to_string;
 use common_datablocks::DataBlock;
+use common_datavalues::{is_decimal, is_temporal, DataValue};
 use msql_srv::*;
 
 pub struct MysqlStream {
@@ -47,7 +48,13 @@ impl MysqlStream {
                     coltype: ColumnType::MYSQL_TYPE_FLOAT,
                     colflags: ColumnFlags::empty(),
                 },
-                DataType::Utf8 => Column {
+                DataType::Decimal(_, _) => Column {
+                    table: "".to_string(),
+                    column: field.name().to_string(),
+                    coltype: ColumnType::MYSQL_TYPE_NEWDECIMAL,
+                    colflags: ColumnFlags::empty(),
+                },
+                DataType::Utf8 | DataType::List(_) => Column {
                     table: "".to_string(),
                     column: field.name().to_string(),
                     coltype: ColumnType::MYSQL_TYPE_VARCHAR,
@@ -59,12 +66,24 @@ impl MysqlStream {
                     coltype: ColumnType::MYSQL_TYPE_SHORT,
                     colflags: ColumnFlags::empty(),
                 },
-                DataType::Date64 => Column {
+                DataType::Date32 => Column {
+                    table: "".to_string(),
+                    column: field.name().to_string(),
+                    coltype: ColumnType::MYSQL_TYPE_DATE,
+                    colflags: ColumnFlags::empty(),
+                },
+                DataType::Date64 | DataType::Timestamp(_, _) => Column {
                     table: "".to_string(),
                     column: field.name().to_string(),
                     coltype: ColumnType::MYSQL_TYPE_TIMESTAMP,
                     colflags: ColumnFlags::empty(),
                 },
+                DataType::Null => Column {
+                    table: "".to_string(),
+                    column: field.name().to_string(),
+                    coltype: ColumnType::MYSQL_TYPE_NULL,
+                    colflags: ColumnFlags::empty(),
+                },
                 _ => bail!("Unsupported column type:{:?}", field.data_type()),
             });
         }
@@ -79,7 +98,18 @@ impl MysqlStream {
                     let mut row = Vec::with_capacity(cols_num);
                     for c in 0..cols_num {
                         let column = block.column(c);
-                        row.push(array_value_to_string(column, r)?);
+                        // The temporal, decimal and list values are formatted like the literals.
+                        let data_type = column.data_type();
+                        if column.is_null(r) {
+                            row.push(None);
+                        } else if is_temporal(data_type)
+                            || is_decimal(data_type)
+                            || matches!(data_type, DataType::List(_))
+                        {
+                            row.push(Some(DataValue::try_from_array(column, r)?.to_string()));
+                        } else {
+                            row.push(Some(array_value_to_string(column, r)?));
+                        }
                     }
                     row_writer.write_row(row)?;
                 }
@@ -12,6 +12,7 @@ use common_planners::{Partition, Partitions, Statistics};
 use uuid::Uuid;
 
 use crate::clusters::{Cluster, ClusterRef};
+use crate::configs::Config;
 use crate::datasources::{DataSource, IDataSource, ITable, ITableFunction};
 use crate::sessions::Settings;
 
@@ -29,12 +30,21 @@ pub type FuseQueryContextRef = Arc<FuseQueryContext>;
 
 impl FuseQueryContext {
     pub fn try_create() -> Result<FuseQueryContextRef> {
+        Self::try_create_with_datasource(DataSource::try_create()?)
+    }
+
+    // The context of a query session, the remote databases are loaded from the store of the config.
+    pub fn try_create_with_conf(conf: Config) -> Result<FuseQueryContextRef> {
+        Self::try_create_with_datasource(DataSource::try_create_with_config(conf)?)
+    }
+
+    fn try_create_with_datasource(datasource: DataSource) -> Result<FuseQueryContextRef> {
         let settings = Settings::create();
         let ctx = FuseQueryContext {
             uuid: Arc::new(RwLock::new(Uuid::new_v4().to_string())),
             settings,
             cluster: Arc::new(RwLock::new(Cluster::empty())),
-            datasource: Arc::new(RwLock::new(Box::new(DataSource::try_create()?))),
+            datasource: Arc::new(RwLock::new(Box::new(datasource))),
             statistics: Arc::new(RwLock::new(Statistics::default())),
             partition_queue: Arc::new(RwLock::new(VecDeque::new())),
         };
@@ -53,6 +63,14 @@ impl FuseQueryContext {
         Ok(Arc::new(self.clone()))
     }
 
+    // Create a context for a sub plan of the query, such as the build side of a join.
+    // It shares the settings and the datasource, but has its own partition pool.
+    pub fn try_create_child(&self) -> Result<FuseQueryContextRef> {
+        let mut ctx = self.clone();
+        ctx.partition_queue = Arc::new(RwLock::new(VecDeque::new()));
+        Ok(Arc::new(ctx))
+    }
+
     // ctx.reset will reset the necessary variables in the session
     pub fn reset(&self) -> Result<()> {
         self.statistics.write().clear();
@@ -10,17 +10,20 @@ use common_infallible::RwLock;
 use common_planners::Partitions;
 use metrics::counter;
 
+use crate::configs::Config;
 use crate::sessions::{FuseQueryContext, FuseQueryContextRef};
 
 pub struct Session {
+    conf: Config,
     sessions: RwLock<HashMap<String, FuseQueryContextRef>>,
 }
 
 pub type SessionRef = Arc<Session>;
 
 impl Session {
-    pub fn create() -> SessionRef {
+    pub fn create(conf: Config) -> SessionRef {
         Arc::new(Session {
+            conf,
             sessions: RwLock::new(HashMap::new()),
         })
     }
@@ -28,7 +31,7 @@ impl Session {
     pub fn try_create_context(&self) -> Result<FuseQueryContextRef> {
         counter!(super::metrics::METRIC_SESSION_CONNECT_NUMBERS, 1);
 
-        let ctx = FuseQueryContext::try_create()?;
+        let ctx = FuseQueryContext::try_create_with_conf(self.conf.clone())?;
         self.sessions.write().insert(ctx.get_id()?, ctx.clone());
         Ok(ctx)
     }
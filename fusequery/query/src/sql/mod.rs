@@ -13,6 +13,7 @@ mod util;
 pub use plan_parser::PlanParser;
 pub use sql_parser::DfParser;
 pub use sql_statement::{
-    DfCreateDatabase, DfCreateTable, DfExplain, DfShowSettings, DfShowTables, DfStatement,
+    DfCreateDatabase, DfCreateTable, DfDropDatabase, DfDropTable, DfExplain, DfShowSettings,
+    DfShowTables, DfStatement, DfTruncateTable,
 };
 pub use util::*;
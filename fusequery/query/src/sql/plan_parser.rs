@@ -7,17 +7,31 @@ use std::sync::Arc;
 
 use anyhow::{anyhow, bail, Result};
 use common_arrow::arrow;
-use common_datavalues::{DataField, DataSchema, DataValue};
+use common_datavalues::{DataField, DataSchema, DataSchemaRef, DataValue};
 use common_planners::{
-    CreateDatabasePlan, CreateTablePlan, ExplainPlan, ExpressionPlan, PlanBuilder, PlanNode,
-    SelectPlan, SettingPlan, StageState, VarValue,
+    col, CreateDatabasePlan, CreateTablePlan, DropDatabasePlan, DropTablePlan, ExplainPlan,
+    ExpressionPlan, InsertIntoPlan, JoinType, PlanBuilder, PlanNode, SelectPlan, SettingPlan,
+    StageState, TruncateTablePlan, VarValue,
+};
+use sqlparser::ast::{
+    BinaryOperator, ColumnOption, FunctionArg, JoinConstraint, JoinOperator, ObjectName, Statement,
+    TableFactor, UnaryOperator,
 };
-use sqlparser::ast::{FunctionArg, Statement, TableFactor};
 
 use crate::datasources::ITable;
 use crate::sessions::FuseQueryContextRef;
 use crate::sql::sql_statement::DfCreateTable;
-use crate::sql::{make_data_type, DfCreateDatabase, DfExplain, DfParser, DfStatement};
+use crate::sql::{
+    make_data_type, DfCreateDatabase, DfDropDatabase, DfDropTable, DfExplain, DfParser,
+    DfStatement, DfTruncateTable,
+};
+
+#[derive(Clone, Copy, PartialEq)]
+enum JoinSide {
+    Left,
+    Right,
+    Both,
+}
 
 pub struct PlanParser {
     ctx: FuseQueryContextRef,
@@ -42,6 +56,9 @@ impl PlanParser {
             DfStatement::Explain(v) => self.sql_explain_to_plan(&v),
             DfStatement::CreateDatabase(v) => self.sql_create_database_to_plan(&v),
             DfStatement::CreateTable(v) => self.sql_create_table_to_plan(&v),
+            DfStatement::DropDatabase(v) => self.sql_drop_database_to_plan(&v),
+            DfStatement::DropTable(v) => self.sql_drop_table_to_plan(&v),
+            DfStatement::TruncateTable(v) => self.sql_truncate_table_to_plan(&v),
 
             // TODO: support like and other filters in show queries
             DfStatement::ShowTables(_) => self.build_from_sql(
@@ -62,6 +79,12 @@ impl PlanParser {
             Statement::SetVariable {
                 variable, value, ..
             } => self.set_variable_to_plan(variable, value),
+            Statement::Insert {
+                table_name,
+                columns,
+                source,
+                ..
+            } => self.insert_into_to_plan(table_name, columns, source),
             _ => bail!("Unsupported statement {:?}", statement),
         }
     }
@@ -108,10 +131,23 @@ impl PlanParser {
 
         let mut fields = vec![];
         for col in create.columns.iter() {
+            // The columns are NOT NULL unless they are declared NULL.
+            let mut nullable = false;
+            for option in col.options.iter() {
+                match option.option {
+                    ColumnOption::Null => nullable = true,
+                    ColumnOption::NotNull => nullable = false,
+                    _ => bail!(
+                        "Unsupported column option {} of column {}",
+                        option,
+                        col.name
+                    ),
+                }
+            }
             fields.push(DataField::new(
                 &col.name.value,
                 make_data_type(&col.data_type)?,
-                false,
+                nullable,
             ));
         }
 
@@ -130,10 +166,132 @@ impl PlanParser {
         }))
     }
 
+    pub fn sql_drop_database_to_plan(&self, drop: &DfDropDatabase) -> Result<PlanNode> {
+        if drop.name.0.is_empty() {
+            bail!("Drop database name is empty");
+        }
+        let db = drop.name.0[0].value.clone();
+
+        Ok(PlanNode::DropDatabase(DropDatabasePlan {
+            if_exists: drop.if_exists,
+            db,
+            schema: Arc::new(arrow::datatypes::Schema::empty()),
+        }))
+    }
+
+    pub fn sql_drop_table_to_plan(&self, drop: &DfDropTable) -> Result<PlanNode> {
+        let (db, table) = self.resolve_table_name(&drop.name, "Drop")?;
+
+        Ok(PlanNode::DropTable(DropTablePlan {
+            if_exists: drop.if_exists,
+            db,
+            table,
+            schema: Arc::new(arrow::datatypes::Schema::empty()),
+        }))
+    }
+
+    pub fn sql_truncate_table_to_plan(&self, truncate: &DfTruncateTable) -> Result<PlanNode> {
+        let (db, table) = self.resolve_table_name(&truncate.name, "Truncate")?;
+
+        Ok(PlanNode::TruncateTable(TruncateTablePlan {
+            db,
+            table,
+            schema: Arc::new(arrow::datatypes::Schema::empty()),
+        }))
+    }
+
+    /// Split `[db.]table` into the database and the table, the database defaults to the current one.
+    fn resolve_table_name(&self, name: &ObjectName, action: &str) -> Result<(String, String)> {
+        let mut db = self.ctx.get_default_db()?;
+        if name.0.is_empty() {
+            bail!("{} table name is empty", action);
+        }
+        let mut table = name.0[0].value.clone();
+        if name.0.len() > 1 {
+            db = table;
+            table = name.0[1].value.clone();
+        }
+        Ok((db, table))
+    }
+
+    /// Generate a logic plan from an INSERT INTO ... VALUES or INSERT INTO ... SELECT
+    pub fn insert_into_to_plan(
+        &self,
+        table_name: &sqlparser::ast::ObjectName,
+        columns: &[sqlparser::ast::Ident],
+        source: &sqlparser::ast::Query,
+    ) -> Result<PlanNode> {
+        let (db, table) = self.resolve_table_name(table_name, "Insert")?;
+        let table_schema = self.ctx.get_table(&db, &table)?.schema()?;
+
+        // All the table columns are inserted if the columns are not specified.
+        let schema = if columns.is_empty() {
+            table_schema
+        } else {
+            let mut fields = Vec::with_capacity(columns.len());
+            for column in columns {
+                let field = table_schema.field_with_name(&column.value)?;
+                if fields.contains(field) {
+                    bail!("Insert column {} is specified more than once", column.value);
+                }
+                fields.push(field.clone());
+            }
+            Arc::new(DataSchema::new(fields))
+        };
+        let expect = schema.fields().len();
+
+        let (values, select) = match &source.body {
+            sqlparser::ast::SetExpr::Values(values) => {
+                let empty_schema = DataSchema::empty();
+                let mut rows = Vec::with_capacity(values.0.len());
+                for row in values.0.iter() {
+                    if row.len() != expect {
+                        bail!(
+                            "Insert values has {} columns, but {} columns are expected",
+                            row.len(),
+                            expect
+                        );
+                    }
+                    rows.push(
+                        row.iter()
+                            .map(|expr| self.sql_to_rex(expr, &empty_schema))
+                            .collect::<Result<Vec<_>>>()?,
+                    );
+                }
+                (rows, None)
+            }
+            _ => {
+                let plan = match self.query_to_plan(source)? {
+                    PlanNode::Select(select) => select.input,
+                    plan => Arc::new(plan),
+                };
+                let actual = plan.schema().fields().len();
+                if actual != expect {
+                    bail!(
+                        "Insert select has {} columns, but {} columns are expected",
+                        actual,
+                        expect
+                    );
+                }
+                (vec![], Some(plan))
+            }
+        };
+
+        Ok(PlanNode::InsertInto(InsertIntoPlan {
+            db,
+            table,
+            schema,
+            values,
+            select,
+        }))
+    }
+
     /// Generate a logic plan from an SQL query
     pub fn query_to_plan(&self, query: &sqlparser::ast::Query) -> Result<PlanNode> {
         match &query.body {
-            sqlparser::ast::SetExpr::Select(s) => self.select_to_plan(s.as_ref(), &query.limit),
+            sqlparser::ast::SetExpr::Select(s) => {
+                self.select_to_plan(s.as_ref(), &query.order_by, &query.limit)
+            }
             _ => bail!("Query {} not implemented yet", query.body),
         }
     }
@@ -142,12 +300,9 @@ impl PlanParser {
     fn select_to_plan(
         &self,
         select: &sqlparser::ast::Select,
+        order_by: &[sqlparser::ast::OrderByExpr],
         limit: &Option<sqlparser::ast::Expr>,
     ) -> Result<PlanNode> {
-        if select.having.is_some() {
-            bail!("HAVING is not implemented yet");
-        }
-
         // from.
         let plan = self.plan_tables_with_joins(&select.from)?;
 
@@ -161,19 +316,49 @@ impl PlanParser {
             .map(|e| self.sql_select_to_rex(&e, &plan.schema()))
             .collect::<Result<Vec<ExpressionPlan>>>()?;
 
+        // having.
+        let having_expr = match &select.having {
+            Some(expr) => Some(self.sql_to_rex(expr, &plan.schema())?),
+            None => None,
+        };
+
         // Aggregator check.
         let mut has_aggregator = false;
-        for expr in &projection_expr {
+        for expr in projection_expr.iter().chain(having_expr.iter()) {
             if expr.has_aggregator()? {
                 has_aggregator = true;
                 break;
             }
         }
 
-        let plan = if !select.group_by.is_empty() || has_aggregator {
-            self.aggregate(&plan, projection_expr, &select.group_by)?
-        } else {
-            self.project(&plan, projection_expr)?
+        // order by, the expressions are resolved on the output of the projection or aggregator.
+        let order_by_exprs = order_by
+            .iter()
+            .map(|e| self.sql_to_rex(&e.expr, &plan.schema()))
+            .collect::<Result<Vec<ExpressionPlan>>>()?;
+
+        let (plan, order_by_exprs, output_columns) =
+            if !select.group_by.is_empty() || has_aggregator {
+                self.aggregate(
+                    &plan,
+                    projection_expr,
+                    &select.group_by,
+                    &having_expr,
+                    &order_by_exprs,
+                )?
+            } else {
+                if having_expr.is_some() {
+                    bail!("HAVING clause requires GROUP BY or aggregate functions");
+                }
+                self.project_with_order_by(&plan, projection_expr, &order_by_exprs)?
+            };
+
+        let plan = self.sort(&plan, order_by, order_by_exprs, limit)?;
+
+        // Drop the hidden columns appended for HAVING and ORDER BY.
+        let plan = match output_columns {
+            Some(columns) => self.project(&plan, columns)?,
+            None => plan,
         };
 
         // limit.
@@ -205,7 +390,7 @@ impl PlanParser {
         match from.len() {
             0 => self.plan_with_dummy_source(),
             1 => self.plan_table_with_joins(&from[0]),
-            _ => bail!("Cannot support JOIN clause"),
+            _ => bail!("Cannot support multiple tables in FROM clause, use JOIN instead"),
         }
     }
 
@@ -222,7 +407,277 @@ impl PlanParser {
     }
 
     fn plan_table_with_joins(&self, t: &sqlparser::ast::TableWithJoins) -> Result<PlanNode> {
-        self.create_relation(&t.relation)
+        let mut plan = self.create_relation(&t.relation)?;
+        for join in &t.joins {
+            plan = self.join(&plan, join)?;
+        }
+        Ok(plan)
+    }
+
+    /// Wrap a plan in a hash join with the joined relation
+    fn join(&self, left: &PlanNode, join: &sqlparser::ast::Join) -> Result<PlanNode> {
+        let (join_type, constraint) = match &join.join_operator {
+            JoinOperator::Inner(constraint) => (JoinType::Inner, constraint),
+            JoinOperator::LeftOuter(constraint) => (JoinType::Left, constraint),
+            other => bail!("Unsupported JOIN operator: {:?}", other),
+        };
+
+        // The right columns clashed with the left are renamed to qualifier.column:
+        // select a.id, b.id from a join b on a.id = b.id
+        let qualifier = Self::relation_qualifier(&join.relation);
+        let right = self.create_relation(&join.relation)?;
+        let left_schema = left.schema();
+        let right_schema = right.schema();
+        let mut renamed = false;
+        let mut right_exprs = Vec::with_capacity(right_schema.fields().len());
+        for field in right_schema.fields() {
+            match &qualifier {
+                Some(qualifier) if left_schema.field_with_name(field.name()).is_ok() => {
+                    renamed = true;
+                    right_exprs.push(col(field.name()).alias(&format!(
+                        "{}.{}",
+                        qualifier,
+                        field.name()
+                    )));
+                }
+                _ => right_exprs.push(col(field.name())),
+            }
+        }
+        let right = if renamed {
+            self.project(&right, right_exprs)?
+        } else {
+            right
+        };
+
+        // Extract the equi-join keys, the other conditions are applied after the inner join.
+        let mut left_keys = vec![];
+        let mut right_keys = vec![];
+        let mut residuals = vec![];
+        match constraint {
+            JoinConstraint::On(expr) => {
+                let mut conjunctions = vec![];
+                Self::split_conjunctions(expr, &mut conjunctions);
+                for conjunction in conjunctions {
+                    if let sqlparser::ast::Expr::BinaryOp {
+                        left: l,
+                        op: BinaryOperator::Eq,
+                        right: r,
+                    } = conjunction
+                    {
+                        let l_side =
+                            Self::join_expr_side(l, &left_schema, &right_schema, &qualifier)?;
+                        let r_side =
+                            Self::join_expr_side(r, &left_schema, &right_schema, &qualifier)?;
+                        match (l_side, r_side) {
+                            (Some(JoinSide::Left), Some(JoinSide::Right)) => {
+                                left_keys.push(self.sql_to_rex(l, &left_schema)?);
+                                right_keys.push(self.sql_to_rex(r, &right.schema())?);
+                                continue;
+                            }
+                            (Some(JoinSide::Right), Some(JoinSide::Left)) => {
+                                left_keys.push(self.sql_to_rex(r, &left_schema)?);
+                                right_keys.push(self.sql_to_rex(l, &right.schema())?);
+                                continue;
+                            }
+                            _ => {}
+                        }
+                    }
+                    residuals.push(conjunction);
+                }
+            }
+            JoinConstraint::Using(idents) => {
+                for ident in idents {
+                    left_keys.push(col(&ident.value));
+                    right_keys.push(match &qualifier {
+                        Some(qualifier) => col(&format!("{}.{}", qualifier, ident.value)),
+                        None => col(&ident.value),
+                    });
+                }
+            }
+            other => bail!("Unsupported JOIN constraint: {:?}", other),
+        }
+
+        if left_keys.is_empty() {
+            bail!("JOIN must have at least one equality condition between the two sides");
+        }
+        if join_type == JoinType::Left && !residuals.is_empty() {
+            bail!(
+                "Only the equality conditions are supported in LEFT JOIN, but got: {}",
+                residuals[0]
+            );
+        }
+
+        // The hash table is built from the smaller side, the left join always builds from the right.
+        // The stage shuffles the join in the cluster.
+        let plan = if join_type == JoinType::Left
+            || Self::estimated_rows(left)? >= Self::estimated_rows(&right)?
+        {
+            PlanBuilder::from(left)
+                .join(join_type, &right, left_keys, right_keys)?
+                .stage(self.ctx.get_id()?, StageState::JoinShuffle)?
+                .build()?
+        } else {
+            // Restore the column order of the left and right.
+            let columns = left_schema
+                .fields()
+                .iter()
+                .chain(right.schema().fields().iter())
+                .map(|field| col(field.name()))
+                .collect::<Vec<ExpressionPlan>>();
+            PlanBuilder::from(&right)
+                .join(join_type, left, right_keys, left_keys)?
+                .stage(self.ctx.get_id()?, StageState::JoinShuffle)?
+                .project(columns)?
+                .build()?
+        };
+
+        let mut predicate: Option<ExpressionPlan> = None;
+        for residual in residuals {
+            let expr = self.sql_to_rex(residual, &plan.schema())?;
+            predicate = Some(match predicate {
+                Some(predicate) => predicate.and(expr),
+                None => expr,
+            });
+        }
+        match predicate {
+            Some(predicate) => PlanBuilder::from(&plan).filter(predicate)?.build(),
+            None => Ok(plan),
+        }
+    }
+
+    /// The qualifier of the relation columns, the alias or the table name.
+    fn relation_qualifier(relation: &TableFactor) -> Option<String> {
+        match relation {
+            TableFactor::Table { name, alias, .. } => match alias {
+                Some(alias) => Some(alias.name.value.clone()),
+                None => name.0.last().map(|v| v.value.clone()),
+            },
+            TableFactor::Derived { alias, .. } => alias.as_ref().map(|v| v.name.value.clone()),
+            _ => None,
+        }
+    }
+
+    fn split_conjunctions<'a>(
+        expr: &'a sqlparser::ast::Expr,
+        conjunctions: &mut Vec<&'a sqlparser::ast::Expr>,
+    ) {
+        match expr {
+            sqlparser::ast::Expr::BinaryOp {
+                left,
+                op: BinaryOperator::And,
+                right,
+            } => {
+                Self::split_conjunctions(left, conjunctions);
+                Self::split_conjunctions(right, conjunctions);
+            }
+            sqlparser::ast::Expr::Nested(e) => Self::split_conjunctions(e, conjunctions),
+            other => conjunctions.push(other),
+        }
+    }
+
+    /// Which side of the join the expression columns are from, None if it has no columns.
+    fn join_expr_side(
+        expr: &sqlparser::ast::Expr,
+        left_schema: &DataSchemaRef,
+        right_schema: &DataSchemaRef,
+        right_qualifier: &Option<String>,
+    ) -> Result<Option<JoinSide>> {
+        let mut columns = vec![];
+        Self::sql_column_references(expr, &mut columns);
+
+        let mut side = None;
+        for (qualifier, name) in columns {
+            let column_side = match qualifier {
+                Some(qualifier) if Some(&qualifier) == right_qualifier.as_ref() => JoinSide::Right,
+                Some(_) => JoinSide::Left,
+                None => match (
+                    left_schema.field_with_name(&name).is_ok(),
+                    right_schema.field_with_name(&name).is_ok(),
+                ) {
+                    (true, false) => JoinSide::Left,
+                    (false, true) => JoinSide::Right,
+                    (true, true) => bail!("Column {} in JOIN is ambiguous", name),
+                    (false, false) => bail!("Unknown column {} in JOIN", name),
+                },
+            };
+            side = match side {
+                Some(side) if side != column_side => Some(JoinSide::Both),
+                _ => Some(column_side),
+            };
+        }
+        Ok(side)
+    }
+
+    /// The (qualifier, name) of the columns referenced by the SQL expression.
+    fn sql_column_references(
+        expr: &sqlparser::ast::Expr,
+        columns: &mut Vec<(Option<String>, String)>,
+    ) {
+        match expr {
+            sqlparser::ast::Expr::Identifier(v) => columns.push((None, v.value.clone())),
+            sqlparser::ast::Expr::CompoundIdentifier(v) if v.len() >= 2 => columns.push((
+                Some(v[v.len() - 2].value.clone()),
+                v[v.len() - 1].value.clone(),
+            )),
+            sqlparser::ast::Expr::BinaryOp { left, right, .. } => {
+                Self::sql_column_references(left, columns);
+                Self::sql_column_references(right, columns);
+            }
+            sqlparser::ast::Expr::Nested(e)
+            | sqlparser::ast::Expr::IsNull(e)
+            | sqlparser::ast::Expr::IsNotNull(e)
+            | sqlparser::ast::Expr::Cast { expr: e, .. }
+            | sqlparser::ast::Expr::UnaryOp { expr: e, .. } => {
+                Self::sql_column_references(e, columns)
+            }
+            sqlparser::ast::Expr::InList { expr, list, .. } => {
+                Self::sql_column_references(expr, columns);
+                for e in list {
+                    Self::sql_column_references(e, columns);
+                }
+            }
+            sqlparser::ast::Expr::Between {
+                expr, low, high, ..
+            } => {
+                Self::sql_column_references(expr, columns);
+                Self::sql_column_references(low, columns);
+                Self::sql_column_references(high, columns);
+            }
+            sqlparser::ast::Expr::Case {
+                operand,
+                conditions,
+                results,
+                else_result,
+            } => {
+                let exprs = operand.iter().chain(else_result.iter()).map(|e| e.as_ref());
+                for expr in exprs.chain(conditions).chain(results) {
+                    Self::sql_column_references(expr, columns);
+                }
+            }
+            sqlparser::ast::Expr::Function(e) => {
+                for arg in &e.args {
+                    match arg {
+                        FunctionArg::Named { arg, .. } | FunctionArg::Unnamed(arg) => {
+                            Self::sql_column_references(arg, columns)
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// The estimated rows of the plan by the statistics of its source.
+    fn estimated_rows(plan: &PlanNode) -> Result<usize> {
+        let mut rows = 0;
+        plan.walk_preorder(|node| match node {
+            PlanNode::ReadSource(v) => {
+                rows = v.statistics.read_rows;
+                Ok(false)
+            }
+            _ => Ok(true),
+        })?;
+        Ok(rows)
     }
 
     fn create_relation(&self, relation: &sqlparser::ast::TableFactor) -> Result<PlanNode> {
@@ -289,12 +744,112 @@ impl PlanParser {
     ) -> Result<ExpressionPlan> {
         match sql {
             sqlparser::ast::Expr::Identifier(ref v) => Ok(ExpressionPlan::Column(v.clone().value)),
+            sqlparser::ast::Expr::CompoundIdentifier(ref v) if !v.is_empty() => {
+                // The qualified name is kept only if the join renamed the column with it.
+                if v.len() >= 2 {
+                    let name = format!("{}.{}", v[v.len() - 2].value, v[v.len() - 1].value);
+                    if schema.field_with_name(&name).is_ok() {
+                        return Ok(ExpressionPlan::Column(name));
+                    }
+                }
+                Ok(ExpressionPlan::Column(v[v.len() - 1].value.clone()))
+            }
             sqlparser::ast::Expr::Value(sqlparser::ast::Value::Number(n, _)) => {
                 Ok(ExpressionPlan::Literal(DataValue::try_from_literal(n)?))
             }
             sqlparser::ast::Expr::Value(sqlparser::ast::Value::SingleQuotedString(s)) => {
                 Ok(ExpressionPlan::Literal(DataValue::String(Some(s.clone()))))
             }
+            sqlparser::ast::Expr::Value(sqlparser::ast::Value::Null) => {
+                Ok(ExpressionPlan::Literal(DataValue::Null))
+            }
+            sqlparser::ast::Expr::IsNull(e) => Ok(ExpressionPlan::Function {
+                op: "isnull".to_string(),
+                args: vec![self.sql_to_rex(e, schema)?],
+            }),
+            sqlparser::ast::Expr::IsNotNull(e) => Ok(ExpressionPlan::Function {
+                op: "isnotnull".to_string(),
+                args: vec![self.sql_to_rex(e, schema)?],
+            }),
+            sqlparser::ast::Expr::UnaryOp { op, expr } => match (op, expr.as_ref()) {
+                (UnaryOperator::Plus, _) => self.sql_to_rex(expr, schema),
+                // The negative number is a literal rather than the negation of the positive one.
+                (
+                    UnaryOperator::Minus,
+                    sqlparser::ast::Expr::Value(sqlparser::ast::Value::Number(n, _)),
+                ) => Ok(ExpressionPlan::Literal(DataValue::try_from_literal(
+                    &format!("-{}", n),
+                )?)),
+                (UnaryOperator::Minus, _) => Ok(ExpressionPlan::UnaryExpression {
+                    op: "negate".to_string(),
+                    expr: Box::new(self.sql_to_rex(expr, schema)?),
+                }),
+                (UnaryOperator::Not, _) => Ok(ExpressionPlan::UnaryExpression {
+                    op: "not".to_string(),
+                    expr: Box::new(self.sql_to_rex(expr, schema)?),
+                }),
+                _ => bail!("Unsupported unary operator {}", op),
+            },
+            sqlparser::ast::Expr::InList {
+                expr,
+                list,
+                negated,
+            } => Ok(ExpressionPlan::InList {
+                expr: Box::new(self.sql_to_rex(expr, schema)?),
+                list: list
+                    .iter()
+                    .map(|e| self.sql_to_rex(e, schema))
+                    .collect::<Result<Vec<_>>>()?,
+                negated: *negated,
+            }),
+            sqlparser::ast::Expr::Between {
+                expr,
+                negated,
+                low,
+                high,
+            } => Ok(ExpressionPlan::Between {
+                expr: Box::new(self.sql_to_rex(expr, schema)?),
+                low: Box::new(self.sql_to_rex(low, schema)?),
+                high: Box::new(self.sql_to_rex(high, schema)?),
+                negated: *negated,
+            }),
+            sqlparser::ast::Expr::Cast { expr, data_type } => Ok(ExpressionPlan::Cast {
+                expr: Box::new(self.sql_to_rex(expr, schema)?),
+                data_type: make_data_type(data_type)?,
+            }),
+            sqlparser::ast::Expr::Case {
+                operand,
+                conditions,
+                results,
+                else_result,
+            } => {
+                let operand = operand
+                    .as_ref()
+                    .map(|e| self.sql_to_rex(e, schema))
+                    .transpose()?;
+                let mut args = Vec::with_capacity(conditions.len() * 2 + 1);
+                for (condition, result) in conditions.iter().zip(results) {
+                    let condition = self.sql_to_rex(condition, schema)?;
+                    args.push(match &operand {
+                        // The simple CASE compares the operand with the WHEN values.
+                        Some(operand) => ExpressionPlan::BinaryExpression {
+                            op: "=".to_string(),
+                            left: Box::new(operand.clone()),
+                            right: Box::new(condition),
+                        },
+                        None => condition,
+                    });
+                    args.push(self.sql_to_rex(result, schema)?);
+                }
+                args.push(match else_result {
+                    Some(e) => self.sql_to_rex(e, schema)?,
+                    None => ExpressionPlan::Literal(DataValue::Null),
+                });
+                Ok(ExpressionPlan::Function {
+                    op: "multiIf".to_string(),
+                    args,
+                })
+            }
             sqlparser::ast::Expr::BinaryOp { left, op, right } => {
                 Ok(ExpressionPlan::BinaryExpression {
                     op: format!("{}", op),
@@ -315,10 +870,15 @@ impl PlanParser {
                         }
                     }
                 }
-                Ok(ExpressionPlan::Function {
-                    op: e.name.to_string(),
-                    args,
-                })
+                let mut op = e.name.to_string();
+                if e.distinct {
+                    // Only count has the DISTINCT form, it's the exact distinct count.
+                    if !op.eq_ignore_ascii_case("count") {
+                        bail!("Unsupported DISTINCT in function {}", op);
+                    }
+                    op = "uniqExact".to_string();
+                }
+                Ok(ExpressionPlan::Function { op, args })
             }
             sqlparser::ast::Expr::Wildcard => Ok(ExpressionPlan::Wildcard),
             _ => bail!("Unsupported ExpressionPlan: {}", sql),
@@ -361,38 +921,365 @@ impl PlanParser {
         PlanBuilder::from(input).project(expr)?.build()
     }
 
+    /// Wrap a plan in a projection, the ORDER BY expressions which can't be evaluated on the
+    /// projection output are appended to it as hidden columns:
+    /// select number from numbers(10) order by number % 3
+    /// Returns the plan, the sort expressions and the output columns if there are hidden ones.
+    fn project_with_order_by(
+        &self,
+        input: &PlanNode,
+        expr: Vec<ExpressionPlan>,
+        order_by: &[ExpressionPlan],
+    ) -> Result<(PlanNode, Vec<ExpressionPlan>, Option<Vec<ExpressionPlan>>)> {
+        let source_schema = input.schema();
+        let plan = self.project(input, expr.clone())?;
+        let output_schema = plan.schema();
+
+        let mut exprs = expr.clone();
+        let mut order_by_exprs = Vec::with_capacity(order_by.len());
+        for order_by_expr in order_by {
+            let resolved =
+                Self::resolve_order_by_expr(order_by_expr.clone(), &expr, &source_schema);
+            if resolved.to_data_field(&output_schema).is_ok() {
+                order_by_exprs.push(resolved);
+            } else {
+                exprs.push(order_by_expr.clone());
+                order_by_exprs.push(col(order_by_expr.to_data_field(&source_schema)?.name()));
+            }
+        }
+
+        if exprs.len() == expr.len() {
+            return Ok((plan, order_by_exprs, None));
+        }
+        let columns = output_schema
+            .fields()
+            .iter()
+            .map(|field| col(field.name()))
+            .collect::<Vec<_>>();
+        Ok((self.project(input, exprs)?, order_by_exprs, Some(columns)))
+    }
+
     /// Wrap a plan for an aggregate
     fn aggregate(
         &self,
         input: &PlanNode,
         aggr_expr: Vec<ExpressionPlan>,
         group_by: &[sqlparser::ast::Expr],
-    ) -> Result<PlanNode> {
+        having: &Option<ExpressionPlan>,
+        order_by: &[ExpressionPlan],
+    ) -> Result<(PlanNode, Vec<ExpressionPlan>, Option<Vec<ExpressionPlan>>)> {
         let group_expr: Vec<ExpressionPlan> = group_by
             .iter()
-            .map(|e| self.sql_to_rex(&e, &input.schema()))
+            .map(|e| {
+                let expr = self.sql_to_rex(&e, &input.schema())?;
+                Ok(Self::resolve_group_by_alias(
+                    expr,
+                    &input.schema(),
+                    &aggr_expr,
+                ))
+            })
+            .collect::<Result<Vec<ExpressionPlan>>>()?;
+
+        // The non-aggregate expressions must be the group by expressions.
+        for expr in &aggr_expr {
+            if !expr.has_aggregator()? && !Self::expr_in_group_by(expr, &group_expr) {
+                bail!(
+                    "Column {:?} is not under aggregate function and not in GROUP BY",
+                    expr
+                );
+            }
+        }
+
+        // The aggregate functions and group by expressions in HAVING and ORDER BY which are not
+        // selected are appended to the aggregator, they are dropped by a projection at last.
+        let select_len = aggr_expr.len();
+        let mut aggr_expr = aggr_expr;
+        let having_expr = match having {
+            Some(expr) => Some(Self::rewrite_aggregate_expr(
+                expr,
+                &input.schema(),
+                &group_expr,
+                &mut aggr_expr,
+            )?),
+            None => None,
+        };
+        let order_by_exprs = order_by
+            .iter()
+            .map(|expr| {
+                Self::rewrite_aggregate_expr(expr, &input.schema(), &group_expr, &mut aggr_expr)
+            })
             .collect::<Result<Vec<ExpressionPlan>>>()?;
 
         // S0: Apply a partial aggregator plan.
         // S1: Apply a fragment plan for distributed planners split.
         // S2: Apply a final aggregator plan.
-        PlanBuilder::from(&input)
+        // S3: Apply a having plan on the final aggregator output.
+        let mut builder = PlanBuilder::from(&input)
             .aggregate_partial(aggr_expr.clone(), group_expr.clone())?
             .stage(self.ctx.get_id()?, StageState::AggregatorMerge)?
-            .aggregate_final(aggr_expr, group_expr)?
+            .aggregate_final(aggr_expr.clone(), group_expr)?;
+        if let Some(expr) = having_expr {
+            builder = builder.having(expr)?;
+        }
+
+        let mut output_columns = None;
+        if aggr_expr.len() > select_len {
+            output_columns = Some(
+                aggr_expr[..select_len]
+                    .iter()
+                    .map(|e| Ok(col(e.to_data_field(&input.schema())?.name())))
+                    .collect::<Result<Vec<ExpressionPlan>>>()?,
+            );
+        }
+        Ok((builder.build()?, order_by_exprs, output_columns))
+    }
+
+    /// Rewrite the HAVING or ORDER BY expression to the columns of the aggregator output:
+    /// select number%3 as k from numbers(10) group by k having count() > 3
+    fn rewrite_aggregate_expr(
+        expr: &ExpressionPlan,
+        schema: &DataSchemaRef,
+        group_expr: &[ExpressionPlan],
+        aggr_expr: &mut Vec<ExpressionPlan>,
+    ) -> Result<ExpressionPlan> {
+        // Already in the aggregator output, either by alias or by the same expression.
+        let expr_str = format!("{:?}", expr);
+        for select_expr in aggr_expr.iter() {
+            let matched = match (select_expr, expr) {
+                (ExpressionPlan::Wildcard, _) => false,
+                (ExpressionPlan::Alias(alias, _), ExpressionPlan::Column(name))
+                    if alias == name =>
+                {
+                    true
+                }
+                (ExpressionPlan::Alias(_, v), _) => format!("{:?}", v) == expr_str,
+                _ => format!("{:?}", select_expr) == expr_str,
+            };
+            if matched {
+                return Ok(col(select_expr.to_data_field(schema)?.name()));
+            }
+        }
+
+        // Not selected yet, append it to the aggregator.
+        if Self::is_aggregate_function(expr)?
+            || group_expr.iter().any(|g| format!("{:?}", g) == expr_str)
+        {
+            aggr_expr.push(expr.clone());
+            return Ok(col(expr.to_data_field(schema)?.name()));
+        }
+
+        Ok(match expr {
+            ExpressionPlan::Alias(alias, v) => ExpressionPlan::Alias(
+                alias.clone(),
+                Box::new(Self::rewrite_aggregate_expr(
+                    v, schema, group_expr, aggr_expr,
+                )?),
+            ),
+            ExpressionPlan::BinaryExpression { left, op, right } => {
+                ExpressionPlan::BinaryExpression {
+                    left: Box::new(Self::rewrite_aggregate_expr(
+                        left, schema, group_expr, aggr_expr,
+                    )?),
+                    op: op.clone(),
+                    right: Box::new(Self::rewrite_aggregate_expr(
+                        right, schema, group_expr, aggr_expr,
+                    )?),
+                }
+            }
+            ExpressionPlan::Function { op, args } => ExpressionPlan::Function {
+                op: op.clone(),
+                args: args
+                    .iter()
+                    .map(|arg| Self::rewrite_aggregate_expr(arg, schema, group_expr, aggr_expr))
+                    .collect::<Result<Vec<ExpressionPlan>>>()?,
+            },
+            ExpressionPlan::Cast { expr, data_type } => ExpressionPlan::Cast {
+                expr: Box::new(Self::rewrite_aggregate_expr(
+                    expr, schema, group_expr, aggr_expr,
+                )?),
+                data_type: data_type.clone(),
+            },
+            ExpressionPlan::UnaryExpression { op, expr } => ExpressionPlan::UnaryExpression {
+                op: op.clone(),
+                expr: Box::new(Self::rewrite_aggregate_expr(
+                    expr, schema, group_expr, aggr_expr,
+                )?),
+            },
+            ExpressionPlan::InList {
+                expr,
+                list,
+                negated,
+            } => ExpressionPlan::InList {
+                expr: Box::new(Self::rewrite_aggregate_expr(
+                    expr, schema, group_expr, aggr_expr,
+                )?),
+                list: list
+                    .iter()
+                    .map(|e| Self::rewrite_aggregate_expr(e, schema, group_expr, aggr_expr))
+                    .collect::<Result<Vec<ExpressionPlan>>>()?,
+                negated: *negated,
+            },
+            ExpressionPlan::Between {
+                expr,
+                low,
+                high,
+                negated,
+            } => ExpressionPlan::Between {
+                expr: Box::new(Self::rewrite_aggregate_expr(
+                    expr, schema, group_expr, aggr_expr,
+                )?),
+                low: Box::new(Self::rewrite_aggregate_expr(
+                    low, schema, group_expr, aggr_expr,
+                )?),
+                high: Box::new(Self::rewrite_aggregate_expr(
+                    high, schema, group_expr, aggr_expr,
+                )?),
+                negated: *negated,
+            },
+            ExpressionPlan::Column(_) => bail!(
+                "Column {:?} is not under aggregate function and not in GROUP BY",
+                expr
+            ),
+            other => other.clone(),
+        })
+    }
+
+    /// An aggregate function call whose arguments have no aggregate function, such as count().
+    fn is_aggregate_function(expr: &ExpressionPlan) -> Result<bool> {
+        if let ExpressionPlan::Function { args, .. } = expr {
+            if expr.has_aggregator()? {
+                for arg in args {
+                    if arg.has_aggregator()? {
+                        return Ok(false);
+                    }
+                }
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    /// Group by alias: select number%3 as k, count(*) from numbers(10) group by k
+    fn resolve_group_by_alias(
+        expr: ExpressionPlan,
+        schema: &DataSchema,
+        select_exprs: &[ExpressionPlan],
+    ) -> ExpressionPlan {
+        if let ExpressionPlan::Column(ref name) = expr {
+            if schema.field_with_name(name).is_err() {
+                for select_expr in select_exprs {
+                    if let ExpressionPlan::Alias(alias, alias_expr) = select_expr {
+                        if alias == name {
+                            return alias_expr.as_ref().clone();
+                        }
+                    }
+                }
+            }
+        }
+        expr
+    }
+
+    fn expr_in_group_by(expr: &ExpressionPlan, group_expr: &[ExpressionPlan]) -> bool {
+        let expr_str = format!("{:?}", expr);
+        if group_expr.iter().any(|g| format!("{:?}", g) == expr_str) {
+            return true;
+        }
+
+        match expr {
+            ExpressionPlan::Alias(_, v) => Self::expr_in_group_by(v, group_expr),
+            ExpressionPlan::Literal(_) => true,
+            ExpressionPlan::BinaryExpression { left, right, .. } => {
+                Self::expr_in_group_by(left, group_expr)
+                    && Self::expr_in_group_by(right, group_expr)
+            }
+            ExpressionPlan::Function { args, .. } => args
+                .iter()
+                .all(|arg| Self::expr_in_group_by(arg, group_expr)),
+            ExpressionPlan::Cast { expr, .. }
+            | ExpressionPlan::UnaryExpression { expr, .. }
+            | ExpressionPlan::Sort { expr, .. } => Self::expr_in_group_by(expr, group_expr),
+            ExpressionPlan::InList { expr, list, .. } => {
+                Self::expr_in_group_by(expr, group_expr)
+                    && list.iter().all(|e| Self::expr_in_group_by(e, group_expr))
+            }
+            ExpressionPlan::Between {
+                expr, low, high, ..
+            } => [expr, low, high]
+                .iter()
+                .all(|e| Self::expr_in_group_by(e, group_expr)),
+            ExpressionPlan::Column(_) | ExpressionPlan::Wildcard => false,
+        }
+    }
+
+    /// Wrap a plan in a sort, the limit is pushed into the sort as top-N
+    fn sort(
+        &self,
+        input: &PlanNode,
+        order_by: &[sqlparser::ast::OrderByExpr],
+        order_by_exprs: Vec<ExpressionPlan>,
+        limit: &Option<sqlparser::ast::Expr>,
+    ) -> Result<PlanNode> {
+        if order_by.is_empty() {
+            return Ok(input.clone());
+        }
+
+        let order_by_exprs = order_by
+            .iter()
+            .zip(order_by_exprs)
+            .map(|(e, expr)| ExpressionPlan::Sort {
+                expr: Box::new(expr),
+                asc: e.asc.unwrap_or(true),
+                nulls_first: e.nulls_first.unwrap_or(false),
+            })
+            .collect::<Vec<ExpressionPlan>>();
+
+        let limit = self.limit_value(input, limit)?;
+        PlanBuilder::from(input)
+            .sort(order_by_exprs, limit)?
             .build()
     }
 
+    /// The ORDER BY expression which is already selected is replaced by the output column:
+    /// select number+1 from numbers(10) order by number+1
+    fn resolve_order_by_expr(
+        expr: ExpressionPlan,
+        select_exprs: &[ExpressionPlan],
+        source_schema: &DataSchemaRef,
+    ) -> ExpressionPlan {
+        let expr_str = format!("{:?}", expr);
+        for select_expr in select_exprs {
+            let inner_expr = match select_expr {
+                ExpressionPlan::Alias(_, v) => v.as_ref(),
+                other => other,
+            };
+
+            if format!("{:?}", inner_expr) == expr_str {
+                if let Ok(field) = select_expr.to_data_field(source_schema) {
+                    return ExpressionPlan::Column(field.name().clone());
+                }
+            }
+        }
+        expr
+    }
+
+    fn limit_value(
+        &self,
+        input: &PlanNode,
+        limit: &Option<sqlparser::ast::Expr>,
+    ) -> Result<Option<usize>> {
+        match *limit {
+            Some(ref limit_expr) => match self.sql_to_rex(&limit_expr, &input.schema())? {
+                ExpressionPlan::Literal(DataValue::UInt64(Some(n))) => Ok(Some(n as usize)),
+                _ => Err(anyhow!("Unexpected expression for LIMIT clause")),
+            },
+            _ => Ok(None),
+        }
+    }
+
     /// Wrap a plan in a limit
     fn limit(&self, input: &PlanNode, limit: &Option<sqlparser::ast::Expr>) -> Result<PlanNode> {
-        match *limit {
-            Some(ref limit_expr) => {
-                let n = match self.sql_to_rex(&limit_expr, &input.schema())? {
-                    ExpressionPlan::Literal(DataValue::UInt64(Some(n))) => Ok(n as usize),
-                    _ => Err(anyhow!("Unexpected expression for LIMIT clause")),
-                }?;
-                Ok(PlanBuilder::from(&input).limit(n)?.build()?)
-            }
+        match self.limit_value(input, limit)? {
+            Some(n) => Ok(PlanBuilder::from(&input).limit(n)?.build()?),
             _ => Ok(input.clone()),
         }
     }
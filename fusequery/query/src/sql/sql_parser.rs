@@ -15,7 +15,8 @@ use sqlparser::{
 };
 
 use crate::sql::{
-    DfCreateDatabase, DfCreateTable, DfExplain, DfShowSettings, DfShowTables, DfStatement,
+    DfCreateDatabase, DfCreateTable, DfDropDatabase, DfDropTable, DfExplain, DfShowSettings,
+    DfShowTables, DfStatement, DfTruncateTable,
 };
 
 // Use `Parser::expected` instead, if possible
@@ -97,6 +98,14 @@ impl<'a> DfParser<'a> {
                         // use custom parsing
                         self.parse_create()
                     }
+                    Keyword::DROP => {
+                        self.parser.next_token();
+                        self.parse_drop()
+                    }
+                    Keyword::TRUNCATE => {
+                        self.parser.next_token();
+                        self.parse_truncate()
+                    }
                     Keyword::EXPLAIN => {
                         self.parser.next_token();
                         self.parse_explain()
@@ -340,18 +349,65 @@ impl<'a> DfParser<'a> {
                 "JSONEachRaw" => Ok(TableEngineType::JsonEachRaw),
                 "CSV" => Ok(TableEngineType::Csv),
                 "Null" => Ok(TableEngineType::Null),
+                "Memory" => Ok(TableEngineType::Memory),
+                "Remote" => Ok(TableEngineType::Remote),
                 _ => self.expected(
-                    "Engine must one of Parquet, JSONEachRaw, Null or CSV",
+                    "Engine must one of Parquet, JSONEachRaw, Null, Memory, Remote or CSV",
                     Token::Word(w),
                 ),
             },
             unexpected => self.expected(
-                "Engine must one of Parquet, JSONEachRaw, Null or CSV",
+                "Engine must one of Parquet, JSONEachRaw, Null, Memory, Remote or CSV",
                 unexpected,
             ),
         }
     }
 
+    fn parse_drop(&mut self) -> Result<DfStatement, ParserError> {
+        match self.parser.next_token() {
+            Token::Word(w) => match w.keyword {
+                Keyword::TABLE => self.parse_drop_table(),
+                Keyword::DATABASE => self.parse_drop_database(),
+                _ => self.expected("drop statement", Token::Word(w)),
+            },
+            unexpected => self.expected("drop statement", unexpected),
+        }
+    }
+
+    fn parse_drop_table(&mut self) -> Result<DfStatement, ParserError> {
+        let if_exists = self.parser.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
+        let table_name = self.parser.parse_object_name()?;
+
+        let drop = DfDropTable {
+            if_exists,
+            name: table_name,
+        };
+
+        Ok(DfStatement::DropTable(drop))
+    }
+
+    fn parse_drop_database(&mut self) -> Result<DfStatement, ParserError> {
+        let if_exists = self.parser.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
+        let db_name = self.parser.parse_object_name()?;
+
+        let drop = DfDropDatabase {
+            if_exists,
+            name: db_name,
+        };
+
+        Ok(DfStatement::DropDatabase(drop))
+    }
+
+    // TRUNCATE [TABLE] tbl_name
+    fn parse_truncate(&mut self) -> Result<DfStatement, ParserError> {
+        self.parser.parse_keyword(Keyword::TABLE);
+        let table_name = self.parser.parse_object_name()?;
+
+        Ok(DfStatement::TruncateTable(DfTruncateTable {
+            name: table_name,
+        }))
+    }
+
     fn consume_token(&mut self, expected: &str) -> bool {
         if self.parser.peek_token().to_string().to_uppercase() == *expected.to_uppercase() {
             self.parser.next_token();
@@ -120,12 +120,64 @@ mod tests {
         let sql = "CREATE TABLE t(c1 int) ENGINE = XX location = 'foo.parquet' ";
         expect_parse_error(
             sql,
-            "Expected Engine must one of Parquet, JSONEachRaw, Null or CSV, found: XX",
+            "Expected Engine must one of Parquet, JSONEachRaw, Null, Memory, Remote or CSV, found: XX",
         )?;
 
         Ok(())
     }
 
+    #[test]
+    fn drop_database() -> Result<(), ParserError> {
+        let sql = "DROP DATABASE db1";
+        let expected = DfStatement::DropDatabase(DfDropDatabase {
+            if_exists: false,
+            name: ObjectName(vec![Ident::new("db1")]),
+        });
+        expect_parse_ok(sql, expected)?;
+
+        let sql = "DROP DATABASE IF EXISTS db1";
+        let expected = DfStatement::DropDatabase(DfDropDatabase {
+            if_exists: true,
+            name: ObjectName(vec![Ident::new("db1")]),
+        });
+        expect_parse_ok(sql, expected)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn drop_table() -> Result<(), ParserError> {
+        let sql = "DROP TABLE t1";
+        let expected = DfStatement::DropTable(DfDropTable {
+            if_exists: false,
+            name: ObjectName(vec![Ident::new("t1")]),
+        });
+        expect_parse_ok(sql, expected)?;
+
+        let sql = "DROP TABLE IF EXISTS db1.t1";
+        let expected = DfStatement::DropTable(DfDropTable {
+            if_exists: true,
+            name: ObjectName(vec![Ident::new("db1"), Ident::new("t1")]),
+        });
+        expect_parse_ok(sql, expected)?;
+
+        // Error cases: Invalid object
+        expect_parse_error("DROP VIEW v1", "Expected drop statement, found: VIEW")?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn truncate_table() -> Result<(), ParserError> {
+        let expected = DfStatement::TruncateTable(DfTruncateTable {
+            name: ObjectName(vec![Ident::new("t1")]),
+        });
+        expect_parse_ok("TRUNCATE TABLE t1", expected.clone())?;
+        expect_parse_ok("TRUNCATE t1", expected)?;
+
+        Ok(())
+    }
+
     #[test]
     fn show_queries() -> Result<(), ParserError> {
         // positive case
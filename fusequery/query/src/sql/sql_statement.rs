@@ -35,6 +35,23 @@ pub struct DfCreateDatabase {
     pub options: Vec<SqlOption>,
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfDropTable {
+    pub if_exists: bool,
+    pub name: ObjectName,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfDropDatabase {
+    pub if_exists: bool,
+    pub name: ObjectName,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfTruncateTable {
+    pub name: ObjectName,
+}
+
 /// Tokens parsed by `DFParser` are converted into these values.
 #[derive(Debug, Clone, PartialEq)]
 pub enum DfStatement {
@@ -45,4 +62,7 @@ pub enum DfStatement {
     ShowSettings(DfShowSettings),
     CreateDatabase(DfCreateDatabase),
     CreateTable(DfCreateTable),
+    DropDatabase(DfDropDatabase),
+    DropTable(DfDropTable),
+    TruncateTable(DfTruncateTable),
 }
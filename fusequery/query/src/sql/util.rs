@@ -4,7 +4,7 @@
 
 use anyhow::{bail, Result};
 use common_arrow::arrow::datatypes::TimeUnit;
-use common_datavalues::DataType;
+use common_datavalues::{check_decimal_type, DataType};
 use sqlparser::ast::DataType as SQLDataType;
 
 /// Maps the SQL type to the corresponding Arrow `DataType`
@@ -14,13 +14,22 @@ pub fn make_data_type(sql_type: &SQLDataType) -> Result<DataType> {
         SQLDataType::Int => Ok(DataType::Int32),
         SQLDataType::SmallInt => Ok(DataType::Int16),
         SQLDataType::Char(_) | SQLDataType::Varchar(_) | SQLDataType::Text => Ok(DataType::Utf8),
-        SQLDataType::Decimal(_, _) => Ok(DataType::Float64),
+        SQLDataType::Decimal(precision, scale) => {
+            // DECIMAL is DECIMAL(10, 0) and DECIMAL(p) is DECIMAL(p, 0) like MySQL.
+            let precision = precision.unwrap_or(10) as usize;
+            let scale = scale.unwrap_or(0) as usize;
+            check_decimal_type(precision, scale)?;
+            Ok(DataType::Decimal(precision, scale))
+        }
         SQLDataType::Float(_) => Ok(DataType::Float32),
         SQLDataType::Real | SQLDataType::Double => Ok(DataType::Float64),
         SQLDataType::Boolean => Ok(DataType::Boolean),
         SQLDataType::Date => Ok(DataType::Date32),
         SQLDataType::Time => Ok(DataType::Time64(TimeUnit::Millisecond)),
-        SQLDataType::Timestamp => Ok(DataType::Date64),
+        SQLDataType::Timestamp => Ok(DataType::Timestamp(TimeUnit::Microsecond, None)),
+        SQLDataType::Custom(name) if name.to_string().eq_ignore_ascii_case("datetime") => {
+            Ok(DataType::Timestamp(TimeUnit::Second, None))
+        }
 
         _ => bail!("The SQL data type {:?} is not implemented", sql_type),
     }
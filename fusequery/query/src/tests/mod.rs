@@ -10,5 +10,5 @@ pub use context::try_create_context;
 pub use number::NumberTestData;
 pub use service::{
     try_create_context_with_nodes, try_create_context_with_nodes_and_priority, try_start_service,
-    try_start_service_with_session_mgr,
+    try_start_service_with_session_mgr, try_start_store_service,
 };
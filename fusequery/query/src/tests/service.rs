@@ -68,6 +68,30 @@ pub async fn try_create_context_with_nodes_and_priority(
     Ok(ctx)
 }
 
+/// Start a FuseStore service with a temporary data path and return the random address.
+pub async fn try_start_store_service() -> Result<String> {
+    let mut rng = rand::thread_rng();
+    let port: u32 = rng.gen_range(12000..13000);
+    let addr = format!("127.0.0.1:{}", port);
+
+    let mut conf = fuse_store::configs::Config::default();
+    conf.rpc_api_address = addr.clone();
+    conf.data_path = std::env::temp_dir()
+        .join(uuid::Uuid::new_v4().to_string())
+        .to_str()
+        .unwrap()
+        .to_string();
+
+    // The store runs on its own runtime, it serves the blocking calls of the remote datasource.
+    let srv = fuse_store::api::RpcService::create(conf);
+    std::thread::spawn(move || -> Result<()> {
+        let runtime = tokio::runtime::Runtime::new()?;
+        runtime.block_on(srv.make_server())
+    });
+    tokio::time::sleep(tokio::time::Duration::from_millis(200)).await;
+    Ok(addr)
+}
+
 // Start one random service and get the session manager.
 async fn start_one_service() -> Result<(String, SessionRef)> {
     let mut rng = rand::thread_rng();
@@ -78,7 +102,7 @@ async fn start_one_service() -> Result<(String, SessionRef)> {
     conf.rpc_api_address = addr.clone();
 
     let cluster = Cluster::create(conf.clone());
-    let session_manager = Session::create();
+    let session_manager = Session::create(conf.clone());
     let srv = RpcService::create(conf, cluster, session_manager.clone());
     tokio::spawn(async move {
         srv.make_server().await?;
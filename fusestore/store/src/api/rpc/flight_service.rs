@@ -2,30 +2,107 @@
 //
 // SPDX-License-Identifier: Apache-2.0.
 
-use std::convert::TryInto;
+use std::convert::{TryFrom, TryInto};
 use std::pin::Pin;
+use std::time::Duration;
 
+use common_arrow::arrow;
+use common_arrow::arrow_flight::utils::{
+    flight_data_from_arrow_batch, flight_data_from_arrow_schema, flight_data_to_arrow_batch,
+};
 use common_arrow::arrow_flight::{
     self,
     flight_service_server::{FlightService as Flight, FlightServiceServer as FlightServer},
     Action, ActionType, Criteria, Empty, FlightData, FlightDescriptor, FlightInfo,
     HandshakeRequest, HandshakeResponse, PutResult, SchemaResult, Ticket,
 };
-use common_flights::store_do_action::DoActionAction;
+use common_datavalues::DataSchema;
+use common_flights::store_do_action::{
+    CreateDatabaseActionResult, CreateTableActionResult, DoActionAction, DropDatabaseActionResult,
+    DropTableActionResult, GetDatabaseActionResult, GetDatabasesActionResult, GetTableActionResult,
+    GetTablesActionResult,
+};
 use common_flights::store_do_get::DoGetAction;
+use common_flights::store_do_put::{AppendActionResult, DoPutAction};
+use common_planners::{Partition, Statistics};
 use futures::Stream;
 use tonic::{Request, Response, Status, Streaming};
 
 use crate::configs::Config;
+use crate::data::{BlockFile, BlockLeases, BlockLeasesRef, BlockStore, BlockStoreRef, Compactor};
+use crate::meta::{Database, MetaStore, MetaStoreRef, Table, RAFT_ACTION_TYPE};
 
 pub type FlightStream<T> =
     Pin<Box<dyn Stream<Item = Result<T, tonic::Status>> + Send + Sync + 'static>>;
 
-pub struct FlightService {}
+pub struct FlightService {
+    meta: MetaStoreRef,
+    blocks: BlockStoreRef,
+    leases: BlockLeasesRef,
+}
 
 impl FlightService {
-    pub fn create(_conf: Config) -> Self {
-        Self {}
+    pub fn try_create(conf: Config) -> anyhow::Result<Self> {
+        let meta = MetaStore::try_create(&conf)?;
+        let blocks = BlockStore::try_create(conf.data_path.as_str())?;
+        let leases = BlockLeases::create(Duration::from_secs(conf.block_lease_secs));
+        if conf.compaction_interval_secs > 0 {
+            Compactor::create(
+                meta.clone(),
+                blocks.clone(),
+                leases.clone(),
+                conf.compaction_fanout,
+            )
+            .start(Duration::from_secs(conf.compaction_interval_secs));
+        }
+
+        Ok(Self {
+            meta,
+            blocks,
+            leases,
+        })
+    }
+
+    pub fn meta(&self) -> MetaStoreRef {
+        self.meta.clone()
+    }
+
+    // The block files of a dropped table are removed in best effort.
+    fn remove_table_blocks(&self, table: &Table) {
+        let files: Vec<String> = table
+            .blocks
+            .iter()
+            .chain(table.detached_blocks.iter())
+            .map(|v| v.file.clone())
+            .collect();
+        if let Err(e) = self.blocks.remove_blocks(&files) {
+            log::error!("Remove blocks of table {} error: {:?}", table.name, e);
+        }
+    }
+
+    // Write every batch of the stream into a block file of the table.
+    async fn write_blocks(
+        &self,
+        table: &Table,
+        stream: &mut Streaming<FlightData>,
+        files: &mut Vec<BlockFile>,
+    ) -> Result<(), Status> {
+        while let Some(flight_data) = stream.message().await? {
+            let batch = flight_data_to_arrow_batch(&flight_data, table.schema.clone(), &[])
+                .map_err(|e| Status::internal(e.to_string()))?;
+            if batch.num_rows() == 0 {
+                continue;
+            }
+
+            let blocks = self.blocks.clone();
+            let table_id = table.table_id;
+            let file = tokio::task::spawn_blocking(move || blocks.write_block(table_id, &batch))
+                .await
+                .map_err(|e| Status::internal(e.to_string()))?
+                .map_err(to_status)?;
+            files.push(file);
+        }
+        Ok(())
     }
 
     pub fn make_server(self) -> FlightServer<impl Flight> {
@@ -72,8 +149,50 @@ impl Flight for FlightService {
     ) -> Result<Response<Self::DoGetStream>, Status> {
         let action: DoGetAction = request.try_into()?;
         match action {
-            DoGetAction::Read(_) => {
-                todo!()
+            DoGetAction::Read(v) => {
+                // Only the blocks of the table can be read, the detached ones are still readable
+                // until they are purged, they may be planned before the compaction.
+                let table = self
+                    .meta
+                    .get_table(&v.db, &v.table)
+                    .await
+                    .map_err(to_status)?;
+                let file = v.partition.name;
+                if !table
+                    .blocks
+                    .iter()
+                    .chain(table.detached_blocks.iter())
+                    .any(|block| block.file == file)
+                {
+                    return Err(Status::not_found(format!(
+                        "Store Error: Unknown block '{}' of table '{}.{}'",
+                        file, v.db, v.table
+                    )));
+                }
+
+                self.leases.lease(std::iter::once(file.as_str()));
+
+                let blocks = self.blocks.clone();
+                let push_downs = v.push_downs;
+                let (schema, batches) =
+                    tokio::task::spawn_blocking(move || blocks.read_block(&file, &push_downs))
+                        .await
+                        .map_err(|e| Status::internal(e.to_string()))?
+                        .map_err(to_status)?;
+
+                // The first flight data is the schema of the projected columns.
+                let options = arrow::ipc::writer::IpcWriteOptions::default();
+                let mut flights: Vec<Result<FlightData, Status>> =
+                    vec![Ok(flight_data_from_arrow_schema(&schema, &options))];
+                for batch in batches {
+                    let (flight_dicts, flight_batch) =
+                        flight_data_from_arrow_batch(&batch, &options);
+                    flights.extend(flight_dicts.into_iter().map(Ok));
+                    flights.push(Ok(flight_batch));
+                }
+                Ok(Response::new(
+                    Box::pin(futures::stream::iter(flights)) as Self::DoGetStream
+                ))
             }
         }
     }
@@ -81,9 +200,68 @@ impl Flight for FlightService {
     type DoPutStream = FlightStream<PutResult>;
     async fn do_put(
         &self,
-        _request: Request<Streaming<FlightData>>,
+        request: Request<Streaming<FlightData>>,
     ) -> Result<Response<Self::DoPutStream>, Status> {
-        unimplemented!()
+        let mut stream = request.into_inner();
+
+        // The first flight data is the schema with the action descriptor.
+        let flight_data = stream
+            .message()
+            .await?
+            .ok_or_else(|| Status::invalid_argument("Put stream is empty"))?;
+        let action: DoPutAction = (&flight_data).try_into()?;
+        match action {
+            DoPutAction::Append(v) => {
+                let table = self
+                    .meta
+                    .get_table(&v.db, &v.table)
+                    .await
+                    .map_err(to_status)?;
+                let schema = DataSchema::try_from(&flight_data)
+                    .map_err(|e| Status::internal(e.to_string()))?;
+                if schema.fields() != table.schema.fields() {
+                    return Err(Status::invalid_argument(format!(
+                        "Store Error: Blocks schema does not match the table '{}.{}'",
+                        v.db, v.table
+                    )));
+                }
+
+                // The blocks are visible only after all of them are durable and committed to the meta,
+                // the written files are removed if the append fails.
+                let mut files = vec![];
+                let result = match self.write_blocks(&table, &mut stream, &mut files).await {
+                    Ok(()) => self
+                        .meta
+                        .append_blocks(&v.db, &v.table, table.table_id, files.clone())
+                        .await
+                        .map_err(to_status),
+                    Err(e) => Err(e),
+                };
+                let blocks = match result {
+                    Ok(blocks) => blocks,
+                    Err(e) => {
+                        let files: Vec<String> = files.into_iter().map(|v| v.file).collect();
+                        if let Err(e) = self.blocks.remove_blocks(&files) {
+                            log::error!("Remove uncommitted blocks error: {:?}", e);
+                        }
+                        return Err(e);
+                    }
+                };
+
+                let result = AppendActionResult {
+                    blocks: blocks.len(),
+                    rows: blocks.iter().map(|v| v.rows).sum(),
+                };
+                let put_result = PutResult {
+                    app_metadata: serde_json::to_vec(&result)
+                        .map_err(|e| Status::internal(e.to_string()))?,
+                };
+                let flights: Vec<Result<PutResult, Status>> = vec![Ok(put_result)];
+                Ok(Response::new(
+                    Box::pin(futures::stream::iter(flights)) as Self::DoPutStream
+                ))
+            }
+        }
     }
 
     type DoExchangeStream = FlightStream<FlightData>;
@@ -99,10 +277,94 @@ impl Flight for FlightService {
         &self,
         request: Request<Action>,
     ) -> Result<Response<Self::DoActionStream>, Status> {
-        let action: DoActionAction = request.try_into()?;
+        // The raft actions between the store nodes.
+        let action = request.into_inner();
+        if action.r#type == RAFT_ACTION_TYPE {
+            let body = self
+                .meta
+                .handle_raft_action(&action.body)
+                .await
+                .map_err(to_status)?;
+            let flights: Vec<Result<arrow_flight::Result, Status>> =
+                vec![Ok(arrow_flight::Result { body })];
+            return Ok(Response::new(
+                Box::pin(futures::stream::iter(flights)) as Self::DoActionStream
+            ));
+        }
+
+        let action: DoActionAction = Request::new(action).try_into()?;
         match action {
-            DoActionAction::CreateDatabase(_) => {
-                todo!()
+            DoActionAction::CreateDatabase(v) => {
+                let database_id = self.meta.create_database(v.plan).await.map_err(to_status)?;
+                action_response(&CreateDatabaseActionResult { database_id })
+            }
+            DoActionAction::GetDatabase(v) => {
+                let database = self.meta.get_database(&v.db).await.map_err(to_status)?;
+                action_response(&database_result(&database))
+            }
+            DoActionAction::GetDatabases(_) => {
+                let databases = self.meta.get_databases().await.map_err(to_status)?;
+                action_response(&GetDatabasesActionResult {
+                    databases: databases.iter().map(database_result).collect(),
+                })
+            }
+            DoActionAction::DropDatabase(v) => {
+                if let Some(database) = self.meta.drop_database(v.plan).await.map_err(to_status)? {
+                    for table in database.tables.values() {
+                        self.remove_table_blocks(table);
+                    }
+                }
+                action_response(&DropDatabaseActionResult {})
+            }
+            DoActionAction::CreateTable(v) => {
+                let table_id = self.meta.create_table(v.plan).await.map_err(to_status)?;
+                action_response(&CreateTableActionResult { table_id })
+            }
+            DoActionAction::GetTable(v) => {
+                let table = self
+                    .meta
+                    .get_table(&v.db, &v.table)
+                    .await
+                    .map_err(to_status)?;
+                action_response(&table_result(&table))
+            }
+            DoActionAction::GetTables(v) => {
+                let tables = self.meta.get_tables(&v.db).await.map_err(to_status)?;
+                action_response(&GetTablesActionResult {
+                    tables: tables.iter().map(table_result).collect(),
+                })
+            }
+            DoActionAction::DropTable(v) => {
+                if let Some(table) = self.meta.drop_table(v.plan).await.map_err(to_status)? {
+                    self.remove_table_blocks(&table);
+                }
+                action_response(&DropTableActionResult {})
+            }
+            DoActionAction::ReadPlan(v) => {
+                // Every block of the table is a partition, which is leased until the query reads it.
+                let table = self
+                    .meta
+                    .get_table(&v.db, &v.table)
+                    .await
+                    .map_err(to_status)?;
+                self.leases
+                    .lease(table.blocks.iter().map(|block| block.file.as_str()));
+                let partitions = table
+                    .blocks
+                    .iter()
+                    .map(|block| Partition {
+                        name: block.file.clone(),
+                        version: 0,
+                    })
+                    .collect();
+                let statistics = Statistics {
+                    read_rows: table.blocks.iter().map(|block| block.rows).sum(),
+                    read_bytes: table.blocks.iter().map(|block| block.bytes).sum(),
+                };
+                action_response(&ReadPlanActionResult {
+                    partitions,
+                    statistics,
+                })
             }
         }
     }
@@ -115,3 +377,37 @@ impl Flight for FlightService {
         unimplemented!()
     }
 }
+
+fn to_status(e: anyhow::Error) -> Status {
+    Status::internal(e.to_string())
+}
+
+fn database_result(database: &Database) -> GetDatabaseActionResult {
+    GetDatabaseActionResult {
+        database_id: database.database_id,
+        db: database.name.clone(),
+    }
+}
+
+fn table_result(table: &Table) -> GetTableActionResult {
+    GetTableActionResult {
+        table_id: table.table_id,
+        db: table.db.clone(),
+        name: table.name.clone(),
+        schema: table.schema.clone(),
+        options: table.options.clone(),
+    }
+}
+
+// The do_action response is a single result with the JSON encoded body.
+fn action_response<T: serde::Serialize>(
+    result: &T,
+) -> Result<Response<FlightStream<arrow_flight::Result>>, Status> {
+    let result = arrow_flight::Result {
+        body: serde_json::to_vec(result).map_err(|e| Status::internal(e.to_string()))?,
+    };
+    let flights: Vec<Result<arrow_flight::Result, Status>> = vec![Ok(result)];
+    Ok(Response::new(
+        Box::pin(futures::stream::iter(flights)) as FlightStream<arrow_flight::Result>
+    ))
+}
@@ -0,0 +1,327 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_flight_meta_actions() -> anyhow::Result<()> {
+    use std::collections::HashMap;
+    use std::convert::TryInto;
+    use std::sync::Arc;
+
+    use common_arrow::arrow::datatypes::{DataType, Field, Schema};
+    use common_arrow::arrow_flight::flight_service_client::FlightServiceClient;
+    use common_arrow::arrow_flight::Action;
+    use common_flights::store_do_action::*;
+    use common_planners::*;
+    use pretty_assertions::assert_eq;
+    use tonic::Request;
+
+    let addr = crate::tests::try_start_service().await?;
+    let mut client = FlightServiceClient::connect(format!("http://{}", addr)).await?;
+
+    async fn do_action(
+        client: &mut FlightServiceClient<tonic::transport::Channel>,
+        action: DoActionAction,
+    ) -> anyhow::Result<Vec<u8>> {
+        let request: Request<Action> = (&action).try_into()?;
+        let mut stream = client.do_action(request).await?.into_inner();
+        match stream.message().await? {
+            None => anyhow::bail!("Can not receive data from store flight server"),
+            Some(resp) => Ok(resp.body),
+        }
+    }
+
+    // Create database.
+    let action = DoActionAction::CreateDatabase(CreateDatabaseAction {
+        plan: CreateDatabasePlan {
+            if_not_exists: false,
+            db: "db1".to_string(),
+            schema: Arc::new(Schema::empty()),
+            engine: DatabaseEngineType::Remote,
+            options: HashMap::new(),
+        },
+    });
+    let actual: CreateDatabaseActionResult =
+        serde_json::from_slice(&do_action(&mut client, action.clone()).await?)?;
+    assert_eq!(CreateDatabaseActionResult { database_id: 1 }, actual);
+
+    // Create again.
+    if let Err(e) = do_action(&mut client, action).await {
+        assert!(e
+            .to_string()
+            .contains("Store Error: Database 'db1' already exists"));
+    } else {
+        assert!(false)
+    }
+
+    // Create table.
+    let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, false)]));
+    let action = DoActionAction::CreateTable(CreateTableAction {
+        plan: CreateTablePlan {
+            if_not_exists: false,
+            db: "db1".to_string(),
+            table: "t1".to_string(),
+            schema: schema.clone(),
+            engine: TableEngineType::Parquet,
+            options: HashMap::new(),
+        },
+    });
+    let actual: CreateTableActionResult =
+        serde_json::from_slice(&do_action(&mut client, action).await?)?;
+    assert_eq!(CreateTableActionResult { table_id: 1 }, actual);
+
+    // Get table.
+    let action = DoActionAction::GetTable(GetTableAction {
+        db: "db1".to_string(),
+        table: "t1".to_string(),
+    });
+    let actual: GetTableActionResult =
+        serde_json::from_slice(&do_action(&mut client, action).await?)?;
+    assert_eq!(
+        GetTableActionResult {
+            table_id: 1,
+            db: "db1".to_string(),
+            name: "t1".to_string(),
+            schema,
+            options: HashMap::new(),
+        },
+        actual
+    );
+
+    // Drop table and list.
+    let action = DoActionAction::DropTable(DropTableAction {
+        plan: DropTablePlan {
+            if_exists: false,
+            db: "db1".to_string(),
+            table: "t1".to_string(),
+            schema: Arc::new(Schema::empty()),
+        },
+    });
+    do_action(&mut client, action).await?;
+    let action = DoActionAction::GetTables(GetTablesAction {
+        db: "db1".to_string(),
+    });
+    let actual: GetTablesActionResult =
+        serde_json::from_slice(&do_action(&mut client, action).await?)?;
+    assert_eq!(0, actual.tables.len());
+
+    // Drop database and list.
+    let action = DoActionAction::DropDatabase(DropDatabaseAction {
+        plan: DropDatabasePlan {
+            if_exists: false,
+            db: "db1".to_string(),
+            schema: Arc::new(Schema::empty()),
+        },
+    });
+    do_action(&mut client, action).await?;
+    let action = DoActionAction::GetDatabases(GetDatabasesAction {});
+    let actual: GetDatabasesActionResult =
+        serde_json::from_slice(&do_action(&mut client, action).await?)?;
+    assert_eq!(0, actual.databases.len());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_flight_do_put() -> anyhow::Result<()> {
+    use std::collections::HashMap;
+    use std::convert::TryInto;
+    use std::sync::Arc;
+
+    use common_arrow::arrow;
+    use common_arrow::arrow::array::Int64Array;
+    use common_arrow::arrow::datatypes::{DataType, Field, Schema};
+    use common_arrow::arrow::record_batch::RecordBatch;
+    use common_arrow::arrow_flight::flight_service_client::FlightServiceClient;
+    use common_arrow::arrow_flight::utils::{
+        flight_data_from_arrow_batch, flight_data_from_arrow_schema,
+    };
+    use common_arrow::arrow_flight::{Action, FlightData};
+    use common_flights::store_do_action::*;
+    use common_flights::store_do_put::*;
+    use common_planners::*;
+    use pretty_assertions::assert_eq;
+    use tonic::Request;
+
+    let addr = crate::tests::try_start_service().await?;
+    let mut client = FlightServiceClient::connect(format!("http://{}", addr)).await?;
+
+    let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, false)]));
+    for action in vec![
+        DoActionAction::CreateDatabase(CreateDatabaseAction {
+            plan: CreateDatabasePlan {
+                if_not_exists: false,
+                db: "db1".to_string(),
+                schema: Arc::new(Schema::empty()),
+                engine: DatabaseEngineType::Remote,
+                options: HashMap::new(),
+            },
+        }),
+        DoActionAction::CreateTable(CreateTableAction {
+            plan: CreateTablePlan {
+                if_not_exists: false,
+                db: "db1".to_string(),
+                table: "t1".to_string(),
+                schema: schema.clone(),
+                engine: TableEngineType::Remote,
+                options: HashMap::new(),
+            },
+        }),
+    ] {
+        let request: Request<Action> = (&action).try_into()?;
+        client.do_action(request).await?;
+    }
+
+    // The first flight data is the schema with the action descriptor.
+    fn flights(schema: &Schema, batches: Vec<RecordBatch>) -> anyhow::Result<Vec<FlightData>> {
+        let action = DoPutAction::Append(AppendAction {
+            db: "db1".to_string(),
+            table: "t1".to_string(),
+        });
+        let options = arrow::ipc::writer::IpcWriteOptions::default();
+        let mut schema_flight_data = flight_data_from_arrow_schema(schema, &options);
+        schema_flight_data.flight_descriptor = Some((&action).try_into()?);
+
+        let mut flights = vec![schema_flight_data];
+        for batch in batches {
+            let (dicts, flight_batch) = flight_data_from_arrow_batch(&batch, &options);
+            flights.extend(dicts);
+            flights.push(flight_batch);
+        }
+        Ok(flights)
+    }
+
+    // Append two blocks.
+    let batches = vec![
+        RecordBatch::try_new(schema.clone(), vec![Arc::new(Int64Array::from(vec![1, 2]))])?,
+        RecordBatch::try_new(
+            schema.clone(),
+            vec![Arc::new(Int64Array::from(vec![3, 4, 5]))],
+        )?,
+    ];
+    let request = Request::new(futures::stream::iter(flights(&schema, batches)?));
+    let mut stream = client.do_put(request).await?.into_inner();
+    let actual: AppendActionResult =
+        serde_json::from_slice(&stream.message().await?.unwrap().app_metadata)?;
+    assert_eq!(AppendActionResult { blocks: 2, rows: 5 }, actual);
+
+    // Append with a mismatched schema.
+    let other = Schema::new(vec![Field::new("b", DataType::Int64, false)]);
+    let request = Request::new(futures::stream::iter(flights(&other, vec![])?));
+    if let Err(e) = client.do_put(request).await {
+        assert_eq!(
+            "Store Error: Blocks schema does not match the table 'db1.t1'",
+            e.message()
+        );
+    } else {
+        assert!(false)
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_flight_do_get_detached_blocks() -> anyhow::Result<()> {
+    use std::collections::HashMap;
+    use std::convert::TryInto;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use common_arrow::arrow::array::Int64Array;
+    use common_arrow::arrow::datatypes::{DataType, Field, Schema};
+    use common_arrow::arrow::record_batch::RecordBatch;
+    use common_arrow::arrow_flight::flight_service_client::FlightServiceClient;
+    use common_arrow::arrow_flight::{Action, Ticket};
+    use common_flights::store_do_action::*;
+    use common_flights::store_do_get::*;
+    use common_planners::*;
+    use pretty_assertions::assert_eq;
+    use tonic::Request;
+
+    use crate::data::*;
+
+    let mut conf = crate::tests::new_test_config();
+    conf.compaction_interval_secs = 0;
+    let addr = conf.rpc_api_address.clone();
+    let meta = crate::tests::try_start_node(conf.clone()).await?;
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    let mut client = FlightServiceClient::connect(format!("http://{}", addr)).await?;
+
+    let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, false)]));
+    meta.create_database(CreateDatabasePlan {
+        if_not_exists: false,
+        db: "db1".to_string(),
+        schema: Arc::new(Schema::empty()),
+        engine: DatabaseEngineType::Remote,
+        options: HashMap::new(),
+    })
+    .await?;
+    let table_id = meta
+        .create_table(CreateTablePlan {
+            if_not_exists: false,
+            db: "db1".to_string(),
+            table: "t1".to_string(),
+            schema: schema.clone(),
+            engine: TableEngineType::Remote,
+            options: HashMap::new(),
+        })
+        .await?;
+
+    let blocks = BlockStore::try_create(conf.data_path.as_str())?;
+    let mut files = vec![];
+    for i in 0..2 {
+        let batch =
+            RecordBatch::try_new(schema.clone(), vec![Arc::new(Int64Array::from(vec![i]))])?;
+        files.push(blocks.write_block(table_id, &batch)?);
+    }
+    meta.append_blocks("db1", "t1", table_id, files).await?;
+
+    // Plan the blocks to read.
+    let action = DoActionAction::ReadPlan(ReadPlanAction {
+        db: "db1".to_string(),
+        table: "t1".to_string(),
+        push_down: PlanNode::Empty(EmptyPlan {
+            schema: Arc::new(Schema::empty()),
+        }),
+    });
+    let request: Request<Action> = (&action).try_into()?;
+    let mut stream = client.do_action(request).await?.into_inner();
+    let plan: ReadPlanActionResult =
+        serde_json::from_slice(&stream.message().await?.unwrap().body)?;
+    assert_eq!(2, plan.partitions.len());
+
+    // The planned blocks are detached by the compaction before they are read.
+    let leases = BlockLeases::create(Duration::from_secs(60));
+    Compactor::create(meta.clone(), blocks, leases, 2)
+        .run_once()
+        .await?;
+    let table = meta.get_table("db1", "t1").await?;
+    assert_eq!(1, table.blocks.len());
+    assert_eq!(2, table.detached_blocks.len());
+
+    let mut rows = 0;
+    for partition in plan.partitions {
+        let action = DoGetAction::Read(ReadAction {
+            db: "db1".to_string(),
+            table: "t1".to_string(),
+            partition,
+            push_downs: Extras::default(),
+        });
+        let request: Request<Ticket> = (&action).try_into()?;
+        let mut stream = client.do_get(request).await?.into_inner();
+        // The first flight data is the schema.
+        stream.message().await?;
+        while let Some(flight_data) = stream.message().await? {
+            let batch = common_arrow::arrow_flight::utils::flight_data_to_arrow_batch(
+                &flight_data,
+                schema.clone(),
+                &[],
+            )?;
+            rows += batch.num_rows();
+        }
+    }
+    assert_eq!(2, rows);
+
+    std::fs::remove_dir_all(&conf.data_path)?;
+    Ok(())
+}
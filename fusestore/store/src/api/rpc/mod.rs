@@ -5,6 +5,8 @@
 #[cfg(test)]
 #[macro_use]
 mod macros;
+#[cfg(test)]
+mod flight_service_test;
 
 mod flight_service;
 mod metrics;
@@ -21,7 +21,7 @@ impl RpcService {
         let addr = self.conf.rpc_api_address.parse::<std::net::SocketAddr>()?;
 
         // Flight service:
-        let flight_srv = FlightService::create(self.conf.clone());
+        let flight_srv = FlightService::try_create(self.conf.clone())?;
 
         Server::builder()
             .add_service(flight_srv.make_server())
@@ -2,6 +2,9 @@
 //
 // SPDX-License-Identifier: Apache-2.0.
 
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result};
 use structopt::StructOpt;
 
 #[derive(Debug, StructOpt, Clone)]
@@ -25,6 +28,42 @@ pub struct Config {
         default_value = "127.0.0.1:9191"
     )]
     pub rpc_api_address: String,
+
+    #[structopt(long, env = "FUSE_STORE_DATA_PATH", default_value = "_data")]
+    pub data_path: String,
+
+    /// The id of the node in the meta raft group.
+    #[structopt(long, env = "FUSE_STORE_ID", default_value = "1")]
+    pub id: u64,
+
+    /// The nodes voting for the meta leader, e.g. `1=127.0.0.1:9191,2=127.0.0.1:9192`.
+    /// Empty means a single node cluster of this node.
+    #[structopt(long, env = "FUSE_STORE_CANDIDATES", default_value = "")]
+    pub candidates: String,
+
+    /// The nodes only replicating the meta, in the same format as the candidates.
+    #[structopt(long, env = "FUSE_STORE_LEARNERS", default_value = "")]
+    pub learners: String,
+
+    /// Take a snapshot of the meta after so many raft logs.
+    #[structopt(long, env = "FUSE_STORE_RAFT_SNAPSHOT_LOGS", default_value = "1024")]
+    pub raft_snapshot_logs: u64,
+
+    /// Compact the blocks every so many seconds, 0 disables the compaction.
+    #[structopt(
+        long,
+        env = "FUSE_STORE_COMPACTION_INTERVAL_SECS",
+        default_value = "60"
+    )]
+    pub compaction_interval_secs: u64,
+
+    /// How many blocks of a level are merged into one block of the next level.
+    #[structopt(long, env = "FUSE_STORE_COMPACTION_FANOUT", default_value = "8")]
+    pub compaction_fanout: usize,
+
+    /// How long the blocks planned or read by a query are kept after they are compacted.
+    #[structopt(long, env = "FUSE_STORE_BLOCK_LEASE_SECS", default_value = "600")]
+    pub block_lease_secs: u64,
 }
 
 impl Config {
@@ -35,6 +74,14 @@ impl Config {
             log_level: "debug".to_string(),
             metric_api_address: "127.0.0.1:7171".to_string(),
             rpc_api_address: "127.0.0.1:9191".to_string(),
+            data_path: "_data".to_string(),
+            id: 1,
+            candidates: "".to_string(),
+            learners: "".to_string(),
+            raft_snapshot_logs: 1024,
+            compaction_interval_secs: 60,
+            compaction_fanout: 8,
+            block_lease_secs: 600,
         }
     }
 
@@ -44,4 +91,33 @@ impl Config {
         cfg.version = include_str!(concat!(env!("OUT_DIR"), "/version-info.txt")).to_string();
         cfg
     }
+
+    /// The candidate nodes by id, this node alone if there are none.
+    pub fn candidate_nodes(&self) -> Result<HashMap<u64, String>> {
+        let mut nodes = parse_nodes(&self.candidates)?;
+        if nodes.is_empty() {
+            nodes.insert(self.id, self.rpc_api_address.clone());
+        }
+        Ok(nodes)
+    }
+
+    /// The learner nodes by id.
+    pub fn learner_nodes(&self) -> Result<HashMap<u64, String>> {
+        parse_nodes(&self.learners)
+    }
+}
+
+fn parse_nodes(nodes: &str) -> Result<HashMap<u64, String>> {
+    nodes
+        .split(',')
+        .map(|v| v.trim())
+        .filter(|v| !v.is_empty())
+        .map(|v| {
+            let mut parts = v.splitn(2, '=');
+            match (parts.next(), parts.next()) {
+                (Some(id), Some(addr)) => Ok((id.trim().parse::<u64>()?, addr.trim().to_string())),
+                _ => Err(anyhow!("Invalid node '{}', expect 'id=address'", v)),
+            }
+        })
+        .collect()
 }
@@ -0,0 +1,45 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use common_infallible::Mutex;
+
+pub type BlockLeasesRef = Arc<BlockLeases>;
+
+/// The block files referenced by the running queries.
+/// A query plans the partitions of a table and reads them later, the store never knows when it finishes.
+/// So planning and reading lease the block files for a while, the detached block files are only
+/// removed after their leases expire.
+pub struct BlockLeases {
+    lease: Duration,
+    expires: Mutex<HashMap<String, Instant>>,
+}
+
+impl BlockLeases {
+    pub fn create(lease: Duration) -> BlockLeasesRef {
+        Arc::new(BlockLeases {
+            lease,
+            expires: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Lease or renew the files.
+    pub fn lease<'a>(&self, files: impl IntoIterator<Item = &'a str>) {
+        let expire = Instant::now() + self.lease;
+        let mut expires = self.expires.lock();
+        for file in files {
+            expires.insert(file.to_string(), expire);
+        }
+    }
+
+    pub fn is_leased(&self, file: &str) -> bool {
+        let now = Instant::now();
+        let mut expires = self.expires.lock();
+        expires.retain(|_, expire| *expire > now);
+        expires.contains_key(file)
+    }
+}
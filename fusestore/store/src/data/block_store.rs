@@ -0,0 +1,143 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::fs::{self, File};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use anyhow::{bail, Result};
+use common_arrow::arrow::datatypes::SchemaRef;
+use common_arrow::arrow::record_batch::{RecordBatch, RecordBatchReader};
+use common_arrow::parquet::arrow::{
+    parquet_to_arrow_schema, ArrowReader, ArrowWriter, ParquetFileArrowReader,
+};
+use common_arrow::parquet::file::reader::{FileReader, SerializedFileReader};
+use common_planners::Extras;
+
+use crate::data::row_group_may_match;
+
+/// A block file written but not yet committed to the table meta.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+pub struct BlockFile {
+    /// The path relative to the data path.
+    pub file: String,
+    pub rows: usize,
+    pub bytes: usize,
+}
+
+pub type BlockStoreRef = Arc<BlockStore>;
+
+/// Every block is an immutable Parquet file with the schema embedded.
+pub struct BlockStore {
+    data_path: PathBuf,
+}
+
+impl BlockStore {
+    const READ_BATCH_SIZE: usize = 4096;
+
+    pub fn try_create(data_path: &str) -> Result<BlockStoreRef> {
+        fs::create_dir_all(data_path)?;
+        Ok(Arc::new(BlockStore {
+            data_path: PathBuf::from(data_path),
+        }))
+    }
+
+    /// Write the batch into a new block file of the table.
+    pub fn write_block(&self, table_id: i64, batch: &RecordBatch) -> Result<BlockFile> {
+        self.write_batches(table_id, batch.schema(), std::slice::from_ref(batch))
+    }
+
+    /// Merge the block files into a new block file of the table, the merged files are kept.
+    pub fn merge_blocks(&self, table_id: i64, files: &[String]) -> Result<BlockFile> {
+        let mut schema = None;
+        let mut batches = vec![];
+        for file in files {
+            let (file_schema, file_batches) = self.read_block(file, &Extras::default())?;
+            schema.get_or_insert(file_schema);
+            batches.extend(file_batches);
+        }
+        match schema {
+            Some(schema) => self.write_batches(table_id, schema, &batches),
+            None => bail!("Store Error: No blocks to merge"),
+        }
+    }
+
+    /// Read the block file with the push downs.
+    /// Only the projected columns are read, the row groups not matched by the filters are skipped.
+    pub fn read_block(
+        &self,
+        file: &str,
+        push_downs: &Extras,
+    ) -> Result<(SchemaRef, Vec<RecordBatch>)> {
+        let mut file_reader = SerializedFileReader::new(File::open(self.data_path.join(file))?)?;
+        let file_metadata = file_reader.metadata().file_metadata();
+        let file_schema = parquet_to_arrow_schema(
+            file_metadata.schema_descr(),
+            file_metadata.key_value_metadata(),
+        )?;
+        file_reader.filter_row_groups(&|row_group, _| {
+            push_downs
+                .filters
+                .iter()
+                .all(|filter| row_group_may_match(filter, &file_schema, row_group))
+        });
+
+        let mut arrow_reader = ParquetFileArrowReader::new(Arc::new(file_reader));
+        let batch_reader = match &push_downs.projection {
+            Some(projection) => arrow_reader
+                .get_record_reader_by_columns(projection.clone(), Self::READ_BATCH_SIZE)?,
+            None => arrow_reader.get_record_reader(Self::READ_BATCH_SIZE)?,
+        };
+        let schema = batch_reader.schema();
+        let batches = batch_reader.collect::<std::result::Result<Vec<_>, _>>()?;
+        Ok((schema, batches))
+    }
+
+    pub fn exists(&self, file: &str) -> bool {
+        self.data_path.join(file).exists()
+    }
+
+    /// Remove the block files, the missing files are ignored.
+    pub fn remove_blocks(&self, files: &[String]) -> Result<()> {
+        for file in files {
+            let path = self.data_path.join(file);
+            if path.exists() {
+                fs::remove_file(path)?;
+            }
+        }
+        Ok(())
+    }
+
+    // The batches are written to a temporary file which is renamed after it is synced to the disk,
+    // so a block file is either complete or not visible at all.
+    fn write_batches(
+        &self,
+        table_id: i64,
+        schema: SchemaRef,
+        batches: &[RecordBatch],
+    ) -> Result<BlockFile> {
+        let relative_dir = format!("blocks/{}", table_id);
+        let dir = self.data_path.join(&relative_dir);
+        fs::create_dir_all(&dir)?;
+
+        let name = format!("{}.parquet", uuid::Uuid::new_v4());
+        let tmp_path = dir.join(format!("{}.tmp", name));
+        let mut writer = ArrowWriter::try_new(File::create(&tmp_path)?, schema, None)?;
+        for batch in batches {
+            writer.write(batch)?;
+        }
+        writer.close()?;
+        File::open(&tmp_path)?.sync_all()?;
+
+        let path = dir.join(&name);
+        fs::rename(&tmp_path, &path)?;
+        File::open(&dir)?.sync_all()?;
+
+        Ok(BlockFile {
+            file: format!("{}/{}", relative_dir, name),
+            rows: batches.iter().map(|v| v.num_rows()).sum(),
+            bytes: fs::metadata(&path)?.len() as usize,
+        })
+    }
+}
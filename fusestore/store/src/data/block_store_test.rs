@@ -0,0 +1,75 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+#[test]
+fn test_block_store() -> anyhow::Result<()> {
+    use std::sync::Arc;
+
+    use common_arrow::arrow::array::{Array, Int64Array};
+    use common_arrow::arrow::datatypes::{DataType, Field, Schema};
+    use common_arrow::arrow::record_batch::RecordBatch;
+    use common_planners::*;
+    use pretty_assertions::assert_eq;
+
+    use crate::data::*;
+
+    let data_path = std::env::temp_dir().join(uuid::Uuid::new_v4().to_string());
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("a", DataType::Int64, false),
+        Field::new("b", DataType::Int64, false),
+    ]));
+    let batch = RecordBatch::try_new(
+        schema,
+        vec![
+            Arc::new(Int64Array::from(vec![1, 2, 3])),
+            Arc::new(Int64Array::from(vec![4, 5, 6])),
+        ],
+    )?;
+
+    let store = BlockStore::try_create(data_path.to_str().unwrap())?;
+    let block = store.write_block(1, &batch)?;
+    assert_eq!(3, block.rows);
+    assert!(block.file.starts_with("blocks/1/"));
+    assert!(block.file.ends_with(".parquet"));
+    assert!(data_path.join(&block.file).exists());
+
+    // No temporary file is left.
+    let files = std::fs::read_dir(data_path.join("blocks/1"))?.count();
+    assert_eq!(1, files);
+
+    // Read all the columns.
+    let (schema, batches) = store.read_block(&block.file, &Extras::default())?;
+    assert_eq!(2, schema.fields().len());
+    assert_eq!(3, batches.iter().map(|v| v.num_rows()).sum::<usize>());
+
+    // Read with projection.
+    let push_downs = Extras {
+        projection: Some(vec![1]),
+        filters: vec![],
+    };
+    let (schema, batches) = store.read_block(&block.file, &push_downs)?;
+    assert_eq!("b", schema.field(0).name());
+    assert_eq!(1, batches[0].num_columns());
+    assert_eq!(3, batches[0].column(0).len());
+
+    // The row group is skipped by the filter.
+    let push_downs = Extras {
+        projection: None,
+        filters: vec![ExpressionPlan::BinaryExpression {
+            left: Box::new(col("b")),
+            op: ">".to_string(),
+            right: Box::new(lit(6i64)),
+        }],
+    };
+    let (_, batches) = store.read_block(&block.file, &push_downs)?;
+    assert_eq!(0, batches.iter().map(|v| v.num_rows()).sum::<usize>());
+
+    store.remove_blocks(&[block.file.clone()])?;
+    assert!(!data_path.join(&block.file).exists());
+    // Removing a missing block is fine.
+    store.remove_blocks(&[block.file])?;
+
+    std::fs::remove_dir_all(data_path)?;
+    Ok(())
+}
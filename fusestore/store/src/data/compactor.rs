@@ -0,0 +1,117 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use anyhow::Result;
+
+use crate::data::{BlockLeasesRef, BlockStoreRef};
+use crate::meta::{Block, MetaStoreRef, Table};
+
+/// Merges the small blocks of the tables into larger ones in background.
+/// Every level of a table is compacted separately: once a level has `fanout` blocks,
+/// they are merged into one block of the next level, which replaces them in the meta at once.
+/// The replaced blocks are detached, their files are removed after no query leases them.
+///
+/// The block files are local to the node which wrote them, so every node only compacts its own files.
+pub struct Compactor {
+    meta: MetaStoreRef,
+    blocks: BlockStoreRef,
+    leases: BlockLeasesRef,
+    fanout: usize,
+}
+
+impl Compactor {
+    pub fn create(
+        meta: MetaStoreRef,
+        blocks: BlockStoreRef,
+        leases: BlockLeasesRef,
+        fanout: usize,
+    ) -> Self {
+        Compactor {
+            meta,
+            blocks,
+            leases,
+            fanout: fanout.max(2),
+        }
+    }
+
+    /// Compact the tables and remove the detached blocks every interval.
+    pub fn start(self, interval: Duration) {
+        tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(interval).await;
+                if let Err(e) = self.run_once().await {
+                    log::error!("Compact blocks error: {:?}", e);
+                }
+            }
+        });
+    }
+
+    pub async fn run_once(&self) -> Result<()> {
+        for database in self.meta.get_databases().await? {
+            for table in database.tables.values() {
+                // The detached blocks are from the previous runs, so they have been leased for a while.
+                if let Err(e) = self.purge_blocks(table).await {
+                    log::error!("Purge blocks of table {} error: {:?}", table.name, e);
+                }
+                if let Err(e) = self.compact_blocks(table).await {
+                    log::error!("Compact blocks of table {} error: {:?}", table.name, e);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    async fn compact_blocks(&self, table: &Table) -> Result<()> {
+        let mut levels: BTreeMap<i64, Vec<&Block>> = BTreeMap::new();
+        for block in table.blocks.iter() {
+            if self.blocks.exists(&block.file) {
+                levels.entry(block.level).or_default().push(block);
+            }
+        }
+
+        for (level, blocks) in levels {
+            for sources in blocks.chunks_exact(self.fanout) {
+                let files: Vec<String> = sources.iter().map(|v| v.file.clone()).collect();
+                let store = self.blocks.clone();
+                let table_id = table.table_id;
+                let file =
+                    tokio::task::spawn_blocking(move || store.merge_blocks(table_id, &files))
+                        .await??;
+
+                // The merged file is removed if the blocks have been changed since they were listed.
+                let block_ids = sources.iter().map(|v| v.block_id).collect();
+                if let Err(e) = self
+                    .meta
+                    .compact_blocks(table, block_ids, file.clone(), level + 1)
+                    .await
+                {
+                    self.blocks.remove_blocks(&[file.file])?;
+                    return Err(e);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // The detached blocks are forgotten by the meta before their files are removed,
+    // so they can't be planned or read any more.
+    async fn purge_blocks(&self, table: &Table) -> Result<()> {
+        let block_ids: Vec<i64> = table
+            .detached_blocks
+            .iter()
+            .filter(|v| self.blocks.exists(&v.file) && !self.leases.is_leased(&v.file))
+            .map(|v| v.block_id)
+            .collect();
+        if block_ids.is_empty() {
+            return Ok(());
+        }
+
+        let purged = self.meta.purge_blocks(table, block_ids).await?;
+        let files: Vec<String> = purged.into_iter().map(|v| v.file).collect();
+        self.blocks.remove_blocks(&files)
+    }
+}
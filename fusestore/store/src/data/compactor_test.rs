@@ -0,0 +1,87 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_compactor() -> anyhow::Result<()> {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use common_arrow::arrow::array::Int64Array;
+    use common_arrow::arrow::datatypes::{DataType, Field, Schema};
+    use common_arrow::arrow::record_batch::RecordBatch;
+    use common_planners::*;
+    use pretty_assertions::assert_eq;
+
+    use crate::data::*;
+    use crate::meta::*;
+
+    let conf = crate::tests::new_test_config();
+    let meta = MetaStore::try_create(&conf)?;
+    let blocks = BlockStore::try_create(conf.data_path.as_str())?;
+    let leases = BlockLeases::create(Duration::from_millis(500));
+    let compactor = Compactor::create(meta.clone(), blocks.clone(), leases.clone(), 2);
+
+    let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, false)]));
+    meta.create_database(CreateDatabasePlan {
+        if_not_exists: false,
+        db: "db1".to_string(),
+        schema: Arc::new(Schema::empty()),
+        engine: DatabaseEngineType::Remote,
+        options: HashMap::new(),
+    })
+    .await?;
+    let table_id = meta
+        .create_table(CreateTablePlan {
+            if_not_exists: false,
+            db: "db1".to_string(),
+            table: "t1".to_string(),
+            schema: schema.clone(),
+            engine: TableEngineType::Parquet,
+            options: HashMap::new(),
+        })
+        .await?;
+
+    // Five small blocks of one row.
+    let mut files = vec![];
+    for i in 0..5 {
+        let batch =
+            RecordBatch::try_new(schema.clone(), vec![Arc::new(Int64Array::from(vec![i]))])?;
+        files.push(blocks.write_block(table_id, &batch)?);
+    }
+    meta.append_blocks("db1", "t1", table_id, files).await?;
+
+    // Level 0: 4 blocks are merged into 2 blocks of level 1, which are merged in the next run.
+    compactor.run_once().await?;
+    let table = meta.get_table("db1", "t1").await?;
+    let levels: Vec<i64> = table.blocks.iter().map(|v| v.level).collect();
+    assert_eq!(vec![0, 1, 1], levels);
+    assert_eq!(5, table.blocks.iter().map(|v| v.rows).sum::<usize>());
+    assert_eq!(4, table.detached_blocks.len());
+
+    // A query is reading a detached block.
+    let leased = table.detached_blocks[0].file.clone();
+    leases.lease(std::iter::once(leased.as_str()));
+
+    compactor.run_once().await?;
+    let table = meta.get_table("db1", "t1").await?;
+    let levels: Vec<i64> = table.blocks.iter().map(|v| v.level).collect();
+    assert_eq!(vec![0, 2], levels);
+    assert_eq!(5, table.blocks.iter().map(|v| v.rows).sum::<usize>());
+    // Only the leased block is kept, with the 2 blocks detached in this run.
+    assert_eq!(3, table.detached_blocks.len());
+    assert!(blocks.exists(&leased));
+    let (_, batches) = blocks.read_block(&table.blocks[1].file, &Extras::default())?;
+    assert_eq!(4, batches.iter().map(|v| v.num_rows()).sum::<usize>());
+
+    // All the detached blocks are removed after the leases expire.
+    tokio::time::sleep(Duration::from_millis(600)).await;
+    compactor.run_once().await?;
+    let table = meta.get_table("db1", "t1").await?;
+    assert_eq!(0, table.detached_blocks.len());
+    assert!(!blocks.exists(&leased));
+
+    std::fs::remove_dir_all(&conf.data_path)?;
+    Ok(())
+}
@@ -0,0 +1,20 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+#[cfg(test)]
+mod block_store_test;
+#[cfg(test)]
+mod compactor_test;
+#[cfg(test)]
+mod row_group_filter_test;
+
+mod block_leases;
+mod block_store;
+mod compactor;
+mod row_group_filter;
+
+pub use block_leases::{BlockLeases, BlockLeasesRef};
+pub use block_store::{BlockFile, BlockStore, BlockStoreRef};
+pub use compactor::Compactor;
+pub use row_group_filter::row_group_may_match;
@@ -0,0 +1,155 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::cmp::Ordering;
+
+use common_arrow::arrow::datatypes::{DataType, Schema};
+use common_arrow::parquet::file::metadata::RowGroupMetaData;
+use common_arrow::parquet::file::statistics::Statistics;
+use common_datavalues::DataValue;
+use common_planners::ExpressionPlan;
+
+// The bound value of a column chunk or a literal.
+enum Bound {
+    Int(i128),
+    Float(f64),
+    Bytes(Vec<u8>),
+}
+
+impl Bound {
+    fn compare(&self, other: &Bound) -> Option<Ordering> {
+        // The integers beyond 2^53 can not be compared with the floats exactly.
+        const EXACT: i128 = 1 << 53;
+        match (self, other) {
+            (Bound::Int(a), Bound::Int(b)) => Some(a.cmp(b)),
+            (Bound::Float(a), Bound::Float(b)) => a.partial_cmp(b),
+            (Bound::Int(a), Bound::Float(b)) if a.abs() < EXACT => (*a as f64).partial_cmp(b),
+            (Bound::Float(a), Bound::Int(b)) if b.abs() < EXACT => a.partial_cmp(&(*b as f64)),
+            (Bound::Bytes(a), Bound::Bytes(b)) => Some(a.cmp(b)),
+            _ => None,
+        }
+    }
+
+    fn from_literal(value: &DataValue) -> Option<Bound> {
+        match value {
+            DataValue::Int8(Some(v)) => Some(Bound::Int(*v as i128)),
+            DataValue::Int16(Some(v)) => Some(Bound::Int(*v as i128)),
+            DataValue::Int32(Some(v)) => Some(Bound::Int(*v as i128)),
+            DataValue::Int64(Some(v)) => Some(Bound::Int(*v as i128)),
+            DataValue::UInt8(Some(v)) => Some(Bound::Int(*v as i128)),
+            DataValue::UInt16(Some(v)) => Some(Bound::Int(*v as i128)),
+            DataValue::UInt32(Some(v)) => Some(Bound::Int(*v as i128)),
+            DataValue::UInt64(Some(v)) => Some(Bound::Int(*v as i128)),
+            DataValue::Float32(Some(v)) => Some(Bound::Float(*v as f64)),
+            DataValue::Float64(Some(v)) => Some(Bound::Float(*v)),
+            DataValue::String(Some(v)) => Some(Bound::Bytes(v.as_bytes().to_vec())),
+            _ => None,
+        }
+    }
+
+    // The (min, max) of the column chunk, None if the statistics is unknown.
+    fn from_statistics(statistics: &Statistics, data_type: &DataType) -> Option<(Bound, Bound)> {
+        if !statistics.has_min_max_set() {
+            return None;
+        }
+
+        // The unsigned integers are stored as the signed ones,
+        // the negative bounds mean the large values are ordered as the signed.
+        let ints = |min: i64, max: i64| match data_type {
+            DataType::UInt32 | DataType::UInt64 if min < 0 || max < 0 => None,
+            DataType::Int8
+            | DataType::Int16
+            | DataType::Int32
+            | DataType::Int64
+            | DataType::UInt8
+            | DataType::UInt16
+            | DataType::UInt32
+            | DataType::UInt64 => Some((Bound::Int(min as i128), Bound::Int(max as i128))),
+            _ => None,
+        };
+        match statistics {
+            Statistics::Int32(v) => ints(*v.min() as i64, *v.max() as i64),
+            Statistics::Int64(v) => ints(*v.min(), *v.max()),
+            Statistics::Float(v) => {
+                Some((Bound::Float(*v.min() as f64), Bound::Float(*v.max() as f64)))
+            }
+            Statistics::Double(v) => Some((Bound::Float(*v.min()), Bound::Float(*v.max()))),
+            Statistics::ByteArray(v) if data_type == &DataType::Utf8 => Some((
+                Bound::Bytes(v.min().data().to_vec()),
+                Bound::Bytes(v.max().data().to_vec()),
+            )),
+            _ => None,
+        }
+    }
+}
+
+/// Check whether the rows of the row group may match the predicate by the column statistics.
+/// Returns true if it's unknown, so only the row groups surely not matched are skipped.
+pub fn row_group_may_match(
+    predicate: &ExpressionPlan,
+    schema: &Schema,
+    row_group: &RowGroupMetaData,
+) -> bool {
+    match predicate {
+        ExpressionPlan::BinaryExpression { left, op, right } => {
+            match op.to_lowercase().as_str() {
+                "and" => {
+                    return row_group_may_match(left, schema, row_group)
+                        && row_group_may_match(right, schema, row_group)
+                }
+                "or" => {
+                    return row_group_may_match(left, schema, row_group)
+                        || row_group_may_match(right, schema, row_group)
+                }
+                _ => {}
+            }
+
+            // Normalize to the form of "column op literal".
+            let (column, op, literal) = match (left.as_ref(), right.as_ref()) {
+                (ExpressionPlan::Column(c), ExpressionPlan::Literal(v)) => (c, op.as_str(), v),
+                (ExpressionPlan::Literal(v), ExpressionPlan::Column(c)) => {
+                    let op = match op.as_str() {
+                        "<" => ">",
+                        "<=" => ">=",
+                        ">" => "<",
+                        ">=" => "<=",
+                        other => other,
+                    };
+                    (c, op, v)
+                }
+                _ => return true,
+            };
+            column_may_match(column, op, literal, schema, row_group).unwrap_or(true)
+        }
+        _ => true,
+    }
+}
+
+fn column_may_match(
+    column: &str,
+    op: &str,
+    literal: &DataValue,
+    schema: &Schema,
+    row_group: &RowGroupMetaData,
+) -> Option<bool> {
+    let index = schema.index_of(column).ok()?;
+    if index >= row_group.num_columns() {
+        return None;
+    }
+    let statistics = row_group.column(index).statistics()?;
+    let (min, max) = Bound::from_statistics(statistics, schema.field(index).data_type())?;
+    let value = Bound::from_literal(literal)?;
+
+    let min_order = min.compare(&value)?;
+    let max_order = max.compare(&value)?;
+    Some(match op {
+        "=" => min_order != Ordering::Greater && max_order != Ordering::Less,
+        "<" => min_order == Ordering::Less,
+        "<=" => min_order != Ordering::Greater,
+        ">" => max_order == Ordering::Greater,
+        ">=" => max_order != Ordering::Less,
+        "<>" | "!=" => !(min_order == Ordering::Equal && max_order == Ordering::Equal),
+        _ => true,
+    })
+}
@@ -0,0 +1,97 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+#[test]
+fn test_row_group_may_match() -> anyhow::Result<()> {
+    use std::fs::File;
+    use std::sync::Arc;
+
+    use common_arrow::arrow::array::{Int64Array, StringArray};
+    use common_arrow::arrow::datatypes::{DataType, Field, Schema};
+    use common_arrow::arrow::record_batch::RecordBatch;
+    use common_arrow::parquet::file::reader::{FileReader, SerializedFileReader};
+    use common_datavalues::DataValue;
+    use common_planners::*;
+    use pretty_assertions::assert_eq;
+
+    use crate::data::*;
+
+    let binary =
+        |left: ExpressionPlan, op: &str, right: ExpressionPlan| ExpressionPlan::BinaryExpression {
+            left: Box::new(left),
+            op: op.to_string(),
+            right: Box::new(right),
+        };
+
+    let data_path = std::env::temp_dir().join(uuid::Uuid::new_v4().to_string());
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("a", DataType::Int64, false),
+        Field::new("b", DataType::Utf8, false),
+    ]));
+    let batch = RecordBatch::try_new(
+        schema.clone(),
+        vec![
+            Arc::new(Int64Array::from(vec![10, 20, 30])),
+            Arc::new(StringArray::from(vec!["x", "y", "z"])),
+        ],
+    )?;
+    let store = BlockStore::try_create(data_path.to_str().unwrap())?;
+    let block = store.write_block(1, &batch)?;
+
+    let reader = SerializedFileReader::new(File::open(data_path.join(&block.file))?)?;
+    let row_group = reader.metadata().row_group(0);
+
+    let tests = vec![
+        ("a = 20", binary(col("a"), "=", lit(20i64)), true),
+        ("a = 40", binary(col("a"), "=", lit(40i64)), false),
+        ("a > 30", binary(col("a"), ">", lit(30i64)), false),
+        ("a >= 30", binary(col("a"), ">=", lit(30i64)), true),
+        ("a < 10", binary(col("a"), "<", lit(10i64)), false),
+        ("10 > a", binary(lit(10i64), ">", col("a")), false),
+        ("a > 5.5", binary(col("a"), ">", lit(5.5f64)), true),
+        ("b = 'w'", binary(col("b"), "=", lit("w")), false),
+        ("b = 'y'", binary(col("b"), "=", lit("y")), true),
+        (
+            "a > 30 or b = 'y'",
+            binary(
+                binary(col("a"), ">", lit(30i64)),
+                "or",
+                binary(col("b"), "=", lit("y")),
+            ),
+            true,
+        ),
+        (
+            "a > 20 and b = 'w'",
+            binary(
+                binary(col("a"), ">", lit(20i64)),
+                "and",
+                binary(col("b"), "=", lit("w")),
+            ),
+            false,
+        ),
+        ("unknown column", binary(col("c"), "=", lit(1i64)), true),
+        (
+            "function",
+            ExpressionPlan::Function {
+                op: "toTypeName".to_string(),
+                args: vec![col("a")],
+            },
+            true,
+        ),
+    ];
+    for (name, predicate, expect) in tests {
+        let actual = row_group_may_match(&predicate, &schema, row_group);
+        assert_eq!(expect, actual, "{}", name);
+    }
+
+    let null = binary(
+        col("a"),
+        "=",
+        ExpressionPlan::Literal(DataValue::Int64(None)),
+    );
+    assert!(row_group_may_match(&null, &schema, row_group));
+
+    std::fs::remove_dir_all(data_path)?;
+    Ok(())
+}
@@ -2,6 +2,11 @@
 //
 // SPDX-License-Identifier: Apache-2.0.
 
+#[cfg(test)]
+pub mod tests;
+
 pub mod api;
 pub mod configs;
+pub mod data;
+pub mod meta;
 pub mod metrics;
@@ -0,0 +1,115 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::fmt;
+
+use anyhow::{anyhow, Result};
+use async_raft::{AppData, AppDataResponse};
+use common_planners::{CreateDatabasePlan, CreateTablePlan, DropDatabasePlan, DropTablePlan};
+
+use crate::data::BlockFile;
+use crate::meta::{Block, Database, Table};
+
+/// A change of the meta, which is replicated by the raft log and applied to the meta of every node.
+#[derive(serde::Serialize, serde::Deserialize, Clone)]
+pub enum MetaCommand {
+    CreateDatabase {
+        plan: CreateDatabasePlan,
+    },
+    DropDatabase {
+        plan: DropDatabasePlan,
+    },
+    CreateTable {
+        plan: CreateTablePlan,
+    },
+    DropTable {
+        plan: DropTablePlan,
+    },
+    AppendBlocks {
+        db: String,
+        table: String,
+        table_id: i64,
+        files: Vec<BlockFile>,
+    },
+    CompactBlocks {
+        db: String,
+        table: String,
+        table_id: i64,
+        block_ids: Vec<i64>,
+        file: BlockFile,
+        level: i64,
+    },
+    PurgeBlocks {
+        db: String,
+        table: String,
+        table_id: i64,
+        block_ids: Vec<i64>,
+    },
+}
+
+// The plans have no Debug, only the names are printed in the raft logs.
+impl fmt::Debug for MetaCommand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MetaCommand::CreateDatabase { plan } => write!(f, "CreateDatabase({})", plan.db),
+            MetaCommand::DropDatabase { plan } => write!(f, "DropDatabase({})", plan.db),
+            MetaCommand::CreateTable { plan } => {
+                write!(f, "CreateTable({}.{})", plan.db, plan.table)
+            }
+            MetaCommand::DropTable { plan } => write!(f, "DropTable({}.{})", plan.db, plan.table),
+            MetaCommand::AppendBlocks {
+                db, table, files, ..
+            } => write!(f, "AppendBlocks({}.{}, {} files)", db, table, files.len()),
+            MetaCommand::CompactBlocks {
+                db,
+                table,
+                block_ids,
+                ..
+            } => write!(f, "CompactBlocks({}.{}, {:?})", db, table, block_ids),
+            MetaCommand::PurgeBlocks {
+                db,
+                table,
+                block_ids,
+                ..
+            } => write!(f, "PurgeBlocks({}.{}, {:?})", db, table, block_ids),
+        }
+    }
+}
+
+impl AppData for MetaCommand {}
+
+/// A read of the meta, which is served by the leader.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+pub enum MetaQuery {
+    GetDatabase { db: String },
+    GetDatabases,
+    GetTable { db: String, table: String },
+    GetTables { db: String },
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+pub enum MetaResponse {
+    DatabaseId(i64),
+    TableId(i64),
+    Database(Database),
+    Databases(Vec<Database>),
+    Table(Table),
+    Tables(Vec<Table>),
+    DroppedDatabase(Option<Database>),
+    DroppedTable(Option<Table>),
+    Blocks(Vec<Block>),
+    /// The command or query is rejected by the meta, the log entry is still applied.
+    Error(String),
+}
+
+impl AppDataResponse for MetaResponse {}
+
+impl MetaResponse {
+    pub fn into_result(self) -> Result<Self> {
+        match self {
+            MetaResponse::Error(e) => Err(anyhow!("{}", e)),
+            v => Ok(v),
+        }
+    }
+}
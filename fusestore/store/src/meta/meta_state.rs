@@ -0,0 +1,342 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::collections::HashMap;
+
+use anyhow::{anyhow, bail, Result};
+use common_datavalues::DataSchemaRef;
+use common_planners::{
+    CreateDatabasePlan, CreateTablePlan, DropDatabasePlan, DropTablePlan, TableOptions,
+};
+
+use crate::data::BlockFile;
+use crate::meta::{MetaCommand, MetaQuery, MetaResponse};
+
+/// The whole meta data of the store, see rfcs/store/2021-03-23-store-design.md.
+/// It's the state machine of the meta raft group, every change is a command applied to it.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Default)]
+pub struct Meta {
+    pub db_name_to_id: HashMap<String, i64>,
+    pub dbs: HashMap<i64, Database>,
+
+    // Monotonically incremental id generators.
+    pub next_db_id: i64,
+    pub next_table_id: i64,
+    pub next_block_id: i64,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+pub struct Database {
+    pub database_id: i64,
+    pub name: String,
+    pub table_name_to_id: HashMap<String, i64>,
+    pub tables: HashMap<i64, Table>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+pub struct Table {
+    pub table_id: i64,
+    pub db: String,
+    pub name: String,
+    pub schema: DataSchemaRef,
+    pub options: TableOptions,
+    pub blocks: Vec<Block>,
+    /// The blocks replaced by compaction, the files are removed once no query reads them.
+    pub detached_blocks: Vec<Block>,
+}
+
+/// An immutable Parquet file of the table.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+pub struct Block {
+    pub block_id: i64,
+    /// The file path relative to the data path.
+    pub file: String,
+    pub rows: usize,
+    pub bytes: usize,
+    /// The appended blocks are at level 0, a compacted block is one level above its sources.
+    pub level: i64,
+}
+
+impl Meta {
+    /// Apply the command to a copy of the meta, the copy replaces the meta only if the command succeeds.
+    /// The result must only depend on the meta and the command, every replica gets the same.
+    pub fn apply(&mut self, command: &MetaCommand) -> MetaResponse {
+        let mut updated = self.clone();
+        let result = match command {
+            MetaCommand::CreateDatabase { plan } => {
+                updated.create_database(plan).map(MetaResponse::DatabaseId)
+            }
+            MetaCommand::DropDatabase { plan } => updated
+                .drop_database(plan)
+                .map(MetaResponse::DroppedDatabase),
+            MetaCommand::CreateTable { plan } => {
+                updated.create_table(plan).map(MetaResponse::TableId)
+            }
+            MetaCommand::DropTable { plan } => {
+                updated.drop_table(plan).map(MetaResponse::DroppedTable)
+            }
+            MetaCommand::AppendBlocks {
+                db,
+                table,
+                table_id,
+                files,
+            } => updated
+                .append_blocks(db, table, *table_id, files)
+                .map(MetaResponse::Blocks),
+            MetaCommand::CompactBlocks {
+                db,
+                table,
+                table_id,
+                block_ids,
+                file,
+                level,
+            } => updated
+                .compact_blocks(db, table, *table_id, block_ids, file, *level)
+                .map(MetaResponse::Blocks),
+            MetaCommand::PurgeBlocks {
+                db,
+                table,
+                table_id,
+                block_ids,
+            } => updated
+                .purge_blocks(db, table, *table_id, block_ids)
+                .map(MetaResponse::Blocks),
+        };
+
+        match result {
+            Ok(response) => {
+                *self = updated;
+                response
+            }
+            Err(e) => MetaResponse::Error(e.to_string()),
+        }
+    }
+
+    pub fn query(&self, query: &MetaQuery) -> MetaResponse {
+        let result = match query {
+            MetaQuery::GetDatabase { db } => self.get_database(db).map(MetaResponse::Database),
+            MetaQuery::GetDatabases => Ok(MetaResponse::Databases(self.get_databases())),
+            MetaQuery::GetTable { db, table } => self.get_table(db, table).map(MetaResponse::Table),
+            MetaQuery::GetTables { db } => self.get_tables(db).map(MetaResponse::Tables),
+        };
+        result.unwrap_or_else(|e| MetaResponse::Error(e.to_string()))
+    }
+
+    fn create_database(&mut self, plan: &CreateDatabasePlan) -> Result<i64> {
+        if let Some(id) = self.db_name_to_id.get(&plan.db) {
+            if plan.if_not_exists {
+                return Ok(*id);
+            }
+            bail!("Store Error: Database '{}' already exists", plan.db);
+        }
+
+        self.next_db_id += 1;
+        let database_id = self.next_db_id;
+        self.db_name_to_id.insert(plan.db.clone(), database_id);
+        self.dbs.insert(
+            database_id,
+            Database {
+                database_id,
+                name: plan.db.clone(),
+                table_name_to_id: HashMap::new(),
+                tables: HashMap::new(),
+            },
+        );
+        Ok(database_id)
+    }
+
+    fn get_database(&self, db: &str) -> Result<Database> {
+        Ok(self.database(db)?.clone())
+    }
+
+    fn get_databases(&self) -> Vec<Database> {
+        let mut databases: Vec<Database> = self.dbs.values().cloned().collect();
+        databases.sort_by_key(|v| v.database_id);
+        databases
+    }
+
+    /// Returns the dropped database, whose block files are no longer referenced.
+    fn drop_database(&mut self, plan: &DropDatabasePlan) -> Result<Option<Database>> {
+        match self.db_name_to_id.remove(&plan.db) {
+            Some(id) => Ok(self.dbs.remove(&id)),
+            None if plan.if_exists => Ok(None),
+            None => bail!("Store Error: Unknown database: '{}'", plan.db),
+        }
+    }
+
+    fn create_table(&mut self, plan: &CreateTablePlan) -> Result<i64> {
+        let next_table_id = self.next_table_id + 1;
+        let database = self.database_mut(&plan.db)?;
+        if let Some(id) = database.table_name_to_id.get(&plan.table) {
+            if plan.if_not_exists {
+                return Ok(*id);
+            }
+            bail!(
+                "Store Error: Table '{}.{}' already exists",
+                plan.db,
+                plan.table
+            );
+        }
+
+        database
+            .table_name_to_id
+            .insert(plan.table.clone(), next_table_id);
+        database.tables.insert(
+            next_table_id,
+            Table {
+                table_id: next_table_id,
+                db: plan.db.clone(),
+                name: plan.table.clone(),
+                schema: plan.schema.clone(),
+                options: plan.options.clone(),
+                blocks: vec![],
+            },
+        );
+        self.next_table_id = next_table_id;
+        Ok(next_table_id)
+    }
+
+    fn get_table(&self, db: &str, table: &str) -> Result<Table> {
+        let database = self.database(db)?;
+        database
+            .table_name_to_id
+            .get(table)
+            .and_then(|id| database.tables.get(id))
+            .cloned()
+            .ok_or_else(|| anyhow!("Store Error: Unknown table: '{}.{}'", db, table))
+    }
+
+    fn get_tables(&self, db: &str) -> Result<Vec<Table>> {
+        let mut tables: Vec<Table> = self.database(db)?.tables.values().cloned().collect();
+        tables.sort_by_key(|v| v.table_id);
+        Ok(tables)
+    }
+
+    /// Returns the dropped table, whose block files are no longer referenced.
+    fn drop_table(&mut self, plan: &DropTablePlan) -> Result<Option<Table>> {
+        let database = match self.database_mut(&plan.db) {
+            Ok(database) => database,
+            Err(_) if plan.if_exists => return Ok(None),
+            Err(e) => return Err(e),
+        };
+        match database.table_name_to_id.remove(&plan.table) {
+            Some(id) => Ok(database.tables.remove(&id)),
+            None if plan.if_exists => Ok(None),
+            None => bail!("Store Error: Unknown table: '{}.{}'", plan.db, plan.table),
+        }
+    }
+
+    /// Commit the written block files to the table at once.
+    /// The table id is checked, the blocks are not committed if the table has been dropped or re-created.
+    fn append_blocks(
+        &mut self,
+        db: &str,
+        table: &str,
+        table_id: i64,
+        files: &[BlockFile],
+    ) -> Result<Vec<Block>> {
+        let mut next_block_id = self.next_block_id;
+        let table_meta = self.table_mut(db, table, table_id)?;
+
+        let mut blocks = vec![];
+        for file in files {
+            next_block_id += 1;
+            blocks.push(Block {
+                block_id: next_block_id,
+                file: file.file.clone(),
+                rows: file.rows,
+                bytes: file.bytes,
+                level: 0,
+            });
+        }
+        table_meta.blocks.extend(blocks.iter().cloned());
+        self.next_block_id = next_block_id;
+        Ok(blocks)
+    }
+
+    /// Replace the blocks with the merged block file at once, the replaced blocks are detached.
+    /// Nothing changes if any of the blocks is no longer in the table.
+    fn compact_blocks(
+        &mut self,
+        db: &str,
+        table: &str,
+        table_id: i64,
+        block_ids: &[i64],
+        file: &BlockFile,
+        level: i64,
+    ) -> Result<Vec<Block>> {
+        let block_id = self.next_block_id + 1;
+        let table_meta = self.table_mut(db, table, table_id)?;
+        if !block_ids
+            .iter()
+            .all(|id| table_meta.blocks.iter().any(|v| v.block_id == *id))
+        {
+            bail!(
+                "Store Error: Blocks of table '{}.{}' have been changed",
+                db,
+                table
+            );
+        }
+
+        let (detached, blocks) = table_meta
+            .blocks
+            .drain(..)
+            .partition(|v| block_ids.contains(&v.block_id));
+        table_meta.blocks = blocks;
+        table_meta.detached_blocks.extend(detached);
+
+        let block = Block {
+            block_id,
+            file: file.file.clone(),
+            rows: file.rows,
+            bytes: file.bytes,
+            level,
+        };
+        table_meta.blocks.push(block.clone());
+        self.next_block_id = block_id;
+        Ok(vec![block])
+    }
+
+    /// Forget the detached blocks whose files are going to be removed, returns the purged blocks.
+    fn purge_blocks(
+        &mut self,
+        db: &str,
+        table: &str,
+        table_id: i64,
+        block_ids: &[i64],
+    ) -> Result<Vec<Block>> {
+        let table_meta = self.table_mut(db, table, table_id)?;
+        let (purged, detached) = table_meta
+            .detached_blocks
+            .drain(..)
+            .partition(|v| block_ids.contains(&v.block_id));
+        table_meta.detached_blocks = detached;
+        Ok(purged)
+    }
+
+    fn table_mut(&mut self, db: &str, table: &str, table_id: i64) -> Result<&mut Table> {
+        self.database_mut(db)?
+            .tables
+            .get_mut(&table_id)
+            .filter(|v| v.name == table)
+            .ok_or_else(|| anyhow!("Store Error: Unknown table: '{}.{}'", db, table))
+    }
+
+    fn database_mut(&mut self, db: &str) -> Result<&mut Database> {
+        let id = self
+            .db_name_to_id
+            .get(db)
+            .ok_or_else(|| anyhow!("Store Error: Unknown database: '{}'", db))?;
+        self.dbs
+            .get_mut(id)
+            .ok_or_else(|| anyhow!("Store Error: Unknown database: '{}'", db))
+    }
+
+    fn database(&self, db: &str) -> Result<&Database> {
+        self.db_name_to_id
+            .get(db)
+            .and_then(|id| self.dbs.get(id))
+            .ok_or_else(|| anyhow!("Store Error: Unknown database: '{}'", db))
+    }
+}
@@ -0,0 +1,365 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{anyhow, bail, Result};
+use async_raft::config::SnapshotPolicy;
+use async_raft::error::{ClientReadError, ClientWriteError, InitializeError};
+use async_raft::raft::ClientWriteRequest;
+use async_raft::{NodeId, Raft};
+use common_planners::{CreateDatabasePlan, CreateTablePlan, DropDatabasePlan, DropTablePlan};
+
+use crate::configs::Config;
+use crate::data::BlockFile;
+use crate::meta::{
+    Block, Database, MetaCommand, MetaQuery, MetaRaftNetwork, MetaRaftNetworkRef, MetaRaftStorage,
+    MetaRaftStorageRef, MetaResponse, RaftAction, Table,
+};
+
+pub type MetaRaft = Raft<MetaCommand, MetaResponse, MetaRaftNetwork, MetaRaftStorage>;
+
+pub type MetaStoreRef = Arc<MetaStore>;
+
+// How long a request waits for a leader to be elected.
+const LEADER_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The meta is replicated by raft among the candidate nodes, one of them is elected as the leader.
+/// Every change is a command committed to the raft log and applied to the meta of every node,
+/// the reads are served by the leader, the requests to the other nodes are forwarded to it.
+/// The learners receive the log and the snapshots as well but never vote.
+pub struct MetaStore {
+    id: NodeId,
+    raft: MetaRaft,
+    storage: MetaRaftStorageRef,
+    network: MetaRaftNetworkRef,
+}
+
+impl MetaStore {
+    pub fn try_create(conf: &Config) -> Result<MetaStoreRef> {
+        let id = conf.id;
+        let candidates = conf.candidate_nodes()?;
+        let learners = conf.learner_nodes()?;
+        if !candidates.contains_key(&id) && !learners.contains_key(&id) {
+            bail!(
+                "Store Error: Node {} is neither a candidate nor a learner",
+                id
+            );
+        }
+
+        let raft_conf = async_raft::Config::build("fuse-store".to_string())
+            .snapshot_policy(SnapshotPolicy::LogsSinceLast(conf.raft_snapshot_logs))
+            .validate()?;
+        let storage = MetaRaftStorage::try_create(id, conf.data_path.as_str())?;
+        let mut addresses = candidates.clone();
+        addresses.extend(learners.clone());
+        let network = MetaRaftNetwork::create(addresses);
+        let raft = Raft::new(id, Arc::new(raft_conf), network.clone(), storage.clone());
+
+        // It's safe to initialize every candidate, the cluster is only initialized once.
+        if candidates.contains_key(&id) {
+            let raft = raft.clone();
+            let members: HashSet<NodeId> = candidates.keys().cloned().collect();
+            tokio::spawn(async move {
+                match raft.initialize(members).await {
+                    Ok(()) | Err(InitializeError::NotAllowed) => {}
+                    Err(e) => log::error!("Initialize meta raft error: {:?}", e),
+                }
+            });
+        }
+
+        // Every new leader adds the learners, which catch up from the log or the snapshot.
+        if !learners.is_empty() {
+            let raft = raft.clone();
+            tokio::spawn(async move {
+                let mut metrics = raft.metrics();
+                let mut term = 0;
+                while metrics.changed().await.is_ok() {
+                    let current = metrics.borrow().clone();
+                    if current.current_leader != Some(id) || current.current_term == term {
+                        continue;
+                    }
+                    term = current.current_term;
+                    for learner in learners.keys().cloned() {
+                        let raft = raft.clone();
+                        tokio::spawn(async move {
+                            if let Err(e) = raft.add_non_voter(learner).await {
+                                log::error!("Add meta learner {} error: {:?}", learner, e);
+                            }
+                        });
+                    }
+                }
+            });
+        }
+
+        Ok(Arc::new(MetaStore {
+            id,
+            raft,
+            storage,
+            network,
+        }))
+    }
+
+    pub fn raft(&self) -> &MetaRaft {
+        &self.raft
+    }
+
+    /// The index of the last log entry applied to the local meta.
+    pub fn last_applied_log(&self) -> u64 {
+        self.storage.last_applied_log()
+    }
+
+    /// Query the local meta without asking the leader, it may be stale on the followers and learners.
+    pub fn local_read(&self, query: MetaQuery) -> Result<MetaResponse> {
+        self.storage.query(&query).into_result()
+    }
+
+    pub async fn create_database(&self, plan: CreateDatabasePlan) -> Result<i64> {
+        match self
+            .write(MetaCommand::CreateDatabase { plan }, true)
+            .await?
+        {
+            MetaResponse::DatabaseId(id) => Ok(id),
+            v => Err(unexpected(v)),
+        }
+    }
+
+    pub async fn get_database(&self, db: &str) -> Result<Database> {
+        let query = MetaQuery::GetDatabase { db: db.to_string() };
+        match self.read(query, true).await? {
+            MetaResponse::Database(database) => Ok(database),
+            v => Err(unexpected(v)),
+        }
+    }
+
+    pub async fn get_databases(&self) -> Result<Vec<Database>> {
+        match self.read(MetaQuery::GetDatabases, true).await? {
+            MetaResponse::Databases(databases) => Ok(databases),
+            v => Err(unexpected(v)),
+        }
+    }
+
+    /// Returns the dropped database, whose block files are no longer referenced.
+    pub async fn drop_database(&self, plan: DropDatabasePlan) -> Result<Option<Database>> {
+        match self.write(MetaCommand::DropDatabase { plan }, true).await? {
+            MetaResponse::DroppedDatabase(database) => Ok(database),
+            v => Err(unexpected(v)),
+        }
+    }
+
+    pub async fn create_table(&self, plan: CreateTablePlan) -> Result<i64> {
+        match self.write(MetaCommand::CreateTable { plan }, true).await? {
+            MetaResponse::TableId(id) => Ok(id),
+            v => Err(unexpected(v)),
+        }
+    }
+
+    pub async fn get_table(&self, db: &str, table: &str) -> Result<Table> {
+        let query = MetaQuery::GetTable {
+            db: db.to_string(),
+            table: table.to_string(),
+        };
+        match self.read(query, true).await? {
+            MetaResponse::Table(table) => Ok(table),
+            v => Err(unexpected(v)),
+        }
+    }
+
+    pub async fn get_tables(&self, db: &str) -> Result<Vec<Table>> {
+        let query = MetaQuery::GetTables { db: db.to_string() };
+        match self.read(query, true).await? {
+            MetaResponse::Tables(tables) => Ok(tables),
+            v => Err(unexpected(v)),
+        }
+    }
+
+    /// Returns the dropped table, whose block files are no longer referenced.
+    pub async fn drop_table(&self, plan: DropTablePlan) -> Result<Option<Table>> {
+        match self.write(MetaCommand::DropTable { plan }, true).await? {
+            MetaResponse::DroppedTable(table) => Ok(table),
+            v => Err(unexpected(v)),
+        }
+    }
+
+    /// Commit the written block files to the table in one meta command.
+    /// The table id is checked, the blocks are not committed if the table has been dropped or re-created.
+    pub async fn append_blocks(
+        &self,
+        db: &str,
+        table: &str,
+        table_id: i64,
+        files: Vec<BlockFile>,
+    ) -> Result<Vec<Block>> {
+        let command = MetaCommand::AppendBlocks {
+            db: db.to_string(),
+            table: table.to_string(),
+            table_id,
+            files,
+        };
+        match self.write(command, true).await? {
+            MetaResponse::Blocks(blocks) => Ok(blocks),
+            v => Err(unexpected(v)),
+        }
+    }
+
+    /// Replace the blocks of the table with the merged block file in one meta command.
+    pub async fn compact_blocks(
+        &self,
+        table: &Table,
+        block_ids: Vec<i64>,
+        file: BlockFile,
+        level: i64,
+    ) -> Result<Block> {
+        let command = MetaCommand::CompactBlocks {
+            db: table.db.clone(),
+            table: table.name.clone(),
+            table_id: table.table_id,
+            block_ids,
+            file,
+            level,
+        };
+        match self.write(command, true).await? {
+            MetaResponse::Blocks(mut blocks) if blocks.len() == 1 => Ok(blocks.remove(0)),
+            v => Err(unexpected(v)),
+        }
+    }
+
+    /// Forget the detached blocks of the table, returns the purged blocks.
+    pub async fn purge_blocks(&self, table: &Table, block_ids: Vec<i64>) -> Result<Vec<Block>> {
+        let command = MetaCommand::PurgeBlocks {
+            db: table.db.clone(),
+            table: table.name.clone(),
+            table_id: table.table_id,
+            block_ids,
+        };
+        match self.write(command, true).await? {
+            MetaResponse::Blocks(blocks) => Ok(blocks),
+            v => Err(unexpected(v)),
+        }
+    }
+
+    /// Handle the raft action from the other nodes, returns the JSON encoded response.
+    pub async fn handle_raft_action(&self, body: &[u8]) -> Result<Vec<u8>> {
+        let action: RaftAction = serde_json::from_slice(body)?;
+        let response = match action {
+            RaftAction::AppendEntries(rpc) => {
+                serde_json::to_vec(&self.raft.append_entries(rpc).await?)?
+            }
+            RaftAction::Vote(rpc) => serde_json::to_vec(&self.raft.vote(rpc).await?)?,
+            RaftAction::InstallSnapshot(rpc) => {
+                serde_json::to_vec(&self.raft.install_snapshot(rpc).await?)?
+            }
+            // The forwarded requests are not forwarded again.
+            RaftAction::ClientWrite(command) => {
+                serde_json::to_vec(&self.write(command, false).await?)?
+            }
+            RaftAction::ClientRead(query) => serde_json::to_vec(&self.read(query, false).await?)?,
+        };
+        Ok(response)
+    }
+
+    /// Commit the command by the leader and return the result of applying it.
+    /// The command is forwarded to the leader at most once, a node receives the forwarded
+    /// command returns an error if it is no longer the leader.
+    async fn write(&self, command: MetaCommand, forward: bool) -> Result<MetaResponse> {
+        let leader = self.wait_for_leader().await?;
+        let response = if leader == self.id {
+            match self
+                .raft
+                .client_write(ClientWriteRequest::new(command))
+                .await
+            {
+                Ok(response) => response.data,
+                Err(ClientWriteError::ForwardToLeader(command, Some(leader))) if forward => {
+                    self.forward(leader, &RaftAction::ClientWrite(command))
+                        .await?
+                }
+                Err(e) => bail!("Store Error: Meta write error: {}", e),
+            }
+        } else if forward {
+            self.forward(leader, &RaftAction::ClientWrite(command))
+                .await?
+        } else {
+            bail!("Store Error: Node {} is not the meta leader", self.id)
+        };
+        response.into_result()
+    }
+
+    /// Query the meta of the leader, after it confirms the leadership and applies the log.
+    /// The query is forwarded to the leader at most once, like the write.
+    async fn read(&self, query: MetaQuery, forward: bool) -> Result<MetaResponse> {
+        let leader = self.wait_for_leader().await?;
+        let response = if leader == self.id {
+            match self.raft.client_read().await {
+                Ok(()) => {
+                    self.wait_for_applied().await?;
+                    self.storage.query(&query)
+                }
+                Err(ClientReadError::ForwardToLeader(Some(leader))) if forward => {
+                    self.forward(leader, &RaftAction::ClientRead(query)).await?
+                }
+                Err(e) => bail!("Store Error: Meta read error: {}", e),
+            }
+        } else if forward {
+            self.forward(leader, &RaftAction::ClientRead(query)).await?
+        } else {
+            bail!("Store Error: Node {} is not the meta leader", self.id)
+        };
+        response.into_result()
+    }
+
+    async fn forward(&self, leader: NodeId, action: &RaftAction) -> Result<MetaResponse> {
+        let body = self.network.send(leader, action).await?;
+        Ok(serde_json::from_slice(&body)?)
+    }
+
+    async fn wait_for_leader(&self) -> Result<NodeId> {
+        tokio::time::timeout(LEADER_TIMEOUT, self.current_leader())
+            .await
+            .map_err(|_| {
+                anyhow!(
+                    "Store Error: No meta leader elected in {:?}",
+                    LEADER_TIMEOUT
+                )
+            })?
+    }
+
+    // The meta is only persisted by the snapshots, after a restart the log entries are applied
+    // again once the leader commits them.
+    async fn wait_for_applied(&self) -> Result<()> {
+        let mut metrics = self.raft.metrics();
+        let index = metrics.borrow().last_log_index;
+        let applied = async move {
+            while metrics.borrow().last_applied < index {
+                metrics.changed().await?;
+            }
+            Ok::<(), anyhow::Error>(())
+        };
+        tokio::time::timeout(LEADER_TIMEOUT, applied)
+            .await
+            .map_err(|_| {
+                anyhow!(
+                    "Store Error: Meta log is not applied in {:?}",
+                    LEADER_TIMEOUT
+                )
+            })?
+    }
+
+    async fn current_leader(&self) -> Result<NodeId> {
+        let mut metrics = self.raft.metrics();
+        loop {
+            let current_leader = metrics.borrow().current_leader;
+            if let Some(leader) = current_leader {
+                return Ok(leader);
+            }
+            metrics.changed().await?;
+        }
+    }
+}
+
+fn unexpected(response: MetaResponse) -> anyhow::Error {
+    anyhow!("Store Error: Unexpected meta response {:?}", response)
+}
@@ -0,0 +1,209 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_meta_store() -> anyhow::Result<()> {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    use common_arrow::arrow::datatypes::{DataType, Field, Schema};
+    use common_planners::*;
+    use pretty_assertions::assert_eq;
+
+    use crate::meta::*;
+
+    let conf = crate::tests::new_test_config();
+    let data_path = conf.data_path.as_str();
+    let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, false)]));
+
+    let store = MetaStore::try_create(&conf)?;
+    let db_plan = CreateDatabasePlan {
+        if_not_exists: false,
+        db: "db1".to_string(),
+        schema: Arc::new(Schema::empty()),
+        engine: DatabaseEngineType::Remote,
+        options: HashMap::new(),
+    };
+    assert_eq!(1, store.create_database(db_plan.clone()).await?);
+    if let Err(e) = store.create_database(db_plan.clone()).await {
+        assert_eq!("Store Error: Database 'db1' already exists", e.to_string());
+    } else {
+        assert!(false)
+    }
+    assert_eq!(
+        1,
+        store
+            .create_database(CreateDatabasePlan {
+                if_not_exists: true,
+                ..db_plan.clone()
+            })
+            .await?
+    );
+
+    let table_plan = CreateTablePlan {
+        if_not_exists: false,
+        db: "db1".to_string(),
+        table: "t1".to_string(),
+        schema: schema.clone(),
+        engine: TableEngineType::Parquet,
+        options: HashMap::new(),
+    };
+    assert_eq!(1, store.create_table(table_plan.clone()).await?);
+    if let Err(e) = store
+        .create_table(CreateTablePlan {
+            db: "db2".to_string(),
+            ..table_plan.clone()
+        })
+        .await
+    {
+        assert_eq!("Store Error: Unknown database: 'db2'", e.to_string());
+    } else {
+        assert!(false)
+    }
+
+    // The meta is loaded back from the data path.
+    store.raft().shutdown().await?;
+    let store = MetaStore::try_create(&conf)?;
+    assert_eq!(1, store.get_database("db1").await?.database_id);
+    let table = store.get_table("db1", "t1").await?;
+    assert_eq!(1, table.table_id);
+    assert_eq!(schema, table.schema);
+    assert_eq!(1, store.get_tables("db1").await?.len());
+
+    // Append blocks.
+    let file = crate::data::BlockFile {
+        file: "blocks/1/a.parquet".to_string(),
+        rows: 3,
+        bytes: 10,
+    };
+    let blocks = store
+        .append_blocks("db1", "t1", 1, vec![file.clone()])
+        .await?;
+    assert_eq!(1, blocks[0].block_id);
+    assert_eq!(1, store.get_table("db1", "t1").await?.blocks.len());
+    if let Err(e) = store.append_blocks("db1", "t1", 2, vec![file]).await {
+        assert_eq!("Store Error: Unknown table: 'db1.t1'", e.to_string());
+    } else {
+        assert!(false)
+    }
+
+    store
+        .drop_table(DropTablePlan {
+            if_exists: false,
+            db: "db1".to_string(),
+            table: "t1".to_string(),
+            schema: Arc::new(Schema::empty()),
+        })
+        .await?;
+    if let Err(e) = store.get_table("db1", "t1").await {
+        assert_eq!("Store Error: Unknown table: 'db1.t1'", e.to_string());
+    } else {
+        assert!(false)
+    }
+
+    store
+        .drop_database(DropDatabasePlan {
+            if_exists: false,
+            db: "db1".to_string(),
+            schema: Arc::new(Schema::empty()),
+        })
+        .await?;
+    assert_eq!(0, store.get_databases().await?.len());
+    store
+        .drop_database(DropDatabasePlan {
+            if_exists: true,
+            db: "db1".to_string(),
+            schema: Arc::new(Schema::empty()),
+        })
+        .await?;
+
+    // Ids are never reused.
+    assert_eq!(2, store.create_database(db_plan).await?);
+
+    std::fs::remove_dir_all(data_path)?;
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_meta_store_cluster() -> anyhow::Result<()> {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    use common_arrow::arrow::datatypes::{DataType, Field, Schema};
+    use common_planners::*;
+    use pretty_assertions::assert_eq;
+
+    use crate::meta::*;
+
+    // Three candidates and a learner, a snapshot is taken every 5 logs.
+    let mut confs: Vec<_> = (1..=4)
+        .map(|id| {
+            let mut conf = crate::tests::new_test_config();
+            conf.id = id;
+            conf.raft_snapshot_logs = 5;
+            conf
+        })
+        .collect();
+    let nodes: Vec<String> = confs
+        .iter()
+        .map(|v| format!("{}={}", v.id, v.rpc_api_address))
+        .collect();
+    for conf in confs.iter_mut() {
+        conf.candidates = nodes[0..3].join(",");
+        conf.learners = nodes[3].clone();
+    }
+
+    let mut stores = vec![];
+    for conf in confs[0..3].iter() {
+        stores.push(crate::tests::try_start_node(conf.clone()).await?);
+    }
+
+    // Write through any node, the followers forward to the leader.
+    let db_plan = CreateDatabasePlan {
+        if_not_exists: false,
+        db: "db1".to_string(),
+        schema: Arc::new(Schema::empty()),
+        engine: DatabaseEngineType::Remote,
+        options: HashMap::new(),
+    };
+    assert_eq!(1, stores[1].create_database(db_plan).await?);
+    for i in 1..=10 {
+        let table_plan = CreateTablePlan {
+            if_not_exists: false,
+            db: "db1".to_string(),
+            table: format!("t{}", i),
+            schema: Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, false)])),
+            engine: TableEngineType::Parquet,
+            options: HashMap::new(),
+        };
+        let table_id = stores[i % 3].create_table(table_plan).await?;
+        assert_eq!(i as i64, table_id);
+    }
+    for store in stores.iter() {
+        assert_eq!(10, store.get_tables("db1").await?.len());
+    }
+
+    // The learner joins late and catches up from the snapshot and the logs after it.
+    let learner = crate::tests::try_start_node(confs[3].clone()).await?;
+    let applied = stores.iter().map(|v| v.last_applied_log()).max().unwrap();
+    let mut retries = 0;
+    while learner.last_applied_log() < applied {
+        retries += 1;
+        assert!(retries < 100, "The learner does not catch up");
+        tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+    }
+    match learner.local_read(MetaQuery::GetTables {
+        db: "db1".to_string(),
+    })? {
+        MetaResponse::Tables(tables) => assert_eq!(10, tables.len()),
+        v => panic!("Unexpected response {:?}", v),
+    }
+    // The learner forwards the reads to the leader as well.
+    assert_eq!(1, learner.get_database("db1").await?.database_id);
+
+    for conf in confs.iter() {
+        std::fs::remove_dir_all(&conf.data_path).ok();
+    }
+    Ok(())
+}
@@ -0,0 +1,20 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+#[cfg(test)]
+mod meta_store_test;
+#[cfg(test)]
+mod raft_storage_test;
+
+mod meta_command;
+mod meta_state;
+mod meta_store;
+mod raft_network;
+mod raft_storage;
+
+pub use meta_command::{MetaCommand, MetaQuery, MetaResponse};
+pub use meta_state::{Block, Database, Meta, Table};
+pub use meta_store::{MetaRaft, MetaStore, MetaStoreRef};
+pub use raft_network::{MetaRaftNetwork, MetaRaftNetworkRef, RaftAction, RAFT_ACTION_TYPE};
+pub use raft_storage::{MetaRaftStorage, MetaRaftStorageRef, MetaSnapshot, MetaStateMachine};
@@ -0,0 +1,123 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use anyhow::{anyhow, Result};
+use async_raft::raft::{
+    AppendEntriesRequest, AppendEntriesResponse, InstallSnapshotRequest, InstallSnapshotResponse,
+    VoteRequest, VoteResponse,
+};
+use async_raft::{NodeId, RaftNetwork};
+use common_arrow::arrow_flight::flight_service_client::FlightServiceClient;
+use common_arrow::arrow_flight::Action;
+use common_infallible::Mutex;
+use tonic::transport::Channel;
+
+use crate::meta::{MetaCommand, MetaQuery};
+
+/// The do_action type of the raft actions, the other actions are store requests.
+pub const RAFT_ACTION_TYPE: &str = "Raft";
+
+/// The messages between the store nodes, they are sent as JSON in the body of a flight action.
+#[derive(serde::Serialize, serde::Deserialize)]
+pub enum RaftAction {
+    AppendEntries(AppendEntriesRequest<MetaCommand>),
+    Vote(VoteRequest),
+    InstallSnapshot(InstallSnapshotRequest),
+    /// A meta command forwarded to the leader.
+    ClientWrite(MetaCommand),
+    /// A meta query forwarded to the leader.
+    ClientRead(MetaQuery),
+}
+
+pub type MetaRaftNetworkRef = Arc<MetaRaftNetwork>;
+
+/// Sends the raft actions to the other nodes by their flight services.
+pub struct MetaRaftNetwork {
+    addresses: HashMap<NodeId, String>,
+    clients: Mutex<HashMap<NodeId, FlightServiceClient<Channel>>>,
+}
+
+impl MetaRaftNetwork {
+    pub fn create(addresses: HashMap<NodeId, String>) -> MetaRaftNetworkRef {
+        Arc::new(MetaRaftNetwork {
+            addresses,
+            clients: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Send the action to the node and return the JSON encoded response.
+    pub async fn send(&self, target: NodeId, action: &RaftAction) -> Result<Vec<u8>> {
+        let mut client = self.client(target).await?;
+        let request = tonic::Request::new(Action {
+            r#type: RAFT_ACTION_TYPE.to_string(),
+            body: serde_json::to_vec(action)?,
+        });
+
+        let result = match client.do_action(request).await {
+            Ok(response) => response.into_inner().message().await,
+            Err(e) => Err(e),
+        };
+        match result {
+            Ok(Some(result)) => Ok(result.body),
+            Ok(None) => Err(anyhow!(
+                "Store Error: Empty raft response from node {}",
+                target
+            )),
+            Err(e) => {
+                // Reconnect next time, the node may have been restarted.
+                self.clients.lock().remove(&target);
+                Err(anyhow!("{}", e.message()))
+            }
+        }
+    }
+
+    async fn client(&self, target: NodeId) -> Result<FlightServiceClient<Channel>> {
+        if let Some(client) = self.clients.lock().get(&target) {
+            return Ok(client.clone());
+        }
+
+        let addr = self
+            .addresses
+            .get(&target)
+            .ok_or_else(|| anyhow!("Store Error: Unknown node {}", target))?;
+        let client = FlightServiceClient::connect(format!("http://{}", addr)).await?;
+        self.clients.lock().insert(target, client.clone());
+        Ok(client)
+    }
+
+    async fn rpc<T: serde::de::DeserializeOwned>(
+        &self,
+        target: NodeId,
+        action: &RaftAction,
+    ) -> Result<T> {
+        let body = self.send(target, action).await?;
+        Ok(serde_json::from_slice(&body)?)
+    }
+}
+
+#[async_trait::async_trait]
+impl RaftNetwork<MetaCommand> for MetaRaftNetwork {
+    async fn append_entries(
+        &self,
+        target: NodeId,
+        rpc: AppendEntriesRequest<MetaCommand>,
+    ) -> Result<AppendEntriesResponse> {
+        self.rpc(target, &RaftAction::AppendEntries(rpc)).await
+    }
+
+    async fn install_snapshot(
+        &self,
+        target: NodeId,
+        rpc: InstallSnapshotRequest,
+    ) -> Result<InstallSnapshotResponse> {
+        self.rpc(target, &RaftAction::InstallSnapshot(rpc)).await
+    }
+
+    async fn vote(&self, target: NodeId, rpc: VoteRequest) -> Result<VoteResponse> {
+        self.rpc(target, &RaftAction::Vote(rpc)).await
+    }
+}
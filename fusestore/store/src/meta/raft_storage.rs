@@ -0,0 +1,417 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs::{self, File, OpenOptions};
+use std::io::{Cursor, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use anyhow::{anyhow, Result};
+use async_raft::raft::{Entry, EntryPayload, MembershipConfig};
+use async_raft::storage::{CurrentSnapshotData, HardState, InitialState};
+use async_raft::{NodeId, RaftStorage};
+use common_infallible::{Mutex, RwLock};
+
+use crate::meta::{Meta, MetaCommand, MetaQuery, MetaResponse};
+
+/// The meta with the index of the last log entry applied to it.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Default)]
+pub struct MetaStateMachine {
+    pub last_applied_log: u64,
+    pub meta: Meta,
+}
+
+/// A snapshot of the state machine, the logs up to the index are replaced by it.
+#[derive(serde::Serialize, serde::Deserialize, Clone)]
+pub struct MetaSnapshot {
+    pub index: u64,
+    pub term: u64,
+    pub membership: MembershipConfig,
+    /// The JSON encoded state machine.
+    pub data: Vec<u8>,
+}
+
+#[derive(Clone, Default)]
+struct RaftState {
+    hard_state: Option<HardState>,
+    log: BTreeMap<u64, Entry<MetaCommand>>,
+    state_machine: MetaStateMachine,
+    snapshot: Option<MetaSnapshot>,
+}
+
+/// A change of the raft log, the log file is the sequence of them, one JSON for each line.
+#[derive(serde::Serialize, serde::Deserialize)]
+enum LogRecord {
+    Append(Entry<MetaCommand>),
+    Delete { start: u64, stop: Option<u64> },
+}
+
+#[derive(Debug)]
+pub struct ShutdownError(String);
+
+impl fmt::Display for ShutdownError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Store Error: Raft storage shutdown: {}", self.0)
+    }
+}
+
+impl std::error::Error for ShutdownError {}
+
+pub type MetaRaftStorageRef = Arc<MetaRaftStorage>;
+
+/// The raft log, the hard state, the state machine and the current snapshot of a node.
+/// The log entries are appended to the log file under the data path, it is only rewritten when
+/// the log is compacted or replaced by a snapshot. The state machine is in memory and persisted
+/// by the snapshots only, the entries after the snapshot are applied again after a restart.
+pub struct MetaRaftStorage {
+    id: NodeId,
+    log_file: PathBuf,
+    hard_state_file: PathBuf,
+    snapshot_file: PathBuf,
+    log_writer: Mutex<File>,
+    state: RwLock<RaftState>,
+}
+
+impl MetaRaftStorage {
+    pub fn try_create(id: NodeId, data_path: &str) -> Result<MetaRaftStorageRef> {
+        fs::create_dir_all(data_path)?;
+        let log_file = Path::new(data_path).join("raft_log.json");
+        let hard_state_file = Path::new(data_path).join("raft_hard_state.json");
+        let snapshot_file = Path::new(data_path).join("raft_snapshot.json");
+
+        let mut state = RaftState::default();
+        if hard_state_file.exists() {
+            state.hard_state = Some(serde_json::from_slice(&fs::read(&hard_state_file)?)?);
+        }
+        if snapshot_file.exists() {
+            let snapshot: MetaSnapshot = serde_json::from_slice(&fs::read(&snapshot_file)?)?;
+            state.state_machine = serde_json::from_slice(&snapshot.data)?;
+            state.snapshot = Some(snapshot);
+        }
+        if log_file.exists() {
+            state.log = Self::load_log(&log_file)?;
+        }
+        let log_writer = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&log_file)?;
+
+        Ok(Arc::new(MetaRaftStorage {
+            id,
+            log_file,
+            hard_state_file,
+            snapshot_file,
+            log_writer: Mutex::new(log_writer),
+            state: RwLock::new(state),
+        }))
+    }
+
+    /// Query the local state machine, which may be behind the leader.
+    pub fn query(&self, query: &MetaQuery) -> MetaResponse {
+        self.state.read().state_machine.meta.query(query)
+    }
+
+    pub fn last_applied_log(&self) -> u64 {
+        self.state.read().state_machine.last_applied_log
+    }
+
+    fn membership(&self, state: &RaftState) -> MembershipConfig {
+        state
+            .log
+            .values()
+            .rev()
+            .find_map(|entry| match &entry.payload {
+                EntryPayload::ConfigChange(cfg) => Some(cfg.membership.clone()),
+                EntryPayload::SnapshotPointer(snapshot) => Some(snapshot.membership.clone()),
+                _ => None,
+            })
+            .unwrap_or_else(|| MembershipConfig::new_initial(self.id))
+    }
+
+    // Replay the records of the log file. The last line is dropped if it is not complete,
+    // the write of it was interrupted and never acknowledged.
+    fn load_log(log_file: &Path) -> Result<BTreeMap<u64, Entry<MetaCommand>>> {
+        let content = fs::read(log_file)?;
+        let mut log = BTreeMap::new();
+        let mut valid = 0;
+        for line in content.split_inclusive(|c| *c == b'\n') {
+            if !line.ends_with(b"\n") {
+                break;
+            }
+            match serde_json::from_slice::<LogRecord>(line)? {
+                LogRecord::Append(entry) => {
+                    log.insert(entry.index, entry);
+                }
+                LogRecord::Delete { start, stop } => {
+                    Self::delete_logs(&mut log, start, stop);
+                }
+            }
+            valid += line.len();
+        }
+
+        if valid < content.len() {
+            log::warn!(
+                "Truncate the incomplete raft log record at {} of {}",
+                valid,
+                log_file.display()
+            );
+            OpenOptions::new()
+                .write(true)
+                .open(log_file)?
+                .set_len(valid as u64)?;
+        }
+        Ok(log)
+    }
+
+    fn delete_logs(log: &mut BTreeMap<u64, Entry<MetaCommand>>, start: u64, stop: Option<u64>) {
+        match stop {
+            Some(stop) => (start..stop).for_each(|index| {
+                log.remove(&index);
+            }),
+            None => {
+                log.split_off(&start);
+            }
+        }
+    }
+
+    // Append the records to the log file, they are synced before the log in memory is changed.
+    fn append_log(&self, records: &[LogRecord]) -> Result<()> {
+        let mut buf = vec![];
+        for record in records {
+            serde_json::to_writer(&mut buf, record)?;
+            buf.push(b'\n');
+        }
+        let mut writer = self.log_writer.lock();
+        writer.write_all(&buf)?;
+        writer.sync_data()?;
+        Ok(())
+    }
+
+    // Replace the log file with the entries, after the compaction or the snapshot installation.
+    fn rewrite_log(&self, log: &BTreeMap<u64, Entry<MetaCommand>>) -> Result<()> {
+        let mut buf = vec![];
+        for entry in log.values() {
+            serde_json::to_writer(&mut buf, &LogRecord::Append(entry.clone()))?;
+            buf.push(b'\n');
+        }
+        let mut writer = self.log_writer.lock();
+        write_file(&self.log_file, &buf)?;
+        *writer = OpenOptions::new().append(true).open(&self.log_file)?;
+        Ok(())
+    }
+
+    fn save_snapshot(&self, snapshot: &MetaSnapshot) -> Result<()> {
+        write_file(&self.snapshot_file, &serde_json::to_vec(snapshot)?)
+    }
+}
+
+// Write the file by renaming a synced temporary file, so it is never seen partially written.
+fn write_file(path: &Path, content: &[u8]) -> Result<()> {
+    let tmp_file = path.with_extension("json.tmp");
+    let mut file = File::create(&tmp_file)?;
+    file.write_all(content)?;
+    file.sync_all()?;
+    fs::rename(&tmp_file, path)?;
+    Ok(())
+}
+
+#[async_trait::async_trait]
+impl RaftStorage<MetaCommand, MetaResponse> for MetaRaftStorage {
+    type Snapshot = Cursor<Vec<u8>>;
+    type ShutdownError = ShutdownError;
+
+    async fn get_membership_config(&self) -> Result<MembershipConfig> {
+        Ok(self.membership(&self.state.read()))
+    }
+
+    async fn get_initial_state(&self) -> Result<InitialState> {
+        let mut state = self.state.write();
+        match state.hard_state.clone() {
+            Some(hard_state) => {
+                let (last_log_index, last_log_term) = match state.log.values().rev().next() {
+                    Some(entry) => (entry.index, entry.term),
+                    None => (0, 0),
+                };
+                Ok(InitialState {
+                    last_log_index,
+                    last_log_term,
+                    last_applied_log: state.state_machine.last_applied_log,
+                    hard_state,
+                    membership: self.membership(&state),
+                })
+            }
+            None => {
+                let initial = InitialState::new_initial(self.id);
+                write_file(
+                    &self.hard_state_file,
+                    &serde_json::to_vec(&initial.hard_state)?,
+                )?;
+                state.hard_state = Some(initial.hard_state.clone());
+                Ok(initial)
+            }
+        }
+    }
+
+    async fn save_hard_state(&self, hs: &HardState) -> Result<()> {
+        let mut state = self.state.write();
+        write_file(&self.hard_state_file, &serde_json::to_vec(hs)?)?;
+        state.hard_state = Some(hs.clone());
+        Ok(())
+    }
+
+    async fn get_log_entries(&self, start: u64, stop: u64) -> Result<Vec<Entry<MetaCommand>>> {
+        if start > stop {
+            return Ok(vec![]);
+        }
+        let state = self.state.read();
+        Ok(state
+            .log
+            .range(start..stop)
+            .map(|(_, v)| v.clone())
+            .collect())
+    }
+
+    async fn delete_logs_from(&self, start: u64, stop: Option<u64>) -> Result<()> {
+        if stop.map(|stop| start > stop).unwrap_or(false) {
+            return Ok(());
+        }
+        let mut state = self.state.write();
+        self.append_log(&[LogRecord::Delete { start, stop }])?;
+        Self::delete_logs(&mut state.log, start, stop);
+        Ok(())
+    }
+
+    async fn append_entry_to_log(&self, entry: &Entry<MetaCommand>) -> Result<()> {
+        let mut state = self.state.write();
+        self.append_log(&[LogRecord::Append(entry.clone())])?;
+        state.log.insert(entry.index, entry.clone());
+        Ok(())
+    }
+
+    async fn replicate_to_log(&self, entries: &[Entry<MetaCommand>]) -> Result<()> {
+        let mut state = self.state.write();
+        let records = entries
+            .iter()
+            .map(|entry| LogRecord::Append(entry.clone()))
+            .collect::<Vec<_>>();
+        self.append_log(&records)?;
+        for entry in entries {
+            state.log.insert(entry.index, entry.clone());
+        }
+        Ok(())
+    }
+
+    async fn apply_entry_to_state_machine(
+        &self,
+        index: &u64,
+        data: &MetaCommand,
+    ) -> Result<MetaResponse> {
+        let mut state = self.state.write();
+        state.state_machine.last_applied_log = *index;
+        Ok(state.state_machine.meta.apply(data))
+    }
+
+    async fn replicate_to_state_machine(&self, entries: &[(&u64, &MetaCommand)]) -> Result<()> {
+        let mut state = self.state.write();
+        for (index, data) in entries {
+            state.state_machine.last_applied_log = **index;
+            if let MetaResponse::Error(e) = state.state_machine.meta.apply(data) {
+                log::debug!("Replicated meta command {:?} is rejected: {}", data, e);
+            }
+        }
+        Ok(())
+    }
+
+    async fn do_log_compaction(&self) -> Result<CurrentSnapshotData<Self::Snapshot>> {
+        let mut state = self.state.write();
+        let index = state.state_machine.last_applied_log;
+        let term = state
+            .log
+            .get(&index)
+            .map(|entry| entry.term)
+            .ok_or_else(|| anyhow!("Store Error: Inconsistent raft log at {}", index))?;
+        let membership = state
+            .log
+            .values()
+            .rev()
+            .skip_while(|entry| entry.index > index)
+            .find_map(|entry| match &entry.payload {
+                EntryPayload::ConfigChange(cfg) => Some(cfg.membership.clone()),
+                EntryPayload::SnapshotPointer(snapshot) => Some(snapshot.membership.clone()),
+                _ => None,
+            })
+            .unwrap_or_else(|| MembershipConfig::new_initial(self.id));
+
+        let snapshot = MetaSnapshot {
+            index,
+            term,
+            membership: membership.clone(),
+            data: serde_json::to_vec(&state.state_machine)?,
+        };
+        // The snapshot is saved before the log is compacted, so the log replaced by it
+        // is never lost.
+        self.save_snapshot(&snapshot)?;
+        let mut log = state.log.split_off(&index);
+        log.insert(
+            index,
+            Entry::new_snapshot_pointer(index, term, "".to_string(), membership),
+        );
+        self.rewrite_log(&log)?;
+        state.log = log;
+        state.snapshot = Some(snapshot.clone());
+
+        Ok(CurrentSnapshotData {
+            term: snapshot.term,
+            index: snapshot.index,
+            membership: snapshot.membership.clone(),
+            snapshot: Box::new(Cursor::new(serde_json::to_vec(&snapshot)?)),
+        })
+    }
+
+    async fn create_snapshot(&self) -> Result<(String, Box<Self::Snapshot>)> {
+        Ok(("".to_string(), Box::new(Cursor::new(Vec::new()))))
+    }
+
+    async fn finalize_snapshot_installation(
+        &self,
+        index: u64,
+        term: u64,
+        delete_through: Option<u64>,
+        id: String,
+        snapshot: Box<Self::Snapshot>,
+    ) -> Result<()> {
+        let snapshot: MetaSnapshot = serde_json::from_slice(snapshot.get_ref())?;
+        let state_machine: MetaStateMachine = serde_json::from_slice(&snapshot.data)?;
+
+        let mut state = self.state.write();
+        self.save_snapshot(&snapshot)?;
+        let mut log = match delete_through {
+            Some(through) => state.log.split_off(&(through + 1)),
+            None => BTreeMap::new(),
+        };
+        log.insert(
+            index,
+            Entry::new_snapshot_pointer(index, term, id, snapshot.membership.clone()),
+        );
+        self.rewrite_log(&log)?;
+        state.log = log;
+        state.state_machine = state_machine;
+        state.snapshot = Some(snapshot);
+        Ok(())
+    }
+
+    async fn get_current_snapshot(&self) -> Result<Option<CurrentSnapshotData<Self::Snapshot>>> {
+        match self.state.read().snapshot.clone() {
+            Some(snapshot) => Ok(Some(CurrentSnapshotData {
+                term: snapshot.term,
+                index: snapshot.index,
+                membership: snapshot.membership.clone(),
+                snapshot: Box::new(Cursor::new(serde_json::to_vec(&snapshot)?)),
+            })),
+            None => Ok(None),
+        }
+    }
+}
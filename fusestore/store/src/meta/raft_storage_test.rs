@@ -0,0 +1,58 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_raft_storage() -> anyhow::Result<()> {
+    use std::io::Write;
+
+    use async_raft::raft::{Entry, EntryPayload};
+    use async_raft::RaftStorage;
+    use pretty_assertions::assert_eq;
+
+    use crate::meta::*;
+
+    let conf = crate::tests::new_test_config();
+    let data_path = conf.data_path.as_str();
+    let entry = |index| Entry {
+        term: 1,
+        index,
+        payload: EntryPayload::Blank,
+    };
+
+    let storage = MetaRaftStorage::try_create(1, data_path)?;
+    storage.get_initial_state().await?;
+    storage.append_entry_to_log(&entry(1)).await?;
+    storage
+        .replicate_to_log(&[entry(2), entry(3), entry(4)])
+        .await?;
+    storage.delete_logs_from(3, None).await?;
+    drop(storage);
+
+    // The records are replayed, the incomplete last line is dropped.
+    let log_file = std::path::Path::new(data_path).join("raft_log.json");
+    std::fs::OpenOptions::new()
+        .append(true)
+        .open(&log_file)?
+        .write_all(b"{\"Append\":")?;
+    let storage = MetaRaftStorage::try_create(1, data_path)?;
+    let state = storage.get_initial_state().await?;
+    assert_eq!(2, state.last_log_index);
+    assert_eq!(0, state.last_applied_log);
+    let indexes: Vec<u64> = storage
+        .get_log_entries(0, 10)
+        .await?
+        .iter()
+        .map(|entry| entry.index)
+        .collect();
+    assert_eq!(vec![1, 2], indexes);
+
+    // The records appended after the truncation are read back as well.
+    storage.append_entry_to_log(&entry(3)).await?;
+    drop(storage);
+    let storage = MetaRaftStorage::try_create(1, data_path)?;
+    assert_eq!(3, storage.get_initial_state().await?.last_log_index);
+
+    std::fs::remove_dir_all(data_path)?;
+    Ok(())
+}
@@ -0,0 +1,7 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+mod service;
+
+pub use service::{new_test_config, try_start_node, try_start_service};
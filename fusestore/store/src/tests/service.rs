@@ -0,0 +1,50 @@
+// Copyright 2020-2021 The Datafuse Authors.
+//
+// SPDX-License-Identifier: Apache-2.0.
+
+use anyhow::Result;
+use rand::Rng;
+use tonic::transport::Server;
+
+use crate::api::rpc::FlightService;
+use crate::api::RpcService;
+use crate::configs::Config;
+use crate::meta::MetaStoreRef;
+
+/// Create a config with a random address and a temporary data path.
+pub fn new_test_config() -> Config {
+    let mut rng = rand::thread_rng();
+    let port: u32 = rng.gen_range(11000..12000);
+
+    let mut conf = Config::default();
+    conf.rpc_api_address = format!("127.0.0.1:{}", port);
+    conf.data_path = std::env::temp_dir()
+        .join(uuid::Uuid::new_v4().to_string())
+        .to_str()
+        .unwrap()
+        .to_string();
+    conf
+}
+
+/// Start a service with a temporary data path and return the random address.
+pub async fn try_start_service() -> Result<String> {
+    let conf = new_test_config();
+    let addr = conf.rpc_api_address.clone();
+
+    let srv = RpcService::create(conf);
+    tokio::spawn(async move {
+        srv.make_server().await?;
+        Ok::<(), anyhow::Error>(())
+    });
+    tokio::time::sleep(tokio::time::Duration::from_millis(200)).await;
+    Ok(addr)
+}
+
+/// Start the flight service of a store node and return its meta store.
+pub async fn try_start_node(conf: Config) -> Result<MetaStoreRef> {
+    let addr = conf.rpc_api_address.parse::<std::net::SocketAddr>()?;
+    let srv = FlightService::try_create(conf)?;
+    let meta = srv.meta();
+    tokio::spawn(Server::builder().add_service(srv.make_server()).serve(addr));
+    Ok(meta)
+}
@@ -0,0 +1,26 @@
+#!/bin/bash
+
+# Copyright 2020-2021 The FuseQuery Authors.
+#
+# SPDX-License-Identifier: Apache-2.0.
+
+# Start a FuseStore cluster of three nodes on localhost, the meta is replicated by raft among them.
+# Stop it with Ctrl-C.
+
+SCRIPT_PATH="$( cd "$( dirname "$0" )" >/dev/null 2>&1 && pwd )"
+cd "$SCRIPT_PATH/.." || exit
+
+cargo build --bin=fuse-store || exit
+
+CANDIDATES="1=127.0.0.1:9191,2=127.0.0.1:9192,3=127.0.0.1:9193"
+for ID in 1 2 3; do
+    FUSE_STORE_ID=$ID \
+    FUSE_STORE_CANDIDATES=$CANDIDATES \
+    FUSE_QUERY_RPC_API_ADDRESS=127.0.0.1:919$ID \
+    FUSE_STORE_METRIC_API_ADDRESS=127.0.0.1:717$ID \
+    FUSE_STORE_DATA_PATH=_data/store$ID \
+    ./target/debug/fuse-store &
+done
+
+trap 'kill $(jobs -p)' EXIT
+wait
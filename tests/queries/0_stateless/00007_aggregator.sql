@@ -1 +1,2 @@
 select 1, sum(number) from numbers_mt(1000000);
+select toString(number % 3) as k, toString(number % 3) > '0', count() from numbers_mt(10) group by toString(number % 3) order by k;
@@ -0,0 +1,5 @@
+SELECT number FROM numbers_mt(10) ORDER BY number DESC LIMIT 3;
+SELECT number%3 AS k, count(number) AS c FROM numbers_mt(100) GROUP BY k ORDER BY k;
+SELECT number FROM numbers_mt(10) ORDER BY number%3, number DESC LIMIT 4;
+SELECT number + 1 AS n FROM numbers_mt(6) ORDER BY number % 3, number;
+SELECT number % 3 AS k FROM numbers_mt(11) GROUP BY k ORDER BY count(), k;
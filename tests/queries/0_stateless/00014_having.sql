@@ -0,0 +1,3 @@
+SELECT number%3 AS k, count(number) AS c FROM numbers_mt(100) GROUP BY k HAVING c > 33;
+SELECT number%3 AS k, max(number) FROM numbers_mt(100) GROUP BY k HAVING count() = 33 ORDER BY k;
+SELECT sum(number) FROM numbers_mt(10) HAVING sum(number) > 40;
@@ -0,0 +1,3 @@
+SELECT a.number, b.number FROM numbers_mt(5) AS a JOIN numbers_mt(3) AS b ON a.number = b.number ORDER BY a.number;
+SELECT a.number, b.number FROM numbers_mt(5) AS a LEFT JOIN numbers_mt(3) AS b USING (number) ORDER BY a.number;
+SELECT count() FROM numbers_mt(10) AS a JOIN numbers_mt(100) AS b ON a.number = b.number % 10;
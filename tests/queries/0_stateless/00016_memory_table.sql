@@ -0,0 +1,5 @@
+CREATE TABLE t_memory(a bigint, b int) ENGINE = Memory;
+INSERT INTO t_memory VALUES (1, 10), (2, 20);
+INSERT INTO t_memory(b, a) SELECT number, number * 100 FROM numbers_mt(3) WHERE number > 0;
+SELECT a, b FROM t_memory ORDER BY a;
+SELECT sum(a), count() FROM t_memory;
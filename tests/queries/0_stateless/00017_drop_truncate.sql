@@ -0,0 +1,8 @@
+CREATE TABLE t_truncate(a bigint) ENGINE = Memory;
+INSERT INTO t_truncate VALUES (1), (2);
+TRUNCATE TABLE t_truncate;
+SELECT count() FROM t_truncate;
+INSERT INTO t_truncate VALUES (3);
+SELECT a FROM t_truncate;
+DROP TABLE t_truncate;
+DROP TABLE IF EXISTS t_truncate;
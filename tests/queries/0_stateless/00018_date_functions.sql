@@ -0,0 +1,7 @@
+SELECT toYear(toStartOfMonth(parseDateTime('2021-03-15 10:20:00', '%Y-%m-%d %H:%M:%S')));
+SELECT formatDateTime(toStartOfInterval(parseDateTime('2021-03-15 10:20:00', '%Y-%m-%d %H:%M:%S'), 15, 'minute'), '%H:%M');
+SELECT formatDateTime(date_trunc('week', '2021-03-04'), '%Y-%m-%d');
+SELECT dateDiff('day', '2021-01-01', '2021-03-01');
+SELECT toDayOfWeek(addDays('2021-03-01', 4));
+SELECT toHour('2021-03-01 00:00:00', 'Asia/Shanghai');
+SELECT toYear(now()) > 2020;
@@ -0,0 +1,5 @@
+CREATE TABLE t_decimal(a DECIMAL(10, 2), b DECIMAL(5, 1)) ENGINE = Memory;
+INSERT INTO t_decimal VALUES (1.10, 0.3), (-2.50, 0.1), (0.05, 2.5);
+SELECT a + b, a - b, a * b, a / b, a % b FROM t_decimal;
+SELECT sum(a), avg(a), min(a), max(a) FROM t_decimal;
+SELECT sum(b) FROM t_decimal WHERE a > 0;
@@ -0,0 +1,8 @@
+CREATE TABLE t_null(a BIGINT NULL, b INT NULL, c INT NOT NULL) ENGINE = Memory;
+INSERT INTO t_null VALUES (1, NULL, 10), (NULL, 2, 20), (NULL, NULL, 30);
+SELECT a + c, a = 1, a IS NULL, b IS NOT NULL FROM t_null;
+SELECT coalesce(a, b, c), ifNull(b, 0), nullIf(c, 20) FROM t_null;
+SELECT count(a), count(), sum(b), avg(a), min(b), max(b) FROM t_null;
+SELECT count() FROM t_null WHERE a IS NULL AND b IS NULL;
+SELECT count(*), count(a) FROM t_null;
+SELECT NULL AND 0, NULL OR 1, NULL AND 1, 1 + NULL;
@@ -0,0 +1,6 @@
+SELECT length('数据库'), lower('Hello'), upper('Hello'), reverse('abc'), trim('  a b  ');
+SELECT substring('Hello, World', 8), substring('Hello, World', 1, 5), substring('Hello', -3, 2);
+SELECT concat('a', 'b', 1), replace('a-b-c', '-', '+'), position('Hello', 'l');
+SELECT startsWith('Hello', 'He'), endsWith('Hello', 'He'), splitByChar(',', 'a,b,,c');
+SELECT number, concat('n', number), length(concat('n', number)) FROM numbers_mt(3) ORDER BY number;
+SELECT upper(NULL), concat('a', NULL);
@@ -0,0 +1,5 @@
+SELECT 'Hello' LIKE 'He%', 'Hello' LIKE '%LL%', 'Hello' ILIKE '%LL%', 'Hello' NOT LIKE 'H_llo', 'Hello' NOT ILIKE 'h%';
+SELECT '100%' LIKE '100\%', '1000' LIKE '100\%', 'a_c' LIKE 'a\_c', 'abc' LIKE 'a\_c';
+SELECT number, concat('n', number) LIKE '%1' FROM numbers_mt(3) ORDER BY number;
+SELECT match('GET /index.html 200', 'GET .+ 2\d\d'), regexpExtract('user=alice;', 'user=(\w+)'), replaceRegexpAll('a-b-c', '(\w)-', '\1+');
+SELECT NULL LIKE 'a%', match(NULL, 'a');
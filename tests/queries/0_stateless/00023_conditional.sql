@@ -0,0 +1,4 @@
+SELECT if(1 > 2, 'a', 'b'), multiIf(1 > 2, 1, 2 > 1, 2, 3), if(NULL, 1, 2);
+SELECT number, CASE WHEN number % 3 = 0 THEN 'fizz' WHEN number % 3 = 1 THEN 'one' ELSE 'other' END FROM numbers_mt(4) ORDER BY number;
+SELECT number, CASE number WHEN 1 THEN 10 WHEN 2 THEN 20.5 END FROM numbers_mt(3) ORDER BY number;
+SELECT sum(if(number > 5, 1, 0)) FROM numbers_mt(10);
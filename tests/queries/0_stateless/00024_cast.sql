@@ -0,0 +1,4 @@
+SELECT CAST(1 + 1 AS VARCHAR), CAST('12' AS BIGINT) + 1, CAST('2021-04-01' AS DATE), '3.5'::DOUBLE;
+SELECT toInt64('42'), toFloat64(1), toString(2), toDate('2021-04-01'), toDateTime('2021-04-01 10:20:30');
+SELECT toInt64OrNull('x'), toInt64OrNull('7'), toDateOrNull('2021-13-01');
+SELECT number, toString(number) FROM numbers_mt(3) WHERE CAST(number AS INT) > 0 ORDER BY number;
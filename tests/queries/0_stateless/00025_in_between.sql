@@ -0,0 +1,6 @@
+SELECT number FROM numbers_mt(10) WHERE number IN (1, 3, 5) ORDER BY number;
+SELECT number FROM numbers_mt(10) WHERE number NOT IN (0, 1, 2, 3, 4, 5, 6, 7) ORDER BY number;
+SELECT number FROM numbers_mt(10) WHERE number BETWEEN 2 AND 4 ORDER BY number;
+SELECT number FROM numbers_mt(5) WHERE number NOT BETWEEN 1 AND 3 ORDER BY number;
+SELECT number, -number FROM numbers_mt(3) WHERE NOT (number = 1) ORDER BY number;
+SELECT -5 + 2, 2 IN (1, 2), NOT (1 = 2);
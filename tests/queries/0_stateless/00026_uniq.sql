@@ -0,0 +1,3 @@
+SELECT count(DISTINCT number % 3), uniqExact(number % 3), uniq(number % 3) FROM numbers_mt(100);
+SELECT number % 2 AS k, count(DISTINCT number % 5) FROM numbers_mt(100) GROUP BY k ORDER BY k;
+SELECT uniqExact(number % 7, number % 3) FROM numbers_mt(100);